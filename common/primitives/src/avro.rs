@@ -0,0 +1,1284 @@
+extern crate alloc;
+use alloc::{
+	boxed::Box,
+	collections::{BTreeMap, BTreeSet},
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode};
+use scale_info::TypeInfo;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Maximum depth of nested types in an Avro schema
+/// Bounds the recursion of both parsing and payload decoding
+pub const AVRO_MAX_NESTING_DEPTH: u32 = 32;
+
+/// Maximum number of array and map items, across all blocks and nesting levels, in a datum
+pub const AVRO_MAX_DATUM_ITEMS: usize = 16_384;
+
+/// Maximum number of values, including every record field and array or map item, decoded from
+/// a datum. Values of zero-byte types such as `null` or empty records take no datum bytes, so
+/// this bounds the work of validating a datum independently of its size.
+pub const AVRO_MAX_DATUM_STEPS: usize = 65_536;
+
+/// Primitive type names have no namespace and can not be used as the name of a named type
+const PRIMITIVE_TYPE_NAMES: [&str; 8] =
+	["null", "boolean", "int", "long", "float", "double", "bytes", "string"];
+
+/// A parsed Avro schema
+///
+/// Logical types are annotations on their underlying type and are not represented: the
+/// specification requires invalid logical types to be ignored, and they do not change the
+/// binary encoding.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AvroSchema {
+	/// `null`
+	Null,
+	/// `boolean`
+	Boolean,
+	/// `int`, 32 bit signed integer
+	Int,
+	/// `long`, 64 bit signed integer
+	Long,
+	/// `float`
+	Float,
+	/// `double`
+	Double,
+	/// `bytes`
+	Bytes,
+	/// `string`
+	String,
+	/// Array of the given items
+	Array(Box<AvroSchema>),
+	/// Map from strings to the given values
+	Map(Box<AvroSchema>),
+	/// Union of the given branches
+	Union(Vec<AvroSchema>),
+	/// A record, enum or fixed type, as an index into [`AvroSchemaDefinition::named_types`]
+	Named(usize),
+}
+
+/// A type that is defined once by its full name and can be referenced afterwards
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AvroNamedType {
+	/// A record with an ordered list of fields
+	Record {
+		/// Full name of the record
+		name: String,
+		/// Fields in encoding order
+		fields: Vec<AvroField>,
+	},
+	/// An enumeration of symbols
+	Enum {
+		/// Full name of the enum
+		name: String,
+		/// Symbols in encoding order
+		symbols: Vec<String>,
+		/// Whether the enum declares a default symbol, used when reading unknown symbols
+		has_default: bool,
+	},
+	/// A fixed number of bytes
+	Fixed {
+		/// Full name of the fixed type
+		name: String,
+		/// Number of bytes
+		size: usize,
+	},
+}
+
+impl AvroNamedType {
+	/// The full name of the type
+	pub fn name(&self) -> &str {
+		match self {
+			AvroNamedType::Record { name, .. } |
+			AvroNamedType::Enum { name, .. } |
+			AvroNamedType::Fixed { name, .. } => name,
+		}
+	}
+}
+
+/// A field of an Avro record
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroField {
+	/// Name of the field
+	pub name: String,
+	/// Type of the field
+	pub schema: AvroSchema,
+	/// Whether the field declares a default value
+	pub has_default: bool,
+}
+
+/// A parsed Avro schema along with the named types it defines
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AvroSchemaDefinition {
+	/// The top level schema
+	pub root: AvroSchema,
+	/// Named types in order of definition, referenced by [`AvroSchema::Named`]
+	pub named_types: Vec<AvroNamedType>,
+}
+
+impl AvroSchemaDefinition {
+	/// Look up a named type referenced by [`AvroSchema::Named`]
+	pub fn named_type(&self, index: usize) -> Option<&AvroNamedType> {
+		self.named_types.get(index)
+	}
+}
+
+/// Error enum for Avro schema parsing, one variant per class of failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvroError {
+	/// The model is not valid JSON
+	InvalidJson,
+	/// A schema is not a JSON string, object or array, or its `type` attribute is not valid
+	InvalidType,
+	/// A type name does not refer to a primitive or a previously defined named type
+	UnknownType,
+	/// A required attribute (`type`, `name`, `fields`, `symbols`, `items`, `values` or `size`) is missing
+	MissingAttribute,
+	/// An attribute has the wrong JSON type or an unsupported value
+	InvalidAttribute,
+	/// A name, namespace or alias does not follow the Avro naming rules
+	InvalidName,
+	/// A named type is defined more than once
+	DuplicateName,
+	/// A record contains two fields with the same name
+	DuplicateFieldName,
+	/// Enum symbols are not unique valid names
+	InvalidEnumSymbols,
+	/// Fixed size is not a non-negative integer
+	InvalidFixedSize,
+	/// A union is empty, contains a nested union or contains the same type more than once
+	InvalidUnion,
+	/// A default value does not match the type it is declared for
+	InvalidDefault,
+	/// Types are nested deeper than [`AVRO_MAX_NESTING_DEPTH`]
+	NestingTooDeep,
+}
+
+/// Parses and validates an Avro schema according to the Avro specification.
+pub fn parse_avro_schema(model: &[u8]) -> Result<AvroSchemaDefinition, AvroError> {
+	let value: Value = serde_json::from_slice(model).map_err(|_| AvroError::InvalidJson)?;
+	let mut parser = Parser::default();
+	let root = parser.parse(&value, "", 0)?;
+	let named_types = parser
+		.named_types
+		.into_iter()
+		.collect::<Option<Vec<AvroNamedType>>>()
+		.ok_or(AvroError::UnknownType)?;
+	Ok(AvroSchemaDefinition { root, named_types })
+}
+
+/// Reason a reader schema can not read data written with a writer schema, following the
+/// schema resolution rules of the Avro specification
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AvroIncompatibility {
+	/// The writer type can not be resolved to the reader type, even with type promotion
+	TypeMismatch,
+	/// Named types being resolved against each other have different unqualified names
+	NameMismatch,
+	/// A reader record field is absent from the writer record and has no default value
+	MissingFieldDefault,
+	/// A writer enum symbol is absent from the reader enum, which has no default symbol
+	MissingEnumSymbol,
+	/// Fixed types being resolved against each other have different sizes
+	FixedSizeMismatch,
+	/// A writer type matches none of the branches of the reader union
+	UnionBranchMismatch,
+}
+
+/// Checks that data written with `writer` can be read with `reader`.
+pub fn check_avro_compatibility(
+	reader: &AvroSchemaDefinition,
+	writer: &AvroSchemaDefinition,
+) -> Result<(), AvroIncompatibility> {
+	Resolver { reader, writer, visited: BTreeSet::new() }.resolve(&reader.root, &writer.root)
+}
+
+/// Error enum for decoding an Avro binary encoded datum, one variant per class of failure
+#[derive(
+	Debug,
+	Clone,
+	Copy,
+	PartialEq,
+	Eq,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	Serialize,
+	Deserialize,
+)]
+pub enum AvroDecodeError {
+	/// The datum ends before a value is complete
+	UnexpectedEnd,
+	/// An `int` or `long` is not a valid zig-zag variable-length encoding of its size
+	InvalidVarint,
+	/// A `boolean` byte is neither 0 nor 1
+	InvalidBoolean,
+	/// A `string` or map key is not valid UTF-8
+	InvalidUtf8,
+	/// A length or block count is negative or larger than the rest of the datum
+	InvalidLength,
+	/// A union branch index is out of range
+	InvalidUnionIndex,
+	/// An enum symbol index is out of range
+	InvalidEnumIndex,
+	/// Values are nested deeper than [`AVRO_MAX_NESTING_DEPTH`]
+	NestingTooDeep,
+	/// Bytes remain after the datum has been decoded
+	TrailingBytes,
+	/// The datum has more than [`AVRO_MAX_DATUM_ITEMS`] array and map items
+	TooManyItems,
+	/// The datum has more than [`AVRO_MAX_DATUM_STEPS`] values
+	TooManySteps,
+}
+
+/// Checks that `datum` is exactly one value in the Avro binary encoding of `definition`.
+/// A block of array or map items may not count more items than there are bytes left in the
+/// datum. As items of zero-byte types take no bytes, the work done is bounded by
+/// [`AVRO_MAX_DATUM_ITEMS`] and [`AVRO_MAX_DATUM_STEPS`] rather than by the size of the datum.
+pub fn validate_avro_datum(
+	definition: &AvroSchemaDefinition,
+	datum: &[u8],
+) -> Result<(), AvroDecodeError> {
+	let mut decoder = Decoder { definition, datum, position: 0, items: 0, steps: 0 };
+	decoder.skip(&definition.root, 0)?;
+	if decoder.position != datum.len() {
+		return Err(AvroDecodeError::TrailingBytes)
+	}
+	Ok(())
+}
+
+/// Key used to detect duplicate union branches
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum UnionBranch {
+	Unnamed(&'static str),
+	Named(usize),
+}
+
+#[derive(Default)]
+struct Parser {
+	/// Named types in order of definition, `None` while a record's fields are being parsed
+	named_types: Vec<Option<AvroNamedType>>,
+	/// Full name to index into `named_types`
+	names: BTreeMap<String, usize>,
+}
+
+impl Parser {
+	fn parse(
+		&mut self,
+		value: &Value,
+		namespace: &str,
+		depth: u32,
+	) -> Result<AvroSchema, AvroError> {
+		if depth > AVRO_MAX_NESTING_DEPTH {
+			return Err(AvroError::NestingTooDeep);
+		}
+		match value {
+			Value::String(name) => self.resolve(name, namespace),
+			Value::Array(branches) => self.parse_union(branches, namespace, depth),
+			Value::Object(object) => self.parse_object(object, namespace, depth),
+			_ => Err(AvroError::InvalidType),
+		}
+	}
+
+	fn resolve(&self, name: &str, namespace: &str) -> Result<AvroSchema, AvroError> {
+		let primitive = match name {
+			"null" => AvroSchema::Null,
+			"boolean" => AvroSchema::Boolean,
+			"int" => AvroSchema::Int,
+			"long" => AvroSchema::Long,
+			"float" => AvroSchema::Float,
+			"double" => AvroSchema::Double,
+			"bytes" => AvroSchema::Bytes,
+			"string" => AvroSchema::String,
+			_ => {
+				validate_full_name(name)?;
+				// names without a dot are looked up in the enclosing namespace first
+				return self
+					.names
+					.get(&full_name(name, namespace))
+					.or_else(|| self.names.get(name))
+					.map(|index| AvroSchema::Named(*index))
+					.ok_or(AvroError::UnknownType);
+			},
+		};
+		Ok(primitive)
+	}
+
+	fn parse_union(
+		&mut self,
+		branches: &[Value],
+		namespace: &str,
+		depth: u32,
+	) -> Result<AvroSchema, AvroError> {
+		if branches.is_empty() {
+			return Err(AvroError::InvalidUnion);
+		}
+		let mut seen = BTreeSet::new();
+		let mut parsed = Vec::with_capacity(branches.len());
+		for branch in branches {
+			let schema = self.parse(branch, namespace, depth + 1)?;
+			let key = match &schema {
+				AvroSchema::Union(_) => return Err(AvroError::InvalidUnion),
+				AvroSchema::Named(index) => UnionBranch::Named(*index),
+				AvroSchema::Null => UnionBranch::Unnamed("null"),
+				AvroSchema::Boolean => UnionBranch::Unnamed("boolean"),
+				AvroSchema::Int => UnionBranch::Unnamed("int"),
+				AvroSchema::Long => UnionBranch::Unnamed("long"),
+				AvroSchema::Float => UnionBranch::Unnamed("float"),
+				AvroSchema::Double => UnionBranch::Unnamed("double"),
+				AvroSchema::Bytes => UnionBranch::Unnamed("bytes"),
+				AvroSchema::String => UnionBranch::Unnamed("string"),
+				AvroSchema::Array(_) => UnionBranch::Unnamed("array"),
+				AvroSchema::Map(_) => UnionBranch::Unnamed("map"),
+			};
+			if !seen.insert(key) {
+				return Err(AvroError::InvalidUnion);
+			}
+			parsed.push(schema);
+		}
+		Ok(AvroSchema::Union(parsed))
+	}
+
+	fn parse_object(
+		&mut self,
+		object: &Map<String, Value>,
+		namespace: &str,
+		depth: u32,
+	) -> Result<AvroSchema, AvroError> {
+		match object.get("type").ok_or(AvroError::MissingAttribute)? {
+			Value::String(type_name) => match type_name.as_str() {
+				"record" | "error" => self.parse_record(object, namespace, depth),
+				"enum" => self.parse_enum(object, namespace),
+				"fixed" => self.parse_fixed(object, namespace),
+				"array" => {
+					let items = object.get("items").ok_or(AvroError::MissingAttribute)?;
+					Ok(AvroSchema::Array(Box::new(self.parse(items, namespace, depth + 1)?)))
+				},
+				"map" => {
+					let values = object.get("values").ok_or(AvroError::MissingAttribute)?;
+					Ok(AvroSchema::Map(Box::new(self.parse(values, namespace, depth + 1)?)))
+				},
+				_ => self.resolve(type_name, namespace),
+			},
+			// `{"type": {...}}` and `{"type": [...]}` wrap another schema
+			inner => self.parse(inner, namespace, depth + 1),
+		}
+	}
+
+	/// Registers a named type before its body is parsed so that it can refer to itself.
+	/// Returns the index of the type, its full name and its namespace.
+	fn define(
+		&mut self,
+		object: &Map<String, Value>,
+		namespace: &str,
+	) -> Result<(usize, String, String), AvroError> {
+		let name = object
+			.get("name")
+			.ok_or(AvroError::MissingAttribute)?
+			.as_str()
+			.ok_or(AvroError::InvalidAttribute)?;
+		let namespace = match object.get("namespace") {
+			None | Some(Value::Null) => namespace,
+			Some(Value::String(namespace)) => {
+				if !namespace.is_empty() {
+					validate_full_name(namespace)?;
+				}
+				namespace.as_str()
+			},
+			Some(_) => return Err(AvroError::InvalidAttribute),
+		};
+		validate_full_name(name)?;
+		let full_name = full_name(name, namespace);
+		let (type_namespace, simple_name) = match full_name.rsplit_once('.') {
+			Some((type_namespace, simple_name)) => (type_namespace.to_string(), simple_name),
+			None => (String::new(), full_name.as_str()),
+		};
+		if PRIMITIVE_TYPE_NAMES.contains(&simple_name) {
+			return Err(AvroError::InvalidName);
+		}
+		validate_aliases(object)?;
+		if self.names.contains_key(&full_name) {
+			return Err(AvroError::DuplicateName);
+		}
+		let index = self.named_types.len();
+		self.named_types.push(None);
+		self.names.insert(full_name.clone(), index);
+		Ok((index, full_name, type_namespace))
+	}
+
+	fn parse_record(
+		&mut self,
+		object: &Map<String, Value>,
+		namespace: &str,
+		depth: u32,
+	) -> Result<AvroSchema, AvroError> {
+		let (index, name, namespace) = self.define(object, namespace)?;
+		let field_values = object
+			.get("fields")
+			.ok_or(AvroError::MissingAttribute)?
+			.as_array()
+			.ok_or(AvroError::InvalidAttribute)?;
+		let mut field_names = BTreeSet::new();
+		let mut fields = Vec::with_capacity(field_values.len());
+		for field in field_values {
+			let field = field.as_object().ok_or(AvroError::InvalidAttribute)?;
+			let field_name = field
+				.get("name")
+				.ok_or(AvroError::MissingAttribute)?
+				.as_str()
+				.ok_or(AvroError::InvalidAttribute)?;
+			validate_name(field_name)?;
+			if !field_names.insert(field_name) {
+				return Err(AvroError::DuplicateFieldName);
+			}
+			let field_type = field.get("type").ok_or(AvroError::MissingAttribute)?;
+			let schema = self.parse(field_type, &namespace, depth + 1)?;
+			match field.get("order").map(|order| order.as_str()) {
+				None | Some(Some("ascending" | "descending" | "ignore")) => {},
+				Some(_) => return Err(AvroError::InvalidAttribute),
+			}
+			validate_aliases(field)?;
+			let default = field.get("default");
+			if let Some(default) = default {
+				if !self.is_valid_default(&schema, default) {
+					return Err(AvroError::InvalidDefault);
+				}
+			}
+			fields.push(AvroField {
+				name: field_name.to_string(),
+				schema,
+				has_default: default.is_some(),
+			});
+		}
+		self.named_types[index] = Some(AvroNamedType::Record { name, fields });
+		Ok(AvroSchema::Named(index))
+	}
+
+	fn parse_enum(
+		&mut self,
+		object: &Map<String, Value>,
+		namespace: &str,
+	) -> Result<AvroSchema, AvroError> {
+		let (index, name, _) = self.define(object, namespace)?;
+		let symbol_values = object
+			.get("symbols")
+			.ok_or(AvroError::MissingAttribute)?
+			.as_array()
+			.ok_or(AvroError::InvalidAttribute)?;
+		let mut seen = BTreeSet::new();
+		let mut symbols = Vec::with_capacity(symbol_values.len());
+		for symbol in symbol_values {
+			let symbol = symbol.as_str().ok_or(AvroError::InvalidEnumSymbols)?;
+			if validate_name(symbol).is_err() || !seen.insert(symbol) {
+				return Err(AvroError::InvalidEnumSymbols);
+			}
+			symbols.push(symbol.to_string());
+		}
+		let default = object.get("default");
+		if let Some(default) = default {
+			if !default.as_str().is_some_and(|default| seen.contains(default)) {
+				return Err(AvroError::InvalidDefault);
+			}
+		}
+		self.named_types[index] =
+			Some(AvroNamedType::Enum { name, symbols, has_default: default.is_some() });
+		Ok(AvroSchema::Named(index))
+	}
+
+	fn parse_fixed(
+		&mut self,
+		object: &Map<String, Value>,
+		namespace: &str,
+	) -> Result<AvroSchema, AvroError> {
+		let (index, name, _) = self.define(object, namespace)?;
+		let size = object
+			.get("size")
+			.ok_or(AvroError::MissingAttribute)?
+			.as_u64()
+			.and_then(|size| usize::try_from(size).ok())
+			.ok_or(AvroError::InvalidFixedSize)?;
+		self.named_types[index] = Some(AvroNamedType::Fixed { name, size });
+		Ok(AvroSchema::Named(index))
+	}
+
+	fn is_valid_default(&self, schema: &AvroSchema, value: &Value) -> bool {
+		match schema {
+			AvroSchema::Null => value.is_null(),
+			AvroSchema::Boolean => value.is_boolean(),
+			AvroSchema::Int => value.as_i64().is_some_and(|int| i32::try_from(int).is_ok()),
+			AvroSchema::Long => value.is_i64(),
+			AvroSchema::Float | AvroSchema::Double => value.is_number(),
+			AvroSchema::Bytes => value.as_str().is_some_and(is_byte_string),
+			AvroSchema::String => value.is_string(),
+			AvroSchema::Array(items) => value
+				.as_array()
+				.is_some_and(|array| array.iter().all(|item| self.is_valid_default(items, item))),
+			AvroSchema::Map(values) => value.as_object().is_some_and(|object| {
+				object.values().all(|item| self.is_valid_default(values, item))
+			}),
+			// a union default must match the first branch of the union
+			AvroSchema::Union(branches) =>
+				branches.first().is_some_and(|branch| self.is_valid_default(branch, value)),
+			AvroSchema::Named(index) => match self.named_types.get(*index) {
+				Some(Some(AvroNamedType::Record { fields, .. })) =>
+					value.as_object().is_some_and(|object| {
+						fields.iter().all(|field| match object.get(&field.name) {
+							Some(item) => self.is_valid_default(&field.schema, item),
+							None => field.has_default,
+						})
+					}),
+				Some(Some(AvroNamedType::Enum { symbols, .. })) => value
+					.as_str()
+					.is_some_and(|symbol| symbols.iter().any(|candidate| candidate == symbol)),
+				Some(Some(AvroNamedType::Fixed { size, .. })) => value
+					.as_str()
+					.is_some_and(|bytes| is_byte_string(bytes) && bytes.chars().count() == *size),
+				// the record is still being defined so its fields are not known yet
+				_ => value.is_object(),
+			},
+		}
+	}
+}
+
+/// Bytes and fixed defaults are strings whose code points are the byte values 0-255
+fn is_byte_string(value: &str) -> bool {
+	value.chars().all(|c| (c as u32) <= 0xFF)
+}
+
+fn full_name(name: &str, namespace: &str) -> String {
+	if name.contains('.') || namespace.is_empty() {
+		name.to_string()
+	} else {
+		format!("{namespace}.{name}")
+	}
+}
+
+/// Names must start with `[A-Za-z_]` and subsequently contain only `[A-Za-z0-9_]`
+fn validate_name(name: &str) -> Result<(), AvroError> {
+	let mut chars = name.chars();
+	let valid_start = chars.next().is_some_and(|c| c.is_ascii_alphabetic() || c == '_');
+	if valid_start && chars.all(|c| c.is_ascii_alphanumeric() || c == '_') {
+		Ok(())
+	} else {
+		Err(AvroError::InvalidName)
+	}
+}
+
+/// Full names and namespaces are a dot separated sequence of names
+fn validate_full_name(name: &str) -> Result<(), AvroError> {
+	name.split('.').try_for_each(validate_name)
+}
+
+fn validate_aliases(object: &Map<String, Value>) -> Result<(), AvroError> {
+	match object.get("aliases") {
+		None => Ok(()),
+		Some(Value::Array(aliases)) => aliases.iter().try_for_each(|alias| {
+			validate_full_name(alias.as_str().ok_or(AvroError::InvalidName)?)
+		}),
+		Some(_) => Err(AvroError::InvalidAttribute),
+	}
+}
+
+struct Decoder<'a> {
+	definition: &'a AvroSchemaDefinition,
+	datum: &'a [u8],
+	position: usize,
+	/// Array and map items counted so far
+	items: usize,
+	/// Values decoded so far
+	steps: usize,
+}
+
+impl<'a> Decoder<'a> {
+	fn remaining(&self) -> usize {
+		self.datum.len().saturating_sub(self.position)
+	}
+
+	fn take(&mut self, len: usize) -> Result<&'a [u8], AvroDecodeError> {
+		let datum = self.datum;
+		let bytes = datum
+			.get(self.position..self.position.saturating_add(len))
+			.ok_or(AvroDecodeError::UnexpectedEnd)?;
+		self.position += len;
+		Ok(bytes)
+	}
+
+	/// Reads a zig-zag variable-length integer of at most `bits` bits
+	fn read_varint(&mut self, bits: u32) -> Result<i64, AvroDecodeError> {
+		let mut value = 0u64;
+		let mut shift = 0u32;
+		loop {
+			let byte = *self.take(1)?.first().ok_or(AvroDecodeError::UnexpectedEnd)?;
+			let payload = u64::from(byte & 0x7f);
+			if shift >= bits || (shift > 0 && payload >> (bits - shift) != 0) {
+				return Err(AvroDecodeError::InvalidVarint)
+			}
+			value |= payload << shift;
+			if byte & 0x80 == 0 {
+				break
+			}
+			shift += 7;
+		}
+		Ok(((value >> 1) as i64) ^ -((value & 1) as i64))
+	}
+
+	/// Reads a non-negative length that fits in the rest of the datum
+	fn read_length(&mut self) -> Result<usize, AvroDecodeError> {
+		let length = self.read_varint(64)?;
+		usize::try_from(length)
+			.ok()
+			.filter(|length| *length <= self.remaining())
+			.ok_or(AvroDecodeError::InvalidLength)
+	}
+
+	/// Reads the item count of the next block of an array or map, skipping the block size.
+	/// The count is added to the items of the whole datum.
+	fn read_block_count(&mut self) -> Result<usize, AvroDecodeError> {
+		let count = self.read_varint(64)?;
+		if count < 0 {
+			self.read_length()?;
+		}
+		let count = usize::try_from(count.unsigned_abs())
+			.ok()
+			.filter(|count| *count <= self.remaining())
+			.ok_or(AvroDecodeError::InvalidLength)?;
+		self.items = self.items.saturating_add(count);
+		if self.items > AVRO_MAX_DATUM_ITEMS {
+			return Err(AvroDecodeError::TooManyItems)
+		}
+		Ok(count)
+	}
+
+	fn skip(&mut self, schema: &AvroSchema, depth: u32) -> Result<(), AvroDecodeError> {
+		if depth > AVRO_MAX_NESTING_DEPTH {
+			return Err(AvroDecodeError::NestingTooDeep)
+		}
+		self.steps += 1;
+		if self.steps > AVRO_MAX_DATUM_STEPS {
+			return Err(AvroDecodeError::TooManySteps)
+		}
+		match schema {
+			AvroSchema::Null => {},
+			AvroSchema::Boolean => match self.take(1)? {
+				[0] | [1] => {},
+				_ => return Err(AvroDecodeError::InvalidBoolean),
+			},
+			AvroSchema::Int => {
+				self.read_varint(32)?;
+			},
+			AvroSchema::Long => {
+				self.read_varint(64)?;
+			},
+			AvroSchema::Float => {
+				self.take(4)?;
+			},
+			AvroSchema::Double => {
+				self.take(8)?;
+			},
+			AvroSchema::Bytes => {
+				let length = self.read_length()?;
+				self.take(length)?;
+			},
+			AvroSchema::String => self.skip_string()?,
+			AvroSchema::Array(items) => loop {
+				let count = self.read_block_count()?;
+				if count == 0 {
+					break
+				}
+				for _ in 0..count {
+					self.skip(items, depth + 1)?;
+				}
+			},
+			AvroSchema::Map(values) => loop {
+				let count = self.read_block_count()?;
+				if count == 0 {
+					break
+				}
+				for _ in 0..count {
+					self.skip_string()?;
+					self.skip(values, depth + 1)?;
+				}
+			},
+			AvroSchema::Union(branches) => {
+				let index = self.read_varint(64)?;
+				let branch = usize::try_from(index)
+					.ok()
+					.and_then(|index| branches.get(index))
+					.ok_or(AvroDecodeError::InvalidUnionIndex)?;
+				self.skip(branch, depth + 1)?;
+			},
+			AvroSchema::Named(index) => {
+				let definition = self.definition;
+				match definition.named_type(*index) {
+					Some(AvroNamedType::Record { fields, .. }) =>
+						for field in fields {
+							self.skip(&field.schema, depth + 1)?;
+						},
+					Some(AvroNamedType::Enum { symbols, .. }) => {
+						let index = self.read_varint(32)?;
+						if !usize::try_from(index).is_ok_and(|index| index < symbols.len()) {
+							return Err(AvroDecodeError::InvalidEnumIndex)
+						}
+					},
+					Some(AvroNamedType::Fixed { size, .. }) => {
+						self.take(*size)?;
+					},
+					// Definitions built by `parse_avro_schema` only reference defined types
+					None => {},
+				}
+			},
+		}
+		Ok(())
+	}
+
+	fn skip_string(&mut self) -> Result<(), AvroDecodeError> {
+		let length = self.read_length()?;
+		core::str::from_utf8(self.take(length)?).map_err(|_| AvroDecodeError::InvalidUtf8)?;
+		Ok(())
+	}
+}
+
+struct Resolver<'a> {
+	reader: &'a AvroSchemaDefinition,
+	writer: &'a AvroSchemaDefinition,
+	/// Pairs of named types already being resolved, which ends the recursion of recursive types
+	visited: BTreeSet<(usize, usize)>,
+}
+
+impl Resolver<'_> {
+	fn resolve(
+		&mut self,
+		reader: &AvroSchema,
+		writer: &AvroSchema,
+	) -> Result<(), AvroIncompatibility> {
+		match (reader, writer) {
+			// Every branch the writer may have selected must be readable
+			(_, AvroSchema::Union(branches)) =>
+				branches.iter().try_for_each(|branch| self.resolve(reader, branch)),
+			// The first matching reader branch is used
+			(AvroSchema::Union(branches), _) => {
+				let branch = branches
+					.iter()
+					.find(|branch| self.matches(branch, writer))
+					.ok_or(AvroIncompatibility::UnionBranchMismatch)?;
+				self.resolve(branch, writer)
+			},
+			(AvroSchema::Array(reader_items), AvroSchema::Array(writer_items)) =>
+				self.resolve(reader_items, writer_items),
+			(AvroSchema::Map(reader_values), AvroSchema::Map(writer_values)) =>
+				self.resolve(reader_values, writer_values),
+			(AvroSchema::Named(reader_index), AvroSchema::Named(writer_index)) =>
+				self.resolve_named(*reader_index, *writer_index),
+			_ if self.matches(reader, writer) => Ok(()),
+			_ => Err(AvroIncompatibility::TypeMismatch),
+		}
+	}
+
+	fn resolve_named(
+		&mut self,
+		reader_index: usize,
+		writer_index: usize,
+	) -> Result<(), AvroIncompatibility> {
+		if !self.visited.insert((reader_index, writer_index)) {
+			return Ok(())
+		}
+		let (Some(reader), Some(writer)) =
+			(self.reader.named_type(reader_index), self.writer.named_type(writer_index))
+		else {
+			return Err(AvroIncompatibility::TypeMismatch)
+		};
+		match (reader, writer) {
+			(
+				AvroNamedType::Record { fields: reader_fields, .. },
+				AvroNamedType::Record { fields: writer_fields, .. },
+			) => {
+				ensure_same_name(reader, writer)?;
+				for reader_field in reader_fields {
+					match writer_fields.iter().find(|field| field.name == reader_field.name) {
+						Some(writer_field) =>
+							self.resolve(&reader_field.schema, &writer_field.schema)?,
+						None if reader_field.has_default => (),
+						None => return Err(AvroIncompatibility::MissingFieldDefault),
+					}
+				}
+				Ok(())
+			},
+			(
+				AvroNamedType::Enum { symbols: reader_symbols, has_default, .. },
+				AvroNamedType::Enum { symbols: writer_symbols, .. },
+			) => {
+				ensure_same_name(reader, writer)?;
+				if !has_default &&
+					writer_symbols.iter().any(|symbol| !reader_symbols.contains(symbol))
+				{
+					return Err(AvroIncompatibility::MissingEnumSymbol)
+				}
+				Ok(())
+			},
+			(
+				AvroNamedType::Fixed { size: reader_size, .. },
+				AvroNamedType::Fixed { size: writer_size, .. },
+			) => {
+				ensure_same_name(reader, writer)?;
+				if reader_size != writer_size {
+					return Err(AvroIncompatibility::FixedSizeMismatch)
+				}
+				Ok(())
+			},
+			_ => Err(AvroIncompatibility::TypeMismatch),
+		}
+	}
+
+	/// Whether a writer type selects a reader union branch, without resolving nested types
+	fn matches(&self, reader: &AvroSchema, writer: &AvroSchema) -> bool {
+		use AvroSchema::*;
+		match (reader, writer) {
+			(Null, Null) |
+			(Boolean, Boolean) |
+			(Int, Int) |
+			(Long, Int | Long) |
+			(Float, Int | Long | Float) |
+			(Double, Int | Long | Float | Double) |
+			(Bytes, Bytes | String) |
+			(String, String | Bytes) |
+			(Array(_), Array(_)) |
+			(Map(_), Map(_)) => true,
+			(Named(reader_index), Named(writer_index)) =>
+				match (self.reader.named_type(*reader_index), self.writer.named_type(*writer_index))
+				{
+					(Some(reader), Some(writer)) =>
+						core::mem::discriminant(reader) == core::mem::discriminant(writer) &&
+							ensure_same_name(reader, writer).is_ok(),
+					_ => false,
+				},
+			_ => false,
+		}
+	}
+}
+
+/// Named types are resolved by their unqualified names
+fn ensure_same_name(
+	reader: &AvroNamedType,
+	writer: &AvroNamedType,
+) -> Result<(), AvroIncompatibility> {
+	if unqualified_name(reader.name()) != unqualified_name(writer.name()) {
+		return Err(AvroIncompatibility::NameMismatch)
+	}
+	Ok(())
+}
+
+fn unqualified_name(name: &str) -> &str {
+	name.rsplit('.').next().unwrap_or(name)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(model: &str) -> Result<AvroSchemaDefinition, AvroError> {
+		parse_avro_schema(model.as_bytes())
+	}
+
+	#[test]
+	fn parse_avro_schema_accepts_primitives_and_unions() {
+		assert_eq!(parse(r#""string""#).map(|d| d.root), Ok(AvroSchema::String));
+		assert_eq!(parse(r#"{"type": "long"}"#).map(|d| d.root), Ok(AvroSchema::Long));
+		assert_eq!(
+			parse(r#"{"type": "int", "logicalType": "date"}"#).map(|d| d.root),
+			Ok(AvroSchema::Int)
+		);
+		assert_eq!(
+			parse(r#"["null", "string", {"type": "array", "items": "long"}]"#).map(|d| d.root),
+			Ok(AvroSchema::Union(vec![
+				AvroSchema::Null,
+				AvroSchema::String,
+				AvroSchema::Array(Box::new(AvroSchema::Long))
+			]))
+		);
+	}
+
+	#[test]
+	fn parse_avro_schema_resolves_named_types() {
+		let definition = parse(
+			r#"{"type":"record","name":"Node","namespace":"org.test","fields":[
+				{"name":"id","type":{"type":"fixed","name":"Id","size":8}},
+				{"name":"kind","type":{"type":"enum","name":"Kind","symbols":["A","B"],"default":"A"}},
+				{"name":"parent","type":["null","Node"],"default":null},
+				{"name":"other","type":"org.test.Id"}
+			]}"#,
+		)
+		.expect("should parse");
+
+		assert_eq!(definition.root, AvroSchema::Named(0));
+		assert_eq!(
+			definition.named_types,
+			vec![
+				AvroNamedType::Record {
+					name: "org.test.Node".to_string(),
+					fields: vec![
+						AvroField {
+							name: "id".to_string(),
+							schema: AvroSchema::Named(1),
+							has_default: false
+						},
+						AvroField {
+							name: "kind".to_string(),
+							schema: AvroSchema::Named(2),
+							has_default: false
+						},
+						AvroField {
+							name: "parent".to_string(),
+							schema: AvroSchema::Union(vec![AvroSchema::Null, AvroSchema::Named(0)]),
+							has_default: true
+						},
+						AvroField {
+							name: "other".to_string(),
+							schema: AvroSchema::Named(1),
+							has_default: false
+						},
+					],
+				},
+				AvroNamedType::Fixed { name: "org.test.Id".to_string(), size: 8 },
+				AvroNamedType::Enum {
+					name: "org.test.Kind".to_string(),
+					symbols: vec!["A".to_string(), "B".to_string()],
+					has_default: true,
+				},
+			]
+		);
+	}
+
+	#[test]
+	fn parse_avro_schema_rejects_invalid_json_and_types() {
+		for (model, expected) in [
+			("", AvroError::InvalidJson),
+			(r#"{ "name": "#, AvroError::InvalidJson),
+			(r#"{ "name", "John Doe" }"#, AvroError::InvalidJson),
+			("null", AvroError::InvalidType),
+			("567", AvroError::InvalidType),
+			("true", AvroError::InvalidType),
+			(r#"{"type": 5}"#, AvroError::InvalidType),
+			(r#"{"name":"John Doe"}"#, AvroError::MissingAttribute),
+			(r#""strung""#, AvroError::UnknownType),
+			(r#"{"type": "Missing"}"#, AvroError::UnknownType),
+		] {
+			assert_eq!(parse(model), Err(expected), "{model}");
+		}
+	}
+
+	#[test]
+	fn parse_avro_schema_rejects_invalid_records() {
+		for (model, expected) in [
+			(r#"{"type":"record","name":"R"}"#, AvroError::MissingAttribute),
+			(r#"{"type":"record","fields":[]}"#, AvroError::MissingAttribute),
+			(r#"{"type":"record","name":"R","fields":{}}"#, AvroError::InvalidAttribute),
+			(
+				r#"{"type":"record","name":"R","fields":[{"name":"a"}]}"#,
+				AvroError::MissingAttribute,
+			),
+			(r#"{"type":"record","name":"1R","fields":[]}"#, AvroError::InvalidName),
+			(r#"{"type":"record","name":"int","fields":[]}"#, AvroError::InvalidName),
+			(
+				r#"{"type":"record","name":"R","namespace":"a-b","fields":[]}"#,
+				AvroError::InvalidName,
+			),
+			(
+				r#"{"type":"record","name":"R","fields":[{"name":"a-b","type":"int"}]}"#,
+				AvroError::InvalidName,
+			),
+			(
+				r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int"},{"name":"a","type":"long"}]}"#,
+				AvroError::DuplicateFieldName,
+			),
+			(
+				r#"{"type":"record","name":"R","fields":[{"name":"a","type":{"type":"fixed","name":"R","size":1}}]}"#,
+				AvroError::DuplicateName,
+			),
+			(
+				r#"{"type":"record","name":"R","fields":[{"name":"a","type":"int","order":"up"}]}"#,
+				AvroError::InvalidAttribute,
+			),
+			(
+				r#"{"type":"record","name":"R","aliases":["1a"],"fields":[]}"#,
+				AvroError::InvalidName,
+			),
+		] {
+			assert_eq!(parse(model), Err(expected), "{model}");
+		}
+	}
+
+	#[test]
+	fn parse_avro_schema_rejects_invalid_complex_types() {
+		for (model, expected) in [
+			(r#"{"type":"array"}"#, AvroError::MissingAttribute),
+			(r#"{"type":"map"}"#, AvroError::MissingAttribute),
+			(r#"{"type":"enum","name":"E"}"#, AvroError::MissingAttribute),
+			(r#"{"type":"enum","name":"E","symbols":["A","A"]}"#, AvroError::InvalidEnumSymbols),
+			(r#"{"type":"enum","name":"E","symbols":["A-B"]}"#, AvroError::InvalidEnumSymbols),
+			(r#"{"type":"enum","name":"E","symbols":[1]}"#, AvroError::InvalidEnumSymbols),
+			(
+				r#"{"type":"enum","name":"E","symbols":["A"],"default":"B"}"#,
+				AvroError::InvalidDefault,
+			),
+			(r#"{"type":"fixed","name":"F"}"#, AvroError::MissingAttribute),
+			(r#"{"type":"fixed","name":"F","size":-1}"#, AvroError::InvalidFixedSize),
+			(r#"{"type":"fixed","name":"F","size":"8"}"#, AvroError::InvalidFixedSize),
+			("[]", AvroError::InvalidUnion),
+			(r#"["null","null"]"#, AvroError::InvalidUnion),
+			(r#"["null",["int"]]"#, AvroError::InvalidUnion),
+			(
+				r#"[{"type":"array","items":"int"},{"type":"array","items":"long"}]"#,
+				AvroError::InvalidUnion,
+			),
+		] {
+			assert_eq!(parse(model), Err(expected), "{model}");
+		}
+	}
+
+	#[test]
+	fn parse_avro_schema_allows_distinct_named_types_in_unions() {
+		assert!(parse(
+			r#"[{"type":"fixed","name":"A","size":1},{"type":"fixed","name":"B","size":1}]"#
+		)
+		.is_ok());
+	}
+
+	#[test]
+	fn parse_avro_schema_validates_defaults() {
+		let field = |field_type: &str, default: &str| {
+			format!(
+				r#"{{"type":"record","name":"R","fields":[{{"name":"a","type":{field_type},"default":{default}}}]}}"#
+			)
+		};
+		for (field_type, default) in [
+			(r#""int""#, "1"),
+			(r#""long""#, "-9007199254740993"),
+			(r#""double""#, "1.5"),
+			(r#""bytes""#, r#""ÿ""#),
+			(r#"{"type":"fixed","name":"F","size":2}"#, r#""ab""#),
+			(r#"["null","string"]"#, "null"),
+			(r#"["string","null"]"#, r#""a""#),
+			(r#"{"type":"array","items":"int"}"#, "[1, 2]"),
+			(r#"{"type":"map","values":"boolean"}"#, r#"{"a": true}"#),
+		] {
+			assert!(parse(&field(field_type, default)).is_ok(), "{field_type} {default}");
+		}
+		for (field_type, default) in [
+			(r#""int""#, "2147483648"),
+			(r#""int""#, "1.5"),
+			(r#""string""#, "null"),
+			(r#""bytes""#, r#""Ā""#),
+			(r#"{"type":"fixed","name":"F","size":2}"#, r#""abc""#),
+			(r#"["null","string"]"#, "1"),
+			// a union default must match the first branch
+			(r#"["null","string"]"#, r#""a""#),
+			(r#"{"type":"array","items":"int"}"#, r#"["a"]"#),
+			(r#"{"type":"record","name":"Inner","fields":[{"name":"b","type":"int"}]}"#, "{}"),
+		] {
+			assert_eq!(
+				parse(&field(field_type, default)),
+				Err(AvroError::InvalidDefault),
+				"{field_type} {default}"
+			);
+		}
+	}
+
+	#[test]
+	fn parse_avro_schema_limits_nesting_depth() {
+		let nested = |depth: u32| {
+			let mut model = String::from(r#""int""#);
+			for _ in 0..depth {
+				model = format!(r#"{{"type":"array","items":{model}}}"#);
+			}
+			model
+		};
+		assert!(parse(&nested(AVRO_MAX_NESTING_DEPTH)).is_ok());
+		assert_eq!(parse(&nested(AVRO_MAX_NESTING_DEPTH + 1)), Err(AvroError::NestingTooDeep));
+	}
+
+	#[test]
+	fn parse_avro_schema_accepts_utf8_documentation() {
+		assert!(parse(
+			r#"{"type":"record","name":"R","doc":"Espíritu navideño","fields":[{"name":"a","type":"string"}]}"#
+		)
+		.is_ok());
+	}
+
+	fn compatibility(reader: &str, writer: &str) -> Result<(), AvroIncompatibility> {
+		check_avro_compatibility(
+			&parse(reader).expect("reader should parse"),
+			&parse(writer).expect("writer should parse"),
+		)
+	}
+
+	#[test]
+	fn check_avro_compatibility_applies_promotions_and_unions() {
+		assert_eq!(compatibility(r#""long""#, r#""int""#), Ok(()));
+		assert_eq!(compatibility(r#""double""#, r#""float""#), Ok(()));
+		assert_eq!(compatibility(r#""bytes""#, r#""string""#), Ok(()));
+		assert_eq!(compatibility(r#""int""#, r#""long""#), Err(AvroIncompatibility::TypeMismatch));
+		assert_eq!(compatibility(r#"["null","long"]"#, r#""int""#), Ok(()));
+		assert_eq!(
+			compatibility(r#"["null","string"]"#, r#""int""#),
+			Err(AvroIncompatibility::UnionBranchMismatch)
+		);
+		assert_eq!(
+			compatibility(r#""string""#, r#"["null","string"]"#),
+			Err(AvroIncompatibility::TypeMismatch)
+		);
+		assert_eq!(
+			compatibility(
+				r#"{"type":"map","values":"double"}"#,
+				r#"{"type":"map","values":"long"}"#
+			),
+			Ok(())
+		);
+	}
+
+	#[test]
+	fn check_avro_compatibility_resolves_record_fields() {
+		let v1 = r#"{"type":"record","name":"a.R","fields":[{"name":"x","type":"int"}]}"#;
+		let v2_default = r#"{"type":"record","name":"b.R","fields":[
+			{"name":"x","type":"long"},{"name":"y","type":"string","default":""}]}"#;
+		let v2_required = r#"{"type":"record","name":"R","fields":[
+			{"name":"x","type":"int"},{"name":"y","type":"string"}]}"#;
+
+		assert_eq!(compatibility(v2_default, v1), Ok(()));
+		assert_eq!(compatibility(v1, v2_required), Ok(()));
+		assert_eq!(compatibility(v2_required, v1), Err(AvroIncompatibility::MissingFieldDefault));
+		assert_eq!(
+			compatibility(r#"{"type":"record","name":"S","fields":[]}"#, v1),
+			Err(AvroIncompatibility::NameMismatch)
+		);
+	}
+
+	#[test]
+	fn check_avro_compatibility_resolves_enums_fixed_and_recursion() {
+		let ab = r#"{"type":"enum","name":"E","symbols":["A","B"]}"#;
+		let abc = r#"{"type":"enum","name":"E","symbols":["A","B","C"]}"#;
+		let ab_default = r#"{"type":"enum","name":"E","symbols":["A","B"],"default":"A"}"#;
+		assert_eq!(compatibility(abc, ab), Ok(()));
+		assert_eq!(compatibility(ab, abc), Err(AvroIncompatibility::MissingEnumSymbol));
+		assert_eq!(compatibility(ab_default, abc), Ok(()));
+
+		assert_eq!(
+			compatibility(
+				r#"{"type":"fixed","name":"F","size":4}"#,
+				r#"{"type":"fixed","name":"F","size":8}"#
+			),
+			Err(AvroIncompatibility::FixedSizeMismatch)
+		);
+
+		let list = r#"{"type":"record","name":"List","fields":[
+			{"name":"value","type":"int"},{"name":"next","type":["null","List"]}]}"#;
+		let list_long = r#"{"type":"record","name":"List","fields":[
+			{"name":"value","type":"long"},{"name":"next","type":["null","List"]}]}"#;
+		assert_eq!(compatibility(list_long, list), Ok(()));
+		assert_eq!(compatibility(list, list_long), Err(AvroIncompatibility::TypeMismatch));
+	}
+
+	fn validate(model: &str, datum: &[u8]) -> Result<(), AvroDecodeError> {
+		validate_avro_datum(&parse(model).expect("model should parse"), datum)
+	}
+
+	#[test]
+	fn validate_avro_datum_accepts_conforming_values() {
+		let record = r#"{"type":"record","name":"R","fields":[
+			{"name":"id","type":"long"},
+			{"name":"name","type":"string"},
+			{"name":"tags","type":{"type":"array","items":"int"}},
+			{"name":"extra","type":{"type":"map","values":"boolean"}},
+			{"name":"parent","type":["null","R"]},
+			{"name":"kind","type":{"type":"enum","name":"K","symbols":["A","B"]}},
+			{"name":"hash","type":{"type":"fixed","name":"H","size":2}},
+			{"name":"score","type":"double"}
+		]}"#;
+		let mut datum = vec![
+			0x96, 0x01, // id: 75
+			0x04, b'h', b'i', // name: "hi"
+			0x04, 0x02, 0x7f, 0x00, // tags: [1, -64]
+			0x01, 0x08, 0x04, b'o', b'k', 0x01, 0x00, // extra: {"ok": true} as a sized block
+			0x02, // parent: R
+			0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xab, 0xcd, // parent fields, parent is null
+		];
+		datum.extend(0f64.to_le_bytes());
+		datum.extend([0x02, 0xab, 0xcd]); // kind: B, hash
+		datum.extend(1.5f64.to_le_bytes());
+		assert_eq!(validate(record, &datum), Ok(()));
+		assert_eq!(validate(r#""null""#, &[]), Ok(()));
+		assert_eq!(validate(r#""int""#, &[0xff, 0xff, 0xff, 0xff, 0x0f]), Ok(()));
+	}
+
+	#[test]
+	fn validate_avro_datum_rejects_malformed_values() {
+		for (model, datum, expected) in [
+			(r#""int""#, &[][..], AvroDecodeError::UnexpectedEnd),
+			(r#""int""#, &[0xff, 0xff, 0xff, 0xff, 0x1f][..], AvroDecodeError::InvalidVarint),
+			(r#""long""#, &[0xff; 11][..], AvroDecodeError::InvalidVarint),
+			(r#""boolean""#, &[2][..], AvroDecodeError::InvalidBoolean),
+			(r#""string""#, &[0x02, 0xff][..], AvroDecodeError::InvalidUtf8),
+			(r#""bytes""#, &[0x01][..], AvroDecodeError::InvalidLength),
+			(r#""bytes""#, &[0x04, 0x00][..], AvroDecodeError::InvalidLength),
+			(
+				r#"{"type":"array","items":"null"}"#,
+				&[0x10, 0x00][..],
+				AvroDecodeError::InvalidLength,
+			),
+			(r#"["null","int"]"#, &[0x04][..], AvroDecodeError::InvalidUnionIndex),
+			(
+				r#"{"type":"enum","name":"E","symbols":["A"]}"#,
+				&[0x02][..],
+				AvroDecodeError::InvalidEnumIndex,
+			),
+			(r#""float""#, &[0x00, 0x00, 0x00][..], AvroDecodeError::UnexpectedEnd),
+			(r#""int""#, &[0x00, 0x00][..], AvroDecodeError::TrailingBytes),
+		] {
+			assert_eq!(validate(model, datum), Err(expected), "{model} {datum:?}");
+		}
+	}
+
+	#[test]
+	fn validate_avro_datum_limits_recursion() {
+		let endless = r#"{"type":"record","name":"R","fields":[{"name":"next","type":"R"}]}"#;
+		assert_eq!(validate(endless, &[]), Err(AvroDecodeError::NestingTooDeep));
+	}
+
+	#[test]
+	fn validate_avro_datum_limits_items_of_zero_byte_types() {
+		let nulls = r#"{"type":"array","items":"null"}"#;
+		// blocks of a single null each, as a block may not count more items than bytes left
+		let datum = |count: usize| {
+			let mut datum = vec![0x02; count];
+			datum.push(0x00);
+			datum
+		};
+		assert_eq!(validate(nulls, &datum(AVRO_MAX_DATUM_ITEMS)), Ok(()));
+		assert_eq!(
+			validate(nulls, &datum(AVRO_MAX_DATUM_ITEMS + 1)),
+			Err(AvroDecodeError::TooManyItems)
+		);
+	}
+
+	#[test]
+	fn validate_avro_datum_limits_steps_of_zero_byte_values() {
+		let fields = (0..AVRO_MAX_DATUM_STEPS / 8)
+			.map(|i| format!(r#"{{"name":"f{i}","type":"null"}}"#))
+			.collect::<Vec<_>>()
+			.join(",");
+		let records = format!(
+			r#"{{"type":"array","items":{{"type":"record","name":"R","fields":[{fields}]}}}}"#
+		);
+		let datum = |count: usize| {
+			let mut datum = vec![0x02; count];
+			datum.push(0x00);
+			datum
+		};
+		// the array, then each record and its fields
+		assert_eq!(validate(&records, &datum(7)), Ok(()));
+		assert_eq!(validate(&records, &datum(8)), Err(AvroDecodeError::TooManySteps));
+	}
+}
@@ -1,6 +1,7 @@
 extern crate alloc;
 use crate::{
 	msa::{DelegatorId, MessageSourceId, ProviderId},
+	node::BlockNumber,
 	schema::{IntentId, IntentSetting, ModelType, PayloadLocation, SchemaId},
 };
 use alloc::vec::Vec;
@@ -72,6 +73,9 @@ pub trait SchemaBenchmarkHelper {
 		payload_location: PayloadLocation,
 		settings: Vec<IntentSetting>,
 	) -> Result<IntentId, DispatchError>;
+
+	/// Sets the message retention period of an Intent
+	fn set_intent_retention_period(intent_id: IntentId, retention: BlockNumber);
 }
 
 impl SchemaBenchmarkHelper for () {
@@ -98,6 +102,9 @@ impl SchemaBenchmarkHelper for () {
 	) -> Result<IntentId, DispatchError> {
 		Ok(IntentId::default())
 	}
+
+	/// Sets the message retention period of an Intent
+	fn set_intent_retention_period(_intent_id: IntentId, _retention: BlockNumber) {}
 }
 
 /// A trait for helping setup state for running benchmarks in Capacity.
@@ -112,3 +119,70 @@ impl RegisterProviderBenchmarkHelper for () {
 		Ok(())
 	}
 }
+
+/// The largest size of [`avro_benchmark_model`], within the size limit of a schema model
+pub const AVRO_BENCHMARK_MODEL_MAX_BYTES: usize = 65_000;
+
+/// Builds an Avro model for benchmarking payload validation, see [`avro_benchmark_payload`].
+/// The model is as large as a schema model may be, as it is parsed again on every validation,
+/// and is a record of a `pad` of bytes and an array of `items`, each a record of as many `null`
+/// fields as fit in the model. Values of these records take a single byte per item, the block
+/// count, while each of them is as many steps of the validator as it has fields.
+pub fn avro_benchmark_model() -> Vec<u8> {
+	build_avro_benchmark_model().0
+}
+
+/// Builds [`avro_benchmark_model`] along with the number of fields of each of its items
+fn build_avro_benchmark_model() -> (Vec<u8>, usize) {
+	let prefix = br#"{"type":"record","name":"AvroBenchmark","fields":[{"name":"pad","type":"bytes"},{"name":"items","type":{"type":"array","items":{"type":"record","name":"Nulls","fields":["#;
+	let suffix = b"]}}}]}";
+	let mut model = prefix.to_vec();
+	let mut fields = 0;
+	loop {
+		let separator: &[u8] = if fields == 0 { b"" } else { b"," };
+		let field = alloc::format!(r#"{{"name":"f{fields}","type":"null"}}"#);
+		if model.len() + separator.len() + field.len() + suffix.len() >
+			AVRO_BENCHMARK_MODEL_MAX_BYTES
+		{
+			break
+		}
+		model.extend_from_slice(separator);
+		model.extend_from_slice(field.as_bytes());
+		fields += 1;
+	}
+	model.extend_from_slice(suffix);
+	(model, fields)
+}
+
+/// Appends `value` as a zigzag varint of exactly `bytes` bytes, padded with continuation bytes
+fn push_padded_varint(payload: &mut Vec<u8>, value: u64, bytes: u32) {
+	let value = value << 1;
+	for byte in 0..bytes {
+		let continuation = if byte + 1 < bytes { 0x80 } else { 0x00 };
+		payload.push(((value >> (7 * byte)) & 0x7f) as u8 | continuation);
+	}
+}
+
+/// Builds a payload of exactly `len` bytes, at least two, that conforms to
+/// [`avro_benchmark_model`]. It has as many items as the validator allows steps for, each in
+/// its own block, which is the most work a validator can be made to do, and the rest of the
+/// payload is the `pad`.
+pub fn avro_benchmark_payload(len: u32) -> Vec<u8> {
+	let len = len.max(2) as usize;
+	// the record, its `pad` and its `items`, then each item and its fields
+	let max_items = (crate::avro::AVRO_MAX_DATUM_STEPS - 3) / (build_avro_benchmark_model().1 + 1);
+	let items = max_items.min(len - 2);
+	// the `pad` is its length and its bytes, with the length padded to fill the payload
+	let pad_total = len - 1 - items;
+	let length_bytes = (1u32..)
+		.find(|bytes| ((pad_total - *bytes as usize) as u64) << 1 < 1u64 << (7 * bytes))
+		.unwrap_or(1);
+	let pad = pad_total - length_bytes as usize;
+	let mut payload = Vec::with_capacity(len);
+	push_padded_varint(&mut payload, pad as u64, length_bytes);
+	payload.resize(payload.len() + pad, 0xa5);
+	// blocks of a single item, then the end of the array
+	payload.resize(payload.len() + items, 0x02);
+	payload.push(0x00);
+	payload
+}
@@ -1,6 +1,4 @@
-#[cfg(test)]
-use cid::multibase::Base;
-use cid::{multibase, Cid};
+use cid::{multibase, multibase::Base, Cid, Version};
 #[cfg(test)]
 use frame_support::assert_ok;
 use frame_support::ensure;
@@ -11,15 +9,30 @@ use sp_runtime::Vec;
 pub type Multihash = cid::multihash::Multihash<64>;
 
 /// SHA2-256 multihash code
-const SHA2_256: u64 = 0x12;
+pub const SHA2_256: u64 = 0x12;
 /// BLAKE3 multihash code
-const BLAKE3: u64 = 0x1e;
+pub const BLAKE3: u64 = 0x1e;
 
 /// List of hash algorithms supported by DSNP
-const DSNP_HASH_ALGORITHMS: &[u64] = &[SHA2_256, BLAKE3];
+pub const DSNP_HASH_ALGORITHMS: &[u64] = &[SHA2_256, BLAKE3];
 
 /// Raw codec for CIDv1 (0x55)
-const RAW: u64 = 0x55;
+pub const RAW: u64 = 0x55;
+/// DAG-PB codec (0x70), used by IPFS for UnixFS files and implied by CIDv0
+pub const DAG_PB: u64 = 0x70;
+/// DAG-CBOR codec (0x71)
+pub const DAG_CBOR: u64 = 0x71;
+/// DAG-JSON codec (0x0129)
+pub const DAG_JSON: u64 = 0x0129;
+/// JSON codec (0x0200)
+pub const JSON: u64 = 0x0200;
+
+/// List of codecs accepted by default for content stored by DSNP. Accepting [`DAG_PB`] does not
+/// accept CIDv0, which must be allowed separately.
+pub const DSNP_CODECS: &[u64] = &[RAW, DAG_PB, DAG_CBOR, DAG_JSON, JSON];
+
+/// Largest file that IPFS stores in a single block with its default chunker (256 KiB)
+pub const MAX_SINGLE_BLOCK_FILE_SIZE: usize = 256 * 1024;
 
 /// Error enum for CID validation
 #[derive(Debug, PartialEq)]
@@ -34,6 +47,10 @@ pub enum CidError {
 	Utf8DecodeError,
 	/// CID parsing error
 	InvalidCid,
+	/// Multihash parsing error
+	InvalidMultihash,
+	/// Unsupported CID codec
+	UnsupportedCidCodec,
 }
 
 /// Computes a CIDv1 (RAW + SHA2-256 multihash)
@@ -44,6 +61,49 @@ pub fn compute_cid_v1(bytes: &[u8]) -> Option<Vec<u8>> {
 	Some(cid.to_bytes())
 }
 
+/// Computes a CIDv1 (DAG-PB + SHA2-256 multihash) of a file stored by IPFS as a single UnixFS block,
+/// as produced by `ipfs add` without raw leaves. Files larger than [`MAX_SINGLE_BLOCK_FILE_SIZE`]
+/// are split into several blocks by IPFS and have no single-block CID.
+pub fn compute_cid_v1_dag_pb(bytes: &[u8]) -> Option<Vec<u8>> {
+	if bytes.len() > MAX_SINGLE_BLOCK_FILE_SIZE {
+		return None
+	}
+	let digest = sha2_256(&unixfs_file_block(bytes));
+	let mh = Multihash::wrap(SHA2_256, &digest).ok()?;
+	let cid = Cid::new_v1(DAG_PB, mh);
+	Some(cid.to_bytes())
+}
+
+/// Encodes a file as a DAG-PB node without links whose data is a UnixFS `File`
+fn unixfs_file_block(bytes: &[u8]) -> Vec<u8> {
+	// UnixFS Data { Type: File, Data: bytes, filesize }; empty data is omitted as IPFS does
+	let mut unixfs = Vec::with_capacity(bytes.len().saturating_add(24));
+	unixfs.extend_from_slice(&[0x08, 0x02]);
+	if !bytes.is_empty() {
+		unixfs.push(0x12);
+		encode_varint(bytes.len() as u64, &mut unixfs);
+		unixfs.extend_from_slice(bytes);
+	}
+	unixfs.push(0x18);
+	encode_varint(bytes.len() as u64, &mut unixfs);
+
+	// PBNode { Data: unixfs }
+	let mut block = Vec::with_capacity(unixfs.len().saturating_add(6));
+	block.push(0x0a);
+	encode_varint(unixfs.len() as u64, &mut block);
+	block.extend_from_slice(&unixfs);
+	block
+}
+
+/// Appends the unsigned LEB128 (protobuf varint) encoding of a value
+fn encode_varint(mut value: u64, out: &mut Vec<u8>) {
+	while value >= 0x80 {
+		out.push((value as u8) | 0x80);
+		value >>= 7;
+	}
+	out.push(value as u8);
+}
+
 /// Validates a CID to conform to IPFS CIDv1 (or higher) formatting and allowed multihashes (does not validate decoded CID fields)
 pub fn validate_cid(in_cid: &[u8]) -> Result<Vec<u8>, CidError> {
 	// Decode SCALE encoded CID into string slice
@@ -60,6 +120,52 @@ pub fn validate_cid(in_cid: &[u8]) -> Result<Vec<u8>, CidError> {
 	Ok(cid_b)
 }
 
+/// Validates a CID in any multibase encoding, or a base58btc CIDv0, against the accepted codecs and
+/// multihash codes. When `allow_cid_v0` is set and DAG-PB is accepted, a CIDv0 is accepted as the
+/// equivalent DAG-PB + SHA2-256 CIDv1.
+/// Returns the binary CIDv1, so that every accepted CID has a single canonical form.
+pub fn validate_cid_with_policy(
+	in_cid: &[u8],
+	codecs: &[u64],
+	multihash_codes: &[u64],
+	allow_cid_v0: bool,
+) -> Result<Vec<u8>, CidError> {
+	let cid_str: &str = core::str::from_utf8(in_cid).map_err(|_| CidError::Utf8DecodeError)?;
+	ensure!(cid_str.len() > 2, CidError::InvalidCid);
+
+	let cid_b = if cid_str.starts_with("Qm") {
+		Base::Base58Btc.decode(cid_str).map_err(|_| CidError::MultibaseDecodeError)?
+	} else {
+		multibase::decode(cid_str).map_err(|_| CidError::MultibaseDecodeError)?.1
+	};
+	let cid = Cid::read_bytes(&cid_b[..]).map_err(|_| CidError::InvalidCid)?;
+	ensure!(multihash_codes.contains(&cid.hash().code()), CidError::UnsupportedCidMultihash);
+	if cid.version() == Version::V0 {
+		ensure!(allow_cid_v0 && codecs.contains(&DAG_PB), CidError::UnsupportedCidVersion);
+	}
+	ensure!(codecs.contains(&cid.codec()), CidError::UnsupportedCidCodec);
+
+	let cid = cid.into_v1().map_err(|_| CidError::InvalidCid)?;
+	Ok(cid.to_bytes())
+}
+
+/// Encodes a binary CID as the canonical lowercase base32 string of its CIDv1
+pub fn cid_to_base32(binary_cid: &[u8]) -> Vec<u8> {
+	Cid::read_bytes(binary_cid)
+		.and_then(|cid| cid.into_v1())
+		.and_then(|cid| cid.to_string_of_base(Base::Base32Lower))
+		// not a CID: keep the encoding of the stored bytes
+		.unwrap_or_else(|_| multibase::encode(Base::Base32Lower, binary_cid))
+		.into_bytes()
+}
+
+/// Validates a binary multihash of content, which must use one of the hash algorithms supported by DSNP
+pub fn validate_multihash(in_multihash: &[u8]) -> Result<(), CidError> {
+	let multihash = Multihash::from_bytes(in_multihash).map_err(|_| CidError::InvalidMultihash)?;
+	ensure!(DSNP_HASH_ALGORITHMS.contains(&multihash.code()), CidError::UnsupportedCidMultihash);
+	Ok(())
+}
+
 #[cfg(test)]
 const DUMMY_CID_SHA512: &str = "bafkrgqb76pscorjihsk77zpyst3p364zlti6aojlu4nga34vhp7t5orzwbwwytvp7ej44r5yhjzneanqwb5arcnvuvfwo2d4qgzyx5hymvto4";
 #[cfg(test)]
@@ -156,3 +262,110 @@ fn validate_cid_unwrap_errors() {
 	let bad_cid = vec![102, 70, 70, 70, 70, 70, 70, 70, 70, 48, 48, 48, 54, 53, 53, 48, 48];
 	assert_eq!(validate_cid(&bad_cid).expect_err("Expected InvalidCid"), CidError::InvalidCid);
 }
+
+#[test]
+fn validate_multihash_supported_algorithms_succeeds() {
+	for code in DSNP_HASH_ALGORITHMS {
+		let multihash = Multihash::wrap(*code, &sha2_256(b"content")).expect("valid digest");
+		assert_ok!(validate_multihash(&multihash.to_bytes()));
+	}
+}
+
+#[test]
+fn validate_multihash_unsupported_algorithm_errors() {
+	// SHA2-512
+	let multihash = Multihash::wrap(0x13, &[0u8; 64]).expect("valid digest");
+	assert_eq!(
+		validate_multihash(&multihash.to_bytes()).expect_err("Expected UnsupportedCidMultihash"),
+		CidError::UnsupportedCidMultihash
+	);
+}
+
+#[test]
+fn validate_multihash_malformed_errors() {
+	let mut multihash = Multihash::wrap(SHA2_256, &sha2_256(b"content"))
+		.expect("valid digest")
+		.to_bytes();
+	multihash.push(0);
+	for bad_multihash in [vec![], vec![0x12, 0x20, 1, 2, 3], multihash] {
+		assert_eq!(
+			validate_multihash(&bad_multihash).expect_err("Expected InvalidMultihash"),
+			CidError::InvalidMultihash
+		);
+	}
+}
+
+#[test]
+fn validate_cid_with_policy_accepts_any_multibase_encoding() {
+	let expected = multibase::decode(DUMMY_CID_BLAKE3).expect("valid multibase").1;
+	for base in [Base::Base32Upper, Base::Base58Btc, Base::Base64, Base::Base16Lower] {
+		let cid = multibase::encode(base, &expected);
+		assert_eq!(
+			validate_cid_with_policy(cid.as_bytes(), DSNP_CODECS, DSNP_HASH_ALGORITHMS, false),
+			Ok(expected.clone())
+		);
+	}
+}
+
+#[test]
+fn validate_cid_with_policy_converts_v0_to_v1() {
+	let cid_v0 = "QmT78zSuBmuS4z925WZfrqQ1qHaJ56DQaTfyMUF7F8ff5o".as_bytes();
+	let cid = validate_cid_with_policy(cid_v0, DSNP_CODECS, DSNP_HASH_ALGORITHMS, true)
+		.expect("CIDv0 should be accepted");
+	assert_eq!(
+		cid_to_base32(&cid),
+		b"bafybeicg2rebjoofv4kbyovkw7af3rpiitvnl6i7ckcywaq6xjcxnc2mby".to_vec()
+	);
+
+	assert_eq!(
+		validate_cid_with_policy(cid_v0, DSNP_CODECS, DSNP_HASH_ALGORITHMS, false),
+		Err(CidError::UnsupportedCidVersion)
+	);
+	assert_eq!(
+		validate_cid_with_policy(cid_v0, &[RAW], DSNP_HASH_ALGORITHMS, true),
+		Err(CidError::UnsupportedCidVersion)
+	);
+}
+
+#[test]
+fn validate_cid_with_policy_rejects_codec_and_multihash_not_in_policy() {
+	assert_eq!(
+		validate_cid_with_policy(
+			DUMMY_CID_SHA256.as_bytes(),
+			&[RAW, DAG_PB],
+			DSNP_HASH_ALGORITHMS,
+			false
+		),
+		Err(CidError::UnsupportedCidCodec)
+	);
+	assert_eq!(
+		validate_cid_with_policy(DUMMY_CID_BLAKE3.as_bytes(), DSNP_CODECS, &[SHA2_256], false),
+		Err(CidError::UnsupportedCidMultihash)
+	);
+	assert_eq!(
+		validate_cid_with_policy(
+			DUMMY_CID_SHA512.as_bytes(),
+			DSNP_CODECS,
+			DSNP_HASH_ALGORITHMS,
+			false
+		),
+		Err(CidError::UnsupportedCidMultihash)
+	);
+}
+
+#[test]
+fn compute_cid_v1_dag_pb_matches_ipfs() {
+	for (bytes, expected) in [
+		(&b"hello world\n"[..], "bafybeicg2rebjoofv4kbyovkw7af3rpiitvnl6i7ckcywaq6xjcxnc2mby"),
+		(&b""[..], "bafybeif7ztnhq65lumvvtr4ekcwd2ifwgm3awq4zfr3srh462rwyinlb4y"),
+	] {
+		let cid = compute_cid_v1_dag_pb(bytes).expect("single block file");
+		assert_eq!(cid_to_base32(&cid), expected.as_bytes().to_vec());
+	}
+}
+
+#[test]
+fn compute_cid_v1_dag_pb_too_large_is_none() {
+	assert_eq!(compute_cid_v1_dag_pb(&vec![0u8; MAX_SINGLE_BLOCK_FILE_SIZE + 1]), None);
+	assert!(compute_cid_v1_dag_pb(&vec![0u8; MAX_SINGLE_BLOCK_FILE_SIZE]).is_some());
+}
@@ -0,0 +1,280 @@
+use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_runtime::RuntimeDebug;
+
+/// PNG file signature
+const PNG_SIGNATURE: &[u8] = &[0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+/// JPEG start of image marker
+const JPEG_SOI: &[u8] = &[0xFF, 0xD8];
+/// VP8 (lossy WebP) frame start code
+const VP8_START_CODE: &[u8] = &[0x9D, 0x01, 0x2A];
+/// VP8L (lossless WebP) signature
+const VP8L_SIGNATURE: u8 = 0x2F;
+
+/// Supported image formats identified by their MIME type
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, RuntimeDebug, Clone, Copy, Decode, Encode, PartialEq, Eq, MaxEncodedLen)]
+pub enum ImageMimeType {
+	/// `image/png`
+	#[cfg_attr(feature = "std", serde(rename = "image/png"))]
+	Png,
+	/// `image/jpeg`
+	#[cfg_attr(feature = "std", serde(rename = "image/jpeg"))]
+	Jpeg,
+	/// `image/webp`
+	#[cfg_attr(feature = "std", serde(rename = "image/webp"))]
+	WebP,
+}
+
+impl ImageMimeType {
+	/// The MIME type string of this format
+	pub fn as_str(&self) -> &'static str {
+		match self {
+			ImageMimeType::Png => "image/png",
+			ImageMimeType::Jpeg => "image/jpeg",
+			ImageMimeType::WebP => "image/webp",
+		}
+	}
+}
+
+/// Format and dimensions read from an image header
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, RuntimeDebug, Clone, Copy, Decode, Encode, PartialEq, Eq, MaxEncodedLen)]
+pub struct ImageMetadata {
+	/// The detected MIME type
+	pub mime_type: ImageMimeType,
+	/// Width in pixels
+	pub width: u32,
+	/// Height in pixels
+	pub height: u32,
+}
+
+/// Error enum for image header parsing
+#[derive(Debug, PartialEq)]
+pub enum ImageError {
+	/// SVG images are not accepted since they can embed scripts
+	SvgNotSupported,
+	/// The bytes are not a PNG, JPEG or WebP image
+	UnsupportedFormat,
+	/// The header of a recognised format is truncated or malformed
+	MalformedHeader,
+}
+
+/// Reads the format and dimensions from the header of a PNG, JPEG or WebP image.
+/// Only the header is inspected, the image data itself is not decoded.
+pub fn parse_image_metadata(bytes: &[u8]) -> Result<ImageMetadata, ImageError> {
+	if bytes.starts_with(PNG_SIGNATURE) {
+		return parse_png(bytes);
+	}
+	if bytes.starts_with(JPEG_SOI) {
+		return parse_jpeg(bytes);
+	}
+	if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+		return parse_webp(bytes);
+	}
+	if is_svg(bytes) {
+		return Err(ImageError::SvgNotSupported);
+	}
+	Err(ImageError::UnsupportedFormat)
+}
+
+/// The first chunk of a PNG must be IHDR which starts with the width and height
+fn parse_png(bytes: &[u8]) -> Result<ImageMetadata, ImageError> {
+	let ihdr = bytes.get(8..24).ok_or(ImageError::MalformedHeader)?;
+	if &ihdr[4..8] != b"IHDR" {
+		return Err(ImageError::MalformedHeader);
+	}
+	Ok(ImageMetadata {
+		mime_type: ImageMimeType::Png,
+		width: read_u32_be(&ihdr[8..12]),
+		height: read_u32_be(&ihdr[12..16]),
+	})
+}
+
+/// Walks the JPEG segments until the first start of frame (SOFn) marker
+fn parse_jpeg(bytes: &[u8]) -> Result<ImageMetadata, ImageError> {
+	let mut offset = JPEG_SOI.len();
+	loop {
+		if *bytes.get(offset).ok_or(ImageError::MalformedHeader)? != 0xFF {
+			return Err(ImageError::MalformedHeader);
+		}
+		// markers may be preceded by any number of fill bytes
+		while bytes.get(offset) == Some(&0xFF) {
+			offset += 1;
+		}
+		let marker = *bytes.get(offset).ok_or(ImageError::MalformedHeader)?;
+		offset += 1;
+		match marker {
+			// standalone markers without a length
+			0x01 | 0xD0..=0xD7 => continue,
+			// end of image or start of scan before any frame header
+			0xD9 | 0xDA => return Err(ImageError::MalformedHeader),
+			// SOF0..SOF15 except DHT, JPG and DAC
+			0xC0..=0xCF if !matches!(marker, 0xC4 | 0xC8 | 0xCC) => {
+				let frame = bytes.get(offset..offset + 7).ok_or(ImageError::MalformedHeader)?;
+				return Ok(ImageMetadata {
+					mime_type: ImageMimeType::Jpeg,
+					width: read_u16_be(&frame[5..7]) as u32,
+					height: read_u16_be(&frame[3..5]) as u32,
+				});
+			},
+			_ => {
+				let length =
+					read_u16_be(bytes.get(offset..offset + 2).ok_or(ImageError::MalformedHeader)?)
+						as usize;
+				if length < 2 {
+					return Err(ImageError::MalformedHeader);
+				}
+				offset += length;
+			},
+		}
+	}
+}
+
+/// Reads the dimensions from the first chunk of a WebP image (VP8, VP8L or VP8X)
+fn parse_webp(bytes: &[u8]) -> Result<ImageMetadata, ImageError> {
+	let fourcc = bytes.get(12..16).ok_or(ImageError::MalformedHeader)?;
+	let data = bytes.get(20..).ok_or(ImageError::MalformedHeader)?;
+	let (width, height) = match fourcc {
+		b"VP8 " => {
+			let frame = data.get(0..10).ok_or(ImageError::MalformedHeader)?;
+			if &frame[3..6] != VP8_START_CODE {
+				return Err(ImageError::MalformedHeader);
+			}
+			(
+				(read_u16_le(&frame[6..8]) & 0x3FFF) as u32,
+				(read_u16_le(&frame[8..10]) & 0x3FFF) as u32,
+			)
+		},
+		b"VP8L" => {
+			let header = data.get(0..5).ok_or(ImageError::MalformedHeader)?;
+			if header[0] != VP8L_SIGNATURE {
+				return Err(ImageError::MalformedHeader);
+			}
+			let bits = u32::from_le_bytes([header[1], header[2], header[3], header[4]]);
+			((bits & 0x3FFF) + 1, ((bits >> 14) & 0x3FFF) + 1)
+		},
+		b"VP8X" => {
+			let header = data.get(0..10).ok_or(ImageError::MalformedHeader)?;
+			(read_u24_le(&header[4..7]) + 1, read_u24_le(&header[7..10]) + 1)
+		},
+		_ => return Err(ImageError::MalformedHeader),
+	};
+	Ok(ImageMetadata { mime_type: ImageMimeType::WebP, width, height })
+}
+
+/// SVG is text, so look for an XML or `<svg` start after an optional BOM and whitespace
+fn is_svg(bytes: &[u8]) -> bool {
+	let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+	let start = bytes.iter().position(|b| !b.is_ascii_whitespace()).unwrap_or(bytes.len());
+	let text = &bytes[start..];
+	text.starts_with(b"<svg") ||
+		(text.starts_with(b"<") && text.windows(4).any(|window| window == b"<svg"))
+}
+
+fn read_u16_be(bytes: &[u8]) -> u16 {
+	u16::from_be_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u16_le(bytes: &[u8]) -> u16 {
+	u16::from_le_bytes([bytes[0], bytes[1]])
+}
+
+fn read_u32_be(bytes: &[u8]) -> u32 {
+	u32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+fn read_u24_le(bytes: &[u8]) -> u32 {
+	u32::from_le_bytes([bytes[0], bytes[1], bytes[2], 0])
+}
+
+#[cfg(test)]
+fn png_header(width: u32, height: u32) -> sp_runtime::Vec<u8> {
+	let mut bytes = PNG_SIGNATURE.to_vec();
+	bytes.extend_from_slice(&13u32.to_be_bytes());
+	bytes.extend_from_slice(b"IHDR");
+	bytes.extend_from_slice(&width.to_be_bytes());
+	bytes.extend_from_slice(&height.to_be_bytes());
+	bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+	bytes
+}
+
+#[test]
+fn parse_image_metadata_reads_png_dimensions() {
+	assert_eq!(
+		parse_image_metadata(&png_header(250, 100)),
+		Ok(ImageMetadata { mime_type: ImageMimeType::Png, width: 250, height: 100 })
+	);
+}
+
+#[test]
+fn parse_image_metadata_rejects_truncated_png() {
+	assert_eq!(parse_image_metadata(&png_header(250, 100)[..20]), Err(ImageError::MalformedHeader));
+}
+
+#[test]
+fn parse_image_metadata_reads_jpeg_dimensions_after_other_segments() {
+	let mut bytes = JPEG_SOI.to_vec();
+	// APP0 segment
+	bytes.extend_from_slice(&[0xFF, 0xE0, 0x00, 0x06, b'J', b'F', b'I', b'F']);
+	// SOF2 (progressive) with fill byte
+	bytes.extend_from_slice(&[0xFF, 0xFF, 0xC2, 0x00, 0x11, 0x08, 0x00, 0x64, 0x00, 0xFA]);
+	assert_eq!(
+		parse_image_metadata(&bytes),
+		Ok(ImageMetadata { mime_type: ImageMimeType::Jpeg, width: 250, height: 100 })
+	);
+}
+
+#[test]
+fn parse_image_metadata_rejects_jpeg_without_frame() {
+	let mut bytes = JPEG_SOI.to_vec();
+	bytes.extend_from_slice(&[0xFF, 0xDA, 0x00, 0x02]);
+	assert_eq!(parse_image_metadata(&bytes), Err(ImageError::MalformedHeader));
+}
+
+#[cfg(test)]
+fn webp_header(fourcc: &[u8], data: &[u8]) -> sp_runtime::Vec<u8> {
+	let mut bytes = b"RIFF".to_vec();
+	bytes.extend_from_slice(&((data.len() + 12) as u32).to_le_bytes());
+	bytes.extend_from_slice(b"WEBP");
+	bytes.extend_from_slice(fourcc);
+	bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+	bytes.extend_from_slice(data);
+	bytes
+}
+
+#[test]
+fn parse_image_metadata_reads_webp_dimensions() {
+	let expected = Ok(ImageMetadata { mime_type: ImageMimeType::WebP, width: 250, height: 100 });
+
+	let lossy = webp_header(b"VP8 ", &[0, 0, 0, 0x9D, 0x01, 0x2A, 250, 0, 100, 0]);
+	assert_eq!(parse_image_metadata(&lossy), expected);
+
+	let bits: u32 = 249 | (99 << 14);
+	let mut lossless_data = vec![VP8L_SIGNATURE];
+	lossless_data.extend_from_slice(&bits.to_le_bytes());
+	assert_eq!(parse_image_metadata(&webp_header(b"VP8L", &lossless_data)), expected);
+
+	let extended = webp_header(b"VP8X", &[0, 0, 0, 0, 249, 0, 0, 99, 0, 0]);
+	assert_eq!(parse_image_metadata(&extended), expected);
+}
+
+#[test]
+fn parse_image_metadata_rejects_svg() {
+	assert_eq!(
+		parse_image_metadata(b"<svg xmlns=\"http://www.w3.org/2000/svg\"></svg>"),
+		Err(ImageError::SvgNotSupported)
+	);
+	assert_eq!(
+		parse_image_metadata(b"\xEF\xBB\xBF  <?xml version=\"1.0\"?><svg></svg>"),
+		Err(ImageError::SvgNotSupported)
+	);
+}
+
+#[test]
+fn parse_image_metadata_rejects_unknown_formats() {
+	assert_eq!(parse_image_metadata(b"GIF89a"), Err(ImageError::UnsupportedFormat));
+	assert_eq!(parse_image_metadata(b""), Err(ImageError::UnsupportedFormat));
+}
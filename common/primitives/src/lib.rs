@@ -11,8 +11,12 @@
 	rustdoc::invalid_codeblock_attributes,
 	missing_docs
 )]
+/// no_std Avro schema parsing and validation
+pub mod avro;
 /// Types for the Handles pallet
 pub mod handles;
+/// no_std image header parsing
+pub mod image;
 /// macros
 pub mod macros;
 /// Structs and traits for the Messages pallet.
@@ -23,6 +27,8 @@ pub mod msa;
 pub mod node;
 /// Structs and traits for parquet
 pub mod parquet;
+/// Types for passkey signatures and passkey keys registered to an MSA
+pub mod passkey;
 /// Structs and traits for better RPCs
 pub mod rpc;
 /// Structs and traits for the Schema pallet
@@ -97,6 +97,71 @@ impl Into<MessageResponse> for MessageResponseV2 {
 	}
 }
 
+/// A type for responding with a single Message in an RPC-call dependent on schema model
+/// IPFS, Parquet: { index, block_number, provider_msa_id, cid, payload_length }
+/// Avro, OnChain: { index, block_number, provider_msa_id, msa_id, payload }
+/// OffChain: { index, block_number, provider_msa_id, uri, content_hash, payload_length }
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct MessageResponseV3 {
+	/// Message source account id of the Provider. This may be the same id as contained in `msa_id`,
+	/// indicating that the original source MSA is acting as its own provider. An id differing from that
+	/// of `msa_id` indicates that `provider_msa_id` was delegated by `msa_id` to send this message on
+	/// its behalf.
+	pub provider_msa_id: MessageSourceId,
+	/// Index in block to get total order.
+	pub index: u16,
+	/// Block-number for which the message was stored.
+	pub block_number: BlockNumber,
+	///  Message source account id (the original source).
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none", default))]
+	pub msa_id: Option<MessageSourceId>,
+	/// Serialized data in a the schemas.
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "as_hex_option", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub payload: Option<Vec<u8>>,
+	/// The content address for an IPFS payload in Base32. Will always be CIDv1.
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "as_string_option", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub cid: Option<Vec<u8>>,
+	///  Offchain payload length (IPFS, OffChain).
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none", default))]
+	pub payload_length: Option<u32>,
+	/// The SchemaId of the schema that defines the payload format
+	pub schema_id: SchemaId,
+	/// The URI of an off-chain payload (OffChain).
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "as_string_option", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub uri: Option<Vec<u8>>,
+	/// Multihash of the content of an off-chain payload (OffChain).
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "as_hex_option", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub content_hash: Option<Vec<u8>>,
+}
+
+impl Into<MessageResponseV2> for MessageResponseV3 {
+	fn into(self) -> MessageResponseV2 {
+		MessageResponseV2 {
+			provider_msa_id: self.provider_msa_id,
+			index: self.index,
+			block_number: self.block_number,
+			msa_id: self.msa_id,
+			payload: self.payload,
+			cid: self.cid,
+			payload_length: self.payload_length,
+			schema_id: self.schema_id,
+		}
+	}
+}
+
 /// A type for requesting paginated messages.
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -155,6 +220,56 @@ impl<T> BlockPaginationResponse<T> {
 		}
 	}
 
+	/// Converts the items of the page, keeping its pagination
+	pub fn map_content<U, F>(self, f: F) -> BlockPaginationResponse<U>
+	where
+		F: FnMut(T) -> U,
+	{
+		BlockPaginationResponse {
+			content: self.content.into_iter().map(f).collect(),
+			has_next: self.has_next,
+			next_block: self.next_block,
+			next_index: self.next_index,
+		}
+	}
+
+	/// Builds a page from the items of each block in the requested range, visiting blocks in order.
+	/// `block_items` returns the items of a single block in index order.
+	pub fn from_blocks<F>(request: &BlockPaginationRequest, mut block_items: F) -> Self
+	where
+		F: FnMut(BlockNumber) -> Vec<T>,
+	{
+		let mut response = Self::new();
+		let mut from_index: u32 = request.from_index;
+
+		'block_loop: for block_number in request.from_block..request.to_block {
+			let list = block_items(block_number);
+
+			// Max messages in a block are constrained to MessageIndex (u16) by the storage,
+			// so this is a safe type coercion. Just to be safe, we'll trap in in debug builds
+			let list_size = list.len();
+			debug_assert!(list_size <= u16::MAX.into(), "unexpected number of messages in block");
+			let list_size: u32 = list_size as u32;
+
+			let iter = list.into_iter().skip(from_index.saturating_sub(1) as usize);
+			// all subsequent blocks in this call should start at index 0
+			from_index = 0;
+			for (i, item) in iter.enumerate() {
+				response.content.push(item);
+
+				if response.check_end_condition_and_set_next_pagination(
+					block_number,
+					i as u32,
+					list_size,
+					request,
+				) {
+					break 'block_loop;
+				}
+			}
+		}
+		response
+	}
+
 	/// Checks if we are at the end of the pagination
 	/// if we are, update the response with the correct next information
 	pub fn check_end_condition_and_set_next_pagination(
@@ -186,6 +301,37 @@ impl<T> BlockPaginationResponse<T> {
 	}
 }
 
+/// A notification sent to subscribers of the messages of a set of intents
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(tag = "event", rename_all = "snake_case"))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub enum MessageNotification<Hash> {
+	/// Messages stored in a block that became part of the best chain
+	Messages {
+		/// Hash of the block
+		block_hash: Hash,
+		/// Number of the block
+		block_number: BlockNumber,
+		/// Messages of the subscribed intents stored in the block, in index order per intent
+		messages: Vec<MessageResponseV3>,
+	},
+	/// A block previously notified with `Messages` is no longer part of the best chain,
+	/// so its messages should be discarded
+	Retracted {
+		/// Hash of the block
+		block_hash: Hash,
+		/// Number of the block
+		block_number: BlockNumber,
+	},
+	/// The chain has been finalized up to this block. Blocks at or below it are never retracted.
+	Finalized {
+		/// Hash of the block
+		block_hash: Hash,
+		/// Number of the block
+		block_number: BlockNumber,
+	},
+}
+
 #[cfg(test)]
 mod tests {
 	use crate::{
@@ -9,6 +9,7 @@ use sp_runtime::{
 	DispatchError, MultiSignature, RuntimeDebug,
 };
 extern crate alloc;
+use crate::image::ImageMetadata;
 pub use crate::schema::{IntentId, SchemaId};
 use alloc::vec::Vec;
 use serde::{ser::SerializeStruct, Serializer};
@@ -305,6 +306,110 @@ pub struct ProviderApplicationContext {
 	pub localized_logo_250_100_png_bytes: Option<Vec<u8>>,
 }
 
+/// RPC Response for getting ProviderApplicationContext including the logo formats and the
+/// suspension status
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Default)]
+pub struct ProviderApplicationContextV2 {
+	/// The default name associated with this entry
+	pub default_name: Vec<u8>,
+	/// The provider associated with the `key`
+	pub provider_id: ProviderId,
+	/// The default Application/Provider logo
+	pub default_logo_250_100_png_bytes: Option<Vec<u8>>,
+	/// The optional application id
+	pub application_id: Option<ApplicationIndex>,
+	/// The optional localized name
+	pub localized_name: Option<Vec<u8>>,
+	/// The optional localized logo bytes
+	pub localized_logo_250_100_png_bytes: Option<Vec<u8>>,
+	/// The MIME type and dimensions of the default logo, if uploaded
+	pub default_logo_metadata: Option<ImageMetadata>,
+	/// The MIME type and dimensions of the localized logo, if uploaded
+	pub localized_logo_metadata: Option<ImageMetadata>,
+	/// Whether the application has been suspended by governance
+	pub is_suspended: bool,
+}
+
+impl Into<ProviderApplicationContext> for ProviderApplicationContextV2 {
+	fn into(self) -> ProviderApplicationContext {
+		ProviderApplicationContext {
+			default_name: self.default_name,
+			provider_id: self.provider_id,
+			default_logo_250_100_png_bytes: self.default_logo_250_100_png_bytes,
+			application_id: self.application_id,
+			localized_name: self.localized_name,
+			localized_logo_250_100_png_bytes: self.localized_logo_250_100_png_bytes,
+		}
+	}
+}
+
+/// Position in the provider and application directory
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Copy, Decode, Encode, PartialEq, Eq, Default)]
+pub struct ProviderDirectoryCursor {
+	/// The provider of the last examined entry
+	pub provider_id: ProviderId,
+	/// The last examined application index of the provider, `None` for the provider itself
+	pub application_id: Option<ApplicationIndex>,
+}
+
+/// RPC Request for a page of the provider and application directory
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Default)]
+pub struct ProviderDirectoryRequest {
+	/// The position returned by the previous page, `None` to start from the beginning
+	pub start_after: Option<ProviderDirectoryCursor>,
+	/// The maximum number of provider and application entries examined for this page
+	pub page_size: u32,
+	/// Only include entries with a localized name for this locale, localized names and logo CIDs
+	/// are returned for it
+	pub locale: Option<Vec<u8>>,
+	/// Only include entries whose logo CID is in the approved logos
+	pub approved_logo_only: bool,
+}
+
+impl ProviderDirectoryRequest {
+	/// Hard limit on the number of provider and application entries examined for a single page
+	pub const MAX_PAGE_SIZE: u32 = 100;
+
+	/// Helper function for request validation.
+	/// * Page size should be greater than zero and not exceed MAX_PAGE_SIZE.
+	pub fn validate(&self) -> bool {
+		self.page_size > 0 && self.page_size <= Self::MAX_PAGE_SIZE
+	}
+}
+
+/// A provider or one of its applications in the provider directory
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Default)]
+pub struct ProviderDirectoryEntry {
+	/// The provider of the entry
+	pub provider_id: ProviderId,
+	/// The application id, `None` for the provider itself
+	pub application_id: Option<ApplicationIndex>,
+	/// The default name of the entry
+	pub default_name: Vec<u8>,
+	/// The localized name for the requested locale if any
+	pub localized_name: Option<Vec<u8>>,
+	/// The default logo CID if it is approved
+	pub default_logo_250_100_png_cid: Option<Vec<u8>>,
+	/// The localized logo CID for the requested locale if it is approved
+	pub localized_logo_250_100_png_cid: Option<Vec<u8>>,
+	/// Whether the application has been suspended by governance
+	pub is_suspended: bool,
+}
+
+/// RPC Response for a page of the provider and application directory
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Default)]
+pub struct ProviderDirectoryResponse {
+	/// Providers and applications matching the request filters
+	pub entries: Vec<ProviderDirectoryEntry>,
+	/// The value to use as `start_after` for the next page, `None` when there are no more pages
+	pub next_start_after: Option<ProviderDirectoryCursor>,
+}
+
 /// The pointer value for the Signature Registry
 #[derive(MaxEncodedLen, TypeInfo, Debug, Clone, Decode, Encode, PartialEq, Eq)]
 pub struct SignatureRegistryPointer<BlockNumber> {
@@ -1,4 +1,4 @@
-use crate::msa::MessageSourceId;
+use crate::{msa::MessageSourceId, node::BlockNumber};
 use numtoa::NumToA;
 use parity_scale_codec::{Decode, Encode};
 #[cfg(feature = "std")]
@@ -62,6 +62,21 @@ pub fn get_msa_account_storage_key_name(msa_id: MessageSourceId) -> Vec<u8> {
 	[MSA_ACCOUNT_STORAGE_NAME_PREFIX, msa_id.numtoa(10, &mut buff)].concat()
 }
 
+/// Offchain index prefix for the messages sent by a provider
+pub const MESSAGES_BY_PROVIDER_INDEX_PREFIX: &[u8; 29] = b"Messages::index::provider-msa";
+/// Offchain index prefix for the messages sent on behalf of an MSA
+pub const MESSAGES_BY_MSA_INDEX_PREFIX: &[u8; 20] = b"Messages::index::msa";
+/// Offchain index key of the `(IntentId, MessageIndex)` entries stored for an MSA in a block.
+/// The parent hash keeps the entries of blocks on different forks apart.
+pub fn get_message_index_key<Hash: Encode>(
+	prefix: &[u8],
+	msa_id: MessageSourceId,
+	block_number: BlockNumber,
+	parent_hash: &Hash,
+) -> Vec<u8> {
+	[prefix, &msa_id.encode(), &block_number.encode(), &parent_hash.encode()].concat()
+}
+
 /// Locks the execution of the function
 #[derive(Debug)]
 pub enum LockStatus {
@@ -106,6 +121,16 @@ mod tests {
 		assert_eq!(result, b"Msa::ofw::keys::2000000".to_vec());
 	}
 
+	#[test]
+	fn get_message_index_key_should_return_expected_value() {
+		let result = get_message_index_key(MESSAGES_BY_MSA_INDEX_PREFIX, 2, 3, &[4u8; 4]);
+		assert_eq!(
+			result,
+			[b"Messages::index::msa".as_slice(), &[2, 0, 0, 0, 0, 0, 0, 0], &[3, 0, 0, 0], &[4; 4]]
+				.concat()
+		);
+	}
+
 	#[test]
 	fn get_index_for_not_set_should_return_none() {
 		let (offchain, _state) = testing::TestOffchainExt::new();
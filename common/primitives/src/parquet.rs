@@ -1,5 +1,5 @@
 extern crate alloc;
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, string::String, vec::Vec};
 
 /// Importing all base types
 pub mod base;
@@ -16,7 +16,369 @@ pub mod temporal;
 /// Importing all labels
 pub mod types;
 
-use crate::parquet::column::ParquetColumn;
+use crate::parquet::{
+	base::ParquetBaseType,
+	column::ParquetColumn,
+	column_compression_codec::ColumnCompressionCodec,
+	numeric::ParquetNumericType,
+	string::ParquetStringType,
+	temporal::{ParquetTemporalType, ParquetTimeUnit},
+	types::ParquetType,
+};
 
 /// Type for Parquet files. Files are just lists of columns.
 pub type ParquetModel = Vec<ParquetColumn>;
+
+/// The most columns a Parquet model may define
+pub const PARQUET_MAX_COLUMNS: usize = 256;
+
+/// Error enum for Parquet model validation, one variant per class of failure
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetError {
+	/// The model is not a JSON list of columns
+	InvalidJson,
+	/// The model has no columns or more than [`PARQUET_MAX_COLUMNS`]
+	InvalidColumnCount,
+	/// A column name is empty or contains whitespace, control characters or one of `;{}(),`
+	InvalidColumnName,
+	/// Two columns have the same name
+	DuplicateColumnName,
+	/// An `INTEGER` bit width is not 8, 16, 32 or 64
+	InvalidIntegerWidth,
+	/// A `DECIMAL` precision is zero or smaller than its scale
+	InvalidDecimal,
+	/// A `FIXED_LEN_BYTE_ARRAY` column can not declare its length
+	UnsupportedType,
+	/// Bloom filters are not supported for `BOOLEAN` columns
+	UnsupportedBloomFilter,
+	/// The compression codec is deprecated and not implemented by common Parquet writers
+	UnsupportedCompressionCodec,
+}
+
+/// Physical types a Parquet column is stored as
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PhysicalType {
+	Boolean,
+	Int32,
+	Int64,
+	Float,
+	Double,
+	Binary,
+	FixedLenByteArray(u8),
+}
+
+/// Parses a Parquet model and validates it with [`validate_parquet_model`].
+pub fn parse_parquet_model(model: &[u8]) -> Result<ParquetModel, ParquetError> {
+	let columns: ParquetModel =
+		serde_json::from_slice(model).map_err(|_| ParquetError::InvalidJson)?;
+	validate_parquet_model(&columns)?;
+	Ok(columns)
+}
+
+/// Checks that a Parquet model can be written by Parquet writers: column names are unique and
+/// usable in a Parquet schema, every logical type can be stored in a physical type, bloom
+/// filters are only requested for types that support them and every compression codec is
+/// supported.
+pub fn validate_parquet_model(model: &ParquetModel) -> Result<(), ParquetError> {
+	if model.is_empty() || model.len() > PARQUET_MAX_COLUMNS {
+		return Err(ParquetError::InvalidColumnCount)
+	}
+	let mut names = BTreeSet::new();
+	for column in model {
+		if !is_valid_column_name(column.name()) {
+			return Err(ParquetError::InvalidColumnName)
+		}
+		if !names.insert(column.name()) {
+			return Err(ParquetError::DuplicateColumnName)
+		}
+		let (physical_type, _) = column_types(column.column_type())?;
+		// the hash of a value is taken over its plain encoding, which bit-packs booleans
+		if column.bloom_filter() && physical_type == PhysicalType::Boolean {
+			return Err(ParquetError::UnsupportedBloomFilter)
+		}
+		// LZO is deprecated by the Parquet format in favor of LZ4_RAW and ZSTD
+		if *column.compression() == ColumnCompressionCodec::Lzo {
+			return Err(ParquetError::UnsupportedCompressionCodec)
+		}
+	}
+	Ok(())
+}
+
+/// Builds the schema of a Parquet model in the message type format read by Parquet writers,
+/// e.g. `message schema { required int64 fromId (INTEGER(64,false)); }`, for off-chain writers.
+/// Arrow writers can convert it with `parquet::arrow::parquet_to_arrow_schema`.
+/// Compression and bloom filters are writer properties and are not part of the schema.
+pub fn parquet_schema_message(model: &ParquetModel) -> Result<String, ParquetError> {
+	validate_parquet_model(model)?;
+	let mut message = String::from("message schema {\n");
+	for column in model {
+		let (physical_type, logical_type) = column_types(column.column_type())?;
+		message.push_str(if column.is_optional() { "  optional " } else { "  required " });
+		match physical_type {
+			PhysicalType::Boolean => message.push_str("boolean"),
+			PhysicalType::Int32 => message.push_str("int32"),
+			PhysicalType::Int64 => message.push_str("int64"),
+			PhysicalType::Float => message.push_str("float"),
+			PhysicalType::Double => message.push_str("double"),
+			PhysicalType::Binary => message.push_str("binary"),
+			PhysicalType::FixedLenByteArray(length) =>
+				message.push_str(&alloc::format!("fixed_len_byte_array({length})")),
+		}
+		message.push(' ');
+		message.push_str(column.name());
+		if let Some(logical_type) = logical_type {
+			message.push_str(" (");
+			message.push_str(&logical_type);
+			message.push(')');
+		}
+		message.push_str(";\n");
+	}
+	message.push('}');
+	Ok(message)
+}
+
+fn is_valid_column_name(name: &str) -> bool {
+	!name.is_empty() &&
+		!name
+			.chars()
+			.any(|c| c.is_whitespace() || c.is_control() || ";{}(),".contains(c))
+}
+
+/// The physical type a column is stored as, and its logical type annotation if any
+fn column_types(column_type: &ParquetType) -> Result<(PhysicalType, Option<String>), ParquetError> {
+	let types = match column_type {
+		ParquetType::BaseType(base_type) => {
+			let physical_type = match base_type {
+				ParquetBaseType::Boolean => PhysicalType::Boolean,
+				ParquetBaseType::Int32 => PhysicalType::Int32,
+				ParquetBaseType::Int64 => PhysicalType::Int64,
+				ParquetBaseType::Float => PhysicalType::Float,
+				ParquetBaseType::Double => PhysicalType::Double,
+				ParquetBaseType::ByteArray => PhysicalType::Binary,
+				ParquetBaseType::FixedLenByteArray => return Err(ParquetError::UnsupportedType),
+			};
+			(physical_type, None)
+		},
+		ParquetType::StringType(ParquetStringType::String) =>
+			(PhysicalType::Binary, Some(String::from("STRING"))),
+		ParquetType::StringType(ParquetStringType::UUID) =>
+			(PhysicalType::FixedLenByteArray(16), Some(String::from("UUID"))),
+		ParquetType::NumericType(ParquetNumericType::Integer(integer)) => {
+			let physical_type = match integer.bit_width {
+				8 | 16 | 32 => PhysicalType::Int32,
+				64 => PhysicalType::Int64,
+				_ => return Err(ParquetError::InvalidIntegerWidth),
+			};
+			(physical_type, Some(alloc::format!("INTEGER({},{})", integer.bit_width, integer.sign)))
+		},
+		ParquetType::NumericType(ParquetNumericType::Decimal(decimal)) => {
+			if decimal.precision() == 0 || decimal.scale() > decimal.precision() {
+				return Err(ParquetError::InvalidDecimal)
+			}
+			let physical_type = match decimal.precision() {
+				1..=9 => PhysicalType::Int32,
+				10..=18 => PhysicalType::Int64,
+				_ => PhysicalType::Binary,
+			};
+			(
+				physical_type,
+				Some(alloc::format!("DECIMAL({},{})", decimal.precision(), decimal.scale())),
+			)
+		},
+		ParquetType::TemporalType(ParquetTemporalType::Date) =>
+			(PhysicalType::Int32, Some(String::from("DATE"))),
+		ParquetType::TemporalType(ParquetTemporalType::Interval) =>
+			(PhysicalType::FixedLenByteArray(12), Some(String::from("INTERVAL"))),
+		ParquetType::TemporalType(ParquetTemporalType::Time(time)) => {
+			let physical_type = match time.unit() {
+				ParquetTimeUnit::Millis => PhysicalType::Int32,
+				ParquetTimeUnit::Micros | ParquetTimeUnit::Nanos => PhysicalType::Int64,
+			};
+			(
+				physical_type,
+				Some(alloc::format!(
+					"TIME({},{})",
+					time_unit_name(time.unit()),
+					time.is_adjusted_to_utc()
+				)),
+			)
+		},
+		ParquetType::TemporalType(ParquetTemporalType::Timestamp(timestamp)) => (
+			PhysicalType::Int64,
+			Some(alloc::format!(
+				"TIMESTAMP({},{})",
+				time_unit_name(timestamp.unit()),
+				timestamp.is_adjusted_to_utc()
+			)),
+		),
+	};
+	Ok(types)
+}
+
+fn time_unit_name(unit: &ParquetTimeUnit) -> &'static str {
+	match unit {
+		ParquetTimeUnit::Millis => "MILLIS",
+		ParquetTimeUnit::Micros => "MICROS",
+		ParquetTimeUnit::Nanos => "NANOS",
+	}
+}
+
+/// Reason a reader Parquet model can not read files written with a writer Parquet model
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParquetIncompatibility {
+	/// A required reader column is absent from the writer model
+	MissingColumn,
+	/// A column has different type labels in the reader and writer models
+	ColumnTypeMismatch,
+	/// A required reader column is optional in the writer model and may contain nulls
+	OptionalColumn,
+}
+
+/// Checks that files written with the `writer` model can be read with the `reader` model.
+/// Columns are matched by name; writer columns unknown to the reader are ignored and optional
+/// reader columns absent from the writer read as null.
+pub fn check_parquet_compatibility(
+	reader: &ParquetModel,
+	writer: &ParquetModel,
+) -> Result<(), ParquetIncompatibility> {
+	for reader_column in reader {
+		match writer.iter().find(|column| column.name() == reader_column.name()) {
+			Some(writer_column) => {
+				if writer_column.column_type() != reader_column.column_type() {
+					return Err(ParquetIncompatibility::ColumnTypeMismatch)
+				}
+				if writer_column.is_optional() && !reader_column.is_optional() {
+					return Err(ParquetIncompatibility::OptionalColumn)
+				}
+			},
+			None if reader_column.is_optional() => (),
+			None => return Err(ParquetIncompatibility::MissingColumn),
+		}
+	}
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	fn parse(model: &str) -> Result<ParquetModel, ParquetError> {
+		parse_parquet_model(model.as_bytes())
+	}
+
+	fn column(name: &str, column_type: &str, compression: &str, bloom_filter: bool) -> String {
+		alloc::format!(
+			r#"{{"name":"{name}","column_type":{column_type},"compression":"{compression}","bloom_filter":{bloom_filter}}}"#
+		)
+	}
+
+	#[test]
+	fn parse_parquet_model_accepts_valid_models() {
+		let model = alloc::format!(
+			"[{},{},{},{},{}]",
+			column(
+				"announcementType",
+				r#"{"INTEGER":{"bit_width":32,"sign":true}}"#,
+				"GZIP",
+				false
+			),
+			column("contentHash", r#""BYTE_ARRAY""#, "SNAPPY", true),
+			column("price", r#"{"DECIMAL":{"scale":2,"precision":20}}"#, "BROTLI", true),
+			column(
+				"createdAt",
+				r#"{"TIMESTAMP":{"is_adjusted_to_utc":true,"unit":"MILLIS"}}"#,
+				"LZ4_RAW",
+				false
+			),
+			column("deleted", r#""BOOLEAN""#, "UNCOMPRESSED", false),
+		);
+		assert_eq!(parse(&model).map(|columns| columns.len()), Ok(5));
+	}
+
+	#[test]
+	fn parse_parquet_model_rejects_invalid_models() {
+		let boolean = r#""BOOLEAN""#;
+		let cases = [
+			(String::from(r#"{"name":"a"}"#), ParquetError::InvalidJson),
+			(String::from("[]"), ParquetError::InvalidColumnCount),
+			(
+				alloc::format!("[{}]", column("", boolean, "GZIP", false)),
+				ParquetError::InvalidColumnName,
+			),
+			(
+				alloc::format!("[{}]", column("a b", boolean, "GZIP", false)),
+				ParquetError::InvalidColumnName,
+			),
+			(
+				alloc::format!(
+					"[{},{}]",
+					column("a", boolean, "GZIP", false),
+					column("a", r#""INT32""#, "GZIP", false)
+				),
+				ParquetError::DuplicateColumnName,
+			),
+			(
+				alloc::format!(
+					"[{}]",
+					column("a", r#"{"INTEGER":{"bit_width":12,"sign":true}}"#, "GZIP", false)
+				),
+				ParquetError::InvalidIntegerWidth,
+			),
+			(
+				alloc::format!(
+					"[{}]",
+					column("a", r#"{"DECIMAL":{"scale":4,"precision":2}}"#, "GZIP", false)
+				),
+				ParquetError::InvalidDecimal,
+			),
+			(
+				alloc::format!("[{}]", column("a", r#""FIXED_LEN_BYTE_ARRAY""#, "GZIP", false)),
+				ParquetError::UnsupportedType,
+			),
+			(
+				alloc::format!("[{}]", column("a", boolean, "GZIP", true)),
+				ParquetError::UnsupportedBloomFilter,
+			),
+			(
+				alloc::format!("[{}]", column("a", boolean, "LZO", false)),
+				ParquetError::UnsupportedCompressionCodec,
+			),
+			(
+				alloc::format!(
+					"[{},{}]",
+					column("a", boolean, "ZSTD", false),
+					column("b", r#""INT32""#, "LZO", false)
+				),
+				ParquetError::UnsupportedCompressionCodec,
+			),
+		];
+		for (model, expected) in cases {
+			assert_eq!(parse(&model), Err(expected), "{model}");
+		}
+
+		let too_many = (0..=PARQUET_MAX_COLUMNS)
+			.map(|i| column(&alloc::format!("c{i}"), boolean, "GZIP", false))
+			.collect::<Vec<_>>()
+			.join(",");
+		assert_eq!(parse(&alloc::format!("[{too_many}]")), Err(ParquetError::InvalidColumnCount));
+	}
+
+	#[test]
+	fn parquet_schema_message_describes_physical_and_logical_types() {
+		let model = parse(&alloc::format!(
+			r#"[{},{},{},{}]"#,
+			column("fromId", r#"{"INTEGER":{"bit_width":64,"sign":false}}"#, "GZIP", true),
+			column("url", r#""STRING""#, "GZIP", false),
+			column("id", r#""UUID""#, "GZIP", true),
+			r#"{"name":"at","column_type":{"TIME":{"is_adjusted_to_utc":false,"unit":"MILLIS"}},"compression":"GZIP","bloom_filter":false,"optional":true}"#,
+		))
+		.expect("model should be valid");
+
+		assert_eq!(
+			parquet_schema_message(&model),
+			Ok(String::from(
+				"message schema {\n  required int64 fromId (INTEGER(64,false));\n  required binary url (STRING);\n  required fixed_len_byte_array(16) id (UUID);\n  optional int32 at (TIME(MILLIS,false));\n}"
+			))
+		);
+	}
+}
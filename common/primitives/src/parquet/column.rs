@@ -37,4 +37,28 @@ impl ParquetColumn {
 			optional: optional.then_some(true),
 		}
 	}
+	/// The label for what this column represents
+	pub fn name(&self) -> &str {
+		&self.name
+	}
+
+	/// Parquet type labels
+	pub fn column_type(&self) -> &ParquetType {
+		&self.column_type
+	}
+
+	/// Compression for column
+	pub fn compression(&self) -> &ColumnCompressionCodec {
+		&self.compression
+	}
+
+	/// Whether or not to use a bloom filter
+	pub fn bloom_filter(&self) -> bool {
+		self.bloom_filter
+	}
+
+	/// Whether the column may contain null values
+	pub fn is_optional(&self) -> bool {
+		self.optional.unwrap_or(false)
+	}
 }
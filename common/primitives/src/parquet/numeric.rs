@@ -27,3 +27,15 @@ pub struct ParquetDecimal {
 	// Note: in theory, precision is unbounded. But a u8 should be fine for practical cases
 	precision: u8,
 }
+
+impl ParquetDecimal {
+	/// The number of digits after the decimal point
+	pub fn scale(&self) -> u8 {
+		self.scale
+	}
+
+	/// The total number of digits
+	pub fn precision(&self) -> u8 {
+		self.precision
+	}
+}
@@ -21,6 +21,18 @@ pub struct ParquetTime {
 	unit: ParquetTimeUnit,
 }
 
+impl ParquetTime {
+	/// Whether the time is normalized to UTC
+	pub fn is_adjusted_to_utc(&self) -> bool {
+		self.is_adjusted_to_utc
+	}
+
+	/// The precision of the time
+	pub fn unit(&self) -> &ParquetTimeUnit {
+		&self.unit
+	}
+}
+
 /// Parquet timestamps: <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md>
 #[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
 pub struct ParquetTimestamp {
@@ -28,10 +40,22 @@ pub struct ParquetTimestamp {
 	unit: ParquetTimeUnit,
 }
 
+impl ParquetTimestamp {
+	/// Whether the timestamp is normalized to UTC
+	pub fn is_adjusted_to_utc(&self) -> bool {
+		self.is_adjusted_to_utc
+	}
+
+	/// The precision of the timestamp
+	pub fn unit(&self) -> &ParquetTimeUnit {
+		&self.unit
+	}
+}
+
 /// Units of time
 #[derive(Clone, PartialEq, Debug, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "UPPERCASE")]
-enum ParquetTimeUnit {
+pub enum ParquetTimeUnit {
 	/// Millisecond precision for time: <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#time>
 	Millis,
 	/// Microsecond precision for time: <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#time>
@@ -1,7 +1,7 @@
 extern crate alloc;
-use crate::impl_codec_bitflags;
 #[cfg(feature = "std")]
 use crate::utils;
+use crate::{avro::AvroDecodeError, impl_codec_bitflags, node::BlockNumber};
 use alloc::{vec, vec::Vec};
 use enumflags2::{bitflags, BitFlags};
 use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, EncodeLike, MaxEncodedLen};
@@ -98,6 +98,14 @@ pub enum SchemaSetting {
 	/// Intent may enforce signature requirement on payload.
 	/// Applied to Intents of type `PayloadLocation::Itemized` or `PayloadLocation::Paginated`.
 	SignatureRequired,
+	/// Intent setting to reject payloads that do not decode against the `AvroBinary` model of
+	/// their schema.
+	/// Applied to Intents of type `PayloadLocation::OnChain`, `PayloadLocation::Itemized` or
+	/// `PayloadLocation::Paginated`.
+	ValidatePayload,
+	/// Intent setting allowing existing items of an `AppendOnly` Intent to be updated in place.
+	/// Applied to Intents of type `PayloadLocation::Itemized` that are `AppendOnly`.
+	AllowUpdates,
 }
 
 /// Wrapper type for `BitFlags<IntentSetting>` that implements `Codec`.
@@ -134,6 +142,103 @@ pub enum SchemaStatus {
 	Unsupported,
 }
 
+impl SchemaStatus {
+	/// A schema can only move forward through its lifecycle: `Active` -> `Deprecated` -> `Unsupported`
+	pub fn can_transition_to(&self, next: SchemaStatus) -> bool {
+		matches!(
+			(self, next),
+			(SchemaStatus::Active, SchemaStatus::Deprecated) |
+				(SchemaStatus::Active, SchemaStatus::Unsupported) |
+				(SchemaStatus::Deprecated, SchemaStatus::Unsupported)
+		)
+	}
+}
+
+/// Compatibility required between consecutive schemas of an Intent
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+	Default,
+)]
+pub enum CompatibilityPolicy {
+	/// New schemas are not checked against previous ones
+	#[default]
+	None,
+	/// A new schema must be able to read data written with the previous schema
+	Backward,
+	/// Data written with a new schema must be readable with the previous schema
+	Forward,
+	/// Both `Backward` and `Forward`
+	Full,
+}
+
+impl CompatibilityPolicy {
+	/// Whether the new schema must be able to read data written with the previous schema
+	pub fn requires_backward(&self) -> bool {
+		matches!(self, CompatibilityPolicy::Backward | CompatibilityPolicy::Full)
+	}
+
+	/// Whether the previous schema must be able to read data written with the new schema
+	pub fn requires_forward(&self) -> bool {
+		matches!(self, CompatibilityPolicy::Forward | CompatibilityPolicy::Full)
+	}
+}
+
+/// Message retention period of an Intent
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct IntentRetention {
+	/// Number of blocks messages of the Intent are retained for
+	pub period: BlockNumber,
+	/// Block the Intent was first given a retention period in; messages stored more than
+	/// `period` blocks before it are not pruned
+	pub since: BlockNumber,
+}
+
+/// Limits on the stateful storage each MSA may use for an Intent
+#[derive(
+	Copy,
+	Clone,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+	Serialize,
+	Deserialize,
+)]
+pub struct StorageQuota {
+	/// Maximum number of bytes stored, counting the full encoded size of each page
+	pub max_bytes: u32,
+	/// Maximum number of pages stored
+	pub max_pages: u32,
+}
+
 /// RPC response structure for an IntentGroup
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -158,6 +263,97 @@ pub struct IntentResponse {
 	pub schema_ids: Option<Vec<SchemaId>>,
 }
 
+/// RPC response structure for an Intent including the status of its Schemas
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct IntentResponseV2 {
+	/// The unique identifier for this Intent
+	pub intent_id: IntentId,
+	/// The payload location
+	pub payload_location: PayloadLocation,
+	/// settings for the Intent
+	pub settings: Vec<IntentSetting>,
+	/// The list of currently-supported SchemaIds for this Intent
+	pub schema_ids: Option<Vec<SchemaId>>,
+	/// All Schemas implementing this Intent with their status, in order of SchemaId
+	pub schemas: Option<Vec<IntentSchemaResponse>>,
+}
+
+impl Into<IntentResponse> for IntentResponseV2 {
+	fn into(self) -> IntentResponse {
+		IntentResponse {
+			intent_id: self.intent_id,
+			payload_location: self.payload_location,
+			settings: self.settings,
+			schema_ids: self.schema_ids,
+		}
+	}
+}
+
+/// RPC response structure for a Schema implementing an Intent
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct IntentSchemaResponse {
+	/// The unique identifier for this Schema
+	pub schema_id: SchemaId,
+	/// The status of this Schema (Active, Deprecated, Unsupported)
+	pub status: SchemaStatus,
+}
+
+/// A type for requesting a page of Intents, Schemas or IntentGroups in order of id.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct EntityPaginationRequest {
+	/// Starting id (inclusive).
+	pub from_id: u16,
+	/// The number of entities in a single page.
+	pub page_size: u16,
+	/// Only include entities whose `protocol.descriptor` name starts with this prefix (e.g. `dsnp.`).
+	/// Schemas are matched by the name of their Intent.
+	#[cfg_attr(
+		feature = "std",
+		serde(with = "as_string_option", skip_serializing_if = "Option::is_none", default)
+	)]
+	pub namespace_prefix: Option<Vec<u8>>,
+	/// Only include Intents and Schemas with this payload location. Not applied to IntentGroups.
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none", default))]
+	pub payload_location: Option<PayloadLocation>,
+	/// Only include Schemas with this status. Not applied to Intents and IntentGroups.
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none", default))]
+	pub status: Option<SchemaStatus>,
+}
+
+impl EntityPaginationRequest {
+	/// Hard limit on the number of items per page that can be returned
+	pub const MAX_PAGE_SIZE: u16 = 1000;
+
+	/// Helper function for request validation.
+	/// * Page size should be between 1 and MAX_PAGE_SIZE.
+	pub fn validate(&self) -> bool {
+		self.page_size > 0 && self.page_size <= Self::MAX_PAGE_SIZE
+	}
+}
+
+/// A type for responding with a page of Intents, Schemas or IntentGroups.
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct EntityPaginationResponse<T> {
+	/// Collection of entities for a given [`EntityPaginationRequest`], in order of id.
+	pub content: Vec<T>,
+	/// Flag to indicate the end of paginated entities.
+	pub has_next: bool,
+	#[cfg_attr(feature = "std", serde(skip_serializing_if = "Option::is_none"))]
+	/// The `from_id` for the following request.
+	pub next_id: Option<u16>,
+}
+
+impl<T> EntityPaginationResponse<T> {
+	/// Generates a new empty Pagination response
+	pub const fn new() -> EntityPaginationResponse<T> {
+		EntityPaginationResponse { content: vec![], has_next: false, next_id: None }
+	}
+}
+
 /// RPC Response form for a Schema
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
@@ -224,7 +420,6 @@ pub struct SchemaInfoResponse {
 	/// The IntentId of the Intent that this Schema implements
 	pub intent_id: IntentId,
 	/// The status of this Schema (Active, Deprecated, Unsupported)
-	/// Currently has no runtime effect; intended for future use.
 	pub status: SchemaStatus,
 }
 
@@ -276,6 +471,33 @@ pub trait SchemaProvider<SchemaId> {
 
 	/// Gets the Intent associated with this `IntentId`, if any
 	fn get_intent_by_id(intent_id: IntentId) -> Option<IntentResponse>;
+
+	/// Checks that a payload decodes against the model of the schema with this `SchemaId`
+	fn validate_payload(schema_id: SchemaId, payload: &[u8]) -> Result<(), PayloadValidationError>;
+
+	/// Gets the message retention of at most `limit` Intents that have one, in storage order
+	/// continuing after the Intent `start_after`
+	fn get_intent_retention_periods(
+		start_after: Option<IntentId>,
+		limit: u32,
+	) -> Vec<(IntentId, IntentRetention)>;
+
+	/// Gets the stateful storage quota of the Intent with this `IntentId`, if it has one
+	fn get_intent_storage_quota(intent_id: IntentId) -> Option<StorageQuota>;
+}
+
+/// Reason a payload was rejected when checked against its schema
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub enum PayloadValidationError {
+	/// The schema does not exist
+	SchemaNotFound,
+	/// Only `AvroBinary` models can be used to validate payloads
+	UnsupportedModelType,
+	/// The schema model is not a valid Avro schema
+	InvalidModel,
+	/// The payload does not decode against the schema model
+	InvalidPayload(AvroDecodeError),
 }
 
 /// This allows other Pallets to check the validity of schema ids.
@@ -336,6 +558,31 @@ pub struct SchemaVersionResponse {
 	pub schema_id: SchemaId,
 }
 
+/// RPC Response from a schema name query including the status of each Schema
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct SchemaVersionResponseV2 {
+	/// Schema name in following format: namespace.descriptor
+	#[cfg_attr(feature = "std", serde(with = "as_string"))]
+	pub schema_name: Vec<u8>,
+	/// The version for this schema
+	pub schema_version: SchemaVersion,
+	/// The unique identifier for this Schema
+	pub schema_id: SchemaId,
+	/// The status of this Schema (Active, Deprecated, Unsupported)
+	pub status: SchemaStatus,
+}
+
+impl Into<SchemaVersionResponse> for SchemaVersionResponseV2 {
+	fn into(self) -> SchemaVersionResponse {
+		SchemaVersionResponse {
+			schema_name: self.schema_name,
+			schema_version: self.schema_version,
+			schema_id: self.schema_id,
+		}
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -346,10 +593,22 @@ mod tests {
 		assert_eq!(settings.get_enabled(), BitFlags::EMPTY);
 	}
 
+	#[test]
+	fn schema_status_only_transitions_forward() {
+		assert!(SchemaStatus::Active.can_transition_to(SchemaStatus::Deprecated));
+		assert!(SchemaStatus::Active.can_transition_to(SchemaStatus::Unsupported));
+		assert!(SchemaStatus::Deprecated.can_transition_to(SchemaStatus::Unsupported));
+		assert!(!SchemaStatus::Deprecated.can_transition_to(SchemaStatus::Active));
+		assert!(!SchemaStatus::Unsupported.can_transition_to(SchemaStatus::Deprecated));
+		assert!(!SchemaStatus::Active.can_transition_to(SchemaStatus::Active));
+	}
+
 	#[test]
 	fn intent_settings_set_from_all_enabled_check() {
 		let settings = IntentSettings::from(BitFlags::ALL);
 		assert!(settings.is_enabled(IntentSetting::AppendOnly));
 		assert!(settings.is_enabled(IntentSetting::SignatureRequired));
+		assert!(settings.is_enabled(IntentSetting::ValidatePayload));
+		assert!(settings.is_enabled(IntentSetting::AllowUpdates));
 	}
 }
@@ -22,11 +22,14 @@ use sp_runtime::{
 };
 extern crate alloc;
 use crate::{msa::H160, utils::to_abi_compatible_number};
-use alloc::boxed::Box;
+use alloc::{boxed::Box, vec::Vec};
 
 /// Ethereum message prefix eip-191
 const ETHEREUM_MESSAGE_PREFIX: &[u8; 26] = b"\x19Ethereum Signed Message:\n";
 
+/// Lowercase hex alphabet used to build the text representation of a `personal_sign` payload
+const HEX_ALPHABET: &[u8; 16] = b"0123456789abcdef";
+
 /// A trait that allows mapping of raw bytes to AccountIds
 pub trait AccountAddressMapper<AccountId> {
 	/// mapping to the desired address
@@ -354,6 +357,23 @@ fn eth_message_hash(message: &[u8]) -> [u8; 32] {
 	sp_io::hashing::keccak_256(concatenated.as_slice())
 }
 
+/// returns the `0x` prefixed lowercase hex text of `message`
+/// Many mobile and hardware wallets only expose `personal_sign` and treat a hex payload as a
+/// UTF-8 string, so the signed message is the hex text itself and not the decoded bytes.
+fn to_prefixed_hex_text(message: &[u8]) -> Vec<u8> {
+	let mut hex_text = Vec::with_capacity(2 + message.len() * 2);
+	hex_text.extend_from_slice(b"0x");
+	for byte in message {
+		hex_text.push(HEX_ALPHABET[(byte >> 4) as usize]);
+		hex_text.push(HEX_ALPHABET[(byte & 0x0f) as usize]);
+	}
+	hex_text
+}
+
+/// Accepted encodings for ethereum compatible signatures are
+/// 1. EIP-191 (`personal_sign`) prefixed message bytes
+/// 2. PolkadotJs raw payload or EIP-712 encoded message, hashed with keccak-256
+/// 3. EIP-191 (`personal_sign`) prefixed `0x` hex text of the message bytes
 fn check_ethereum_signature<L: Lazy<[u8]>>(
 	signature: &ecdsa::Signature,
 	mut msg: L,
@@ -372,7 +392,13 @@ fn check_ethereum_signature<L: Lazy<[u8]>>(
 	// PolkadotJs raw payload signatures
 	// or Ethereum based EIP-712 compatible signatures
 	let hashed = sp_io::hashing::keccak_256(msg.get());
-	verify_signature(signature.as_ref(), &hashed, signer)
+	if verify_signature(signature.as_ref(), &hashed, signer) {
+		return true
+	}
+
+	// signature of ethereum prefixed hex text eip-191
+	let hex_text_prefixed = eth_message_hash(&to_prefixed_hex_text(msg.get()));
+	verify_signature(signature.as_ref(), &hex_text_prefixed, signer)
 }
 
 /// returns the ethereum encoded prefix and domain separator for EIP-712 signatures
@@ -420,6 +446,7 @@ mod tests {
 		signatures::{UnifiedSignature, UnifiedSigner},
 	};
 	use impl_serde::serialize::from_hex;
+	use parity_scale_codec::Encode;
 	use sp_core::{ecdsa, sr25519, Pair};
 	use sp_runtime::{
 		traits::{IdentifyAccount, Verify},
@@ -458,6 +485,71 @@ mod tests {
 		assert!(unified_signature.verify(&payload[..], &unified_signer.into_account()));
 	}
 
+	#[test]
+	fn ethereum_personal_sign_eip191_signatures_over_scale_encoded_payload_should_work() {
+		let payload = ClaimHandlePayload { base_handle: b"Alice".to_vec(), expiration: 100u32 };
+		let encoded_payload = payload.encode();
+
+		// following signature is generated via `personal_sign` over the raw payload bytes using
+		// the well-known development key 0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80
+		let signature_raw = from_hex("0x97f0780be536a523abb1103925464f3f457c23184fff2783c50f8589e20727e859b643a26c1c9ea25421056f47c318ac570eab68578f527108e6670090c2a6671c").expect("Should convert");
+		let unified_signature = UnifiedSignature::from(ecdsa::Signature::from_raw(
+			signature_raw.try_into().expect("should convert"),
+		));
+
+		// 0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266 is the eth address associated with this key
+		let public_key = ecdsa::Public::from_raw(
+			from_hex("0x038318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75")
+				.expect("should convert")
+				.try_into()
+				.expect("invalid size"),
+		);
+		let unified_signer = UnifiedSigner::from(public_key);
+		assert!(unified_signature.verify(&encoded_payload[..], &unified_signer.into_account()));
+	}
+
+	#[test]
+	fn ethereum_personal_sign_eip191_signatures_over_hex_text_payload_should_work() {
+		let payload = ClaimHandlePayload { base_handle: b"Alice".to_vec(), expiration: 100u32 };
+		let encoded_payload = payload.encode();
+
+		// following signature is generated via `personal_sign` over the UTF-8 text
+		// "0x14416c69636564000000" using the same development key as above
+		let signature_raw = from_hex("0x0eefeb690924bcb8a0e082082c0b10b8f3c253af874c3946a48039a4430dcb2975aeb6bfc471f6a2fb20ca8353ab24888028ed2fb6b1e10efd9881208ab2b6291b").expect("Should convert");
+		let unified_signature = UnifiedSignature::from(ecdsa::Signature::from_raw(
+			signature_raw.try_into().expect("should convert"),
+		));
+
+		let public_key = ecdsa::Public::from_raw(
+			from_hex("0x038318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75")
+				.expect("should convert")
+				.try_into()
+				.expect("invalid size"),
+		);
+		let unified_signer = UnifiedSigner::from(public_key);
+		assert!(unified_signature.verify(&encoded_payload[..], &unified_signer.into_account()));
+	}
+
+	#[test]
+	fn ethereum_personal_sign_eip191_signatures_over_different_payload_should_fail() {
+		let payload = ClaimHandlePayload { base_handle: b"Alice".to_vec(), expiration: 101u32 };
+		let encoded_payload = payload.encode();
+
+		let signature_raw = from_hex("0x0eefeb690924bcb8a0e082082c0b10b8f3c253af874c3946a48039a4430dcb2975aeb6bfc471f6a2fb20ca8353ab24888028ed2fb6b1e10efd9881208ab2b6291b").expect("Should convert");
+		let unified_signature = UnifiedSignature::from(ecdsa::Signature::from_raw(
+			signature_raw.try_into().expect("should convert"),
+		));
+
+		let public_key = ecdsa::Public::from_raw(
+			from_hex("0x038318535b54105d4a7aae60c08fc45f9687181b4fdfc625bd1a753fa7397fed75")
+				.expect("should convert")
+				.try_into()
+				.expect("invalid size"),
+		);
+		let unified_signer = UnifiedSigner::from(public_key);
+		assert!(!unified_signature.verify(&encoded_payload[..], &unified_signer.into_account()));
+	}
+
 	#[test]
 	fn ethereum_raw_signatures_should_work() {
 		// payload is random and the signature is generated over that payload by PolkadotJs and ethereum keypair
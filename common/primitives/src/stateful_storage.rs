@@ -1,13 +1,16 @@
 use crate::msa::MessageSourceId;
 #[cfg(feature = "std")]
 use crate::utils;
-use parity_scale_codec::{Decode, Encode};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 #[cfg(feature = "std")]
 use serde::{Deserialize, Serialize};
+use sp_core::{storage::ChildInfo, Hasher};
+use sp_runtime::traits::Header as HeaderT;
+use sp_trie::{read_child_trie_value, read_trie_value, LayoutV1, StorageProof};
 extern crate alloc;
-use crate::schema::{IntentId, SchemaId};
-use alloc::vec::Vec;
+use crate::schema::{IntentId, SchemaId, StorageQuota};
+use alloc::{vec, vec::Vec};
 #[cfg(feature = "std")]
 use utils::*;
 
@@ -18,6 +21,60 @@ pub type PageHash = u32;
 /// PageNonce is the type/size of a nonce value embedded into a Page
 pub type PageNonce = u16;
 
+/// The location of a stateful storage page in the state of the chain, which allows the page to be
+/// proven against the state root of a block
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct PageStorageKey {
+	/// Storage key of the child trie holding the pages of the MSA (without the child storage prefix)
+	#[cfg_attr(feature = "std", serde(with = "as_hex", default))]
+	pub child_trie_key: Vec<u8>,
+	/// Key of the page inside the child trie
+	#[cfg_attr(feature = "std", serde(with = "as_hex", default))]
+	pub page_key: Vec<u8>,
+}
+
+/// Compression codec of the data of a Paginated page
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+)]
+pub enum PageCompression {
+	/// LZ4 block format, without a size prefix
+	#[codec(index = 0)]
+	Lz4,
+	/// Zstandard frame format
+	#[codec(index = 1)]
+	Zstd,
+}
+
+/// Errors when decompressing the data of a Paginated page
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PageDecompressionError {
+	/// The data is not valid for the codec
+	Malformed,
+	/// The decompressed data is larger than allowed
+	TooLarge,
+}
+
+/// Errors when checking the read proof of a stateful storage page
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum PageProofError {
+	/// The proof is incomplete or does not match the state root
+	InvalidProof,
+	/// The root of the child trie found in the proof is malformed
+	InvalidChildTrieRoot,
+}
+
 /// A type to expose paginated type of stateful storage
 // TODO: Remove once v1 RPC API is gone (ie, all nodes have upgraded and v1 Runtime API is removed)
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -59,6 +116,32 @@ pub struct PaginatedStorageResponseV2 {
 	pub payload: Vec<u8>,
 }
 
+/// A type to expose paginated type of stateful storage, with the compression of the page
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct PaginatedStorageResponseV3 {
+	/// IntentId of the page
+	pub intent_id: IntentId,
+	///  id of the page
+	pub page_id: PageId,
+	///  Message source account id (the original source).
+	pub msa_id: MessageSourceId,
+	///  Schema id of requested storage
+	pub schema_id: SchemaId,
+	/// Hash of the page content, over the raw (compressed) payload
+	pub content_hash: PageHash,
+	/// Nonce of the page
+	pub nonce: PageNonce,
+	/// Codec the payload is compressed with, if any
+	pub compression: Option<PageCompression>,
+	/// Raw data of the page as stored, compressed if `compression` is set
+	#[cfg_attr(feature = "std", serde(with = "as_hex", default))]
+	pub payload: Vec<u8>,
+	/// Decompressed data of a compressed page, serialized in the schema.
+	#[cfg_attr(feature = "std", serde(with = "as_hex_option", default))]
+	pub decompressed_payload: Option<Vec<u8>>,
+}
+
 /// A type to expose itemized page of stateful storage
 // TODO: Remove once v1 RPC API is gone (ie, all nodes have upgraded and v1 Runtime API is removed)
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -117,6 +200,42 @@ pub struct ItemizedStorageResponseV2 {
 	pub payload: Vec<u8>,
 }
 
+/// The stateful storage used by an MSA for an Intent
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(
+	Default,
+	Clone,
+	Copy,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEq,
+	Debug,
+	TypeInfo,
+	Eq,
+	MaxEncodedLen,
+)]
+pub struct IntentStorageUsage {
+	/// Total encoded size, in bytes, of the stored pages
+	pub bytes: u32,
+	/// Number of stored pages
+	pub pages: u32,
+}
+
+/// A type to expose the stateful storage used by an MSA for an Intent
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[derive(Default, Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq)]
+pub struct StorageUsageResponse {
+	/// IntentId of the stored pages
+	pub intent_id: IntentId,
+	/// Total encoded size, in bytes, of the stored pages
+	pub bytes: u32,
+	/// Number of stored pages
+	pub pages: u32,
+	/// Storage quota of the Intent, if any
+	pub quota: Option<StorageQuota>,
+}
+
 impl PaginatedStorageResponseV2 {
 	/// Returns a new instance with associated parameters
 	pub fn new(
@@ -190,3 +309,250 @@ impl Into<ItemizedStoragePageResponse> for ItemizedStoragePageResponseV2 {
 		}
 	}
 }
+
+/// Checks a read proof of a stateful storage page against the state root of a block header.
+/// Returns the encoded page, or `None` if the proof shows that the page does not exist.
+pub fn verify_page_proof<Header: HeaderT>(
+	header: &Header,
+	storage_key: &PageStorageKey,
+	proof: Vec<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, PageProofError> {
+	verify_page_proof_with_root::<Header::Hashing>(header.state_root(), storage_key, proof)
+}
+
+/// Checks a read proof of a stateful storage page against a state root.
+/// Returns the encoded page, or `None` if the proof shows that the page does not exist.
+pub fn verify_page_proof_with_root<H: Hasher>(
+	state_root: &H::Out,
+	storage_key: &PageStorageKey,
+	proof: Vec<Vec<u8>>,
+) -> Result<Option<Vec<u8>>, PageProofError> {
+	let db = StorageProof::new(proof).into_memory_db::<H>();
+	let child_info = ChildInfo::new_default(&storage_key.child_trie_key);
+
+	// the root of the child trie is stored in the main trie under its prefixed key
+	let encoded_child_root = read_trie_value::<LayoutV1<H>, _>(
+		&db,
+		state_root,
+		&child_info.prefixed_storage_key().into_inner(),
+		None,
+		None,
+	)
+	.map_err(|_| PageProofError::InvalidProof)?;
+	let Some(encoded_child_root) = encoded_child_root else {
+		// the MSA has no stateful storage at all
+		return Ok(None)
+	};
+
+	let mut child_root = H::Out::default();
+	if child_root.as_ref().len() != encoded_child_root.len() {
+		return Err(PageProofError::InvalidChildTrieRoot)
+	}
+	child_root.as_mut().copy_from_slice(&encoded_child_root);
+
+	read_child_trie_value::<LayoutV1<H>, _>(
+		child_info.keyspace(),
+		&db,
+		&child_root,
+		&storage_key.page_key,
+		None,
+		None,
+	)
+	.map_err(|_| PageProofError::InvalidProof)
+}
+
+/// Decompresses the data of a Paginated page, failing as soon as the decompressed data grows
+/// larger than `max_size` bytes, so that the cost of decompression is bounded by `max_size`.
+pub fn decompress_page_data(
+	compression: PageCompression,
+	data: &[u8],
+	max_size: usize,
+) -> Result<Vec<u8>, PageDecompressionError> {
+	match compression {
+		PageCompression::Lz4 => {
+			let mut decompressed = vec![0u8; max_size];
+			let len =
+				lz4_flex::block::decompress_into(data, &mut decompressed).map_err(|e| match e {
+					lz4_flex::block::DecompressError::OutputTooSmall { .. } =>
+						PageDecompressionError::TooLarge,
+					_ => PageDecompressionError::Malformed,
+				})?;
+			decompressed.truncate(len);
+			Ok(decompressed)
+		},
+		PageCompression::Zstd => {
+			use ruzstd::io::Read;
+			let mut decoder = ruzstd::StreamingDecoder::new(data)
+				.map_err(|_| PageDecompressionError::Malformed)?;
+			// one spare byte tells a page of exactly `max_size` bytes from a larger one
+			let mut decompressed = vec![0u8; max_size.saturating_add(1)];
+			let mut len = 0;
+			loop {
+				let read = decoder
+					.read(&mut decompressed[len..])
+					.map_err(|_| PageDecompressionError::Malformed)?;
+				if read == 0 {
+					break
+				}
+				len += read;
+				if len > max_size {
+					return Err(PageDecompressionError::TooLarge)
+				}
+			}
+			decompressed.truncate(len);
+			Ok(decompressed)
+		},
+	}
+}
+
+/// Compresses page data with the LZ4 block format expected by [`PageCompression::Lz4`]
+#[cfg(any(feature = "std", feature = "runtime-benchmarks"))]
+pub fn compress_page_data_lz4(data: &[u8]) -> Vec<u8> {
+	lz4_flex::block::compress(data)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::storage::{StateVersion, Storage, StorageChild};
+	use sp_runtime::{generic::Header, traits::BlakeTwo256};
+	use sp_state_machine::{prove_child_read, InMemoryBackend};
+	use std::collections::{BTreeMap, HashMap};
+
+	const CHILD_TRIE_KEY: &[u8] = b"msa-child-trie";
+	const SMALL_PAGE_KEY: &[u8] = b"page-1";
+	const LARGE_PAGE_KEY: &[u8] = b"page-2";
+
+	fn storage_key(page_key: &[u8]) -> PageStorageKey {
+		PageStorageKey { child_trie_key: CHILD_TRIE_KEY.to_vec(), page_key: page_key.to_vec() }
+	}
+
+	fn mock_header(state_root: sp_core::H256) -> Header<u32, BlakeTwo256> {
+		Header::new(1, Default::default(), state_root, Default::default(), Default::default())
+	}
+
+	/// Builds a state holding two pages in a child trie and proves the reads of the given keys
+	fn prove_pages(keys: &[&[u8]]) -> (Header<u32, BlakeTwo256>, Vec<Vec<u8>>) {
+		let child_info = ChildInfo::new_default(CHILD_TRIE_KEY);
+		let storage = Storage {
+			top: BTreeMap::from([(b"top-key".to_vec(), b"top-value".to_vec())]),
+			children_default: HashMap::from([(
+				CHILD_TRIE_KEY.to_vec(),
+				StorageChild {
+					data: BTreeMap::from([
+						(SMALL_PAGE_KEY.to_vec(), b"page one".to_vec()),
+						(LARGE_PAGE_KEY.to_vec(), vec![2u8; 1024]),
+					]),
+					child_info: child_info.clone(),
+				},
+			)]),
+		};
+		let backend: InMemoryBackend<BlakeTwo256> = (storage, StateVersion::V1).into();
+		let header = mock_header(*backend.root());
+		let proof = prove_child_read(backend, &child_info, keys).unwrap();
+		(header, proof.into_iter_nodes().collect())
+	}
+
+	#[test]
+	fn verify_page_proof_should_return_proven_pages() {
+		let (header, proof) = prove_pages(&[SMALL_PAGE_KEY, LARGE_PAGE_KEY]);
+
+		assert_eq!(
+			verify_page_proof(&header, &storage_key(SMALL_PAGE_KEY), proof.clone()),
+			Ok(Some(b"page one".to_vec()))
+		);
+		assert_eq!(
+			verify_page_proof(&header, &storage_key(LARGE_PAGE_KEY), proof),
+			Ok(Some(vec![2u8; 1024]))
+		);
+	}
+
+	#[test]
+	fn verify_page_proof_of_missing_page_should_return_none() {
+		let (header, proof) = prove_pages(&[b"page-3"]);
+
+		assert_eq!(verify_page_proof(&header, &storage_key(b"page-3"), proof), Ok(None));
+	}
+
+	#[test]
+	fn verify_page_proof_with_other_state_root_should_fail() {
+		let (_, proof) = prove_pages(&[SMALL_PAGE_KEY]);
+		let header = mock_header(sp_core::H256::repeat_byte(1));
+
+		assert_eq!(
+			verify_page_proof(&header, &storage_key(SMALL_PAGE_KEY), proof),
+			Err(PageProofError::InvalidProof)
+		);
+	}
+
+	#[test]
+	fn verify_page_proof_with_incomplete_proof_should_fail() {
+		let (header, proof) = prove_pages(&[SMALL_PAGE_KEY]);
+
+		assert_eq!(
+			verify_page_proof(&header, &storage_key(LARGE_PAGE_KEY), proof),
+			Err(PageProofError::InvalidProof)
+		);
+		assert_eq!(
+			verify_page_proof(&header, &storage_key(SMALL_PAGE_KEY), Vec::new()),
+			Err(PageProofError::InvalidProof)
+		);
+	}
+
+	/// `b"frequency " * 40` compressed with `zstd -19 --no-check`
+	const ZSTD_PAGE: &str = "28b52ffd6090008d0000506672657175656e637920010083550b12";
+
+	fn page_data() -> Vec<u8> {
+		b"frequency ".repeat(40)
+	}
+
+	#[test]
+	fn decompress_page_data_should_decompress_lz4() {
+		let compressed = compress_page_data_lz4(&page_data());
+
+		assert_eq!(
+			decompress_page_data(PageCompression::Lz4, &compressed, page_data().len()),
+			Ok(page_data())
+		);
+	}
+
+	#[test]
+	fn decompress_page_data_should_decompress_zstd() {
+		let compressed = sp_core::bytes::from_hex(ZSTD_PAGE).unwrap();
+
+		assert_eq!(
+			decompress_page_data(PageCompression::Zstd, &compressed, page_data().len()),
+			Ok(page_data())
+		);
+	}
+
+	#[test]
+	fn decompress_page_data_larger_than_max_size_should_fail() {
+		let lz4 = compress_page_data_lz4(&page_data());
+		let zstd = sp_core::bytes::from_hex(ZSTD_PAGE).unwrap();
+		let max_size = page_data().len() - 1;
+
+		assert_eq!(
+			decompress_page_data(PageCompression::Lz4, &lz4, max_size),
+			Err(PageDecompressionError::TooLarge)
+		);
+		assert_eq!(
+			decompress_page_data(PageCompression::Zstd, &zstd, max_size),
+			Err(PageDecompressionError::TooLarge)
+		);
+	}
+
+	#[test]
+	fn decompress_page_data_with_malformed_data_should_fail() {
+		let data = page_data();
+
+		assert_eq!(
+			decompress_page_data(PageCompression::Zstd, &data, 1024),
+			Err(PageDecompressionError::Malformed)
+		);
+		assert_eq!(
+			decompress_page_data(PageCompression::Lz4, &[0xf0], 1024),
+			Err(PageDecompressionError::Malformed)
+		);
+	}
+}
@@ -717,7 +717,7 @@ export function getOrCreateDummySchema(
     source,
     'test.dummySchema',
     { payloadLocation: 'OnChain', settings: [] },
-    { model: { type: 'record', name: 'Dummy on-chain schema', fields: [] }, modelType: 'AvroBinary' },
+    { model: { type: 'record', name: 'DummyOnChainSchema', fields: [] }, modelType: 'AvroBinary' },
     nonce
   );
 }
@@ -133,7 +133,7 @@ describe('#schemas pallet tests', function () {
         intentId: () => createdIntents[0].intentId,
         model: [1000, 3],
         modelType: 'AvroBinary',
-        error: 'InvalidSchema',
+        error: 'InvalidAvroType',
       },
       {
         id: 'if Parquet schema is invalid',
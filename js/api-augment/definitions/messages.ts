@@ -30,7 +30,9 @@ export default {
       msa_id: 'Option<MessageSourceId>', //  Message source account id (the original source)
       index: 'u16', // index in block to get total order
       block_number: 'BlockNumber',
-      payload_length: 'Option<u32>', // Length of IPFS payload file
+      payload_length: 'Option<u32>', // Length of IPFS or OffChain payload file
+      uri: 'Option<Vec<u8>>', // The URI of an OffChain payload
+      content_hash: 'Option<Vec<u8>>', // Multihash of the content of an OffChain payload
     },
     BlockPaginationResponseMessage: {
       content: 'Vec<MessageResponse>',
@@ -74,6 +74,26 @@ export default {
       ],
       type: 'Vec<DelegationResponse>',
     },
+    getProviderDirectory: {
+      description: 'Get a page of registered providers and their applications',
+      params: [
+        {
+          name: 'request',
+          type: 'ProviderDirectoryRequest',
+        },
+      ],
+      type: 'ProviderDirectoryResponse',
+    },
+    getApprovedLogo: {
+      description: 'Get the uploaded bytes of an approved logo by its CID',
+      params: [
+        {
+          name: 'logo_cid',
+          type: 'String',
+        },
+      ],
+      type: 'Option<Vec<u8>>',
+    },
   },
   types: {
     MessageSourceId: 'u64',
@@ -98,6 +118,29 @@ export default {
       permissions: 'Vec<DelegationGrant>',
       revoked_at: 'BlockNumber',
     },
+    ProviderDirectoryCursor: {
+      provider_id: 'ProviderId',
+      application_id: 'Option<u16>',
+    },
+    ProviderDirectoryRequest: {
+      start_after: 'Option<ProviderDirectoryCursor>',
+      page_size: 'u32',
+      locale: 'Option<Vec<u8>>',
+      approved_logo_only: 'bool',
+    },
+    ProviderDirectoryEntry: {
+      provider_id: 'ProviderId',
+      application_id: 'Option<u16>',
+      default_name: 'Vec<u8>',
+      localized_name: 'Option<Vec<u8>>',
+      default_logo_250_100_png_cid: 'Option<Vec<u8>>',
+      localized_logo_250_100_png_cid: 'Option<Vec<u8>>',
+      is_suspended: 'bool',
+    },
+    ProviderDirectoryResponse: {
+      entries: 'Vec<ProviderDirectoryEntry>',
+      next_start_after: 'Option<ProviderDirectoryCursor>',
+    },
     // Runtime types
     // Not sure why these have to be noted here, but they do
     CommonPrimitivesMsaDelegatorId: 'u64',
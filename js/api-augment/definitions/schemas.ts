@@ -10,6 +10,36 @@ export default {
       ],
       type: 'Option<SchemaResponse>',
     },
+    listIntents: {
+      description: 'List Intents, filtered by namespace prefix and payload location',
+      params: [
+        {
+          name: 'pagination',
+          type: 'EntityPaginationRequest',
+        },
+      ],
+      type: 'EntityPaginationResponseIntent',
+    },
+    listSchemas: {
+      description: 'List Schemas without models, filtered by namespace prefix, payload location and status',
+      params: [
+        {
+          name: 'pagination',
+          type: 'EntityPaginationRequest',
+        },
+      ],
+      type: 'EntityPaginationResponseSchema',
+    },
+    listIntentGroups: {
+      description: 'List IntentGroups, filtered by namespace prefix',
+      params: [
+        {
+          name: 'pagination',
+          type: 'EntityPaginationRequest',
+        },
+      ],
+      type: 'EntityPaginationResponseIntentGroup',
+    },
   },
   types: {
     SchemaId: 'u16',
@@ -22,6 +52,28 @@ export default {
       payload_location: 'PayloadLocation',
       settings: 'Vec<SchemaSetting>',
     },
+    EntityPaginationRequest: {
+      from_id: 'u16', // inclusive
+      page_size: 'u16',
+      namespace_prefix: 'Option<String>',
+      payload_location: 'Option<PayloadLocation>',
+      status: 'Option<SchemaStatus>',
+    },
+    EntityPaginationResponseIntent: {
+      content: 'Vec<IntentResponseV2>',
+      has_next: 'bool',
+      next_id: 'Option<u16>',
+    },
+    EntityPaginationResponseSchema: {
+      content: 'Vec<SchemaInfoResponse>',
+      has_next: 'bool',
+      next_id: 'Option<u16>',
+    },
+    EntityPaginationResponseIntentGroup: {
+      content: 'Vec<IntentGroupResponse>',
+      has_next: 'bool',
+      next_id: 'Option<u16>',
+    },
     IntentGroupId: 'u16',
     IntentGroupResponse: {
       intent_group_id: 'IntentGroupId',
@@ -34,8 +86,19 @@ export default {
       settings: 'Vec<IntentSetting>',
       schema_ids: 'Option<Vec<SchemaId>>',
     },
+    IntentResponseV2: {
+      intent_id: 'IntentId',
+      payload_location: 'PayloadLocation',
+      settings: 'Vec<IntentSetting>',
+      schema_ids: 'Option<Vec<SchemaId>>',
+      schemas: 'Option<Vec<IntentSchemaResponse>>',
+    },
+    IntentSchemaResponse: {
+      schema_id: 'SchemaId',
+      status: 'SchemaStatus',
+    },
     IntentSetting: {
-      _enum: ['AppendOnly', 'SignatureRequired'],
+      _enum: ['AppendOnly', 'SignatureRequired', 'ValidatePayload', 'AllowUpdates'],
     },
     MappedEntityIdentifier: {
       _enum: {
@@ -71,7 +134,7 @@ export default {
       status: 'SchemaStatus',
     },
     SchemaSetting: {
-      _enum: ['AppendOnly', 'SignatureRequired'],
+      _enum: ['AppendOnly', 'SignatureRequired', 'ValidatePayload', 'AllowUpdates'],
     },
     SchemaStatus: {
       _enum: ['Active', 'Deprecated', 'Unsupported'],
@@ -81,5 +144,11 @@ export default {
       schema_version: 'SchemaVersion',
       schema_id: 'SchemaId',
     },
+    SchemaVersionResponseV2: {
+      schema_name: 'String',
+      schema_version: 'SchemaVersion',
+      schema_id: 'SchemaId',
+      status: 'SchemaStatus',
+    },
   },
 };
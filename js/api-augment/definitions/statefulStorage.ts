@@ -28,6 +28,42 @@ export default {
       ],
       type: 'ItemizedStoragePageResponse',
     },
+    getPaginatedStorageWithCompression: {
+      description: 'Gets pages of stateful storage with the decompressed data of compressed pages',
+      params: [
+        {
+          name: 'msa_id',
+          type: 'MessageSourceId',
+        },
+        {
+          name: 'intent_id',
+          type: 'IntentId',
+        },
+      ],
+      type: 'Vec<PaginatedStorageResponseV3>',
+    },
+    getPageProof: {
+      description: 'Gets a read proof of a page of stateful storage',
+      params: [
+        {
+          name: 'msa_id',
+          type: 'MessageSourceId',
+        },
+        {
+          name: 'intent_id',
+          type: 'IntentId',
+        },
+        {
+          name: 'page_id',
+          type: 'Option<PageId>',
+        },
+        {
+          name: 'at',
+          type: 'Option<BlockHash>',
+        },
+      ],
+      type: 'PageProof',
+    },
   },
   types: {
     PageId: 'u16',
@@ -47,6 +83,20 @@ export default {
       nonce: 'PageNonce',
       payload: 'Vec<u8>',
     },
+    PageCompression: {
+      _enum: ['Lz4', 'Zstd'],
+    },
+    PaginatedStorageResponseV3: {
+      intent_id: 'IntentId',
+      page_id: 'PageId',
+      msa_id: 'MessageSourceId',
+      schema_id: 'SchemaId',
+      content_hash: 'PageHash',
+      nonce: 'PageNonce',
+      compression: 'Option<PageCompression>',
+      payload: 'Vec<u8>',
+      decompressed_payload: 'Option<Vec<u8>>',
+    },
     ItemizedStoragePageResponse: {
       msa_id: 'MessageSourceId',
       intent_id: 'IntentId',
@@ -54,5 +104,14 @@ export default {
       nonce: 'PageNonce',
       items: 'Vec<ItemizedStorageResponse>',
     },
+    PageStorageKey: {
+      child_trie_key: 'Vec<u8>',
+      page_key: 'Vec<u8>',
+    },
+    PageProof: {
+      at: 'BlockHash',
+      storage_key: 'PageStorageKey',
+      proof: 'Vec<Bytes>',
+    },
   },
 };
@@ -184,11 +184,12 @@ pub fn start_frequency_dev_sealing_node(
 			false => None,
 		};
 
-		Box::new(move |_| {
+		Box::new(move |subscription_executor| {
 			let deps = crate::rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				command_sink: command_sink.clone(),
+				subscription_executor,
 			};
 			crate::rpc::create_full(deps, backend.clone()).map_err(Into::into)
 		})
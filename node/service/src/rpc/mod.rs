@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 use common_primitives::node::{AccountId, Balance, Block, Hash, Index as Nonce};
 
-use sc_client_api::{AuxStore, StorageProvider};
+use sc_client_api::{AuxStore, BlockchainEvents, ProofProvider, StorageProvider};
 use sc_client_db::Backend as DbBackend;
 use sc_consensus_manual_seal::rpc::{EngineCommand, ManualSeal, ManualSealApiServer};
 pub use sc_rpc::{DenyUnsafe, SubscriptionTaskExecutor};
@@ -31,6 +31,8 @@ pub struct FullDeps<C, P> {
 	pub pool: Arc<P>,
 	/// Manual seal command sink
 	pub command_sink: Option<futures::channel::mpsc::Sender<EngineCommand<Hash>>>,
+	/// Executor for the tasks driving RPC subscriptions
+	pub subscription_executor: SubscriptionTaskExecutor,
 }
 
 /// Instantiate all RPC extensions.
@@ -44,6 +46,8 @@ where
 		+ AuxStore
 		+ HeaderMetadata<Block, Error = BlockChainError>
 		+ StorageProvider<Block, DbBackend<Block>>
+		+ BlockchainEvents<Block>
+		+ ProofProvider<Block>
 		+ Send
 		+ Sync
 		+ 'static,
@@ -57,7 +61,7 @@ where
 	C::Api: pallet_msa_runtime_api::MsaRuntimeApi<Block, AccountId>,
 	C::Api: pallet_stateful_storage_runtime_api::StatefulStorageRuntimeApi<Block>,
 	C::Api: pallet_handles_runtime_api::HandlesRuntimeApi<Block>,
-	OffchainDB: sp_core::offchain::OffchainStorage + 'static,
+	OffchainDB: sp_core::offchain::OffchainStorage + Clone + 'static,
 	P: TransactionPool + Sync + Send + 'static,
 {
 	use pallet_transaction_payment_rpc::{TransactionPayment, TransactionPaymentApiServer};
@@ -67,20 +71,30 @@ where
 	use frequency_rpc::{FrequencyRpcApiServer, FrequencyRpcHandler};
 	use pallet_frequency_tx_payment_rpc::{CapacityPaymentApiServer, CapacityPaymentHandler};
 	use pallet_handles_rpc::{HandlesApiServer, HandlesHandler};
-	use pallet_messages_rpc::{MessagesApiServer, MessagesHandler};
+	use pallet_messages_rpc::{
+		MessagesApiServer, MessagesHandler, MessagesSubscriptionApiServer,
+		MessagesSubscriptionHandler,
+	};
 	use pallet_msa_rpc::{MsaApiServer, MsaHandler};
 	use pallet_schemas_rpc::{SchemasApiServer, SchemasHandler};
-	use pallet_stateful_storage_rpc::{StatefulStorageApiServer, StatefulStorageHandler};
+	use pallet_stateful_storage_rpc::{
+		StatefulStorageApiServer, StatefulStorageHandler, StatefulStorageProofApiServer,
+		StatefulStorageProofHandler,
+	};
 
 	let mut module = RpcExtension::new(());
-	let FullDeps { client, pool, command_sink } = deps;
+	let FullDeps { client, pool, command_sink, subscription_executor } = deps;
 
 	module.merge(System::new(client.clone(), pool.clone()).into_rpc())?;
 	module.merge(TransactionPayment::new(client.clone()).into_rpc())?;
-	module.merge(MessagesHandler::new(client.clone()).into_rpc())?;
+	module.merge(MessagesHandler::new(client.clone(), offchain.clone()).into_rpc())?;
+	module.merge(
+		MessagesSubscriptionHandler::new(client.clone(), subscription_executor).into_rpc(),
+	)?;
 	module.merge(SchemasHandler::new(client.clone()).into_rpc())?;
 	module.merge(MsaHandler::new(client.clone(), offchain).into_rpc())?;
 	module.merge(StatefulStorageHandler::new(client.clone()).into_rpc())?;
+	module.merge(StatefulStorageProofHandler::new(client.clone()).into_rpc())?;
 	module.merge(HandlesHandler::new(client.clone()).into_rpc())?;
 	module.merge(CapacityPaymentHandler::new(client.clone()).into_rpc())?;
 	module.merge(FrequencyRpcHandler::new(client, pool).into_rpc())?;
@@ -283,11 +283,12 @@ pub async fn start_parachain_node(
 			false => None,
 		};
 
-		Box::new(move |_| {
+		Box::new(move |subscription_executor| {
 			let deps = crate::rpc::FullDeps {
 				client: client.clone(),
 				pool: transaction_pool.clone(),
 				command_sink: None,
+				subscription_executor,
 			};
 
 			crate::rpc::create_full(deps, backend.clone()).map_err(Into::into)
@@ -141,6 +141,8 @@ impl pallet_msa::Config for Test {
 	type MaxLogoCidSize = MsaMaxLogoCidSize;
 	type MaxLogoSize = MsaMaxLogoSize;
 	type MaxLocaleCount = MsaMaxLocaleCount;
+	type MinLogoDimension = MsaMinLogoDimension;
+	type MaxLogoDimension = MsaMaxLogoDimension;
 	type SchemaValidator = Schemas;
 	type HandleProvider = ();
 	type MortalityWindowSize = ConstU32<100>;
@@ -46,6 +46,9 @@ pub trait WeightInfo {
 	// Messages
 	fn add_onchain_message(n: u32) -> Weight;
 	fn add_ipfs_message() -> Weight;
+	fn add_ipfs_messages(n: u32) -> Weight;
+	fn add_onchain_messages(n: u32, b: u32) -> Weight;
+	fn add_offchain_message() -> Weight;
 	// Stateful-storage
 	fn apply_item_actions(n: u32) -> Weight;
 	fn upsert_page(s: u32) -> Weight;
@@ -198,13 +201,79 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Messages::MessagesV2` (r:0 w:1)
 	/// Proof: `Messages::MessagesV2` (`max_values`: None, `max_size`: Some(3123), added: 5598, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
 	fn add_ipfs_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `814`
 		//  Estimated: `4008`
 		// Minimum execution time: 31_646_000 picoseconds.
 		Weight::from_parts(32_704_000, 4008)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn add_ipfs_messages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `4008`
+		// Minimum execution time: 17_102_000 picoseconds.
+		Weight::from_parts(17_950_000, 4008)
+			// Standard Error: 1_914
+			.saturating_add(Weight::from_parts(26_870_000, 0).saturating_mul(n.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2490).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:20 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	/// The range of component `b` is `[1, 61440]`.
+	fn add_onchain_messages(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `612`
+		//  Estimated: `3513`
+		// Minimum execution time: 18_430_000 picoseconds.
+		Weight::from_parts(19_120_000, 3513)
+			// Standard Error: 2_507
+			.saturating_add(Weight::from_parts(35_410_000, 0).saturating_mul(n.into()))
+			// Standard Error: 145
+			.saturating_add(Weight::from_parts(959, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 5182).saturating_mul(n.into()))
+	}
+	/// Storage: `Messages::AllowedUriSchemes` (r:1 w:0)
+	/// Proof: `Messages::AllowedUriSchemes` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:1)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	fn add_offchain_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `851`
+		//  Estimated: `4008`
+		// Minimum execution time: 33_408_000 picoseconds.
+		Weight::from_parts(34_512_000, 4008)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
@@ -33,7 +33,25 @@ fn test_weights_are_stable() {
 				432456221,
 				4177,
 			),
-			("add_ipfs_message", SubstrateWeight::<Test>::add_ipfs_message(), 333677000, 4008),
+			("add_ipfs_message", SubstrateWeight::<Test>::add_ipfs_message(), 422870000, 4008),
+			(
+				"add_ipfs_messages",
+				SubstrateWeight::<Test>::add_ipfs_messages(10),
+				2582836000,
+				28908,
+			),
+			(
+				"add_onchain_messages",
+				SubstrateWeight::<Test>::add_onchain_messages(10, 1000),
+				3473102000,
+				55333,
+			),
+			(
+				"add_offchain_message",
+				SubstrateWeight::<Test>::add_offchain_message(),
+				424678000,
+				4008,
+			),
 			(
 				"apply_item_actions",
 				SubstrateWeight::<Test>::apply_item_actions(100),
@@ -3,6 +3,8 @@
 use super::*;
 use crate::Pallet as MessagesPallet;
 use common_primitives::{
+	benchmarks::{avro_benchmark_model, avro_benchmark_payload},
+	cid::Multihash,
 	msa::{DelegatorId, ProviderId},
 	schema::*,
 };
@@ -59,6 +61,15 @@ fn ipfs_message<T: Config>(intent_id: IntentId, schema_id: SchemaId) -> Dispatch
 	Ok(())
 }
 
+/// A distinct, valid URI scheme of the given length
+fn uri_scheme(i: u32, len: u32) -> UriScheme {
+	let suffix = [b'a' + (i % 26) as u8, b'a' + (i / 26 % 26) as u8];
+	[vec![b'x'; len as usize - suffix.len()], suffix.to_vec()]
+		.concat()
+		.try_into()
+		.expect("Invalid scheme")
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -66,21 +77,22 @@ mod benchmarks {
 
 	#[benchmark]
 	fn add_onchain_message(
-		n: Linear<0, { T::MessagesMaxPayloadSizeBytes::get() - 1 }>,
+		n: Linear<1, { T::MessagesMaxPayloadSizeBytes::get() - 1 }>,
 	) -> Result<(), BenchmarkError> {
 		let message_source_id = DelegatorId(2);
 		let caller: T::AccountId = whitelisted_caller();
 
 		// The schemas pallet genesis does not contain an OnChain intent/schema, so we create one here
+		// that validates every payload
 		let intent_id = T::SchemaBenchmarkHelper::create_intent(
 			b"benchmark.onchain-intent".to_vec(),
 			PayloadLocation::OnChain,
-			Vec::default(),
+			vec![IntentSetting::ValidatePayload],
 		)?;
 
 		let schema_id = T::SchemaBenchmarkHelper::create_schema(
 			intent_id,
-			Vec::from(r#"{"Name": "Bond", "Code": "007"}"#.as_bytes()),
+			avro_benchmark_model(),
 			ModelType::AvroBinary,
 			PayloadLocation::OnChain,
 		)?;
@@ -92,7 +104,7 @@ mod benchmarks {
 			[schema_id].to_vec()
 		));
 
-		let payload = vec![1; n as usize];
+		let payload = avro_benchmark_payload(n);
 		for _ in 1..MAX_MESSAGES_IN_BLOCK {
 			assert_ok!(onchain_message::<T>(intent_id, schema_id));
 		}
@@ -142,6 +154,271 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_offchain_message() -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let intent_id = T::SchemaBenchmarkHelper::create_intent(
+			b"benchmark.offchain-intent".to_vec(),
+			PayloadLocation::OffChain,
+			vec![],
+		)?;
+		let schema_id = T::SchemaBenchmarkHelper::create_schema(
+			intent_id,
+			avro_benchmark_model(),
+			ModelType::AvroBinary,
+			PayloadLocation::OffChain,
+		)?;
+
+		// The allowed scheme is the last one checked
+		let schemes: BoundedVec<UriScheme, T::MaxUriSchemes> = (1..T::MaxUriSchemes::get())
+			.map(|i| uri_scheme(i, MAX_URI_SCHEME_LENGTH))
+			.chain([UriScheme::try_from(b"https".to_vec()).expect("Invalid scheme")])
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("Invalid schemes");
+		AllowedUriSchemes::<T>::put(schemes);
+
+		let prefix = b"https://".to_vec();
+		let uri: BoundedVec<u8, T::MaxOffchainUriLength> =
+			[prefix.clone(), vec![b'a'; T::MaxOffchainUriLength::get() as usize - prefix.len()]]
+				.concat()
+				.try_into()
+				.expect("Invalid URI");
+		let multihash: ContentMultihash =
+			Multihash::wrap(0x12, &sp_io::hashing::sha2_256(b"content"))
+				.expect("Invalid multihash")
+				.to_bytes()
+				.try_into()
+				.expect("Invalid multihash");
+
+		assert_ok!(T::MsaBenchmarkHelper::add_key(ProviderId(1).into(), caller.clone()));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), schema_id, uri, multihash, IPFS_PAYLOAD_LENGTH);
+
+		assert_eq!(
+			MessagesPallet::<T>::get_messages_by_intent_and_block(
+				intent_id,
+				PayloadLocation::OffChain,
+				BlockNumberFor::<T>::one()
+			)
+			.len(),
+			1
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_allowed_uri_schemes(
+		n: Linear<0, { T::MaxUriSchemes::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let schemes: BoundedVec<UriScheme, T::MaxUriSchemes> = (0..n)
+			.map(|i| uri_scheme(i, MAX_URI_SCHEME_LENGTH))
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("Invalid schemes");
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, schemes.clone());
+
+		assert_eq!(AllowedUriSchemes::<T>::get(), schemes);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_cid_policy() -> Result<(), BenchmarkError> {
+		let entries = T::MaxCidPolicyEntries::get() as u64;
+		let policy = CidPolicy::<T::MaxCidPolicyEntries> {
+			codecs: (0..entries).collect::<Vec<_>>().try_into().expect("Invalid codecs"),
+			multihash_codes: (0..entries).collect::<Vec<_>>().try_into().expect("Invalid codes"),
+			allow_cid_v0: true,
+		};
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, policy.clone());
+
+		assert_eq!(AcceptedCidPolicy::<T>::get(), policy);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_ipfs_messages(
+		n: Linear<1, { T::MaxMessagesPerBatch::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let cid = "bafkreidgvpkjawlxz6sffxzwgooowe5yt7i6wsyg236mfoks77nywkptdq"
+			.as_bytes()
+			.to_vec();
+
+		// Every message is written to its own schema, so that nothing is cached
+		let mut schema_ids = Vec::new();
+		for i in 0..n {
+			let name = [
+				b"benchmark.batch-ipfs-intent-".as_slice(),
+				&[b'a' + (i % 26) as u8, b'a' + (i / 26) as u8],
+			]
+			.concat();
+			let intent_id =
+				T::SchemaBenchmarkHelper::create_intent(name, PayloadLocation::IPFS, vec![])?;
+			schema_ids.push(T::SchemaBenchmarkHelper::create_schema(
+				intent_id,
+				avro_benchmark_model(),
+				ModelType::AvroBinary,
+				PayloadLocation::IPFS,
+			)?);
+		}
+
+		assert_ok!(T::MsaBenchmarkHelper::add_key(ProviderId(1).into(), caller.clone()));
+		for _ in n..MAX_MESSAGES_IN_BLOCK {
+			assert_ok!(ipfs_message::<T>(IPFS_SCHEMA_ID as IntentId, IPFS_SCHEMA_ID));
+		}
+		let entries: BoundedVec<IpfsMessageEntry, T::MaxMessagesPerBatch> = schema_ids
+			.into_iter()
+			.map(|schema_id| IpfsMessageEntry {
+				schema_id,
+				cid: cid.clone(),
+				payload_length: IPFS_PAYLOAD_LENGTH,
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("Invalid batch");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), entries);
+
+		assert_eq!(BlockMessageIndex::<T>::get() as u32, MAX_MESSAGES_IN_BLOCK);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn add_onchain_messages(
+		n: Linear<1, { T::MaxMessagesPerBatch::get() }>,
+		b: Linear<1, { T::MaxMessagesPerBatch::get() * T::MessagesMaxPayloadSizeBytes::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let message_source_id = DelegatorId(2);
+		let caller: T::AccountId = whitelisted_caller();
+
+		// Every message is written to its own validated schema, so that nothing is cached
+		let mut schema_ids = Vec::new();
+		for i in 0..n {
+			let name = [
+				b"benchmark.batch-intent-".as_slice(),
+				&[b'a' + (i % 26) as u8, b'a' + (i / 26) as u8],
+			]
+			.concat();
+			let intent_id = T::SchemaBenchmarkHelper::create_intent(
+				name,
+				PayloadLocation::OnChain,
+				vec![IntentSetting::ValidatePayload],
+			)?;
+			schema_ids.push(T::SchemaBenchmarkHelper::create_schema(
+				intent_id,
+				avro_benchmark_model(),
+				ModelType::AvroBinary,
+				PayloadLocation::OnChain,
+			)?);
+		}
+
+		assert_ok!(T::MsaBenchmarkHelper::add_key(ProviderId(1).into(), caller.clone()));
+		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(
+			ProviderId(1),
+			message_source_id,
+			schema_ids.clone()
+		));
+
+		let payload_len = (b / n).min(T::MessagesMaxPayloadSizeBytes::get());
+		let entries: BoundedVec<OnChainMessageEntry, T::MaxMessagesPerBatch> = schema_ids
+			.into_iter()
+			.map(|schema_id| OnChainMessageEntry {
+				schema_id,
+				on_behalf_of: Some(message_source_id.into()),
+				payload: avro_benchmark_payload(payload_len),
+			})
+			.collect::<Vec<_>>()
+			.try_into()
+			.expect("Invalid batch");
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(caller), entries);
+
+		assert_eq!(BlockMessageIndex::<T>::get() as u32, n);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_expired_messages(
+		n: Linear<1, { MAX_RETENTION_INTENTS_PER_BLOCK }>,
+	) -> Result<(), BenchmarkError> {
+		for i in 0..n {
+			let name = [
+				b"benchmark.retained-intent-".as_slice(),
+				&[b'a' + (i % 26) as u8, b'a' + (i / 26) as u8],
+			]
+			.concat();
+			let intent_id =
+				T::SchemaBenchmarkHelper::create_intent(name, PayloadLocation::OnChain, vec![])?;
+			T::SchemaBenchmarkHelper::set_intent_retention_period(intent_id, 1);
+		}
+
+		// Every cursor advances over a single empty block
+		let retention_periods =
+			T::SchemaProvider::get_intent_retention_periods(None, MAX_RETENTION_INTENTS_PER_BLOCK);
+		let mut current = BlockNumberFor::<T>::one();
+		for (intent_id, retention) in retention_periods.iter() {
+			RetentionCursors::<T>::insert(
+				intent_id,
+				RetentionCursor { next_block: BlockNumberFor::<T>::one(), partial: false },
+			);
+			current = current.max(BlockNumberFor::<T>::one() + retention.period.into());
+		}
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			MessagesPallet::<T>::prune_expired_messages(current, &mut meter);
+		}
+
+		for (intent_id, _) in retention_periods.iter() {
+			assert!(RetentionCursors::<T>::get(intent_id).next_block > BlockNumberFor::<T>::one());
+		}
+		Ok(())
+	}
+
+	#[benchmark]
+	fn prune_messages_in_block(m: Linear<0, 1000>) -> Result<(), BenchmarkError> {
+		let intent_id = T::SchemaBenchmarkHelper::create_intent(
+			b"benchmark.retained-intent".to_vec(),
+			PayloadLocation::OnChain,
+			vec![],
+		)?;
+		T::SchemaBenchmarkHelper::set_intent_retention_period(intent_id, 1);
+		let (intent_id, retention) = T::SchemaProvider::get_intent_retention_periods(None, 1)
+			.into_iter()
+			.next()
+			.expect("retention period should be set");
+
+		for _ in 0..m {
+			assert_ok!(onchain_message::<T>(intent_id, 1));
+		}
+		RetentionCursors::<T>::insert(
+			intent_id,
+			RetentionCursor { next_block: BlockNumberFor::<T>::one(), partial: false },
+		);
+		let current = BlockNumberFor::<T>::one() + retention.period.into();
+		let mut meter = WeightMeter::new();
+
+		#[block]
+		{
+			MessagesPallet::<T>::prune_expired_messages(current, &mut meter);
+		}
+
+		assert_eq!(
+			MessagesV3::<T>::iter_prefix((BlockNumberFor::<T>::one(), intent_id)).count(),
+			0
+		);
+		Ok(())
+	}
+
 	/// Benchmark a single step of the `v3::MigrateV2ToV3` migration. Here we benchmark the cost
 	/// to migrate a _single record_. This weight is then used in the migration itself for self-metering.
 	#[benchmark]
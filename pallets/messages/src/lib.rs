@@ -1,4 +1,4 @@
-//! Stores messages for `IPFS` and `OnChain` Schema payload locations
+//! Stores messages for `IPFS`, `OffChain` and `OnChain` Schema payload locations
 //!
 //! ## Quick Links
 //! - [Configuration: `Config`](Config)
@@ -33,17 +33,28 @@ mod types;
 pub mod migration;
 
 use core::{convert::TryInto, fmt::Debug};
-use frame_support::{ensure, pallet_prelude::Weight, traits::Get, BoundedVec};
-use sp_runtime::DispatchError;
+use frame_support::{
+	ensure, pallet_prelude::Weight, traits::Get, weights::WeightMeter, BoundedVec,
+};
+use sp_runtime::{
+	traits::{CheckedSub, One},
+	DispatchError,
+};
 
 extern crate alloc;
 extern crate core;
 
-use alloc::vec::Vec;
+use alloc::{
+	collections::{btree_map::Entry, BTreeMap, BTreeSet},
+	vec::Vec,
+};
 use common_primitives::{
 	cid::*,
 	messages::*,
 	msa::{DelegatorId, GrantValidator, MessageSourceId, MsaLookup, MsaValidator, ProviderId},
+	offchain::{
+		get_message_index_key, MESSAGES_BY_MSA_INDEX_PREFIX, MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+	},
 	schema::*,
 };
 use frame_support::dispatch::DispatchResult;
@@ -89,6 +100,28 @@ pub mod pallet {
 		#[pallet::constant]
 		type MessagesMaxPayloadSizeBytes: Get<u32> + Clone + Debug + MaxEncodedLen;
 
+		/// The maximum number of messages that can be submitted in a single batch.
+		#[pallet::constant]
+		type MaxMessagesPerBatch: Get<u32>;
+
+		/// The maximum length in bytes of the URI of an off-chain message payload.
+		#[pallet::constant]
+		type MaxOffchainUriLength: Get<u32>;
+
+		/// The maximum number of URI schemes allowed for off-chain message payloads.
+		#[pallet::constant]
+		type MaxUriSchemes: Get<u32>;
+
+		/// The origin that is allowed to set the URI schemes allowed for off-chain message payloads.
+		type UriSchemeGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of codecs, and of multihash codes, accepted for IPFS message CIDs.
+		#[pallet::constant]
+		type MaxCidPolicyEntries: Get<u32>;
+
+		/// The origin that is allowed to set the CIDs accepted for IPFS messages.
+		type CidPolicyGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
 		/// How often to emit status events during a storage migration.
 		/// Try to make this larger than the number of message migrations that will fit
 		/// in a block by weight, as multiple of these events in a block is not really useful
@@ -127,6 +160,46 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// A temporary storage of the sources of the messages stored in the current block,
+	/// offchain indexed and cleared at the end of the block
+	/// - Value: (provider MSA, MSA the message was sent on behalf of, IntentId, MessageIndex)
+	#[pallet::storage]
+	#[pallet::whitelist_storage]
+	#[pallet::unbounded]
+	pub(super) type BlockMessageSources<T: Config> = StorageValue<
+		_,
+		Vec<(MessageSourceId, Option<MessageSourceId>, IntentId, MessageIndex)>,
+		ValueQuery,
+	>;
+
+	/// Pruning progress for each Intent that has a message retention period
+	/// - Key: IntentId
+	/// - Value: [`RetentionCursor`]
+	#[pallet::storage]
+	pub(super) type RetentionCursors<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, RetentionCursor<BlockNumberFor<T>>, ValueQuery>;
+
+	/// The last Intent visited by the retention pruner; the next block continues after it
+	#[pallet::storage]
+	pub(super) type RetentionIntentCursor<T: Config> = StorageValue<_, IntentId, OptionQuery>;
+
+	/// URI schemes allowed for the payloads of off-chain messages, such as `https` or `ar`.
+	/// Schemes are stored in lowercase.
+	#[pallet::storage]
+	pub(super) type AllowedUriSchemes<T: Config> =
+		StorageValue<_, BoundedVec<UriScheme, T::MaxUriSchemes>, ValueQuery>;
+
+	#[pallet::type_value]
+	/// The CID policy defaults to the codecs and hash algorithms supported by DSNP
+	pub fn CidPolicyDefault<T: Config>() -> CidPolicy<T::MaxCidPolicyEntries> {
+		CidPolicy::default()
+	}
+
+	/// The codecs and multihash codes of the CIDs accepted for IPFS messages
+	#[pallet::storage]
+	pub(super) type AcceptedCidPolicy<T: Config> =
+		StorageValue<_, CidPolicy<T::MaxCidPolicyEntries>, ValueQuery, CidPolicyDefault<T>>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Deprecated: Too many messages are added to existing block
@@ -155,6 +228,33 @@ pub mod pallet {
 
 		/// Invalid CID
 		InvalidCid,
+
+		/// Schema is unsupported and can no longer be written to
+		SchemaUnsupported,
+
+		/// Payload does not conform to the schema model
+		InvalidPayload,
+
+		/// A message batch must contain at least one message
+		EmptyMessageBatch,
+
+		/// URI is malformed
+		InvalidUri,
+
+		/// URI scheme is not allowed for off-chain payloads
+		UriSchemeNotAllowed,
+
+		/// Content multihash is malformed or uses an unsupported hash algorithm
+		InvalidMultihash,
+
+		/// URI scheme is malformed, not lowercase or duplicated
+		InvalidUriScheme,
+
+		/// CID codec is not accepted by the CID policy
+		UnsupportedCidCodec,
+
+		/// CID policy is empty or has duplicated entries
+		InvalidCidPolicy,
 	}
 
 	#[pallet::event]
@@ -179,6 +279,22 @@ pub mod pallet {
 			/// Total number of messages migrated in the current migration
 			cumulative_total_migrated: u64,
 		},
+		/// Warning that a message was written with a deprecated schema
+		/// Writers should move to a newer version of the schema before it becomes unsupported
+		DeprecatedSchemaUsed {
+			/// The deprecated schema
+			schema_id: SchemaId,
+		},
+		/// The URI schemes allowed for off-chain payloads were updated
+		AllowedUriSchemesUpdated {
+			/// The URI schemes now allowed
+			schemes: BoundedVec<UriScheme, T::MaxUriSchemes>,
+		},
+		/// The CIDs accepted for IPFS messages were updated
+		CidPolicyUpdated {
+			/// The CID policy now in effect
+			policy: CidPolicy<T::MaxCidPolicyEntries>,
+		},
 	}
 
 	#[pallet::hooks]
@@ -186,21 +302,33 @@ pub mod pallet {
 		fn on_initialize(_current: BlockNumberFor<T>) -> Weight {
 			<BlockMessageIndex<T>>::set(0u16);
 			// allocates 1 read and 1 write for any access of `MessageIndex` in every block
-			T::DbWeight::get().reads(1u64).saturating_add(T::DbWeight::get().writes(1u64))
-			// TODO: add retention policy execution GitHub Issue: #126 and #25
+			// and 1 read and 1 write for taking `BlockMessageSources` in `on_finalize`
+			T::DbWeight::get().reads(2u64).saturating_add(T::DbWeight::get().writes(2u64))
+		}
+
+		fn on_finalize(current: BlockNumberFor<T>) {
+			Self::offchain_index_message_sources(current);
+		}
+
+		fn on_idle(current: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::prune_expired_messages(current, &mut meter);
+			meter.consumed()
 		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Adds a message for a resource hosted on IPFS. The input consists of
-		/// a Base32-encoded [CID](https://docs.ipfs.tech/concepts/content-addressing/#version-1-v1)
-		/// as well as a 32-bit content length. The stored payload will contain the
-		/// CID encoded as binary, as well as the 32-bit message content length.
+		/// a multibase-encoded [CID](https://docs.ipfs.tech/concepts/content-addressing/#version-1-v1)
+		/// (or a base58btc CIDv0 when allowed) as well as a 32-bit content length. The CID codec and
+		/// multihash must be accepted by the current [`CidPolicy`]. The stored payload will contain the
+		/// CID encoded as binary CIDv1, as well as the 32-bit message content length.
 		/// The actual message content will be on IPFS.
 		///
 		/// # Events
 		/// * [`Event::MessagesInBlock`] - Messages Stored in the block
+		/// * [`Event::DeprecatedSchemaUsed`] - The schema is deprecated
 		///
 		/// # Errors
 		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large.
@@ -208,8 +336,10 @@ pub mod pallet {
 		/// * [`Error::InvalidPayloadLocation`] - The schema is not an IPFS payload location.
 		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA.
 		/// * [`Error::TypeConversionOverflow`] - Failed to add the message to storage as it is very full.
-		/// * [`Error::UnsupportedCidVersion`] - CID version is not supported (V0).
-		/// * [`Error::InvalidCid`] - Unable to parse provided CID.
+		/// * [`Error::UnsupportedCidVersion`] - CIDv0 is not supported by the CID policy.
+		/// * [`Error::UnsupportedCidCodec`] - CID codec is not supported by the CID policy.
+		/// * [`Error::InvalidCid`] - Unable to parse provided CID or its multihash is not supported.
+		/// * [`Error::SchemaUnsupported`] - The schema is unsupported.
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(T::WeightInfo::add_ipfs_message())]
@@ -232,6 +362,7 @@ pub mod pallet {
 					schema.payload_location == PayloadLocation::IPFS,
 					Error::<T>::InvalidPayloadLocation
 				);
+				Self::ensure_schema_writable(&schema)?;
 
 				let provider_msa_id = Self::find_msa_id(&provider_key)?;
 				let current_block = frame_system::Pallet::<T>::block_number();
@@ -255,6 +386,7 @@ pub mod pallet {
 		///
 		/// # Events
 		/// * [`Event::MessagesInBlock`] - In the next block
+		/// * [`Event::DeprecatedSchemaUsed`] - The schema is deprecated
 		///
 		/// # Errors
 		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large.
@@ -263,6 +395,8 @@ pub mod pallet {
 		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA.
 		/// * [`Error::UnAuthorizedDelegate`] - Trying to add a message without a proper delegation between the origin and the on_behalf_of MSA.
 		/// * [`Error::TypeConversionOverflow`] - Failed to add the message to storage as it is very full.
+		/// * [`Error::SchemaUnsupported`] - The schema is unsupported.
+		/// * [`Error::InvalidPayload`] - The Intent requires payload validation and the payload does not conform to the schema model.
 		///
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::add_onchain_message(payload.len() as u32))]
@@ -282,6 +416,8 @@ pub mod pallet {
 					schema.payload_location == PayloadLocation::OnChain,
 					Error::<T>::InvalidPayloadLocation
 				);
+				Self::ensure_schema_writable(&schema)?;
+				Self::ensure_valid_payload(&schema, &bounded_payload)?;
 
 				let provider_msa_id = Self::find_msa_id(&provider_key)?;
 				let provider_id = ProviderId(provider_msa_id);
@@ -319,10 +455,290 @@ pub mod pallet {
 				Err(Error::<T>::InvalidSchemaId.into())
 			}
 		}
+
+		/// Adds a batch of messages for resources hosted on IPFS. Each entry is validated
+		/// and stored exactly as with [`Pallet::add_ipfs_message`], but the provider MSA is
+		/// resolved once and each schema is only looked up once per batch.
+		/// The batch is atomic: if any entry fails, no messages are stored.
+		///
+		/// # Events
+		/// * [`Event::MessagesInBlock`] - Messages Stored in the block
+		/// * [`Event::DeprecatedSchemaUsed`] - A schema is deprecated (once per schema)
+		///
+		/// # Errors
+		/// * [`Error::EmptyMessageBatch`] - No entries were provided.
+		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large.
+		/// * [`Error::InvalidSchemaId`] - Schema not found.
+		/// * [`Error::InvalidPayloadLocation`] - The schema is not an IPFS payload location.
+		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA.
+		/// * [`Error::UnsupportedCidVersion`] - CIDv0 is not supported by the CID policy.
+		/// * [`Error::UnsupportedCidCodec`] - CID codec is not supported by the CID policy.
+		/// * [`Error::InvalidCid`] - Unable to parse provided CID or its multihash is not supported.
+		/// * [`Error::SchemaUnsupported`] - The schema is unsupported.
+		///
+		#[pallet::call_index(2)]
+		#[pallet::weight(T::WeightInfo::add_ipfs_messages(entries.len() as u32))]
+		pub fn add_ipfs_messages(
+			origin: OriginFor<T>,
+			entries: BoundedVec<IpfsMessageEntry, T::MaxMessagesPerBatch>,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			ensure!(!entries.is_empty(), Error::<T>::EmptyMessageBatch);
+
+			let provider_msa_id = Self::find_msa_id(&provider_key)?;
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut schemas = BTreeMap::new();
+			let mut emit_event = false;
+			let cid_policy = AcceptedCidPolicy::<T>::get();
+
+			for entry in entries {
+				let schema = Self::get_cached_writable_schema(
+					&mut schemas,
+					entry.schema_id,
+					PayloadLocation::IPFS,
+				)?;
+				let cid_binary = Self::validate_cid_with_policy(&entry.cid, &cid_policy)?;
+				let payload_tuple: OffchainPayloadType = (cid_binary, entry.payload_length);
+				let bounded_payload: BoundedVec<u8, T::MessagesMaxPayloadSizeBytes> = payload_tuple
+					.encode()
+					.try_into()
+					.map_err(|_| Error::<T>::ExceedsMaxMessagePayloadSizeBytes)?;
+
+				emit_event |= Self::add_message(
+					provider_msa_id,
+					None,
+					bounded_payload,
+					schema.intent_id,
+					entry.schema_id,
+					current_block,
+				)?;
+			}
+
+			if emit_event {
+				Self::deposit_event(Event::MessagesInBlock);
+			}
+			Ok(())
+		}
+
+		/// Adds a batch of on-chain messages. Each entry is validated and stored exactly as
+		/// with [`Pallet::add_onchain_message`], but the provider MSA is resolved once and
+		/// each schema and delegation grant is only checked once per batch.
+		/// The batch is atomic: if any entry fails, no messages are stored.
+		///
+		/// # Events
+		/// * [`Event::MessagesInBlock`] - Messages Stored in the block
+		/// * [`Event::DeprecatedSchemaUsed`] - A schema is deprecated (once per schema)
+		///
+		/// # Errors
+		/// * [`Error::EmptyMessageBatch`] - No entries were provided.
+		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large.
+		/// * [`Error::InvalidSchemaId`] - Schema not found.
+		/// * [`Error::InvalidPayloadLocation`] - The schema is not an OnChain payload location.
+		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA.
+		/// * [`Error::UnAuthorizedDelegate`] - Trying to add a message without a proper delegation between the origin and the on_behalf_of MSA.
+		/// * [`Error::SchemaUnsupported`] - The schema is unsupported.
+		/// * [`Error::InvalidPayload`] - The Intent requires payload validation and the payload does not conform to the schema model.
+		///
+		#[pallet::call_index(3)]
+		#[pallet::weight(T::WeightInfo::add_onchain_messages(
+			entries.len() as u32,
+			Pallet::<T>::sum_payload_bytes(entries),
+		))]
+		pub fn add_onchain_messages(
+			origin: OriginFor<T>,
+			entries: BoundedVec<OnChainMessageEntry, T::MaxMessagesPerBatch>,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			ensure!(!entries.is_empty(), Error::<T>::EmptyMessageBatch);
+
+			let provider_msa_id = Self::find_msa_id(&provider_key)?;
+			let provider_id = ProviderId(provider_msa_id);
+			let current_block = frame_system::Pallet::<T>::block_number();
+			let mut schemas = BTreeMap::new();
+			let mut granted: BTreeSet<(MessageSourceId, IntentId)> = BTreeSet::new();
+			let mut emit_event = false;
+
+			for entry in entries {
+				let bounded_payload: BoundedVec<u8, T::MessagesMaxPayloadSizeBytes> = entry
+					.payload
+					.try_into()
+					.map_err(|_| Error::<T>::ExceedsMaxMessagePayloadSizeBytes)?;
+				let schema = Self::get_cached_writable_schema(
+					&mut schemas,
+					entry.schema_id,
+					PayloadLocation::OnChain,
+				)?;
+				Self::ensure_valid_payload(schema, &bounded_payload)?;
+
+				// On-chain messages either are sent from the user themselves, or on behalf of another MSA Id
+				let delegator_msa_id = match entry.on_behalf_of {
+					Some(delegator_msa_id) => {
+						if granted.insert((delegator_msa_id, schema.intent_id)) {
+							T::SchemaGrantValidator::ensure_valid_grant(
+								provider_id,
+								DelegatorId(delegator_msa_id),
+								schema.intent_id,
+								current_block,
+							)
+							.map_err(|_| Error::<T>::UnAuthorizedDelegate)?;
+						}
+						delegator_msa_id
+					},
+					None => provider_msa_id, // Delegate is also the Provider
+				};
+
+				emit_event |= Self::add_message(
+					provider_msa_id,
+					Some(delegator_msa_id),
+					bounded_payload,
+					schema.intent_id,
+					entry.schema_id,
+					current_block,
+				)?;
+			}
+
+			if emit_event {
+				Self::deposit_event(Event::MessagesInBlock);
+			}
+			Ok(())
+		}
+
+		/// Adds a message for a resource hosted off-chain at a URI other than IPFS, such as
+		/// HTTPS, Arweave or S3. The stored payload will contain the URI, a multihash of the
+		/// message content and the 32-bit message content length.
+		/// The URI scheme must be one of the schemes allowed by governance.
+		///
+		/// # Events
+		/// * [`Event::MessagesInBlock`] - Messages Stored in the block
+		/// * [`Event::DeprecatedSchemaUsed`] - The schema is deprecated
+		///
+		/// # Errors
+		/// * [`Error::ExceedsMaxMessagePayloadSizeBytes`] - Payload is too large.
+		/// * [`Error::InvalidSchemaId`] - Schema not found.
+		/// * [`Error::InvalidPayloadLocation`] - The schema is not an OffChain payload location.
+		/// * [`Error::InvalidMessageSourceAccount`] - Origin must be from an MSA.
+		/// * [`Error::InvalidUri`] - Unable to parse the scheme of the provided URI.
+		/// * [`Error::UriSchemeNotAllowed`] - The URI scheme is not allowed by governance.
+		/// * [`Error::InvalidMultihash`] - Unable to parse the provided multihash or its hash algorithm is not supported.
+		/// * [`Error::SchemaUnsupported`] - The schema is unsupported.
+		///
+		#[pallet::call_index(4)]
+		#[pallet::weight(T::WeightInfo::add_offchain_message())]
+		pub fn add_offchain_message(
+			origin: OriginFor<T>,
+			#[pallet::compact] schema_id: SchemaId,
+			uri: BoundedVec<u8, T::MaxOffchainUriLength>,
+			multihash: ContentMultihash,
+			#[pallet::compact] payload_length: u32,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			Self::ensure_allowed_uri(&uri)?;
+			validate_multihash(&multihash).map_err(|_| Error::<T>::InvalidMultihash)?;
+			let payload_tuple: OffchainUriPayloadType =
+				(uri.into_inner(), multihash.into_inner(), payload_length);
+			let bounded_payload: BoundedVec<u8, T::MessagesMaxPayloadSizeBytes> = payload_tuple
+				.encode()
+				.try_into()
+				.map_err(|_| Error::<T>::ExceedsMaxMessagePayloadSizeBytes)?;
+
+			if let Some(schema) = T::SchemaProvider::get_schema_info_by_id(schema_id) {
+				ensure!(
+					schema.payload_location == PayloadLocation::OffChain,
+					Error::<T>::InvalidPayloadLocation
+				);
+				Self::ensure_schema_writable(&schema)?;
+
+				let provider_msa_id = Self::find_msa_id(&provider_key)?;
+				let current_block = frame_system::Pallet::<T>::block_number();
+				if Self::add_message(
+					provider_msa_id,
+					None,
+					bounded_payload,
+					schema.intent_id,
+					schema_id,
+					current_block,
+				)? {
+					Self::deposit_event(Event::MessagesInBlock);
+				}
+				Ok(())
+			} else {
+				Err(Error::<T>::InvalidSchemaId.into())
+			}
+		}
+
+		/// Sets the URI schemes allowed for the payloads of off-chain messages, replacing the
+		/// current ones. Schemes are given without the trailing `:`, in lowercase, such as `https`.
+		///
+		/// # Events
+		/// * [`Event::AllowedUriSchemesUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidUriScheme`] - A scheme is malformed, not lowercase or duplicated.
+		///
+		#[pallet::call_index(5)]
+		#[pallet::weight(T::WeightInfo::set_allowed_uri_schemes(schemes.len() as u32))]
+		pub fn set_allowed_uri_schemes(
+			origin: OriginFor<T>,
+			schemes: BoundedVec<UriScheme, T::MaxUriSchemes>,
+		) -> DispatchResult {
+			T::UriSchemeGovernanceOrigin::ensure_origin(origin)?;
+
+			let mut seen = BTreeSet::new();
+			for scheme in schemes.iter() {
+				ensure!(
+					Self::is_valid_uri_scheme(scheme) &&
+						scheme.iter().all(|c| !c.is_ascii_uppercase()) &&
+						seen.insert(scheme),
+					Error::<T>::InvalidUriScheme
+				);
+			}
+
+			AllowedUriSchemes::<T>::put(&schemes);
+			Self::deposit_event(Event::AllowedUriSchemesUpdated { schemes });
+			Ok(())
+		}
+
+		/// Sets the codecs and multihash codes of the CIDs accepted for IPFS messages, replacing
+		/// the current policy. Messages already stored are not affected.
+		///
+		/// # Events
+		/// * [`Event::CidPolicyUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidCidPolicy`] - The policy has no codec or multihash code, or duplicates.
+		///
+		#[pallet::call_index(6)]
+		#[pallet::weight(T::WeightInfo::set_cid_policy())]
+		pub fn set_cid_policy(
+			origin: OriginFor<T>,
+			policy: CidPolicy<T::MaxCidPolicyEntries>,
+		) -> DispatchResult {
+			T::CidPolicyGovernanceOrigin::ensure_origin(origin)?;
+
+			for codes in [&policy.codecs, &policy.multihash_codes] {
+				ensure!(
+					!codes.is_empty() && codes.iter().collect::<BTreeSet<_>>().len() == codes.len(),
+					Error::<T>::InvalidCidPolicy
+				);
+			}
+
+			AcceptedCidPolicy::<T>::put(&policy);
+			Self::deposit_event(Event::CidPolicyUpdated { policy });
+			Ok(())
+		}
 	}
 }
 
 impl<T: Config> Pallet<T> {
+	/// Sums the payload bytes over all messages in an on-chain batch
+	pub fn sum_payload_bytes(
+		entries: &BoundedVec<OnChainMessageEntry, T::MaxMessagesPerBatch>,
+	) -> u32 {
+		entries
+			.iter()
+			.fold(0, |acc, entry| acc.saturating_add(entry.payload.len() as u32))
+	}
+
 	/// Stores a message for a given schema id.
 	/// returns true if it needs to emit an event
 	/// # Errors
@@ -346,10 +762,68 @@ impl<T: Config> Pallet<T> {
 		};
 
 		<MessagesV3<T>>::insert((current_block, intent_id, index), msg);
+		BlockMessageSources::<T>::append((provider_msa_id, msa_id, intent_id, index));
 		BlockMessageIndex::<T>::set(index.saturating_add(1));
 		Ok(first)
 	}
 
+	/// Checks the schema status before writing a message.
+	/// Writes to deprecated schemas are allowed but emit a warning event.
+	///
+	/// # Errors
+	/// * [`Error::SchemaUnsupported`]
+	///
+	pub fn ensure_schema_writable(schema: &SchemaInfoResponse) -> DispatchResult {
+		match schema.status {
+			SchemaStatus::Active => {},
+			SchemaStatus::Deprecated =>
+				Self::deposit_event(Event::DeprecatedSchemaUsed { schema_id: schema.schema_id }),
+			SchemaStatus::Unsupported => return Err(Error::<T>::SchemaUnsupported.into()),
+		}
+		Ok(())
+	}
+
+	/// Checks the payload against the schema model when the Intent requires it
+	///
+	/// # Errors
+	/// * [`Error::InvalidPayload`] - The payload does not conform to the schema model
+	///
+	pub fn ensure_valid_payload(schema: &SchemaInfoResponse, payload: &[u8]) -> DispatchResult {
+		if schema.settings.contains(&IntentSetting::ValidatePayload) {
+			T::SchemaProvider::validate_payload(schema.schema_id, payload)
+				.map_err(|_| Error::<T>::InvalidPayload)?;
+		}
+		Ok(())
+	}
+
+	/// Gets a schema for a message in a batch, looking it up and checking that it is
+	/// writable at the given payload location only the first time it is seen.
+	///
+	/// # Errors
+	/// * [`Error::InvalidSchemaId`]
+	/// * [`Error::InvalidPayloadLocation`]
+	/// * [`Error::SchemaUnsupported`]
+	///
+	fn get_cached_writable_schema(
+		schemas: &mut BTreeMap<SchemaId, SchemaInfoResponse>,
+		schema_id: SchemaId,
+		payload_location: PayloadLocation,
+	) -> Result<&SchemaInfoResponse, DispatchError> {
+		match schemas.entry(schema_id) {
+			Entry::Occupied(entry) => Ok(&*entry.into_mut()),
+			Entry::Vacant(entry) => {
+				let schema = T::SchemaProvider::get_schema_info_by_id(schema_id)
+					.ok_or(Error::<T>::InvalidSchemaId)?;
+				ensure!(
+					schema.payload_location == payload_location,
+					Error::<T>::InvalidPayloadLocation
+				);
+				Self::ensure_schema_writable(&schema)?;
+				Ok(&*entry.insert(schema))
+			},
+		}
+	}
+
 	/// Resolve an MSA from an account key(key)
 	/// An MSA Id associated with the account key is returned, if one exists.
 	///
@@ -361,80 +835,57 @@ impl<T: Config> Pallet<T> {
 			.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?)
 	}
 
-	/// Retrieve the messages for a particular intent and block range (paginated)
+	/// Retrieve the messages for a particular intent and block range (paginated), without the
+	/// URI and content hash of OffChain messages
 	pub fn get_messages_by_intent_id(
 		intent_id: IntentId,
 		pagination: BlockPaginationRequest,
 	) -> BlockPaginationResponse<MessageResponseV2> {
-		let mut response = BlockPaginationResponse::new();
+		Self::get_messages_by_intent_id_v2(intent_id, pagination).map_content(Into::into)
+	}
 
+	/// Retrieve the messages for a particular intent and block range (paginated)
+	pub fn get_messages_by_intent_id_v2(
+		intent_id: IntentId,
+		pagination: BlockPaginationRequest,
+	) -> BlockPaginationResponse<MessageResponseV3> {
 		// Request Validation
 		if !pagination.validate() {
-			return response
+			return BlockPaginationResponse::new()
 		}
 
 		// Schema Fetch and Check
 		let intent = match <T>::SchemaProvider::get_intent_by_id(intent_id) {
 			Some(intent) => intent,
-			None => return response,
+			None => return BlockPaginationResponse::new(),
 		};
 
-		let mut from_index: u32 = pagination.from_index;
-
-		'block_loop: for block_number in pagination.from_block..pagination.to_block {
-			let list: Vec<MessageResponseV2> = Self::get_messages_by_intent_and_block(
+		BlockPaginationResponse::from_blocks(&pagination, |block_number| {
+			Self::get_messages_by_intent_and_block(
 				intent_id,
 				intent.payload_location,
 				block_number.into(),
-			);
-
-			// Max messages in a block are constrained to MessageIndex (u16) by the storage,
-			// so this is a safe type coercion. Just to be safe, we'll trap in in debug builds
-			let list_size = list.len();
-			debug_assert!(
-				list_size <= MessageIndex::MAX.into(),
-				"unexpected number of messages in block"
-			);
-			let list_size: u32 = list_size as u32;
-
-			let iter = list.into_iter().skip(from_index.saturating_sub(1) as usize);
-			// all subsequent blocks in this call should start at index 0
-			from_index = 0;
-			'message_loop: for (i, m) in iter.enumerate() {
-				response.content.push(m);
-
-				if response.check_end_condition_and_set_next_pagination(
-					block_number,
-					i as u32,
-					list_size,
-					&pagination,
-				) {
-					break 'block_loop;
-				}
-
-				continue 'message_loop;
-			}
-		}
-		response
+			)
+		})
 	}
 
 	/// Gets messages for a given IntentId and block number.
 	///
 	/// Payload location is included to map to correct response (To avoid fetching the Intent in this method)
 	///
-	/// Result is a vector of [`MessageResponseV2`].
+	/// Result is a vector of [`MessageResponseV3`].
 	///
 	pub fn get_messages_by_intent_and_block(
 		intent_id: IntentId,
 		payload_location: PayloadLocation,
 		block_number: BlockNumberFor<T>,
-	) -> Vec<MessageResponseV2> {
+	) -> Vec<MessageResponseV3> {
 		let block_number_value: BlockNumber = block_number.try_into().unwrap_or_default();
 
 		match payload_location {
 			PayloadLocation::Itemized | PayloadLocation::Paginated => Vec::new(),
 			_ => {
-				let mut messages: Vec<MessageResponseV2> =
+				let mut messages: Vec<MessageResponseV3> =
 					MessagesV3::<T>::iter_prefix((block_number, intent_id))
 						.filter_map(|(index, msg)| {
 							msg.map_to_response((block_number_value, payload_location, index))
@@ -446,15 +897,241 @@ impl<T: Config> Pallet<T> {
 		}
 	}
 
-	/// Validates a CID to conform to IPFS CIDv1 (or higher) formatting (does not validate decoded CID fields)
+	/// Gets the messages stored in a block at the given `(IntentId, MessageIndex)` keys, without
+	/// the URI and content hash of OffChain messages.
+	///
+	/// Keys without a stored message, such as pruned messages, are skipped.
+	pub fn get_messages_by_index(
+		block_number: BlockNumberFor<T>,
+		message_keys: Vec<(IntentId, MessageIndex)>,
+	) -> Vec<MessageResponseV2> {
+		Self::get_messages_by_index_v2(block_number, message_keys)
+			.into_iter()
+			.map(Into::into)
+			.collect()
+	}
+
+	/// Gets the messages stored in a block at the given `(IntentId, MessageIndex)` keys.
+	///
+	/// Keys without a stored message, such as pruned messages, are skipped.
+	pub fn get_messages_by_index_v2(
+		block_number: BlockNumberFor<T>,
+		message_keys: Vec<(IntentId, MessageIndex)>,
+	) -> Vec<MessageResponseV3> {
+		let block_number_value: BlockNumber = block_number.try_into().unwrap_or_default();
+		let mut payload_locations: BTreeMap<SchemaId, Option<PayloadLocation>> = BTreeMap::new();
+
+		message_keys
+			.into_iter()
+			.filter_map(|(intent_id, index)| -> Option<MessageResponseV3> {
+				let message = MessagesV3::<T>::get((block_number, intent_id, index))?;
+				let payload_location =
+					*payload_locations.entry(message.schema_id).or_insert_with(|| {
+						T::SchemaProvider::get_schema_info_by_id(message.schema_id)
+							.map(|schema| schema.payload_location)
+					});
+				message.map_to_response((block_number_value, payload_location?, index))
+			})
+			.collect()
+	}
+
+	/// Offchain indexes the `(IntentId, MessageIndex)` of each message stored in the block, both
+	/// by the provider that sent it and by the MSA it was sent on behalf of.
+	fn offchain_index_message_sources(current: BlockNumberFor<T>) {
+		let sources = BlockMessageSources::<T>::take();
+		if sources.is_empty() {
+			return
+		}
+
+		let block_number: BlockNumber = current.try_into().unwrap_or_default();
+		let parent_hash = frame_system::Pallet::<T>::parent_hash();
+		let mut index: BTreeMap<(&[u8], MessageSourceId), Vec<(IntentId, MessageIndex)>> =
+			BTreeMap::new();
+		for (provider_msa_id, msa_id, intent_id, message_index) in sources {
+			index
+				.entry((MESSAGES_BY_PROVIDER_INDEX_PREFIX.as_slice(), provider_msa_id))
+				.or_default()
+				.push((intent_id, message_index));
+			if let Some(msa_id) = msa_id {
+				index
+					.entry((MESSAGES_BY_MSA_INDEX_PREFIX.as_slice(), msa_id))
+					.or_default()
+					.push((intent_id, message_index));
+			}
+		}
+
+		for ((prefix, msa_id), message_keys) in index {
+			let key = get_message_index_key(prefix, msa_id, block_number, &parent_hash);
+			sp_io::offchain_index::set(&key, &message_keys.encode());
+		}
+	}
+
+	/// Retrieve the oldest block whose messages for an Intent have not been pruned.
+	///
+	/// Messages in earlier blocks, back to one retention period before the retention was set,
+	/// have been removed by the retention pruner and are only available from an archive node.
+	pub fn get_oldest_retained_block(intent_id: IntentId) -> BlockNumberFor<T> {
+		let cursor = RetentionCursors::<T>::get(intent_id);
+		if cursor.partial {
+			cursor.next_block.saturating_add(One::one())
+		} else {
+			cursor.next_block
+		}
+	}
+
+	/// Removes messages older than the retention period of their Intent, within the weight
+	/// available in `meter`.
+	///
+	/// Each Intent with a retention period keeps a [`RetentionCursor`] of the oldest block that
+	/// still needs pruning, starting from one retention period before the retention was set.
+	/// At most [`MAX_RETENTION_INTENTS_PER_BLOCK`] Intents are visited per block, continuing
+	/// after the last Intent visited, so that a single Intent with a large backlog does not
+	/// starve the others.
+	pub fn prune_expired_messages(current: BlockNumberFor<T>, meter: &mut WeightMeter) {
+		if meter
+			.try_consume(T::WeightInfo::prune_expired_messages(MAX_RETENTION_INTENTS_PER_BLOCK))
+			.is_err()
+		{
+			return
+		}
+
+		let start_after = RetentionIntentCursor::<T>::get();
+		let mut retention_periods = T::SchemaProvider::get_intent_retention_periods(
+			start_after,
+			MAX_RETENTION_INTENTS_PER_BLOCK,
+		);
+		if retention_periods.is_empty() && start_after.is_some() {
+			retention_periods = T::SchemaProvider::get_intent_retention_periods(
+				None,
+				MAX_RETENTION_INTENTS_PER_BLOCK,
+			);
+		}
+		let Some((last_intent_id, _)) = retention_periods.last().copied() else {
+			RetentionIntentCursor::<T>::kill();
+			return
+		};
+		// wrap around to the first Intent once the last one has been visited
+		let next_start = if retention_periods.len() < MAX_RETENTION_INTENTS_PER_BLOCK as usize {
+			None
+		} else {
+			Some(last_intent_id)
+		};
+
+		let block_weight = T::WeightInfo::prune_messages_in_block(0);
+		let message_weight = T::WeightInfo::prune_messages_in_block(1).saturating_sub(block_weight);
+
+		for (intent_id, retention) in retention_periods.iter() {
+			let Some(last_expired) = current.checked_sub(&retention.period.into()) else {
+				continue
+			};
+			let initial_cursor = RetentionCursors::<T>::get(intent_id);
+			let first_block: BlockNumberFor<T> =
+				retention.since.saturating_sub(retention.period).into();
+			let mut cursor = if initial_cursor.next_block < first_block {
+				RetentionCursor { next_block: first_block, partial: false }
+			} else {
+				initial_cursor
+			};
+			let mut out_of_weight = false;
+
+			while cursor.next_block <= last_expired {
+				if !meter.can_consume(block_weight.saturating_add(message_weight)) {
+					out_of_weight = true;
+					break
+				}
+				meter.consume(block_weight);
+				let limit = meter
+					.remaining()
+					.checked_div_per_component(&message_weight)
+					.unwrap_or(u64::MAX)
+					.min(MessageIndex::MAX as u64 + 1) as u32;
+				let result =
+					MessagesV3::<T>::clear_prefix((cursor.next_block, *intent_id), limit, None);
+				meter.consume(message_weight.saturating_mul(result.unique.into()));
+
+				if result.maybe_cursor.is_some() {
+					cursor.partial = true;
+					out_of_weight = true;
+					break
+				}
+				cursor = RetentionCursor {
+					next_block: cursor.next_block.saturating_add(One::one()),
+					partial: false,
+				};
+			}
+
+			if cursor != initial_cursor {
+				RetentionCursors::<T>::insert(intent_id, cursor);
+			}
+			if out_of_weight {
+				// the next block continues with the Intents after this one
+				RetentionIntentCursor::<T>::set(Some(*intent_id));
+				return
+			}
+		}
+		RetentionIntentCursor::<T>::set(next_start);
+	}
+
+	/// Checks that a URI scheme starts with a letter followed by letters, digits, `+`, `-` or `.`
+	/// ([RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-3.1))
+	pub fn is_valid_uri_scheme(scheme: &[u8]) -> bool {
+		match scheme.split_first() {
+			Some((first, rest)) =>
+				first.is_ascii_alphabetic() &&
+					rest.iter().all(|c| c.is_ascii_alphanumeric() || b"+-.".contains(c)),
+			None => false,
+		}
+	}
+
+	/// Checks that a URI is printable ASCII with a scheme allowed for off-chain payloads.
+	/// Schemes are case-insensitive.
+	///
+	/// # Errors
+	/// * [`Error::InvalidUri`] - The URI is not printable ASCII or has no valid scheme
+	/// * [`Error::UriSchemeNotAllowed`] - The URI scheme is not allowed by governance
+	///
+	pub fn ensure_allowed_uri(uri: &[u8]) -> DispatchResult {
+		ensure!(uri.iter().all(|c| c.is_ascii_graphic()), Error::<T>::InvalidUri);
+		let (scheme, rest) = match uri.iter().position(|c| *c == b':') {
+			Some(separator) => (&uri[..separator], &uri[separator + 1..]),
+			None => return Err(Error::<T>::InvalidUri.into()),
+		};
+		ensure!(Self::is_valid_uri_scheme(scheme) && !rest.is_empty(), Error::<T>::InvalidUri);
+
+		let scheme = scheme.to_ascii_lowercase();
+		ensure!(
+			AllowedUriSchemes::<T>::get().iter().any(|allowed| allowed[..] == scheme[..]),
+			Error::<T>::UriSchemeNotAllowed
+		);
+		Ok(())
+	}
+
+	/// Validates a CID against the current [`CidPolicy`], returning it as a binary CIDv1
+	/// (does not validate decoded CID fields)
 	///
 	/// # Errors
-	/// * [`Error::UnsupportedCidVersion`] - CID version is not supported (V0)
-	/// * [`Error::InvalidCid`] - Unable to parse provided CID
+	/// * [`Error::UnsupportedCidVersion`] - CIDv0 is not supported by the CID policy
+	/// * [`Error::UnsupportedCidCodec`] - CID codec is not supported by the CID policy
+	/// * [`Error::InvalidCid`] - Unable to parse provided CID or its multihash is not supported
 	///
 	pub fn validate_cid(in_cid: &[u8]) -> Result<Vec<u8>, DispatchError> {
-		Ok(validate_cid(in_cid).map_err(|e| match e {
+		Self::validate_cid_with_policy(in_cid, &AcceptedCidPolicy::<T>::get())
+	}
+
+	/// Validates a CID against the given [`CidPolicy`], returning it as a binary CIDv1
+	pub fn validate_cid_with_policy(
+		in_cid: &[u8],
+		policy: &CidPolicy<T::MaxCidPolicyEntries>,
+	) -> Result<Vec<u8>, DispatchError> {
+		Ok(validate_cid_with_policy(
+			in_cid,
+			&policy.codecs,
+			&policy.multihash_codes,
+			policy.allow_cid_v0,
+		)
+		.map_err(|e| match e {
 			CidError::UnsupportedCidVersion => Error::<T>::UnsupportedCidVersion,
+			CidError::UnsupportedCidCodec => Error::<T>::UnsupportedCidCodec,
 			_ => Error::<T>::InvalidCid,
 		})?)
 	}
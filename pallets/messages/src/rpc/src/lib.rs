@@ -10,20 +10,34 @@
 
 #[cfg(feature = "std")]
 use common_helpers::rpc::map_rpc_result;
-use common_primitives::{messages::*, schema::*};
+use common_primitives::{
+	messages::*,
+	msa::MessageSourceId,
+	node::BlockNumber,
+	offchain::{
+		get_message_index_key, MESSAGES_BY_MSA_INDEX_PREFIX, MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+	},
+	schema::*,
+};
 use frame_support::{ensure, fail};
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	proc_macros::rpc,
+	tracing::warn,
 	types::{ErrorObject, ErrorObjectOwned},
 };
 use pallet_messages_runtime_api::MessagesRuntimeApi;
 use pallet_schemas_runtime_api::SchemasRuntimeApi;
+use parity_scale_codec::Decode;
+use parking_lot::RwLock;
 use sp_api::ProvideRuntimeApi;
 use sp_blockchain::HeaderBackend;
 use sp_runtime::traits::Block as BlockT;
 use std::sync::Arc;
 
+mod subscription;
+pub use subscription::*;
+
 #[cfg(test)]
 mod tests;
 
@@ -36,19 +50,66 @@ pub trait MessagesApi {
 		&self,
 		intent_id: IntentId,
 		pagination: BlockPaginationRequest,
-	) -> RpcResult<BlockPaginationResponse<MessageResponseV2>>;
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>>;
+
+	/// Retrieve paginated messages sent by a provider, across all intents.
+	/// Requires offchain indexing to be enabled on the node.
+	#[method(name = "messages_getByProviderMsaId")]
+	fn get_messages_by_provider_msa_id(
+		&self,
+		provider_msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>>;
+
+	/// Retrieve paginated messages sent on behalf of an MSA, across all intents.
+	/// Requires offchain indexing to be enabled on the node.
+	#[method(name = "messages_getByMsaId")]
+	fn get_messages_by_msa_id(
+		&self,
+		msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>>;
 }
 
 /// The client handler for the API used by Frequency Service RPC with `jsonrpsee`
-pub struct MessagesHandler<C, M> {
+pub struct MessagesHandler<C, M, OffchainDB> {
 	client: Arc<C>,
+	offchain: Arc<RwLock<Option<OffchainDB>>>,
 	_marker: std::marker::PhantomData<M>,
 }
 
-impl<C, M> MessagesHandler<C, M> {
+impl<C, M, OffchainDB> MessagesHandler<C, M, OffchainDB>
+where
+	OffchainDB: Send + Sync,
+{
 	/// Create new instance with the given reference to the client.
-	pub fn new(client: Arc<C>) -> Self {
-		Self { client, _marker: Default::default() }
+	pub fn new(client: Arc<C>, offchain: Option<OffchainDB>) -> Self {
+		Self { client, offchain: Arc::new(RwLock::new(offchain)), _marker: Default::default() }
+	}
+}
+
+/// The MSA that messages are indexed by
+#[derive(Clone, Copy, Debug)]
+enum MessageSourceRole {
+	/// The provider that sent the message
+	Provider,
+	/// The MSA the message was sent on behalf of
+	Source,
+}
+
+impl MessageSourceRole {
+	fn index_prefix(self) -> &'static [u8] {
+		match self {
+			Self::Provider => MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+			Self::Source => MESSAGES_BY_MSA_INDEX_PREFIX,
+		}
+	}
+
+	fn matches(self, message: &MessageResponseV3, msa_id: MessageSourceId) -> bool {
+		match self {
+			Self::Provider => message.provider_msa_id == msa_id,
+			Self::Source => message.msa_id == Some(msa_id),
+		}
 	}
 }
 
@@ -63,6 +124,14 @@ pub enum MessageRpcError {
 	InvalidSchemaId,
 	/// Intent Id doesn't exist or errored when retrieving from state
 	InvalidIntentId,
+	/// Error acquiring lock
+	ErrorAcquiringLock,
+	/// Error decoding data
+	ErrorDecodingData,
+	/// Offchain indexing is not enabled
+	OffchainIndexingNotEnabled,
+	/// Subscription request is bad
+	InvalidSubscriptionRequest,
 }
 
 impl From<MessageRpcError> for ErrorObjectOwned {
@@ -73,22 +142,87 @@ impl From<MessageRpcError> for ErrorObjectOwned {
 			MessageRpcError::TypeConversionOverflow => ErrorObject::owned(2, msg, None::<()>),
 			MessageRpcError::InvalidSchemaId => ErrorObject::owned(3, msg, None::<()>),
 			MessageRpcError::InvalidIntentId => ErrorObject::owned(4, msg, None::<()>),
+			MessageRpcError::ErrorAcquiringLock => ErrorObject::owned(5, msg, None::<()>),
+			MessageRpcError::ErrorDecodingData => ErrorObject::owned(6, msg, None::<()>),
+			MessageRpcError::OffchainIndexingNotEnabled => ErrorObject::owned(7, msg, None::<()>),
+			MessageRpcError::InvalidSubscriptionRequest => ErrorObject::owned(8, msg, None::<()>),
 		}
 	}
 }
 
+impl<C, Block, OffchainDB> MessagesHandler<C, Block, OffchainDB>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + 'static,
+	C::Api: MessagesRuntimeApi<Block>,
+	OffchainDB: sp_core::offchain::OffchainStorage + 'static,
+{
+	/// Pages through the offchain index of the messages of an MSA in the requested block range
+	fn get_messages_by_source(
+		&self,
+		role: MessageSourceRole,
+		msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>> {
+		// Request Validation
+		ensure!(pagination.validate(), MessageRpcError::InvalidPaginationRequest);
+
+		let reader = self.offchain.try_read().ok_or(MessageRpcError::ErrorAcquiringLock)?;
+		let offchain = reader.as_ref().ok_or(MessageRpcError::OffchainIndexingNotEnabled)?;
+
+		// Connect to on-chain data
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+
+		let mut decoding_failed = false;
+		let response = BlockPaginationResponse::from_blocks(&pagination, |block_number| {
+			let Some(parent_hash) = self.parent_hash(block_number) else { return Vec::new() };
+			let key =
+				get_message_index_key(role.index_prefix(), msa_id, block_number, &parent_hash);
+			let Some(raw) = offchain.get(sp_offchain::STORAGE_PREFIX, &key) else {
+				return Vec::new()
+			};
+			let Ok(message_keys) = Vec::<(IntentId, u16)>::decode(&mut &raw[..]) else {
+				decoding_failed = true;
+				return Vec::new()
+			};
+
+			// Entries are checked against chain state, as a block on another fork may have
+			// overwritten them
+			api.get_messages_by_index_v2(at, block_number, message_keys)
+				.unwrap_or_else(|e| {
+					warn!("ApiError from get_messages_by_index_v2! {:?}", e);
+					Vec::new()
+				})
+				.into_iter()
+				.filter(|message| role.matches(message, msa_id))
+				.collect()
+		});
+
+		ensure!(!decoding_failed, MessageRpcError::ErrorDecodingData);
+		Ok(response)
+	}
+
+	/// Hash of the parent of a block on the canonical chain
+	fn parent_hash(&self, block_number: BlockNumber) -> Option<Block::Hash> {
+		let parent_number = block_number.checked_sub(1)?;
+		self.client.hash(parent_number.into()).ok().flatten()
+	}
+}
+
 #[async_trait]
-impl<C, Block> MessagesApiServer for MessagesHandler<C, Block>
+impl<C, Block, OffchainDB> MessagesApiServer for MessagesHandler<C, Block, OffchainDB>
 where
 	Block: BlockT,
 	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + 'static,
 	C::Api: MessagesRuntimeApi<Block> + SchemasRuntimeApi<Block>,
+	OffchainDB: sp_core::offchain::OffchainStorage + 'static,
 {
 	fn get_messages_by_intent_id(
 		&self,
 		intent_id: IntentId,
 		pagination: BlockPaginationRequest,
-	) -> RpcResult<BlockPaginationResponse<MessageResponseV2>> {
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>> {
 		// Request Validation
 		ensure!(pagination.validate(), MessageRpcError::InvalidPaginationRequest);
 
@@ -102,7 +236,23 @@ where
 			_ => fail!(MessageRpcError::InvalidIntentId),
 		};
 
-		let response = api.get_messages_by_intent_id(at, intent_id, pagination).unwrap_or_default();
+		let response =
+			api.get_messages_by_intent_id_v2(at, intent_id, pagination).unwrap_or_default();
 		map_rpc_result(Ok(response))
 	}
+	fn get_messages_by_provider_msa_id(
+		&self,
+		provider_msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>> {
+		self.get_messages_by_source(MessageSourceRole::Provider, provider_msa_id, pagination)
+	}
+
+	fn get_messages_by_msa_id(
+		&self,
+		msa_id: MessageSourceId,
+		pagination: BlockPaginationRequest,
+	) -> RpcResult<BlockPaginationResponse<MessageResponseV3>> {
+		self.get_messages_by_source(MessageSourceRole::Source, msa_id, pagination)
+	}
 }
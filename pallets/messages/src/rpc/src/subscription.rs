@@ -0,0 +1,346 @@
+//! Subscription to the messages of a set of intents, driven by block import notifications
+
+use crate::MessageRpcError;
+use common_primitives::{messages::*, node::BlockNumber, schema::*};
+use futures::{stream, FutureExt, StreamExt};
+use jsonrpsee::{
+	proc_macros::rpc, tracing::warn, PendingSubscriptionSink, SubscriptionMessage, SubscriptionSink,
+};
+use pallet_messages_runtime_api::MessagesRuntimeApi;
+use pallet_schemas_runtime_api::SchemasRuntimeApi;
+use sc_client_api::{BlockImportNotification, BlockchainEvents, FinalityNotification};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::traits::SpawnNamed;
+use sp_runtime::traits::{Block as BlockT, Header as HeaderT, UniqueSaturatedInto};
+use std::{collections::BTreeMap, sync::Arc};
+
+/// Maximum number of intents a single subscription may follow
+pub const MAX_INTENTS_PER_SUBSCRIPTION: usize = 16;
+
+/// Frequency Messages Subscription RPC API
+#[rpc(client, server)]
+pub trait MessagesSubscriptionApi<BlockHash> {
+	/// Subscribe to the messages of the given intents as blocks join the best chain.
+	/// When `from_block` is given, messages already stored from that block up to the current best
+	/// block are sent first. Messages of blocks that leave the best chain are retracted, and
+	/// finalization is notified so that subscribers know which messages are final.
+	#[subscription(
+		name = "messages_subscribeByIntentId" => "messages_intentMessages",
+		unsubscribe = "messages_unsubscribeByIntentId",
+		item = MessageNotification<BlockHash>
+	)]
+	fn subscribe_messages_by_intent_id(
+		&self,
+		intent_ids: Vec<IntentId>,
+		from_block: Option<BlockNumber>,
+	);
+}
+
+/// The subscription handler for the API used by Frequency Service RPC with `jsonrpsee`
+pub struct MessagesSubscriptionHandler<C, M> {
+	client: Arc<C>,
+	executor: Arc<dyn SpawnNamed>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> MessagesSubscriptionHandler<C, M> {
+	/// Create new instance with the given reference to the client and the executor that runs the
+	/// subscription tasks.
+	pub fn new(client: Arc<C>, executor: Arc<dyn SpawnNamed>) -> Self {
+		Self { client, executor, _marker: Default::default() }
+	}
+}
+
+/// A chain event a subscription reacts to
+enum ChainEvent<Block: BlockT> {
+	Imported(BlockImportNotification<Block>),
+	Finalized(FinalityNotification<Block>),
+}
+
+/// Keeps track of the blocks whose messages were sent to a subscriber and are not final yet, so
+/// that they can be retracted if they leave the best chain.
+pub(crate) struct MessageSubscription<Hash> {
+	reported: Vec<(BlockNumber, Hash)>,
+}
+
+impl<Hash: Copy + PartialEq> MessageSubscription<Hash> {
+	pub(crate) fn new() -> Self {
+		Self { reported: Vec::new() }
+	}
+
+	/// Whether the messages of the block have already been sent
+	pub(crate) fn is_reported(&self, block_number: BlockNumber, block_hash: Hash) -> bool {
+		self.reported.contains(&(block_number, block_hash))
+	}
+
+	/// Notification of the messages of a block on the best chain, if it has any left to send
+	pub(crate) fn report(
+		&mut self,
+		block_number: BlockNumber,
+		block_hash: Hash,
+		messages: Vec<MessageResponseV3>,
+	) -> Option<MessageNotification<Hash>> {
+		if messages.is_empty() || self.is_reported(block_number, block_hash) {
+			return None
+		}
+		self.reported.push((block_number, block_hash));
+		Some(MessageNotification::Messages { block_hash, block_number, messages })
+	}
+
+	/// Notification that a block left the best chain, if its messages were sent
+	pub(crate) fn retract(
+		&mut self,
+		block_number: BlockNumber,
+		block_hash: Hash,
+	) -> Option<MessageNotification<Hash>> {
+		let position = self
+			.reported
+			.iter()
+			.position(|reported| *reported == (block_number, block_hash))?;
+		self.reported.remove(position);
+		Some(MessageNotification::Retracted { block_hash, block_number })
+	}
+
+	/// Notification that the chain is final up to the block. Blocks at or below it are forgotten.
+	pub(crate) fn finalize(
+		&mut self,
+		block_number: BlockNumber,
+		block_hash: Hash,
+	) -> MessageNotification<Hash> {
+		self.reported.retain(|(reported_number, _)| *reported_number > block_number);
+		MessageNotification::Finalized { block_hash, block_number }
+	}
+}
+
+impl<C, Block> MessagesSubscriptionHandler<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + HeaderBackend<Block> + BlockchainEvents<Block> + Send + Sync,
+	C::Api: MessagesRuntimeApi<Block> + SchemasRuntimeApi<Block>,
+{
+	/// Checks the subscription request against the current best block
+	fn validate(
+		&self,
+		intent_ids: &[IntentId],
+		from_block: Option<BlockNumber>,
+	) -> Result<(), MessageRpcError> {
+		if intent_ids.is_empty() || intent_ids.len() > MAX_INTENTS_PER_SUBSCRIPTION {
+			return Err(MessageRpcError::InvalidSubscriptionRequest)
+		}
+
+		let info = self.client.info();
+		let best_number: BlockNumber = info.best_number.unique_saturated_into();
+		if let Some(from_block) = from_block {
+			if best_number.saturating_sub(from_block) >= BlockPaginationRequest::MAX_BLOCK_RANGE {
+				return Err(MessageRpcError::InvalidSubscriptionRequest)
+			}
+		}
+
+		let api = self.client.runtime_api();
+		for intent_id in intent_ids {
+			match api.get_intent_by_id(info.best_hash, *intent_id, false) {
+				Ok(Some(_)) => (),
+				_ => return Err(MessageRpcError::InvalidIntentId),
+			}
+		}
+		Ok(())
+	}
+
+	/// Messages of the intents stored in blocks `[from_block, to_block)`, grouped by block, as of
+	/// the state at `at`
+	fn messages_by_block(
+		&self,
+		at: Block::Hash,
+		intent_ids: &[IntentId],
+		from_block: BlockNumber,
+		to_block: BlockNumber,
+	) -> BTreeMap<BlockNumber, Vec<MessageResponseV3>> {
+		let api = self.client.runtime_api();
+		let mut messages_by_block = BTreeMap::<BlockNumber, Vec<MessageResponseV3>>::new();
+		for intent_id in intent_ids {
+			let mut pagination = BlockPaginationRequest {
+				from_block,
+				from_index: 0,
+				to_block,
+				page_size: BlockPaginationRequest::MAX_PAGE_SIZE,
+			};
+			loop {
+				let page =
+					match api.get_messages_by_intent_id_v2(at, *intent_id, pagination.clone()) {
+						Ok(page) => page,
+						Err(e) => {
+							warn!("ApiError from get_messages_by_intent_id_v2! {:?}", e);
+							break
+						},
+					};
+				for message in page.content {
+					messages_by_block.entry(message.block_number).or_default().push(message);
+				}
+				match (page.has_next, page.next_block, page.next_index) {
+					(true, Some(next_block), Some(next_index)) => {
+						pagination.from_block = next_block;
+						pagination.from_index = next_index;
+					},
+					_ => break,
+				}
+			}
+		}
+		messages_by_block
+	}
+
+	/// Messages of the intents stored in a single block, as of the state of that block
+	fn block_messages(
+		&self,
+		block_hash: Block::Hash,
+		block_number: BlockNumber,
+		intent_ids: &[IntentId],
+	) -> Vec<MessageResponseV3> {
+		self.messages_by_block(block_hash, intent_ids, block_number, block_number.saturating_add(1))
+			.remove(&block_number)
+			.unwrap_or_default()
+	}
+
+	/// Notifications of the messages already stored from `from_block` up to the best block
+	fn backfill(
+		&self,
+		subscription: &mut MessageSubscription<Block::Hash>,
+		intent_ids: &[IntentId],
+		from_block: BlockNumber,
+	) -> Vec<MessageNotification<Block::Hash>> {
+		let info = self.client.info();
+		let best_number: BlockNumber = info.best_number.unique_saturated_into();
+		if from_block > best_number {
+			return Vec::new()
+		}
+
+		self.messages_by_block(
+			info.best_hash,
+			intent_ids,
+			from_block,
+			best_number.saturating_add(1),
+		)
+		.into_iter()
+		.filter_map(|(block_number, messages)| {
+			let block_hash = self.client.hash(block_number.into()).ok().flatten()?;
+			subscription.report(block_number, block_hash, messages)
+		})
+		.collect()
+	}
+
+	/// Notifications for a chain event
+	fn on_event(
+		&self,
+		subscription: &mut MessageSubscription<Block::Hash>,
+		intent_ids: &[IntentId],
+		event: ChainEvent<Block>,
+	) -> Vec<MessageNotification<Block::Hash>> {
+		match event {
+			ChainEvent::Imported(notification) => {
+				if !notification.is_new_best {
+					return Vec::new()
+				}
+
+				let mut notifications = Vec::new();
+				let mut enacted = Vec::new();
+				if let Some(tree_route) = &notification.tree_route {
+					for retracted in tree_route.retracted() {
+						notifications.extend(
+							subscription
+								.retract(retracted.number.unique_saturated_into(), retracted.hash),
+						);
+					}
+					enacted.extend(
+						tree_route
+							.enacted()
+							.iter()
+							.map(|block| (block.number.unique_saturated_into(), block.hash)),
+					);
+				}
+				enacted.push((
+					(*notification.header.number()).unique_saturated_into(),
+					notification.hash,
+				));
+
+				for (block_number, block_hash) in enacted {
+					if subscription.is_reported(block_number, block_hash) {
+						continue
+					}
+					let messages = self.block_messages(block_hash, block_number, intent_ids);
+					notifications.extend(subscription.report(block_number, block_hash, messages));
+				}
+				notifications
+			},
+			ChainEvent::Finalized(notification) => vec![subscription.finalize(
+				(*notification.header.number()).unique_saturated_into(),
+				notification.hash,
+			)],
+		}
+	}
+}
+
+/// Sends a notification to the subscriber, returning false if the subscription is over
+async fn send<Hash: serde::Serialize>(
+	sink: &SubscriptionSink,
+	notification: &MessageNotification<Hash>,
+) -> bool {
+	match SubscriptionMessage::from_json(notification) {
+		Ok(message) => sink.send(message).await.is_ok(),
+		Err(e) => {
+			warn!("Unable to serialize message notification! {:?}", e);
+			false
+		},
+	}
+}
+
+impl<C, Block> MessagesSubscriptionApiServer<Block::Hash> for MessagesSubscriptionHandler<C, Block>
+where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block>
+		+ HeaderBackend<Block>
+		+ BlockchainEvents<Block>
+		+ Send
+		+ Sync
+		+ 'static,
+	C::Api: MessagesRuntimeApi<Block> + SchemasRuntimeApi<Block>,
+{
+	fn subscribe_messages_by_intent_id(
+		&self,
+		pending: PendingSubscriptionSink,
+		intent_ids: Vec<IntentId>,
+		from_block: Option<BlockNumber>,
+	) {
+		// Subscribe before the backfill so that no block is missed in between
+		let imports = self.client.import_notification_stream().map(ChainEvent::Imported);
+		let finality = self.client.finality_notification_stream().map(ChainEvent::Finalized);
+		let mut events = stream::select(imports, finality);
+
+		let handler = Self::new(self.client.clone(), self.executor.clone());
+		let fut = async move {
+			if let Err(e) = handler.validate(&intent_ids, from_block) {
+				pending.reject(e).await;
+				return
+			}
+			let Ok(sink) = pending.accept().await else { return };
+
+			let mut subscription = MessageSubscription::new();
+			if let Some(from_block) = from_block {
+				for notification in handler.backfill(&mut subscription, &intent_ids, from_block) {
+					if !send(&sink, &notification).await {
+						return
+					}
+				}
+			}
+
+			while let Some(event) = events.next().await {
+				for notification in handler.on_event(&mut subscription, &intent_ids, event) {
+					if !send(&sink, &notification).await {
+						return
+					}
+				}
+			}
+		};
+
+		self.executor.spawn("messages-rpc-subscription", Some("rpc"), fut.boxed());
+	}
+}
@@ -3,21 +3,25 @@ mod rpc_mock;
 use super::*;
 use rpc_mock::*;
 
-use common_primitives::node::Block;
+use common_primitives::node::{Block, BlockNumber, Hash};
 use pallet_messages_runtime_api::MessagesRuntimeApi;
+use parity_scale_codec::Encode;
+use sp_core::offchain::{testing::TestPersistentOffchainDB, OffchainStorage};
 use std::sync::Arc;
 
 const INTENT_ID_EMPTY: u16 = 1;
 const INTENT_ID_HAS_MESSAGES: u16 = 2;
 const SCHEMA_ID_EMPTY: u16 = 1;
 const SCHEMA_ID_HAS_MESSAGES: u16 = 2;
+const PROVIDER_MSA_ID: MessageSourceId = 1;
+const DELEGATOR_MSA_ID: MessageSourceId = 2;
 const DUMMY_CID: &str = "bafkreidgvpkjawlxz6sffxzwgooowe5yt7i6wsyg236mfoks77nywkptdq";
 
 // Simulate finding 2 messages per block until the pagination limit is reached
 fn test_messages(
 	block_pagination_request: BlockPaginationRequest,
-) -> BlockPaginationResponse<MessageResponseV2> {
-	let mut response = BlockPaginationResponse::<MessageResponseV2> {
+) -> BlockPaginationResponse<MessageResponseV3> {
+	let mut response = BlockPaginationResponse::<MessageResponseV3> {
 		content: vec![],
 		has_next: false,
 		next_block: None,
@@ -28,7 +32,7 @@ fn test_messages(
 		block_pagination_request.from_block..=block_pagination_request.to_block
 	{
 		for index in 0..2u16 {
-			response.content.push(MessageResponseV2 {
+			response.content.push(MessageResponseV3 {
 				schema_id: 1,
 				payload: None,
 				msa_id: None,
@@ -37,6 +41,8 @@ fn test_messages(
 				block_number,
 				cid: Some(DUMMY_CID.as_bytes().to_vec()),
 				payload_length: Some(42),
+				uri: None,
+				content_hash: None,
 			});
 
 			if response.content.len() >= block_pagination_request.page_size as usize {
@@ -59,13 +65,31 @@ fn test_messages(
 
 sp_api::mock_impl_runtime_apis! {
 	impl MessagesRuntimeApi<Block> for TestRuntimeApi {
-		fn get_messages_by_intent_id(intent_id: IntentId, pagination: BlockPaginationRequest) ->
-			BlockPaginationResponse<MessageResponseV2> {
+		fn get_messages_by_intent_id_v2(intent_id: IntentId, pagination: BlockPaginationRequest) ->
+			BlockPaginationResponse<MessageResponseV3> {
 				match intent_id {
 					INTENT_ID_HAS_MESSAGES => test_messages(pagination),
-					_ => BlockPaginationResponse::<MessageResponseV2>::default(),
+					_ => BlockPaginationResponse::<MessageResponseV3>::default(),
 				}
 			}
+
+		fn get_messages_by_index_v2(block_number: BlockNumber, message_keys: Vec<(IntentId, u16)>) -> Vec<MessageResponseV3> {
+			message_keys
+				.into_iter()
+				.map(|(_, index)| MessageResponseV3 {
+					schema_id: SCHEMA_ID_HAS_MESSAGES,
+					payload: Some(vec![1, 2, 3]),
+					msa_id: Some(DELEGATOR_MSA_ID),
+					provider_msa_id: PROVIDER_MSA_ID,
+					index,
+					block_number,
+					cid: None,
+					payload_length: None,
+					uri: None,
+					content_hash: None,
+				})
+				.collect()
+		}
 	}
 
 	impl SchemasRuntimeApi<Block> for TestRuntimeApi {
@@ -119,7 +143,7 @@ sp_api::mock_impl_runtime_apis! {
 
 type GetMessagesByIntentResult = Result<
 	common_primitives::messages::BlockPaginationResponse<
-		common_primitives::messages::MessageResponseV2,
+		common_primitives::messages::MessageResponseV3,
 	>,
 	jsonrpsee::types::ErrorObjectOwned,
 >;
@@ -127,7 +151,7 @@ type GetMessagesByIntentResult = Result<
 #[tokio::test]
 async fn get_messages_by_intent_with_invalid_request_should_panic() {
 	let client = Arc::new(TestApi {});
-	let api = MessagesHandler::new(client);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
 
 	let result: GetMessagesByIntentResult = api.get_messages_by_intent_id(
 		INTENT_ID_EMPTY, // Intent Id
@@ -141,7 +165,7 @@ async fn get_messages_by_intent_with_invalid_request_should_panic() {
 #[tokio::test]
 async fn get_messages_by_intent_with_bad_intent_id_should_err() {
 	let client = Arc::new(TestApi {});
-	let api = MessagesHandler::new(client);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
 
 	let result: GetMessagesByIntentResult = api.get_messages_by_intent_id(
 		0, // Intent Id
@@ -155,7 +179,7 @@ async fn get_messages_by_intent_with_bad_intent_id_should_err() {
 #[tokio::test]
 async fn get_messages_by_intent_with_success() {
 	let client = Arc::new(TestApi {});
-	let api = MessagesHandler::new(client);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
 	let pagination =
 		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 2 };
 
@@ -174,3 +198,186 @@ async fn get_messages_by_intent_with_success() {
 	assert_eq!(Some(2), response.next_block);
 	assert_eq!(Some(0), response.next_index);
 }
+
+fn offchain_db_with_message_index(
+	prefix: &[u8],
+	msa_id: MessageSourceId,
+	block_number: BlockNumber,
+	message_keys: Vec<(IntentId, u16)>,
+) -> TestPersistentOffchainDB {
+	let mut db = TestPersistentOffchainDB::new();
+	db.set(
+		sp_offchain::STORAGE_PREFIX,
+		&get_message_index_key(prefix, msa_id, block_number, &Hash::default()),
+		&message_keys.encode(),
+	);
+	db
+}
+
+#[tokio::test]
+async fn get_messages_by_provider_msa_id_with_disabled_offchain_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result: GetMessagesByIntentResult = api.get_messages_by_provider_msa_id(
+		PROVIDER_MSA_ID,
+		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 10 },
+	);
+
+	assert!(result.is_err());
+	assert_eq!("OffchainIndexingNotEnabled", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_messages_by_msa_id_with_invalid_request_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(
+		client,
+		Some(TestPersistentOffchainDB::new()),
+	);
+
+	let result: GetMessagesByIntentResult = api.get_messages_by_msa_id(
+		DELEGATOR_MSA_ID,
+		BlockPaginationRequest { from_block: 1, to_block: 2, from_index: 0, page_size: 0 },
+	);
+
+	assert!(result.is_err());
+	assert_eq!("InvalidPaginationRequest", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_messages_by_provider_msa_id_with_success() {
+	let client = Arc::new(TestApi {});
+	let db = offchain_db_with_message_index(
+		MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+		PROVIDER_MSA_ID,
+		2,
+		vec![(INTENT_ID_HAS_MESSAGES, 0), (INTENT_ID_EMPTY, 3), (INTENT_ID_HAS_MESSAGES, 4)],
+	);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, Some(db));
+
+	let result: GetMessagesByIntentResult = api.get_messages_by_provider_msa_id(
+		PROVIDER_MSA_ID,
+		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 2 },
+	);
+
+	let response = result.unwrap();
+	assert_eq!(
+		vec![(2, 0), (2, 3)],
+		response.content.iter().map(|m| (m.block_number, m.index)).collect::<Vec<_>>()
+	);
+	// The third message of block 2 is on the next page
+	assert!(response.has_next);
+	assert_eq!(Some(2), response.next_block);
+	assert_eq!(Some(2), response.next_index);
+}
+
+#[tokio::test]
+async fn get_messages_by_msa_id_with_success() {
+	let client = Arc::new(TestApi {});
+	let db = offchain_db_with_message_index(
+		MESSAGES_BY_MSA_INDEX_PREFIX,
+		DELEGATOR_MSA_ID,
+		3,
+		vec![(INTENT_ID_HAS_MESSAGES, 1)],
+	);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, Some(db));
+
+	let result: GetMessagesByIntentResult = api.get_messages_by_msa_id(
+		DELEGATOR_MSA_ID,
+		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 10 },
+	);
+
+	let response = result.unwrap();
+	assert_eq!(1, response.content.len());
+	assert_eq!(Some(DELEGATOR_MSA_ID), response.content[0].msa_id);
+	assert_eq!(3, response.content[0].block_number);
+	assert!(!response.has_next);
+}
+
+#[tokio::test]
+async fn get_messages_by_msa_id_should_skip_messages_of_other_msas() {
+	let client = Arc::new(TestApi {});
+	// the index entry is overwritten, e.g. by a block on another fork
+	let db = offchain_db_with_message_index(
+		MESSAGES_BY_MSA_INDEX_PREFIX,
+		PROVIDER_MSA_ID,
+		3,
+		vec![(INTENT_ID_HAS_MESSAGES, 1)],
+	);
+	let api = MessagesHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, Some(db));
+
+	let result: GetMessagesByIntentResult = api.get_messages_by_msa_id(
+		PROVIDER_MSA_ID,
+		BlockPaginationRequest { from_block: 1, to_block: 5, from_index: 0, page_size: 10 },
+	);
+
+	assert!(result.unwrap().content.is_empty());
+}
+
+fn message_in_block(block_number: BlockNumber) -> MessageResponseV3 {
+	MessageResponseV3 {
+		schema_id: SCHEMA_ID_HAS_MESSAGES,
+		payload: Some(vec![1, 2, 3]),
+		msa_id: Some(DELEGATOR_MSA_ID),
+		provider_msa_id: PROVIDER_MSA_ID,
+		index: 0,
+		block_number,
+		cid: None,
+		payload_length: None,
+		uri: None,
+		content_hash: None,
+	}
+}
+
+#[test]
+fn message_subscription_should_report_blocks_with_messages_once() {
+	let mut subscription = MessageSubscription::<Hash>::new();
+	let block_hash = Hash::repeat_byte(1);
+
+	assert_eq!(subscription.report(1, Hash::repeat_byte(2), vec![]), None);
+	assert_eq!(
+		subscription.report(1, block_hash, vec![message_in_block(1)]),
+		Some(MessageNotification::Messages {
+			block_hash,
+			block_number: 1,
+			messages: vec![message_in_block(1)],
+		})
+	);
+	assert!(subscription.is_reported(1, block_hash));
+	assert_eq!(subscription.report(1, block_hash, vec![message_in_block(1)]), None);
+}
+
+#[test]
+fn message_subscription_should_only_retract_reported_blocks() {
+	let mut subscription = MessageSubscription::<Hash>::new();
+	let reported_hash = Hash::repeat_byte(1);
+	let empty_hash = Hash::repeat_byte(2);
+	subscription.report(1, reported_hash, vec![message_in_block(1)]);
+	subscription.report(2, empty_hash, vec![]);
+
+	assert_eq!(
+		subscription.retract(1, reported_hash),
+		Some(MessageNotification::Retracted { block_hash: reported_hash, block_number: 1 })
+	);
+	assert_eq!(subscription.retract(1, reported_hash), None);
+	assert_eq!(subscription.retract(2, empty_hash), None);
+
+	// a block re-enacted after a reorg is reported again
+	assert!(subscription.report(1, reported_hash, vec![message_in_block(1)]).is_some());
+}
+
+#[test]
+fn message_subscription_should_forget_finalized_blocks() {
+	let mut subscription = MessageSubscription::<Hash>::new();
+	subscription.report(1, Hash::repeat_byte(1), vec![message_in_block(1)]);
+	subscription.report(2, Hash::repeat_byte(2), vec![message_in_block(2)]);
+
+	assert_eq!(
+		subscription.finalize(1, Hash::repeat_byte(1)),
+		MessageNotification::Finalized { block_hash: Hash::repeat_byte(1), block_number: 1 }
+	);
+	assert!(!subscription.is_reported(1, Hash::repeat_byte(1)));
+	assert_eq!(subscription.retract(1, Hash::repeat_byte(1)), None);
+	assert!(subscription.is_reported(2, Hash::repeat_byte(2)));
+}
@@ -56,6 +56,6 @@ impl<Block: BlockT> HeaderBackend<Block> for TestApi {
 		&self,
 		_number: NumberFor<Block>,
 	) -> std::result::Result<Option<Block::Hash>, sp_blockchain::Error> {
-		Ok(None)
+		Ok(Some(Default::default()))
 	}
 }
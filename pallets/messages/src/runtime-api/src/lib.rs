@@ -43,6 +43,25 @@ sp_api::decl_runtime_apis! {
 		#[api_version(2)]
 		fn get_messages_by_intent_id(intent_id: IntentId, pagination: BlockPaginationRequest) -> BlockPaginationResponse<MessageResponseV2>;
 
+		/// Retrieve the oldest block whose messages for an intent have not been pruned.
+		/// Older messages are only available from an archive node.
+		#[api_version(3)]
+		fn get_oldest_retained_block(intent_id: IntentId) -> BlockNumber;
+
+		/// Retrieve the messages stored in a block at the given (intent id, message index) keys
+		#[api_version(4)]
+		fn get_messages_by_index(block_number: BlockNumber, message_keys: Vec<(IntentId, u16)>) -> Vec<MessageResponseV2>;
+
+		/// Retrieve the messages for a particular intent and block range (paginated), including
+		/// the URI and content hash of OffChain messages
+		#[api_version(5)]
+		fn get_messages_by_intent_id_v2(intent_id: IntentId, pagination: BlockPaginationRequest) -> BlockPaginationResponse<MessageResponseV3>;
+
+		/// Retrieve the messages stored in a block at the given (intent id, message index) keys,
+		/// including the URI and content hash of OffChain messages
+		#[api_version(5)]
+		fn get_messages_by_index_v2(block_number: BlockNumber, message_keys: Vec<(IntentId, u16)>) -> Vec<MessageResponseV3>;
+
 		/// Retrieve a schema by id
 		// TODO: Remove once all RPC nodes have been updated to call the schemas pallet runtime for this
 		#[deprecated(note = "Use SchemasRuntimeApi_get_schema_by_id instead")]
@@ -0,0 +1,260 @@
+use super::other_tests::{DUMMY_CID_SHA256, DUMMY_CID_SHA512};
+use crate::{
+	pallet::MessagesV3, tests::mock::*, Error, Event as MessageEvent, IpfsMessageEntry, Message,
+	OnChainMessageEntry,
+};
+use common_primitives::schema::*;
+use frame_support::{assert_noop, assert_ok, traits::Get};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+
+fn onchain_entry(
+	schema_id: SchemaId,
+	on_behalf_of: Option<u64>,
+	payload: Vec<u8>,
+) -> OnChainMessageEntry {
+	OnChainMessageEntry { schema_id, on_behalf_of, payload }
+}
+
+fn ipfs_entry(cid: &[u8]) -> IpfsMessageEntry {
+	IpfsMessageEntry {
+		schema_id: IPFS_SCHEMA_ID,
+		cid: cid.to_vec(),
+		payload_length: IPFS_PAYLOAD_LENGTH,
+	}
+}
+
+#[test]
+fn add_onchain_messages_should_store_all_messages_in_order() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		let provider_msa_id = get_msa_from_account(caller_1);
+
+		assert_ok!(MessagesPallet::add_onchain_messages(
+			RuntimeOrigin::signed(caller_1),
+			vec![
+				onchain_entry(ON_CHAIN_SCHEMA_ID, None, vec![1]),
+				onchain_entry(ON_CHAIN_SCHEMA_ID, Some(DUMMY_MSA_ID), vec![2]),
+				onchain_entry(VALIDATED_SCHEMA_ID, None, vec![0x04, 0x02, 0x7f, 0x00]),
+			]
+			.try_into()
+			.unwrap()
+		));
+
+		assert_eq!(
+			MessagesV3::<Test>::get((1, ON_CHAIN_SCHEMA_ID, 0u16)),
+			Some(Message {
+				schema_id: ON_CHAIN_SCHEMA_ID,
+				msa_id: Some(provider_msa_id),
+				payload: vec![1].try_into().unwrap(),
+				provider_msa_id,
+			})
+		);
+		assert_eq!(
+			MessagesV3::<Test>::get((1, ON_CHAIN_SCHEMA_ID, 1u16)),
+			Some(Message {
+				schema_id: ON_CHAIN_SCHEMA_ID,
+				msa_id: Some(DUMMY_MSA_ID),
+				payload: vec![2].try_into().unwrap(),
+				provider_msa_id,
+			})
+		);
+		assert!(MessagesV3::<Test>::get((1, VALIDATED_SCHEMA_ID, 2u16)).is_some());
+		System::assert_last_event(MessageEvent::MessagesInBlock.into());
+	});
+}
+
+#[test]
+fn add_onchain_messages_should_not_store_any_message_if_one_fails() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		assert_noop!(
+			MessagesPallet::add_onchain_messages(
+				RuntimeOrigin::signed(caller_1),
+				vec![
+					onchain_entry(ON_CHAIN_SCHEMA_ID, None, vec![1]),
+					onchain_entry(VALIDATED_SCHEMA_ID, None, vec![0x04, 0x02]),
+				]
+				.try_into()
+				.unwrap()
+			),
+			Error::<Test>::InvalidPayload
+		);
+	});
+}
+
+#[test]
+fn add_onchain_messages_without_delegation_should_fail() {
+	new_test_ext().execute_with(|| {
+		// maps to the provider that has no delegations
+		let caller_1 = 2000;
+
+		assert_noop!(
+			MessagesPallet::add_onchain_messages(
+				RuntimeOrigin::signed(caller_1),
+				vec![
+					onchain_entry(ON_CHAIN_SCHEMA_ID, None, vec![1]),
+					onchain_entry(ON_CHAIN_SCHEMA_ID, Some(DUMMY_MSA_ID), vec![2]),
+				]
+				.try_into()
+				.unwrap()
+			),
+			Error::<Test>::UnAuthorizedDelegate
+		);
+	});
+}
+
+#[test]
+fn add_onchain_messages_with_invalid_entries_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		let too_large =
+			vec![1; <Test as crate::Config>::MessagesMaxPayloadSizeBytes::get() as usize + 1];
+
+		for (entry, error) in [
+			(onchain_entry(INVALID_SCHEMA_ID, None, vec![1]), Error::<Test>::InvalidSchemaId),
+			(onchain_entry(IPFS_SCHEMA_ID, None, vec![1]), Error::<Test>::InvalidPayloadLocation),
+			(onchain_entry(UNSUPPORTED_SCHEMA_ID, None, vec![1]), Error::<Test>::SchemaUnsupported),
+			(
+				onchain_entry(ON_CHAIN_SCHEMA_ID, None, too_large),
+				Error::<Test>::ExceedsMaxMessagePayloadSizeBytes,
+			),
+		] {
+			assert_noop!(
+				MessagesPallet::add_onchain_messages(
+					RuntimeOrigin::signed(caller_1),
+					vec![onchain_entry(ON_CHAIN_SCHEMA_ID, None, vec![1]), entry]
+						.try_into()
+						.unwrap()
+				),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn add_onchain_messages_with_deprecated_schema_should_warn_once() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		assert_ok!(MessagesPallet::add_onchain_messages(
+			RuntimeOrigin::signed(caller_1),
+			vec![
+				onchain_entry(DEPRECATED_SCHEMA_ID, None, vec![1]),
+				onchain_entry(DEPRECATED_SCHEMA_ID, None, vec![2]),
+			]
+			.try_into()
+			.unwrap()
+		));
+
+		let warnings = System::events()
+			.into_iter()
+			.filter(|record| {
+				record.event ==
+					MessageEvent::DeprecatedSchemaUsed { schema_id: DEPRECATED_SCHEMA_ID }.into()
+			})
+			.count();
+		assert_eq!(warnings, 1);
+		assert!(MessagesV3::<Test>::get((1, DEPRECATED_SCHEMA_ID, 1u16)).is_some());
+	});
+}
+
+#[test]
+fn add_ipfs_messages_should_store_all_messages_in_order() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		let provider_msa_id = get_msa_from_account(caller_1);
+
+		assert_ok!(MessagesPallet::add_ipfs_messages(
+			RuntimeOrigin::signed(caller_1),
+			vec![ipfs_entry(DUMMY_CID_BASE32), ipfs_entry(DUMMY_CID_SHA256.as_bytes())]
+				.try_into()
+				.unwrap()
+		));
+
+		let expected_payload = (
+			multibase::decode(core::str::from_utf8(DUMMY_CID_BASE32).unwrap()).unwrap().1,
+			IPFS_PAYLOAD_LENGTH,
+		)
+			.encode();
+		assert_eq!(
+			MessagesV3::<Test>::get((1, IPFS_SCHEMA_ID, 0u16)),
+			Some(Message {
+				schema_id: IPFS_SCHEMA_ID,
+				msa_id: None,
+				payload: expected_payload.try_into().unwrap(),
+				provider_msa_id,
+			})
+		);
+		assert!(MessagesV3::<Test>::get((1, IPFS_SCHEMA_ID, 1u16)).is_some());
+		System::assert_last_event(MessageEvent::MessagesInBlock.into());
+	});
+}
+
+#[test]
+fn add_ipfs_messages_with_invalid_entries_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		for (entry, error) in [
+			(
+				IpfsMessageEntry { schema_id: ON_CHAIN_SCHEMA_ID, ..ipfs_entry(DUMMY_CID_BASE32) },
+				Error::<Test>::InvalidPayloadLocation,
+			),
+			(ipfs_entry(b"foo"), Error::<Test>::InvalidCid),
+			(ipfs_entry(DUMMY_CID_SHA512.as_bytes()), Error::<Test>::InvalidCid),
+		] {
+			assert_noop!(
+				MessagesPallet::add_ipfs_messages(
+					RuntimeOrigin::signed(caller_1),
+					vec![ipfs_entry(DUMMY_CID_BASE32), entry].try_into().unwrap()
+				),
+				error
+			);
+		}
+	});
+}
+
+#[test]
+fn add_messages_with_empty_batch_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		assert_noop!(
+			MessagesPallet::add_ipfs_messages(RuntimeOrigin::signed(caller_1), Default::default()),
+			Error::<Test>::EmptyMessageBatch
+		);
+		assert_noop!(
+			MessagesPallet::add_onchain_messages(
+				RuntimeOrigin::signed(caller_1),
+				Default::default()
+			),
+			Error::<Test>::EmptyMessageBatch
+		);
+	});
+}
+
+#[test]
+fn add_messages_with_invalid_msa_account_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 1000;
+
+		assert_noop!(
+			MessagesPallet::add_ipfs_messages(
+				RuntimeOrigin::signed(caller_1),
+				vec![ipfs_entry(DUMMY_CID_BASE32)].try_into().unwrap()
+			),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+		assert_noop!(
+			MessagesPallet::add_onchain_messages(
+				RuntimeOrigin::signed(caller_1),
+				vec![onchain_entry(ON_CHAIN_SCHEMA_ID, None, vec![1])].try_into().unwrap()
+			),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+	});
+}
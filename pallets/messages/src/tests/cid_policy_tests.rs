@@ -0,0 +1,196 @@
+use super::other_tests::{DUMMY_CID_BLAKE3, DUMMY_CID_SHA256};
+use crate::{
+	pallet::AcceptedCidPolicy, tests::mock::*, CidPolicy, Error, Event as MessageEvent,
+	IpfsMessageEntry,
+};
+use common_primitives::{cid::*, schema::*};
+use frame_support::{assert_noop, assert_ok};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_runtime::DispatchError::BadOrigin;
+
+const CIDV0: &[u8] = b"QmYzm8KGxRHr7nGn5g5Z9Zv9r8nN5WNn7Ajya6x7RxmAB1";
+const CIDV0_AS_V1: &[u8] = b"bafybeie6lazdstuvclfotshaziauhuy2vbphn2gsk5hlflk3jzqlnues3a";
+const DUMMY_CID_BASE58: &[u8] = b"zb2rhfP73hfCa5Vp7ZcMYUPN4eFS9E9qhABfqvGcLorSWu4zf";
+
+fn policy(
+	codecs: &[u64],
+	multihash_codes: &[u64],
+) -> CidPolicy<<Test as crate::Config>::MaxCidPolicyEntries> {
+	CidPolicy {
+		codecs: codecs.to_vec().try_into().unwrap(),
+		multihash_codes: multihash_codes.to_vec().try_into().unwrap(),
+		allow_cid_v0: false,
+	}
+}
+
+fn policy_with_cid_v0(
+	codecs: &[u64],
+	multihash_codes: &[u64],
+) -> CidPolicy<<Test as crate::Config>::MaxCidPolicyEntries> {
+	CidPolicy { allow_cid_v0: true, ..policy(codecs, multihash_codes) }
+}
+
+fn stored_cid(index: u16) -> Vec<u8> {
+	let messages =
+		MessagesPallet::get_messages_by_intent_and_block(IPFS_SCHEMA_ID, PayloadLocation::IPFS, 1);
+	messages[index as usize].cid.clone().unwrap()
+}
+
+#[test]
+fn default_cid_policy_should_accept_dsnp_codecs_and_hashes() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(AcceptedCidPolicy::<Test>::get(), policy(DSNP_CODECS, DSNP_HASH_ALGORITHMS));
+	});
+}
+
+#[test]
+fn default_cid_policy_should_reject_cid_v0() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MessagesPallet::add_ipfs_message(
+				RuntimeOrigin::signed(5),
+				IPFS_SCHEMA_ID,
+				CIDV0.to_vec(),
+				IPFS_PAYLOAD_LENGTH
+			),
+			Error::<Test>::UnsupportedCidVersion
+		);
+
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			policy_with_cid_v0(DSNP_CODECS, DSNP_HASH_ALGORITHMS)
+		));
+		assert_ok!(MessagesPallet::add_ipfs_message(
+			RuntimeOrigin::signed(5),
+			IPFS_SCHEMA_ID,
+			CIDV0.to_vec(),
+			IPFS_PAYLOAD_LENGTH
+		));
+	});
+}
+
+#[test]
+fn set_cid_policy_should_replace_policy() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			policy(&[RAW, DAG_PB], &[SHA2_256])
+		));
+
+		assert_eq!(AcceptedCidPolicy::<Test>::get(), policy(&[RAW, DAG_PB], &[SHA2_256]));
+		System::assert_last_event(
+			MessageEvent::CidPolicyUpdated { policy: policy(&[RAW, DAG_PB], &[SHA2_256]) }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_cid_policy_without_governance_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MessagesPallet::set_cid_policy(RuntimeOrigin::signed(5), policy(&[RAW], &[SHA2_256])),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_cid_policy_with_invalid_policy_should_fail() {
+	new_test_ext().execute_with(|| {
+		for invalid in [
+			policy(&[], &[SHA2_256]),
+			policy(&[RAW], &[]),
+			policy(&[RAW, RAW], &[SHA2_256]),
+			policy(&[RAW], &[SHA2_256, SHA2_256]),
+		] {
+			assert_noop!(
+				MessagesPallet::set_cid_policy(RuntimeOrigin::root(), invalid),
+				Error::<Test>::InvalidCidPolicy
+			);
+		}
+	});
+}
+
+#[test]
+fn add_ipfs_message_should_store_canonical_base32_cid() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		let base32_upper = DUMMY_CID_BASE32.to_ascii_uppercase();
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			policy_with_cid_v0(DSNP_CODECS, DSNP_HASH_ALGORITHMS)
+		));
+
+		for cid in [CIDV0, DUMMY_CID_BASE58, &base32_upper[..], DUMMY_CID_BASE64] {
+			assert_ok!(MessagesPallet::add_ipfs_message(
+				RuntimeOrigin::signed(caller_1),
+				IPFS_SCHEMA_ID,
+				cid.to_vec(),
+				IPFS_PAYLOAD_LENGTH
+			));
+		}
+
+		assert_eq!(stored_cid(0), CIDV0_AS_V1.to_vec());
+		for index in 1..4 {
+			assert_eq!(stored_cid(index), DUMMY_CID_BASE32.to_vec());
+		}
+	});
+}
+
+#[test]
+fn add_ipfs_message_with_cid_not_in_policy_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			policy(&[RAW], &[SHA2_256])
+		));
+
+		for (cid, error) in [
+			(DUMMY_CID_SHA256.as_bytes(), Error::<Test>::UnsupportedCidCodec),
+			(DUMMY_CID_BLAKE3.as_bytes(), Error::<Test>::InvalidCid),
+			(CIDV0, Error::<Test>::UnsupportedCidVersion),
+		] {
+			assert_noop!(
+				MessagesPallet::add_ipfs_message(
+					RuntimeOrigin::signed(caller_1),
+					IPFS_SCHEMA_ID,
+					cid.to_vec(),
+					IPFS_PAYLOAD_LENGTH
+				),
+				error
+			);
+		}
+		assert_ok!(MessagesPallet::add_ipfs_message(
+			RuntimeOrigin::signed(caller_1),
+			IPFS_SCHEMA_ID,
+			DUMMY_CID_BASE32.to_vec(),
+			IPFS_PAYLOAD_LENGTH
+		));
+	});
+}
+
+#[test]
+fn add_ipfs_messages_with_cid_not_in_policy_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			policy(&[RAW], &[SHA2_256])
+		));
+		let entry = |cid: &[u8]| IpfsMessageEntry {
+			schema_id: IPFS_SCHEMA_ID,
+			cid: cid.to_vec(),
+			payload_length: IPFS_PAYLOAD_LENGTH,
+		};
+
+		assert_noop!(
+			MessagesPallet::add_ipfs_messages(
+				RuntimeOrigin::signed(caller_1),
+				vec![entry(DUMMY_CID_BASE32), entry(CIDV0)].try_into().unwrap()
+			),
+			Error::<Test>::UnsupportedCidVersion
+		);
+	});
+}
@@ -1,9 +1,11 @@
 use crate as pallet_messages;
 use common_primitives::{
+	avro::{parse_avro_schema, validate_avro_datum},
 	msa::{
 		Delegation, DelegationValidator, DelegatorId, GrantValidator, MessageSourceId, MsaLookup,
 		MsaValidator, ProviderId, ProviderLookup,
 	},
+	node::BlockNumber,
 	schema::*,
 };
 use frame_support::{derive_impl, pallet_prelude::Weight};
@@ -15,6 +17,7 @@ use frame_support::{
 	traits::{ConstU16, ConstU32, OnFinalize, OnInitialize},
 };
 use frame_system as system;
+use frame_system::EnsureRoot;
 use parity_scale_codec::{Encode, MaxEncodedLen};
 use sp_core::H256;
 use sp_runtime::{
@@ -31,6 +34,22 @@ pub const IPFS_SCHEMA_ID: SchemaId = 20;
 
 pub const ON_CHAIN_SCHEMA_ID: SchemaId = 16001;
 
+pub const DEPRECATED_SCHEMA_ID: SchemaId = 16002;
+
+pub const UNSUPPORTED_SCHEMA_ID: SchemaId = 16003;
+
+pub const VALIDATED_SCHEMA_ID: SchemaId = 16004;
+
+pub const RETAINED_SCHEMA_ID: SchemaId = 16005;
+
+pub const RETENTION_PERIOD: BlockNumber = 10;
+
+pub const OFFCHAIN_SCHEMA_ID: SchemaId = 16006;
+
+pub const VALIDATED_IPFS_SCHEMA_ID: SchemaId = 16007;
+
+pub const VALIDATED_SCHEMA_MODEL: &str = r#"{"type":"array","items":"int"}"#;
+
 pub const IPFS_PAYLOAD_LENGTH: u32 = 1200;
 
 pub const DUMMY_CID_BASE32: &[u8; 59] =
@@ -104,6 +123,7 @@ pub type MaxSchemaGrantsPerDelegation = ConstU32<30>;
 parameter_types! {
 	pub const MessagesMaxPayloadSizeBytes: u32 = 1024 * 3;
 	pub const MessagesMigrateEmitEvery: u32 = 100;
+	pub static RetentionSince: BlockNumber = 0;
 }
 
 impl std::fmt::Debug for MessagesMaxPayloadSizeBytes {
@@ -234,6 +254,42 @@ impl SchemaProvider<u16> for SchemaHandler {
 			})
 		}
 
+		if schema_id == OFFCHAIN_SCHEMA_ID {
+			return Some(SchemaResponseV2 {
+				schema_id,
+				intent_id: schema_id,
+				model: r#"schema"#.to_string().as_bytes().to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::OffChain,
+				settings: Vec::new(),
+				status: SchemaStatus::Active,
+			})
+		}
+
+		if schema_id == VALIDATED_IPFS_SCHEMA_ID {
+			return Some(SchemaResponseV2 {
+				schema_id,
+				intent_id: schema_id,
+				model: VALIDATED_SCHEMA_MODEL.as_bytes().to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::IPFS,
+				settings: vec![IntentSetting::ValidatePayload],
+				status: SchemaStatus::Active,
+			})
+		}
+
+		if schema_id == VALIDATED_SCHEMA_ID {
+			return Some(SchemaResponseV2 {
+				schema_id,
+				intent_id: schema_id,
+				model: VALIDATED_SCHEMA_MODEL.as_bytes().to_vec(),
+				model_type: ModelType::AvroBinary,
+				payload_location: PayloadLocation::OnChain,
+				settings: vec![IntentSetting::ValidatePayload],
+				status: SchemaStatus::Active,
+			})
+		}
+
 		Some(SchemaResponseV2 {
 			schema_id,
 			intent_id: schema_id,
@@ -241,7 +297,11 @@ impl SchemaProvider<u16> for SchemaHandler {
 			model_type: ModelType::AvroBinary,
 			payload_location: PayloadLocation::OnChain,
 			settings: Vec::new(),
-			status: SchemaStatus::Active,
+			status: match schema_id {
+				DEPRECATED_SCHEMA_ID => SchemaStatus::Deprecated,
+				UNSUPPORTED_SCHEMA_ID => SchemaStatus::Unsupported,
+				_ => SchemaStatus::Active,
+			},
 		})
 	}
 
@@ -259,6 +319,66 @@ impl SchemaProvider<u16> for SchemaHandler {
 	fn get_intent_by_id(_intent_id: IntentId) -> Option<IntentResponse> {
 		None
 	}
+
+	fn validate_payload(schema_id: SchemaId, payload: &[u8]) -> Result<(), PayloadValidationError> {
+		let schema =
+			Self::get_schema_by_id(schema_id).ok_or(PayloadValidationError::SchemaNotFound)?;
+		let definition =
+			parse_avro_schema(&schema.model).map_err(|_| PayloadValidationError::InvalidModel)?;
+		validate_avro_datum(&definition, payload).map_err(PayloadValidationError::InvalidPayload)
+	}
+
+	fn get_intent_retention_periods(
+		start_after: Option<IntentId>,
+		_limit: u32,
+	) -> Vec<(IntentId, IntentRetention)> {
+		match start_after {
+			Some(_) => Vec::new(),
+			None => vec![(
+				RETAINED_SCHEMA_ID,
+				IntentRetention { period: RETENTION_PERIOD, since: RetentionSince::get() },
+			)],
+		}
+	}
+
+	fn get_intent_storage_quota(_intent_id: IntentId) -> Option<StorageQuota> {
+		None
+	}
+}
+
+/// Creates the mocked OffChain schema for benchmarks of OffChain messages
+#[cfg(feature = "runtime-benchmarks")]
+pub struct SchemaBenchmarkHelperMock;
+#[cfg(feature = "runtime-benchmarks")]
+impl common_primitives::benchmarks::SchemaBenchmarkHelper for SchemaBenchmarkHelperMock {
+	fn set_schema_count(_schema_id: SchemaId) {}
+
+	fn set_intent_count(_intent_id: IntentId) {}
+
+	fn create_schema(
+		_intent_id: IntentId,
+		_model: Vec<u8>,
+		_model_type: ModelType,
+		payload_location: PayloadLocation,
+	) -> Result<SchemaId, DispatchError> {
+		Ok(match payload_location {
+			PayloadLocation::OffChain => OFFCHAIN_SCHEMA_ID,
+			_ => SchemaId::default(),
+		})
+	}
+
+	fn create_intent(
+		_name_payload: Vec<u8>,
+		payload_location: PayloadLocation,
+		_settings: Vec<IntentSetting>,
+	) -> Result<IntentId, DispatchError> {
+		Ok(match payload_location {
+			PayloadLocation::OffChain => OFFCHAIN_SCHEMA_ID,
+			_ => IntentId::default(),
+		})
+	}
+
+	fn set_intent_retention_period(_intent_id: IntentId, _retention: BlockNumber) {}
 }
 
 impl pallet_messages::Config for Test {
@@ -268,12 +388,18 @@ impl pallet_messages::Config for Test {
 	type SchemaProvider = SchemaHandler;
 	type WeightInfo = ();
 	type MessagesMaxPayloadSizeBytes = MessagesMaxPayloadSizeBytes;
+	type MaxMessagesPerBatch = ConstU32<5>;
+	type MaxOffchainUriLength = ConstU32<100>;
+	type MaxUriSchemes = ConstU32<4>;
+	type UriSchemeGovernanceOrigin = EnsureRoot<u64>;
+	type MaxCidPolicyEntries = ConstU32<8>;
+	type CidPolicyGovernanceOrigin = EnsureRoot<u64>;
 
 	/// A set of helper functions for benchmarking.
 	#[cfg(feature = "runtime-benchmarks")]
 	type MsaBenchmarkHelper = ();
 	#[cfg(feature = "runtime-benchmarks")]
-	type SchemaBenchmarkHelper = ();
+	type SchemaBenchmarkHelper = SchemaBenchmarkHelperMock;
 	type MigrateEmitEvery = ConstU32<1>;
 }
 
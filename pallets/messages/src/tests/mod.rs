@@ -1,2 +1,7 @@
+mod batch_tests;
+mod cid_policy_tests;
 pub mod mock;
+mod offchain_index_tests;
+mod offchain_message_tests;
 mod other_tests;
+mod retention_tests;
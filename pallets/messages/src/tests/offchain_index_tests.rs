@@ -0,0 +1,108 @@
+use crate::{pallet::BlockMessageSources, tests::mock::*};
+use common_primitives::{
+	messages::MessageResponseV2,
+	offchain::{
+		get_message_index_key, MESSAGES_BY_MSA_INDEX_PREFIX, MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+	},
+	schema::*,
+};
+use frame_support::{assert_ok, traits::OnFinalize};
+use parity_scale_codec::Decode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::offchain::testing::TestPersistentOffchainDB;
+
+fn get_indexed_messages(
+	db: &TestPersistentOffchainDB,
+	prefix: &[u8],
+	msa_id: u64,
+	block_number: u32,
+	parent_hash: &sp_core::H256,
+) -> Option<Vec<(IntentId, u16)>> {
+	db.get(&get_message_index_key(prefix, msa_id, block_number, parent_hash))
+		.map(|raw| Vec::<(IntentId, u16)>::decode(&mut &raw[..]).unwrap())
+}
+
+#[test]
+fn on_finalize_should_offchain_index_messages_by_provider_and_msa() {
+	let mut ext = new_test_ext();
+	let caller_1 = 5;
+	let parent_hash = ext.execute_with(|| {
+		assert_ok!(MessagesPallet::add_onchain_message(
+			RuntimeOrigin::signed(caller_1),
+			Some(DUMMY_MSA_ID),
+			ON_CHAIN_SCHEMA_ID,
+			vec![1]
+		));
+		assert_ok!(MessagesPallet::add_ipfs_message(
+			RuntimeOrigin::signed(caller_1),
+			IPFS_SCHEMA_ID,
+			DUMMY_CID_BASE32.to_vec(),
+			IPFS_PAYLOAD_LENGTH
+		));
+
+		MessagesPallet::on_finalize(1);
+
+		assert!(BlockMessageSources::<Test>::get().is_empty());
+		System::parent_hash()
+	});
+	ext.persist_offchain_overlay();
+	let db = ext.offchain_db();
+
+	assert_eq!(
+		get_indexed_messages(
+			&db,
+			MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+			get_msa_from_account(caller_1),
+			1,
+			&parent_hash
+		),
+		Some(vec![(ON_CHAIN_SCHEMA_ID, 0), (IPFS_SCHEMA_ID, 1)])
+	);
+	assert_eq!(
+		get_indexed_messages(&db, MESSAGES_BY_MSA_INDEX_PREFIX, DUMMY_MSA_ID, 1, &parent_hash),
+		Some(vec![(ON_CHAIN_SCHEMA_ID, 0)])
+	);
+	assert_eq!(
+		get_indexed_messages(
+			&db,
+			MESSAGES_BY_PROVIDER_INDEX_PREFIX,
+			get_msa_from_account(caller_1),
+			2,
+			&parent_hash
+		),
+		None
+	);
+}
+
+#[test]
+fn get_messages_by_index_should_return_stored_messages_only() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		assert_ok!(MessagesPallet::add_onchain_message(
+			RuntimeOrigin::signed(caller_1),
+			Some(DUMMY_MSA_ID),
+			ON_CHAIN_SCHEMA_ID,
+			vec![1]
+		));
+
+		let messages = MessagesPallet::get_messages_by_index(
+			1,
+			vec![(ON_CHAIN_SCHEMA_ID, 0), (ON_CHAIN_SCHEMA_ID, 1), (IPFS_SCHEMA_ID, 0)],
+		);
+
+		assert_eq!(
+			messages,
+			vec![MessageResponseV2 {
+				provider_msa_id: get_msa_from_account(caller_1),
+				index: 0,
+				block_number: 1,
+				msa_id: Some(DUMMY_MSA_ID),
+				payload: Some(vec![1]),
+				cid: None,
+				payload_length: None,
+				schema_id: ON_CHAIN_SCHEMA_ID,
+			}]
+		);
+	});
+}
@@ -0,0 +1,239 @@
+use crate::{
+	pallet::AllowedUriSchemes, tests::mock::*, ContentMultihash, Error, Event as MessageEvent,
+	UriScheme,
+};
+use common_primitives::{
+	cid::Multihash,
+	messages::{MessageResponseV2, MessageResponseV3},
+	schema::*,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_runtime::DispatchError::BadOrigin;
+
+fn schemes(schemes: &[&[u8]]) -> BoundedVec<UriScheme, <Test as crate::Config>::MaxUriSchemes> {
+	schemes
+		.iter()
+		.map(|scheme| UriScheme::try_from(scheme.to_vec()).unwrap())
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+fn uri(uri: &[u8]) -> BoundedVec<u8, <Test as crate::Config>::MaxOffchainUriLength> {
+	uri.to_vec().try_into().unwrap()
+}
+
+fn sha2_256_multihash() -> ContentMultihash {
+	Multihash::wrap(0x12, &sp_io::hashing::sha2_256(b"content"))
+		.unwrap()
+		.to_bytes()
+		.try_into()
+		.unwrap()
+}
+
+fn allow_schemes(allowed: &[&[u8]]) {
+	assert_ok!(MessagesPallet::set_allowed_uri_schemes(RuntimeOrigin::root(), schemes(allowed)));
+}
+
+#[test]
+fn set_allowed_uri_schemes_should_replace_schemes() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https", b"ar"]);
+		allow_schemes(&[b"s3"]);
+
+		assert_eq!(AllowedUriSchemes::<Test>::get(), schemes(&[b"s3"]));
+		System::assert_last_event(
+			MessageEvent::AllowedUriSchemesUpdated { schemes: schemes(&[b"s3"]) }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_allowed_uri_schemes_without_governance_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MessagesPallet::set_allowed_uri_schemes(RuntimeOrigin::signed(5), schemes(&[b"https"])),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn set_allowed_uri_schemes_with_invalid_scheme_should_fail() {
+	new_test_ext().execute_with(|| {
+		for invalid in [
+			schemes(&[b""]),
+			schemes(&[b"1https"]),
+			schemes(&[b"https:"]),
+			schemes(&[b"HTTPS"]),
+			schemes(&[b"https", b"https"]),
+		] {
+			assert_noop!(
+				MessagesPallet::set_allowed_uri_schemes(RuntimeOrigin::root(), invalid),
+				Error::<Test>::InvalidUriScheme
+			);
+		}
+	});
+}
+
+#[test]
+fn add_offchain_message_should_store_message() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+		allow_schemes(&[b"https", b"ar"]);
+
+		assert_ok!(MessagesPallet::add_offchain_message(
+			RuntimeOrigin::signed(caller_1),
+			OFFCHAIN_SCHEMA_ID,
+			uri(b"ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U"),
+			sha2_256_multihash(),
+			IPFS_PAYLOAD_LENGTH,
+		));
+		// schemes are case-insensitive
+		assert_ok!(MessagesPallet::add_offchain_message(
+			RuntimeOrigin::signed(caller_1),
+			OFFCHAIN_SCHEMA_ID,
+			uri(b"HTTPS://example.com/announcement.parquet"),
+			sha2_256_multihash(),
+			IPFS_PAYLOAD_LENGTH,
+		));
+
+		let messages = MessagesPallet::get_messages_by_intent_and_block(
+			OFFCHAIN_SCHEMA_ID,
+			PayloadLocation::OffChain,
+			1,
+		);
+		assert_eq!(
+			messages[0],
+			MessageResponseV3 {
+				provider_msa_id: get_msa_from_account(caller_1),
+				index: 0,
+				block_number: 1,
+				schema_id: OFFCHAIN_SCHEMA_ID,
+				uri: Some(b"ar://bNbA3TEQVL60xlgCcqdz4ZPHFZ711cZ3hmkpGttDt_U".to_vec()),
+				content_hash: Some(sha2_256_multihash().into_inner()),
+				payload_length: Some(IPFS_PAYLOAD_LENGTH),
+				..Default::default()
+			}
+		);
+		assert_eq!(messages.len(), 2);
+		System::assert_last_event(MessageEvent::MessagesInBlock.into());
+	});
+}
+
+#[test]
+fn offchain_message_uri_and_content_hash_should_only_be_returned_by_v2_queries() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https"]);
+		assert_ok!(MessagesPallet::add_offchain_message(
+			RuntimeOrigin::signed(5),
+			OFFCHAIN_SCHEMA_ID,
+			uri(b"https://example.com/announcement.parquet"),
+			sha2_256_multihash(),
+			IPFS_PAYLOAD_LENGTH,
+		));
+
+		let message_keys = vec![(OFFCHAIN_SCHEMA_ID, 0)];
+		let messages_v3 = MessagesPallet::get_messages_by_index_v2(1, message_keys.clone());
+		let messages_v2 = MessagesPallet::get_messages_by_index(1, message_keys);
+
+		assert_eq!(messages_v3[0].uri, Some(b"https://example.com/announcement.parquet".to_vec()));
+		assert_eq!(messages_v3[0].content_hash, Some(sha2_256_multihash().into_inner()));
+		let expected: MessageResponseV2 = messages_v3[0].clone().into();
+		assert_eq!(messages_v2, vec![expected]);
+		assert_eq!(messages_v2[0].payload_length, Some(IPFS_PAYLOAD_LENGTH));
+	});
+}
+
+#[test]
+fn add_offchain_message_with_scheme_not_allowed_should_fail() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https"]);
+
+		assert_noop!(
+			MessagesPallet::add_offchain_message(
+				RuntimeOrigin::signed(5),
+				OFFCHAIN_SCHEMA_ID,
+				uri(b"http://example.com/announcement.parquet"),
+				sha2_256_multihash(),
+				IPFS_PAYLOAD_LENGTH,
+			),
+			Error::<Test>::UriSchemeNotAllowed
+		);
+	});
+}
+
+#[test]
+fn add_offchain_message_with_invalid_uri_should_fail() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https"]);
+
+		for invalid in [
+			uri(b""),
+			uri(b"example.com"),
+			uri(b"https:"),
+			uri(b"://example.com"),
+			uri(b"1https://example.com"),
+			uri(b"https://example.com/an announcement"),
+		] {
+			assert_noop!(
+				MessagesPallet::add_offchain_message(
+					RuntimeOrigin::signed(5),
+					OFFCHAIN_SCHEMA_ID,
+					invalid,
+					sha2_256_multihash(),
+					IPFS_PAYLOAD_LENGTH,
+				),
+				Error::<Test>::InvalidUri
+			);
+		}
+	});
+}
+
+#[test]
+fn add_offchain_message_with_invalid_multihash_should_fail() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https"]);
+		let sha2_512 = Multihash::wrap(0x13, &[0u8; 64]).unwrap().to_bytes();
+
+		for invalid in [vec![], vec![0x12, 0x20, 1, 2, 3], sha2_512] {
+			let invalid = ContentMultihash::try_from(invalid).unwrap();
+			assert_noop!(
+				MessagesPallet::add_offchain_message(
+					RuntimeOrigin::signed(5),
+					OFFCHAIN_SCHEMA_ID,
+					uri(b"https://example.com/announcement.parquet"),
+					invalid,
+					IPFS_PAYLOAD_LENGTH,
+				),
+				Error::<Test>::InvalidMultihash
+			);
+		}
+	});
+}
+
+#[test]
+fn add_offchain_message_with_invalid_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		allow_schemes(&[b"https"]);
+
+		for (schema_id, error) in [
+			(INVALID_SCHEMA_ID, Error::<Test>::InvalidSchemaId),
+			(IPFS_SCHEMA_ID, Error::<Test>::InvalidPayloadLocation),
+			(ON_CHAIN_SCHEMA_ID, Error::<Test>::InvalidPayloadLocation),
+		] {
+			assert_noop!(
+				MessagesPallet::add_offchain_message(
+					RuntimeOrigin::signed(5),
+					schema_id,
+					uri(b"https://example.com/announcement.parquet"),
+					sha2_256_multihash(),
+					IPFS_PAYLOAD_LENGTH,
+				),
+				error
+			);
+		}
+	});
+}
@@ -2,10 +2,11 @@ extern crate alloc;
 use crate::{
 	pallet::{Config, MessagesV3},
 	tests::mock::*,
-	BlockMessageIndex, Error, Event as MessageEvent, MapToResponse, Message,
+	BlockMessageIndex, CidPolicy, Error, Event as MessageEvent, IpfsMessageEntry, MapToResponse,
+	Message,
 };
 use alloc::vec::Vec;
-use common_primitives::{messages::MessageResponseV2, schema::*};
+use common_primitives::{cid, messages::MessageResponseV3, schema::*};
 use frame_support::{assert_err, assert_noop, assert_ok, traits::OnInitialize, BoundedVec};
 use frame_system::{EventRecord, Phase};
 use multibase::Base;
@@ -235,7 +236,7 @@ fn get_messages_by_intent_with_ipfs_payload_location_should_return_offchain_payl
 		assert_eq!(list.len(), 1);
 		assert_eq!(
 			list[0],
-			MessageResponseV2 {
+			MessageResponseV3 {
 				schema_id: intent_id,
 				payload: None,
 				index: 0,
@@ -281,7 +282,7 @@ fn decode_message_with_invalid_payload_should_yield_empty_cid() {
 			provider_msa_id: 1,
 			schema_id: 1,
 		};
-		let mapped_response: MessageResponseV2 =
+		let mapped_response: MessageResponseV3 =
 			bad_message.map_to_response((0, PayloadLocation::IPFS, 0)).unwrap();
 		assert_eq!(
 			mapped_response.cid,
@@ -451,10 +452,18 @@ fn add_ipfs_message_with_blake3_cid_should_succeed() {
 	})
 }
 #[test]
-fn add_ipfs_message_cid_v0_errors() {
+fn add_ipfs_message_cid_v0_without_dag_pb_in_policy_errors() {
 	new_test_ext().execute_with(|| {
 		let caller_1 = 5u64;
 		const CIDV0: &str = "QmYzm8KGxRHr7nGn5g5Z9Zv9r8nN5WNn7Ajya6x7RxmAB1";
+		assert_ok!(MessagesPallet::set_cid_policy(
+			RuntimeOrigin::root(),
+			CidPolicy {
+				codecs: vec![cid::RAW].try_into().unwrap(),
+				multihash_codes: vec![cid::SHA2_256].try_into().unwrap(),
+				allow_cid_v0: true,
+			}
+		));
 
 		assert_noop!(
 			MessagesPallet::add_ipfs_message(
@@ -526,7 +535,7 @@ fn map_to_response_on_chain() {
 		msa_id: None,
 		schema_id: 1,
 	};
-	let expected = MessageResponseV2 {
+	let expected = MessageResponseV3 {
 		provider_msa_id: DUMMY_MSA_ID,
 		index: 1u16,
 		block_number: 42,
@@ -534,6 +543,8 @@ fn map_to_response_on_chain() {
 		payload: Some(payload_vec),
 		cid: None,
 		payload_length: None,
+		uri: None,
+		content_hash: None,
 		schema_id: 1,
 	};
 	assert_eq!(msg.map_to_response((42, 1, PayloadLocation::OnChain, 1)).unwrap(), expected);
@@ -545,7 +556,7 @@ fn map_to_response_ipfs() {
 	let payload_tuple: crate::OffchainPayloadType = (multibase::decode(cid).unwrap().1, 10);
 	let payload = BoundedVec::<u8, ConstU32<500>>::try_from(payload_tuple.encode()).unwrap();
 	let msg = Message { payload, provider_msa_id: 10u64, msa_id: None, schema_id: 1 };
-	let expected = MessageResponseV2 {
+	let expected = MessageResponseV3 {
 		provider_msa_id: DUMMY_MSA_ID,
 		index: 1u16,
 		block_number: 42,
@@ -553,7 +564,109 @@ fn map_to_response_ipfs() {
 		payload: None,
 		cid: Some(cid.as_bytes().to_vec()),
 		payload_length: Some(10),
+		uri: None,
+		content_hash: None,
 		schema_id: 1,
 	};
 	assert_eq!(msg.map_to_response((42, 1, PayloadLocation::IPFS, 1)).unwrap(), expected);
 }
+
+#[test]
+fn add_onchain_message_with_deprecated_schema_should_emit_warning() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		assert_ok!(MessagesPallet::add_onchain_message(
+			RuntimeOrigin::signed(caller_1),
+			None,
+			DEPRECATED_SCHEMA_ID,
+			generate_payload(1)
+		));
+
+		System::assert_last_event(
+			MessageEvent::DeprecatedSchemaUsed { schema_id: DEPRECATED_SCHEMA_ID }.into(),
+		);
+		assert!(MessagesV3::<Test>::get((1, DEPRECATED_SCHEMA_ID, 0u16)).is_some());
+	});
+}
+
+#[test]
+fn add_onchain_message_with_unsupported_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		assert_noop!(
+			MessagesPallet::add_onchain_message(
+				RuntimeOrigin::signed(caller_1),
+				None,
+				UNSUPPORTED_SCHEMA_ID,
+				generate_payload(1)
+			),
+			Error::<Test>::SchemaUnsupported
+		);
+	});
+}
+
+#[test]
+fn add_onchain_message_with_conforming_payload_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		// an array with one block of two ints followed by the end of the array
+		assert_ok!(MessagesPallet::add_onchain_message(
+			RuntimeOrigin::signed(caller_1),
+			None,
+			VALIDATED_SCHEMA_ID,
+			vec![0x04, 0x02, 0x7f, 0x00]
+		));
+
+		assert!(MessagesV3::<Test>::get((1, VALIDATED_SCHEMA_ID, 0u16)).is_some());
+	});
+}
+
+#[test]
+fn add_ipfs_message_with_payload_validation_should_not_validate_cid() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5u64;
+
+		// the Intent validates payloads, but only on-chain payloads are checked
+		assert_ok!(MessagesPallet::add_ipfs_message(
+			RuntimeOrigin::signed(caller_1),
+			VALIDATED_IPFS_SCHEMA_ID,
+			DUMMY_CID_SHA256.as_bytes().to_vec(),
+			IPFS_PAYLOAD_LENGTH
+		));
+		assert_ok!(MessagesPallet::add_ipfs_messages(
+			RuntimeOrigin::signed(caller_1),
+			vec![IpfsMessageEntry {
+				schema_id: VALIDATED_IPFS_SCHEMA_ID,
+				cid: DUMMY_CID_SHA256.as_bytes().to_vec(),
+				payload_length: IPFS_PAYLOAD_LENGTH,
+			}]
+			.try_into()
+			.unwrap()
+		));
+
+		assert!(MessagesV3::<Test>::get((1, VALIDATED_IPFS_SCHEMA_ID, 0u16)).is_some());
+		assert!(MessagesV3::<Test>::get((1, VALIDATED_IPFS_SCHEMA_ID, 1u16)).is_some());
+	});
+}
+
+#[test]
+fn add_onchain_message_with_non_conforming_payload_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = 5;
+
+		for payload in [vec![], vec![0x04, 0x02], vec![0x00, 0x00], generate_payload(1)] {
+			assert_noop!(
+				MessagesPallet::add_onchain_message(
+					RuntimeOrigin::signed(caller_1),
+					None,
+					VALIDATED_SCHEMA_ID,
+					payload
+				),
+				Error::<Test>::InvalidPayload
+			);
+		}
+	});
+}
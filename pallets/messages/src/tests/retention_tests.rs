@@ -0,0 +1,128 @@
+use crate::{
+	pallet::{MessagesV3, RetentionCursors},
+	tests::mock::*,
+	weights::WeightInfo,
+	RetentionCursor, MAX_RETENTION_INTENTS_PER_BLOCK,
+};
+use common_primitives::schema::*;
+use frame_support::{assert_ok, pallet_prelude::Weight, traits::Hooks};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+
+fn add_messages(intent_id: IntentId, block_number: u32, count: u16) {
+	for _ in 0..count {
+		assert_ok!(MessagesPallet::add_message(
+			1,
+			None,
+			vec![1, 2, 3].try_into().unwrap(),
+			intent_id,
+			intent_id,
+			block_number,
+		));
+	}
+}
+
+fn message_count(intent_id: IntentId, block_number: u32) -> usize {
+	MessagesV3::<Test>::iter_prefix((block_number, intent_id)).count()
+}
+
+#[test]
+fn on_idle_should_prune_expired_messages_only() {
+	new_test_ext().execute_with(|| {
+		add_messages(RETAINED_SCHEMA_ID, 1, 2);
+		add_messages(RETAINED_SCHEMA_ID, 2, 1);
+		add_messages(ON_CHAIN_SCHEMA_ID, 1, 1);
+
+		let current = 1 + RETENTION_PERIOD;
+		MessagesPallet::on_idle(current, Weight::MAX);
+
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 1), 0);
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 2), 1);
+		assert_eq!(message_count(ON_CHAIN_SCHEMA_ID, 1), 1);
+		assert_eq!(
+			RetentionCursors::<Test>::get(RETAINED_SCHEMA_ID),
+			RetentionCursor { next_block: 2, partial: false }
+		);
+		assert_eq!(MessagesPallet::get_oldest_retained_block(RETAINED_SCHEMA_ID), 2);
+		assert_eq!(MessagesPallet::get_oldest_retained_block(ON_CHAIN_SCHEMA_ID), 0);
+	});
+}
+
+#[test]
+fn on_idle_before_retention_period_elapses_should_not_prune() {
+	new_test_ext().execute_with(|| {
+		add_messages(RETAINED_SCHEMA_ID, 1, 1);
+
+		MessagesPallet::on_idle(RETENTION_PERIOD, Weight::MAX);
+
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 1), 1);
+		assert_eq!(MessagesPallet::get_oldest_retained_block(RETAINED_SCHEMA_ID), 1);
+	});
+}
+
+#[test]
+fn on_idle_without_enough_weight_should_not_prune() {
+	new_test_ext().execute_with(|| {
+		add_messages(RETAINED_SCHEMA_ID, 1, 1);
+
+		let consumed =
+			MessagesPallet::on_idle(1 + RETENTION_PERIOD, Weight::from_parts(1_000, 1_000));
+
+		assert_eq!(consumed, Weight::zero());
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 1), 1);
+		assert_eq!(RetentionCursors::<Test>::get(RETAINED_SCHEMA_ID), RetentionCursor::default());
+	});
+}
+
+#[test]
+fn on_idle_should_resume_partially_pruned_block() {
+	let mut ext = new_test_ext();
+	ext.execute_with(|| add_messages(RETAINED_SCHEMA_ID, 1, 3));
+	// pruning is bounded for messages in the backend, not the overlay
+	ext.commit_all().unwrap();
+
+	let current = 1 + RETENTION_PERIOD;
+	ext.execute_with(|| {
+		let limit = <() as WeightInfo>::prune_expired_messages(MAX_RETENTION_INTENTS_PER_BLOCK)
+			.saturating_add(<() as WeightInfo>::prune_messages_in_block(2));
+
+		let consumed = MessagesPallet::on_idle(current, limit);
+
+		assert!(consumed.all_lte(limit));
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 1), 1);
+		assert_eq!(
+			RetentionCursors::<Test>::get(RETAINED_SCHEMA_ID),
+			RetentionCursor { next_block: 1, partial: true }
+		);
+		assert_eq!(MessagesPallet::get_oldest_retained_block(RETAINED_SCHEMA_ID), 2);
+	});
+	ext.commit_all().unwrap();
+
+	ext.execute_with(|| {
+		MessagesPallet::on_idle(current + 1, Weight::MAX);
+
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 1), 0);
+		assert_eq!(
+			RetentionCursors::<Test>::get(RETAINED_SCHEMA_ID),
+			RetentionCursor { next_block: 3, partial: false }
+		);
+	});
+}
+
+#[test]
+fn on_idle_should_start_pruning_one_retention_period_before_retention_was_set() {
+	new_test_ext().execute_with(|| {
+		RetentionSince::set(1_000);
+		add_messages(RETAINED_SCHEMA_ID, 5, 1);
+		add_messages(RETAINED_SCHEMA_ID, 995, 1);
+
+		MessagesPallet::on_idle(1_006, Weight::MAX);
+
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 5), 1);
+		assert_eq!(message_count(RETAINED_SCHEMA_ID, 995), 0);
+		assert_eq!(
+			RetentionCursors::<Test>::get(RETAINED_SCHEMA_ID),
+			RetentionCursor { next_block: 997, partial: false }
+		);
+	});
+}
@@ -1,22 +1,38 @@
 use common_primitives::{
+	cid::{cid_to_base32, DSNP_CODECS, DSNP_HASH_ALGORITHMS},
 	messages::MessageResponse,
 	msa::MessageSourceId,
 	node::BlockNumber,
 	schema::{PayloadLocation, SchemaId},
 };
 use core::fmt::Debug;
-use frame_support::{traits::Get, BoundedVec};
-use multibase::Base;
-use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::{
+	traits::{ConstU32, Get},
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebugNoBound,
+};
+use parity_scale_codec::{Decode, DecodeWithMemTracking, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 extern crate alloc;
 use alloc::vec::Vec;
-use common_primitives::messages::MessageResponseV2;
+use common_primitives::messages::MessageResponseV3;
 
 /// Payloads stored offchain contain a tuple of (bytes(the payload reference), payload length).
 pub type OffchainPayloadType = (Vec<u8>, u32);
+/// Payloads stored at an off-chain URI contain a tuple of (URI, multihash of the content, payload length).
+pub type OffchainUriPayloadType = (Vec<u8>, Vec<u8>, u32);
+/// Scheme of an off-chain payload URI, such as `https`
+pub type UriScheme = BoundedVec<u8, ConstU32<MAX_URI_SCHEME_LENGTH>>;
+/// Maximum length of a URI scheme
+pub const MAX_URI_SCHEME_LENGTH: u32 = 16;
+/// Binary multihash of the content of an off-chain payload
+pub type ContentMultihash = BoundedVec<u8, ConstU32<MAX_MULTIHASH_LENGTH>>;
+/// Maximum length of a binary multihash: a hash code varint of up to 9 bytes, a one-byte digest
+/// size and a digest of up to 64 bytes
+pub const MAX_MULTIHASH_LENGTH: u32 = 74;
 /// Index of message in the block
 pub type MessageIndex = u16;
+/// Maximum number of Intents whose expired messages are pruned in one block
+pub const MAX_RETENTION_INTENTS_PER_BLOCK: u32 = 8;
 
 /// A single message type definition.
 #[derive(Default, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
@@ -39,6 +55,77 @@ where
 	pub schema_id: SchemaId,
 }
 
+/// The CIDs accepted for IPFS messages
+#[derive(
+	CloneNoBound,
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	PartialEqNoBound,
+	RuntimeDebugNoBound,
+	TypeInfo,
+	EqNoBound,
+	MaxEncodedLen,
+)]
+#[scale_info(skip_type_params(MaxEntries))]
+#[codec(mel_bound(MaxEntries: Get<u32>))]
+pub struct CidPolicy<MaxEntries>
+where
+	MaxEntries: Get<u32>,
+{
+	///  Accepted CID codecs, such as raw (0x55) or dag-pb (0x70)
+	pub codecs: BoundedVec<u64, MaxEntries>,
+	///  Accepted multihash codes, such as sha2-256 (0x12) or blake3 (0x1e)
+	pub multihash_codes: BoundedVec<u64, MaxEntries>,
+	///  Whether CIDv0 is accepted, as the equivalent dag-pb CIDv1, when dag-pb is an accepted codec
+	pub allow_cid_v0: bool,
+}
+
+impl<MaxEntries: Get<u32>> Default for CidPolicy<MaxEntries> {
+	/// The codecs and hash algorithms supported by DSNP, without CIDv0
+	fn default() -> Self {
+		Self {
+			codecs: BoundedVec::truncate_from(DSNP_CODECS.to_vec()),
+			multihash_codes: BoundedVec::truncate_from(DSNP_HASH_ALGORITHMS.to_vec()),
+			allow_cid_v0: false,
+		}
+	}
+}
+
+/// A single IPFS message in a batch submitted with [`add_ipfs_messages`](crate::Call::add_ipfs_messages)
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, PartialEq, Debug, TypeInfo, Eq)]
+pub struct IpfsMessageEntry {
+	///  The IPFS schema the message is written to
+	#[codec(compact)]
+	pub schema_id: SchemaId,
+	///  Multibase-encoded CIDv1, or base58btc CIDv0, of the message content
+	pub cid: Vec<u8>,
+	///  Length of the message content hosted on IPFS
+	#[codec(compact)]
+	pub payload_length: u32,
+}
+
+/// A single on-chain message in a batch submitted with [`add_onchain_messages`](crate::Call::add_onchain_messages)
+#[derive(Clone, Encode, Decode, DecodeWithMemTracking, PartialEq, Debug, TypeInfo, Eq)]
+pub struct OnChainMessageEntry {
+	///  The on-chain schema the message is written to
+	#[codec(compact)]
+	pub schema_id: SchemaId,
+	///  The delegator the message is sent on behalf of; `None` if the provider is the source
+	pub on_behalf_of: Option<MessageSourceId>,
+	///  Data structured by the schema's model
+	pub payload: Vec<u8>,
+}
+
+/// Pruning progress of an Intent that has a message retention period
+#[derive(Default, Clone, Copy, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+pub struct RetentionCursor<BlockNumber> {
+	///  The oldest block whose messages have not been fully pruned
+	pub next_block: BlockNumber,
+	///  `true` if some, but not all, of the messages in `next_block` have been pruned
+	pub partial: bool,
+}
+
 /// Trait for converting message storage to response type
 pub trait MapToResponse<I, R> {
 	/// Maps a stored message to an RPC response
@@ -72,7 +159,7 @@ impl<MaxDataSize: Get<u32> + Debug>
 				let (binary_cid, payload_length) =
 					OffchainPayloadType::decode(&mut &self.payload[..]).unwrap_or_default();
 				Some(MessageResponse {
-					cid: Some(multibase::encode(Base::Base32Lower, binary_cid).as_bytes().to_vec()),
+					cid: Some(cid_to_base32(&binary_cid)),
 					payload_length: Some(payload_length),
 					payload: None,
 					..base_response
@@ -84,16 +171,16 @@ impl<MaxDataSize: Get<u32> + Debug>
 }
 
 impl<MaxDataSize: Get<u32> + Debug>
-	MapToResponse<(BlockNumber, SchemaId, PayloadLocation, u16), MessageResponseV2>
+	MapToResponse<(BlockNumber, SchemaId, PayloadLocation, u16), MessageResponseV3>
 	for Message<MaxDataSize>
 {
-	/// Helper function to handle response type [`MessageResponseV2`] depending on the Payload Location (on chain or IPFS)
+	/// Helper function to handle response type [`MessageResponseV3`] depending on the Payload Location (on chain or IPFS)
 	fn map_to_response(
 		&self,
 		index_values: (BlockNumber, SchemaId, PayloadLocation, u16),
-	) -> Option<MessageResponseV2> {
+	) -> Option<MessageResponseV3> {
 		let (block_number, schema_id, payload_location, index) = index_values;
-		let base_response = MessageResponseV2 {
+		let base_response = MessageResponseV3 {
 			provider_msa_id: self.provider_msa_id,
 			index,
 			block_number,
@@ -103,7 +190,7 @@ impl<MaxDataSize: Get<u32> + Debug>
 		};
 
 		match payload_location {
-			PayloadLocation::OnChain => Some(MessageResponseV2 {
+			PayloadLocation::OnChain => Some(MessageResponseV3 {
 				payload: Some(self.payload.to_vec()),
 				cid: None,
 				payload_length: None,
@@ -112,12 +199,22 @@ impl<MaxDataSize: Get<u32> + Debug>
 			PayloadLocation::IPFS => {
 				let (binary_cid, payload_length) =
 					OffchainPayloadType::decode(&mut &self.payload[..]).unwrap_or_default();
-				Some(MessageResponseV2 {
-					cid: Some(multibase::encode(Base::Base32Lower, binary_cid).as_bytes().to_vec()),
+				Some(MessageResponseV3 {
+					cid: Some(cid_to_base32(&binary_cid)),
 					payload_length: Some(payload_length),
 					payload: None,
 					..base_response
 				})
+			},
+			PayloadLocation::OffChain => {
+				let (uri, content_hash, payload_length) =
+					OffchainUriPayloadType::decode(&mut &self.payload[..]).unwrap_or_default();
+				Some(MessageResponseV3 {
+					uri: Some(uri),
+					content_hash: Some(content_hash),
+					payload_length: Some(payload_length),
+					..base_response
+				})
 			}, // Message types of Itemized and Paginated are retrieved differently
 			_ => None,
 		}
@@ -125,15 +222,15 @@ impl<MaxDataSize: Get<u32> + Debug>
 }
 
 impl<MaxDataSize: Get<u32> + Debug>
-	MapToResponse<(BlockNumber, PayloadLocation, u16), MessageResponseV2> for Message<MaxDataSize>
+	MapToResponse<(BlockNumber, PayloadLocation, u16), MessageResponseV3> for Message<MaxDataSize>
 {
-	/// Helper function to handle response type [`MessageResponseV2`] depending on the Payload Location (on chain or IPFS)
+	/// Helper function to handle response type [`MessageResponseV3`] depending on the Payload Location (on chain or IPFS)
 	fn map_to_response(
 		&self,
 		index_values: (BlockNumber, PayloadLocation, u16),
-	) -> Option<MessageResponseV2> {
+	) -> Option<MessageResponseV3> {
 		let (block_number, payload_location, index) = index_values;
-		let base_response = MessageResponseV2 {
+		let base_response = MessageResponseV3 {
 			provider_msa_id: self.provider_msa_id,
 			index,
 			block_number,
@@ -143,7 +240,7 @@ impl<MaxDataSize: Get<u32> + Debug>
 		};
 
 		match payload_location {
-			PayloadLocation::OnChain => Some(MessageResponseV2 {
+			PayloadLocation::OnChain => Some(MessageResponseV3 {
 				payload: Some(self.payload.to_vec()),
 				cid: None,
 				payload_length: None,
@@ -152,12 +249,22 @@ impl<MaxDataSize: Get<u32> + Debug>
 			PayloadLocation::IPFS => {
 				let (binary_cid, payload_length) =
 					OffchainPayloadType::decode(&mut &self.payload[..]).unwrap_or_default();
-				Some(MessageResponseV2 {
-					cid: Some(multibase::encode(Base::Base32Lower, binary_cid).as_bytes().to_vec()),
+				Some(MessageResponseV3 {
+					cid: Some(cid_to_base32(&binary_cid)),
 					payload_length: Some(payload_length),
 					payload: None,
 					..base_response
 				})
+			},
+			PayloadLocation::OffChain => {
+				let (uri, content_hash, payload_length) =
+					OffchainUriPayloadType::decode(&mut &self.payload[..]).unwrap_or_default();
+				Some(MessageResponseV3 {
+					uri: Some(uri),
+					content_hash: Some(content_hash),
+					payload_length: Some(payload_length),
+					..base_response
+				})
 			}, // Message types of Itemized and Paginated are retrieved differently
 			_ => None,
 		}
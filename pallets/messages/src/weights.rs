@@ -37,6 +37,13 @@ pub trait WeightInfo {
 	fn add_ipfs_message() -> Weight;
 	fn v2_to_v3_step() -> Weight;
 	fn v2_to_v3_final_step() -> Weight;
+	fn add_ipfs_messages(n: u32, ) -> Weight;
+	fn add_onchain_messages(n: u32, b: u32, ) -> Weight;
+	fn prune_expired_messages(n: u32, ) -> Weight;
+	fn prune_messages_in_block(m: u32, ) -> Weight;
+	fn add_offchain_message() -> Weight;
+	fn set_allowed_uri_schemes(n: u32, ) -> Weight;
+	fn set_cid_policy() -> Weight;
 }
 
 /// Weights for `pallet_messages` using the Substrate node and recommended hardware.
@@ -44,22 +51,20 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `Messages::MessagesV3` (r:0 w:1)
 	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 3071]`.
+	/// The range of component `n` is `[1, 3071]`.
 	fn add_onchain_message(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `548`
-		//  Estimated: `4177`
-		// Minimum execution time: 38_633_000 picoseconds.
-		Weight::from_parts(41_435_137, 4177)
-			// Standard Error: 235
-			.saturating_add(Weight::from_parts(543, 0).saturating_mul(n.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(47_826_342, 69474)
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
@@ -68,13 +73,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Messages::MessagesV3` (r:0 w:1)
 	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
 	fn add_ipfs_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `471`
 		//  Estimated: `4008`
 		// Minimum execution time: 36_560_000 picoseconds.
 		Weight::from_parts(38_764_000, 4008)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads(3_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
 	/// Storage: UNKNOWN KEY `0x9ea3e2d10fdb9a071f2f534d51b0961f963740dfb0edb77dddcbb1e5542cf4d2` (r:2 w:1)
@@ -103,28 +110,142 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1_u64))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn add_ipfs_messages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `4008`
+		// Minimum execution time: 20_191_000 picoseconds.
+		Weight::from_parts(21_480_000, 4008)
+			// Standard Error: 1_573_000
+			.saturating_add(Weight::from_parts(15_730_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2492).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:20 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:20 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	/// The range of component `b` is `[1, 61440]`.
+	fn add_onchain_messages(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3513`
+		// Minimum execution time: 21_540_000 picoseconds.
+		Weight::from_parts(22_915_000, 3513)
+			// Standard Error: 2_431_000
+			.saturating_add(Weight::from_parts(24_310_000, 0).saturating_mul(n.into()))
+			// Standard Error: 27
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(b.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 73173).saturating_mul(n.into()))
+	}
+	/// Storage: `Messages::RetentionIntentCursor` (r:1 w:1)
+	/// Proof: `Messages::RetentionIntentCursor` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:9 w:0)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::RetentionCursors` (r:10 w:10)
+	/// Proof: `Messages::RetentionCursors` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn prune_expired_messages(n: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(4_120_000, 1489)
+			.saturating_add(Weight::from_parts(7_850_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2490).saturating_mul(n.into()))
+	}
+	/// Storage: `Messages::MessagesV3` (r:1 w:1000)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 1000]`.
+	fn prune_messages_in_block(m: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(3_460_000, 3593)
+			.saturating_add(Weight::from_parts(1_210_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `Messages::AllowedUriSchemes` (r:1 w:0)
+	/// Proof: `Messages::AllowedUriSchemes` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:1)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	fn add_offchain_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `508`
+		//  Estimated: `4008`
+		// Minimum execution time: 38_737_000 picoseconds.
+		Weight::from_parts(41_210_000, 4008)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messages::AllowedUriSchemes` (r:0 w:1)
+	/// Proof: `Messages::AllowedUriSchemes` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_allowed_uri_schemes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_651_000 picoseconds.
+		Weight::from_parts(8_140_000, 0)
+			// Standard Error: 31_000
+			.saturating_add(Weight::from_parts(312_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messages::AcceptedCidPolicy` (r:0 w:1)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
+	fn set_cid_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_437_000 picoseconds.
+		Weight::from_parts(7_912_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: `Messages::MessagesV3` (r:0 w:1)
 	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
-	/// The range of component `n` is `[0, 3071]`.
+	/// The range of component `n` is `[1, 3071]`.
 	fn add_onchain_message(n: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `548`
-		//  Estimated: `4177`
-		// Minimum execution time: 38_633_000 picoseconds.
-		Weight::from_parts(41_435_137, 4177)
-			// Standard Error: 235
-			.saturating_add(Weight::from_parts(543, 0).saturating_mul(n.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(47_826_342, 69474)
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
@@ -133,13 +254,15 @@ impl WeightInfo for () {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Messages::MessagesV3` (r:0 w:1)
 	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
 	fn add_ipfs_message() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `471`
 		//  Estimated: `4008`
 		// Minimum execution time: 36_560_000 picoseconds.
 		Weight::from_parts(38_764_000, 4008)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
 	/// Storage: UNKNOWN KEY `0x9ea3e2d10fdb9a071f2f534d51b0961f963740dfb0edb77dddcbb1e5542cf4d2` (r:2 w:1)
@@ -168,6 +291,122 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1_u64))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
 	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::AcceptedCidPolicy` (r:1 w:0)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	fn add_ipfs_messages(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `4008`
+		// Minimum execution time: 20_191_000 picoseconds.
+		Weight::from_parts(21_480_000, 4008)
+			// Standard Error: 1_573_000
+			.saturating_add(Weight::from_parts(15_730_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2492).saturating_mul(n.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:20 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:20 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:20 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:20)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 20]`.
+	/// The range of component `b` is `[1, 61440]`.
+	fn add_onchain_messages(n: u32, b: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `471`
+		//  Estimated: `3513`
+		// Minimum execution time: 21_540_000 picoseconds.
+		Weight::from_parts(22_915_000, 3513)
+			// Standard Error: 2_431_000
+			.saturating_add(Weight::from_parts(24_310_000, 0).saturating_mul(n.into()))
+			// Standard Error: 27
+			.saturating_add(Weight::from_parts(1_874, 0).saturating_mul(b.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 73173).saturating_mul(n.into()))
+	}
+	/// Storage: `Messages::RetentionIntentCursor` (r:1 w:1)
+	/// Proof: `Messages::RetentionIntentCursor` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:9 w:0)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::RetentionCursors` (r:10 w:10)
+	/// Proof: `Messages::RetentionCursors` (`max_values`: None, `max_size`: Some(15), added: 2490, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 8]`.
+	fn prune_expired_messages(n: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(4_120_000, 1489)
+			.saturating_add(Weight::from_parts(7_850_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 2490).saturating_mul(n.into()))
+	}
+	/// Storage: `Messages::MessagesV3` (r:1 w:1000)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 1000]`.
+	fn prune_messages_in_block(m: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(3_460_000, 3593)
+			.saturating_add(Weight::from_parts(1_210_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+	}
+	/// Storage: `Messages::AllowedUriSchemes` (r:1 w:0)
+	/// Proof: `Messages::AllowedUriSchemes` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Messages::MessagesV3` (r:0 w:1)
+	/// Proof: `Messages::MessagesV3` (`max_values`: None, `max_size`: Some(3125), added: 5600, mode: `MaxEncodedLen`)
+	fn add_offchain_message() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `508`
+		//  Estimated: `4008`
+		// Minimum execution time: 38_737_000 picoseconds.
+		Weight::from_parts(41_210_000, 4008)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messages::AllowedUriSchemes` (r:0 w:1)
+	/// Proof: `Messages::AllowedUriSchemes` (`max_values`: Some(1), `max_size`: Some(273), added: 768, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[0, 16]`.
+	fn set_allowed_uri_schemes(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_651_000 picoseconds.
+		Weight::from_parts(8_140_000, 0)
+			// Standard Error: 31_000
+			.saturating_add(Weight::from_parts(312_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Messages::AcceptedCidPolicy` (r:0 w:1)
+	/// Proof: `Messages::AcceptedCidPolicy` (`max_values`: Some(1), `max_size`: Some(131), added: 626, mode: `MaxEncodedLen`)
+	fn set_cid_policy() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 7_437_000 picoseconds.
+		Weight::from_parts(7_912_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
 }
 
 
@@ -241,4 +480,64 @@ mod tests {
 				> 3960
 		);
 	}
+	#[test]
+	fn test_add_ipfs_messages() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
+	#[test]
+	fn test_add_onchain_messages() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3513
+		);
+	}
+	#[test]
+	fn test_prune_expired_messages() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 1489
+		);
+	}
+	#[test]
+	fn test_prune_messages_in_block() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3593
+		);
+	}
+	#[test]
+	fn test_add_offchain_message() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4008
+		);
+	}
 }
@@ -4,7 +4,8 @@ use super::*;
 #[allow(unused)]
 use crate::Pallet as Msa;
 use crate::{
-	types::{compute_cid, RecoveryCommitmentPayload},
+	test_common::utilities::{get_p256_public_key, get_p256_secret, passkey_sign},
+	types::{compute_cid, png_logo_bytes, RecoveryCommitmentPayload},
 	MsaIdToRecoveryCommitment,
 };
 use common_primitives::{
@@ -264,6 +265,31 @@ fn generate_provider_registry_entry<T: Config>(
 	}
 }
 
+fn create_provider_with_application<T: Config>(
+	name: &'static str,
+	index: u32,
+	application: ApplicationContext<
+		T::MaxProviderNameSize,
+		T::MaxLanguageCodeSize,
+		T::MaxLogoCidSize,
+		T::MaxLocaleCount,
+	>,
+) -> (ProviderId, T::AccountId) {
+	let provider_key = create_account::<T>(name, index);
+	let (provider_msa_id, _) = Msa::<T>::create_account(provider_key.clone()).unwrap();
+	assert_ok!(Msa::<T>::create_provider_via_governance_v2(
+		RawOrigin::Root.into(),
+		provider_key.clone(),
+		ProviderRegistryEntry::default()
+	));
+	assert_ok!(Msa::<T>::create_application_via_governance(
+		RawOrigin::Root.into(),
+		provider_key.clone(),
+		application
+	));
+	(ProviderId(provider_msa_id), provider_key)
+}
+
 #[benchmarks(where
 	T: Config + Send + Sync,
 )]
@@ -374,6 +400,42 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn add_passkey_to_msa() -> Result<(), BenchmarkError> {
+		prep_signature_registry::<T>();
+
+		let (provider_public_key, _, _) = create_msa_account_and_keys::<T>();
+		let (delegator_public_key, delegator_key_pair, delegator_msa_id) =
+			create_msa_account_and_keys::<T>();
+
+		let secret = get_p256_secret();
+		let passkey_public_key = get_p256_public_key(&secret);
+		let add_passkey_payload = AddPasskeyData::<T> {
+			msa_id: delegator_msa_id,
+			expiration: 10u32.into(),
+			new_passkey_public_key: passkey_public_key.clone(),
+		};
+		let new_passkey_proof = passkey_sign(&secret, &add_passkey_payload.encode());
+
+		let encoded_add_passkey_payload = wrap_binary_data(add_passkey_payload.encode());
+		let owner_signature = MultiSignature::Sr25519(
+			delegator_key_pair.sign(&encoded_add_passkey_payload).unwrap().into(),
+		);
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(provider_public_key.clone()),
+			delegator_public_key.clone(),
+			owner_signature,
+			new_passkey_proof,
+			add_passkey_payload,
+		);
+
+		let passkey_account = Msa::<T>::passkey_account_id(&passkey_public_key)?;
+		assert!(PublicKeyToMsaId::<T>::get(passkey_account).is_some());
+		Ok(())
+	}
+
 	#[benchmark]
 	fn delete_msa_public_key() -> Result<(), BenchmarkError> {
 		frame_system::Pallet::<T>::set_block_number(1u32.into());
@@ -936,7 +998,7 @@ mod benchmarks {
 	#[benchmark]
 	fn upload_logo() -> Result<(), BenchmarkError> {
 		let max_logo_size = T::MaxLogoSize::get();
-		let max_logo_bytes = vec![0u8; max_logo_size as usize];
+		let max_logo_bytes = png_logo_bytes(250, 100, max_logo_size as usize);
 		let logo_cid = compute_cid(&max_logo_bytes);
 		let provider_caller: T::AccountId = whitelisted_caller();
 		let (_, provider_public_key) = Msa::<T>::create_account(provider_caller.clone()).unwrap();
@@ -962,6 +1024,7 @@ mod benchmarks {
 		assert!(ApprovedLogos::<T>::get(input_bounded_cid.clone()).is_some());
 		let stored_logo_bytes = ApprovedLogos::<T>::get(&input_bounded_cid).unwrap();
 		assert_eq!(stored_logo_bytes, input_bounded_logo);
+		assert!(ApprovedLogoMetadata::<T>::get(&input_bounded_cid).is_some());
 		Ok(())
 	}
 
@@ -1172,6 +1235,98 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn retire_application() -> Result<(), BenchmarkError> {
+		let application_name = (1..T::MaxProviderNameSize::get() as u8).collect::<Vec<_>>();
+		// Application with maximum number of logos for worst-case removal
+		let application_payload = generate_provider_registry_entry::<T>(
+			T::MaxLocaleCount::get() as usize,
+			T::MaxLocaleCount::get() as usize,
+			application_name,
+			10101111u64,
+			true,
+		);
+		let (provider_id, provider_key) =
+			create_provider_with_application::<T>("provider", 0, application_payload);
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(provider_key), 0u16);
+
+		assert!(ProviderToApplicationRegistry::<T>::get(provider_id, 0).is_none());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn suspend_application_via_governance() -> Result<(), BenchmarkError> {
+		let (provider_id, _) =
+			create_provider_with_application::<T>("provider", 0, ApplicationContext::default());
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, provider_id, 0u16);
+
+		assert!(SuspendedApplications::<T>::contains_key(provider_id, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn resume_application_via_governance() -> Result<(), BenchmarkError> {
+		let (provider_id, _) =
+			create_provider_with_application::<T>("provider", 0, ApplicationContext::default());
+		assert_ok!(Msa::<T>::suspend_application_via_governance(
+			RawOrigin::Root.into(),
+			provider_id,
+			0u16
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, provider_id, 0u16);
+
+		assert!(!SuspendedApplications::<T>::contains_key(provider_id, 0));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn offer_application_transfer() -> Result<(), BenchmarkError> {
+		let (provider_id, provider_key) =
+			create_provider_with_application::<T>("provider", 0, ApplicationContext::default());
+		let (new_provider_id, _) =
+			create_provider_with_application::<T>("new_provider", 1, ApplicationContext::default());
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(provider_key), 0u16, new_provider_id);
+
+		assert_eq!(PendingApplicationTransfers::<T>::get(provider_id, 0), Some(new_provider_id));
+		Ok(())
+	}
+
+	#[benchmark]
+	fn accept_application_transfer() -> Result<(), BenchmarkError> {
+		let application_name = (1..T::MaxProviderNameSize::get() as u8).collect::<Vec<_>>();
+		let application_payload = generate_provider_registry_entry::<T>(
+			T::MaxLocaleCount::get() as usize,
+			T::MaxLocaleCount::get() as usize,
+			application_name,
+			10101111u64,
+			true,
+		);
+		let (provider_id, provider_key) =
+			create_provider_with_application::<T>("provider", 0, application_payload);
+		let (new_provider_id, new_provider_key) =
+			create_provider_with_application::<T>("new_provider", 1, ApplicationContext::default());
+		assert_ok!(Msa::<T>::offer_application_transfer(
+			RawOrigin::Signed(provider_key).into(),
+			0u16,
+			new_provider_id
+		));
+
+		#[extrinsic_call]
+		_(RawOrigin::Signed(new_provider_key), provider_id, 0u16);
+
+		assert!(ProviderToApplicationRegistry::<T>::get(provider_id, 0).is_none());
+		assert!(ProviderToApplicationRegistry::<T>::get(new_provider_id, 1).is_some());
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		Msa,
 		crate::tests::mock::new_test_ext_keystore(),
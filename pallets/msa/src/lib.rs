@@ -58,8 +58,10 @@ use common_primitives::{
 	capacity::TargetValidator,
 	cid::compute_cid_v1,
 	handles::HandleProvider,
+	image::{parse_image_metadata, ImageError, ImageMetadata},
 	msa::*,
 	node::{EIP712Encode, ProposalProvider},
+	passkey::{PasskeyPublicKey, VerifiablePasskeySignature},
 	schema::{SchemaId, SchemaValidator},
 	signatures::{AccountAddressMapper, EthereumAddressMapper},
 };
@@ -72,13 +74,13 @@ use sp_io::hashing::keccak_256;
 use sp_runtime::{
 	traits::{
 		AsSystemOriginSigner, BlockNumberProvider, Convert, DispatchInfoOf, Dispatchable,
-		PostDispatchInfoOf, TransactionExtension, ValidateResult, Zero,
+		PostDispatchInfoOf, TrailingZeroInput, TransactionExtension, ValidateResult, Zero,
 	},
 	ArithmeticError, DispatchError, MultiSignature, Weight,
 };
 pub use types::{
-	AddKeyData, AddProvider, ApplicationIndex, AuthorizedKeyData, PermittedDelegationIntents,
-	RecoveryCommitment, RecoveryCommitmentPayload,
+	AddKeyData, AddPasskeyData, AddProvider, ApplicationIndex, AuthorizedKeyData,
+	PermittedDelegationIntents, RecoveryCommitment, RecoveryCommitmentPayload,
 };
 pub use weights::*;
 
@@ -90,6 +92,9 @@ pub use offchain_storage::*;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 
+#[cfg(any(feature = "runtime-benchmarks", test))]
+mod test_common;
+
 #[cfg(test)]
 mod tests;
 
@@ -177,6 +182,14 @@ pub mod pallet {
 		/// Total number of locales supported
 		#[pallet::constant]
 		type MaxLocaleCount: Get<u32> + Clone + Debug + PartialEq + Eq;
+
+		/// Minimum logo width and height (in pixels)
+		#[pallet::constant]
+		type MinLogoDimension: Get<u32>;
+
+		/// Maximum logo width and height (in pixels)
+		#[pallet::constant]
+		type MaxLogoDimension: Get<u32>;
 	}
 
 	/// Storage version for the MSA pallet.
@@ -315,6 +328,21 @@ pub mod pallet {
 	pub type ApprovedLogos<T: Config> =
 		StorageMap<_, Twox64Concat, LogoCid<T>, BoundedVec<u8, T::MaxLogoSize>, OptionQuery>;
 
+	/// Storage type for the format and dimensions of uploaded logos in `ApprovedLogos`
+	/// - key: Logo Cid
+	/// - value: Detected MIME type and dimensions
+	#[pallet::storage]
+	pub type ApprovedLogoMetadata<T: Config> =
+		StorageMap<_, Twox64Concat, LogoCid<T>, ImageMetadata, OptionQuery>;
+
+	/// Storage type for the number of provider and application registry entries that
+	/// reference a logo in `ApprovedLogos`
+	/// - key: Logo Cid
+	/// - value: Number of references
+	#[pallet::storage]
+	pub type ApprovedLogoReferences<T: Config> =
+		StorageMap<_, Twox64Concat, LogoCid<T>, u32, ValueQuery>;
+
 	/// Monotonically increasing index of applications for a given provider.
 	/// Starts at 0 when provider first gets an application approved.
 	#[pallet::storage]
@@ -341,6 +369,34 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	/// Applications that have been suspended by governance
+	/// - Keys: (ProviderId, ApplicationIndex)
+	/// - Value: Block number at which the application was suspended
+	#[pallet::storage]
+	pub type SuspendedApplications<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProviderId,
+		Twox64Concat,
+		ApplicationIndex,
+		BlockNumberFor<T>,
+		OptionQuery,
+	>;
+
+	/// Pending application transfers offered by the current owner
+	/// - Keys: (Current owner ProviderId, ApplicationIndex)
+	/// - Value: The ProviderId the application is offered to
+	#[pallet::storage]
+	pub type PendingApplicationTransfers<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		ProviderId,
+		Twox64Concat,
+		ApplicationIndex,
+		ProviderId,
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub (super) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -455,6 +511,58 @@ pub mod pallet {
 			/// The MSA id associated with the provider
 			provider_id: ProviderId,
 		},
+		/// A passkey has been associated with a MessageSourceId
+		PasskeyAdded {
+			/// The MSA for the Event
+			msa_id: MessageSourceId,
+
+			/// The account id controlled by the passkey that was added to the MSA
+			key: T::AccountId,
+
+			/// The passkey public key
+			passkey_public_key: PasskeyPublicKey,
+		},
+		/// An application was retired by its provider and removed from the registry
+		ApplicationRetired {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+			/// The application id of the retired application
+			application_id: ApplicationIndex,
+		},
+		/// An application was suspended by governance
+		ApplicationSuspended {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+			/// The application id of the suspended application
+			application_id: ApplicationIndex,
+		},
+		/// A suspended application was reinstated by governance
+		ApplicationResumed {
+			/// The MSA id associated with the provider
+			provider_id: ProviderId,
+			/// The application id of the reinstated application
+			application_id: ApplicationIndex,
+		},
+		/// A provider offered to transfer one of its applications to another provider
+		ApplicationTransferOffered {
+			/// The MSA id of the provider that currently owns the application
+			provider_id: ProviderId,
+			/// The application id under the current provider
+			application_id: ApplicationIndex,
+			/// The MSA id of the provider the application is offered to
+			new_provider_id: ProviderId,
+		},
+		/// An application was transferred to another provider
+		ApplicationTransferred {
+			/// The MSA id of the provider that previously owned the application
+			provider_id: ProviderId,
+			/// The application id under the previous provider
+			application_id: ApplicationIndex,
+			/// The MSA id of the provider that now owns the application
+			new_provider_id: ProviderId,
+			/// The application id under the new provider
+			new_application_id: ApplicationIndex,
+		},
 	}
 
 	#[pallet::error]
@@ -579,6 +687,15 @@ pub mod pallet {
 		/// Invalid logo bytes that do not match the approved logo
 		InvalidLogoBytes,
 
+		/// Logo is not a PNG, JPEG or WebP image (SVG is not accepted)
+		UnsupportedLogoFormat,
+
+		/// Logo image header is truncated or malformed
+		InvalidLogoImage,
+
+		/// Logo width or height is outside of the allowed bounds
+		InvalidLogoDimensions,
+
 		/// Application not found for given application_id
 		ApplicationNotFound,
 
@@ -590,6 +707,21 @@ pub mod pallet {
 
 		/// The requested item does not have a current permission delegation in force
 		PermissionNotGranted,
+
+		/// The passkey public key could not be converted into an account id
+		InvalidPasskeyPublicKey,
+
+		/// The application is suspended
+		ApplicationSuspended,
+
+		/// The application is not suspended
+		ApplicationNotSuspended,
+
+		/// No pending transfer of the application to the caller exists
+		ApplicationTransferNotFound,
+
+		/// An application cannot be transferred to its current provider
+		InvalidApplicationTransferRecipient,
 	}
 
 	impl<T: Config> BlockNumberProvider for Pallet<T> {
@@ -1489,6 +1621,10 @@ pub mod pallet {
 		/// * [`Error::ProviderNotRegistered`] - If the provider is not registered.
 		/// * [`Error::InvalidCid`] - If the provided CID is invalid and not in approved logos.
 		/// * [`Error::LogoCidNotApproved`] - If the logo CID is not in the approved logos list.
+		/// * [`Error::InvalidLogoBytes`] - If the logo bytes do not match the approved CID.
+		/// * [`Error::UnsupportedLogoFormat`] - If the logo is not a PNG, JPEG or WebP image.
+		/// * [`Error::InvalidLogoImage`] - If the logo image header is malformed.
+		/// * [`Error::InvalidLogoDimensions`] - If the logo dimensions are outside of the allowed bounds.
 		///
 		#[pallet::call_index(23)]
 		#[pallet::weight(T::WeightInfo::upload_logo())]
@@ -1509,7 +1645,9 @@ pub mod pallet {
 			let computed_cid_binary =
 				compute_cid_v1(logo_bytes.as_slice()).ok_or(Error::<T>::InvalidLogoBytes)?;
 			ensure!(input_cid_binary == computed_cid_binary, Error::<T>::InvalidLogoBytes);
+			let metadata = Self::ensure_valid_logo_image(&logo_bytes)?;
 			ApprovedLogos::<T>::insert(&logo_cid, logo_bytes);
+			ApprovedLogoMetadata::<T>::insert(&logo_cid, metadata);
 
 			Self::deposit_event(Event::ApplicationContextUpdated {
 				provider_id: ProviderId(provider_msa_id),
@@ -1740,6 +1878,274 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Adds a P256 passkey to an MSA as a first-class key.
+		///
+		/// The passkey controls the account id derived by [`PasskeyPublicKey::to_account_id32`],
+		/// which is registered like any other MSA key. Transactions for this account can be submitted
+		/// through `pallet_passkey` using only the passkey signature, and the passkey can be removed
+		/// with `delete_msa_public_key` using the same account id.
+		///
+		/// # Requirements
+		/// * The `add_passkey_payload` must be signed by an MSA control key of `msa_id` in `msa_owner_proof`.
+		/// * The SCALE encoded `add_passkey_payload` must be signed by the new passkey in `new_passkey_proof`.
+		///
+		/// # Events
+		/// * [`Event::PasskeyAdded`]
+		///
+		/// # Errors
+		/// * [`Error::MsaOwnershipInvalidSignature`] - `msa_owner_public_key` is not a valid signer of the provided `add_passkey_payload`.
+		/// * [`Error::NewKeyOwnershipInvalidSignature`] - the passkey is not a valid signer of the provided `add_passkey_payload`.
+		/// * [`Error::InvalidPasskeyPublicKey`] - the passkey account id could not be derived.
+		/// * [`Error::NoKeyExists`] - the MSA id for the account in `add_passkey_payload` does not exist.
+		/// * [`Error::NotMsaOwner`] - `msa_owner_public_key` does not belong to the `add_passkey_payload` MSA.
+		/// * [`Error::KeyAlreadyRegistered`] - the passkey is already registered to an MSA.
+		/// * [`Error::KeyLimitExceeded`] - the MSA has reached the maximum number of keys.
+		/// * [`Error::ProofHasExpired`] - the current block is less than the `expired` block number set in `AddPasskeyData`.
+		/// * [`Error::ProofNotYetValid`] - the `expired` block number set in `AddPasskeyData` is greater than the current block number plus mortality_block_limit().
+		/// * [`Error::SignatureAlreadySubmitted`] - signature has already been used.
+		///
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::add_passkey_to_msa())]
+		pub fn add_passkey_to_msa(
+			origin: OriginFor<T>,
+			msa_owner_public_key: T::AccountId,
+			msa_owner_proof: MultiSignature,
+			new_passkey_proof: VerifiablePasskeySignature,
+			add_passkey_payload: AddPasskeyData<T>,
+		) -> DispatchResult {
+			let _ = ensure_signed(origin)?;
+
+			ensure!(
+				Self::verify_signature(
+					&msa_owner_proof,
+					&msa_owner_public_key,
+					&add_passkey_payload
+				),
+				Error::<T>::MsaOwnershipInvalidSignature
+			);
+
+			let passkey_public_key = add_passkey_payload.new_passkey_public_key.clone();
+			new_passkey_proof
+				.try_verify(&add_passkey_payload.encode(), &passkey_public_key)
+				.map_err(|_| Error::<T>::NewKeyOwnershipInvalidSignature)?;
+
+			Self::register_signature(&msa_owner_proof, add_passkey_payload.expiration)?;
+
+			let msa_id = add_passkey_payload.msa_id;
+
+			Self::ensure_msa_owner(&msa_owner_public_key, msa_id)?;
+
+			let passkey_account = Self::passkey_account_id(&passkey_public_key)?;
+			Self::add_key(msa_id, &passkey_account)?;
+
+			let indexed_event = Event::PublicKeyAdded { msa_id, key: passkey_account.clone() };
+			Self::add_event_to_offchain_index(Some(&indexed_event), msa_id);
+			Self::deposit_event(Event::PasskeyAdded {
+				msa_id,
+				key: passkey_account,
+				passkey_public_key,
+			});
+
+			Ok(())
+		}
+
+		/// Retires an application of the calling provider and removes it from the registry along
+		/// with its approved logos. The application index is never reused.
+		///
+		/// # Events
+		/// * [`Event::ApplicationRetired`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - If there is not MSA for `origin`.
+		/// * [`Error::ProviderNotRegistered`] - If the provider is not registered.
+		/// * [`Error::ApplicationNotFound`] - If the application is not registered.
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::retire_application())]
+		#[allow(clippy::useless_conversion)]
+		pub fn retire_application(
+			origin: OriginFor<T>,
+			application_index: ApplicationIndex,
+		) -> DispatchResultWithPostInfo {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			ensure!(
+				Self::is_registered_provider(provider_msa_id),
+				Error::<T>::ProviderNotRegistered
+			);
+			let provider_id = ProviderId(provider_msa_id);
+			let total_logos_removed = Self::retire_application_for(provider_id, application_index)?;
+			let event =
+				Event::ApplicationRetired { provider_id, application_id: application_index };
+			Self::add_event_to_offchain_index(Some(&event), provider_id.into());
+			Self::deposit_event(event);
+			Self::refund_logo_removal_weight_by_count(
+				total_logos_removed,
+				T::WeightInfo::retire_application(),
+			)
+		}
+
+		/// Suspends an application by means of governance approval.
+		/// A suspended application is reported as such by `get_provider_application_context`
+		/// and cannot be transferred until it is resumed.
+		///
+		/// # Events
+		/// * [`Event::ApplicationSuspended`]
+		///
+		/// # Errors
+		/// * [`Error::ApplicationNotFound`] - If the application is not registered.
+		/// * [`Error::ApplicationSuspended`] - If the application is already suspended.
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::suspend_application_via_governance())]
+		pub fn suspend_application_via_governance(
+			origin: OriginFor<T>,
+			provider_id: ProviderId,
+			application_index: ApplicationIndex,
+		) -> DispatchResult {
+			T::CreateProviderViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				ProviderToApplicationRegistry::<T>::contains_key(provider_id, application_index),
+				Error::<T>::ApplicationNotFound
+			);
+			ensure!(
+				!SuspendedApplications::<T>::contains_key(provider_id, application_index),
+				Error::<T>::ApplicationSuspended
+			);
+			SuspendedApplications::<T>::insert(
+				provider_id,
+				application_index,
+				frame_system::Pallet::<T>::block_number(),
+			);
+			PendingApplicationTransfers::<T>::remove(provider_id, application_index);
+			let event =
+				Event::ApplicationSuspended { provider_id, application_id: application_index };
+			Self::add_event_to_offchain_index(Some(&event), provider_id.into());
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		/// Reinstates a suspended application by means of governance approval.
+		///
+		/// # Events
+		/// * [`Event::ApplicationResumed`]
+		///
+		/// # Errors
+		/// * [`Error::ApplicationNotSuspended`] - If the application is not suspended.
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::resume_application_via_governance())]
+		pub fn resume_application_via_governance(
+			origin: OriginFor<T>,
+			provider_id: ProviderId,
+			application_index: ApplicationIndex,
+		) -> DispatchResult {
+			T::CreateProviderViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(
+				SuspendedApplications::<T>::take(provider_id, application_index).is_some(),
+				Error::<T>::ApplicationNotSuspended
+			);
+			let event =
+				Event::ApplicationResumed { provider_id, application_id: application_index };
+			Self::add_event_to_offchain_index(Some(&event), provider_id.into());
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		/// Offers to transfer an application of the calling provider to another provider.
+		/// The transfer only takes effect once the receiving provider calls `accept_application_transfer`.
+		/// A new offer for the same application replaces the previous one.
+		///
+		/// # Events
+		/// * [`Event::ApplicationTransferOffered`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - If there is not MSA for `origin`.
+		/// * [`Error::ProviderNotRegistered`] - If either provider is not registered.
+		/// * [`Error::ApplicationNotFound`] - If the application is not registered.
+		/// * [`Error::ApplicationSuspended`] - If the application is suspended.
+		/// * [`Error::InvalidApplicationTransferRecipient`] - If the application is offered to its current provider.
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::offer_application_transfer())]
+		pub fn offer_application_transfer(
+			origin: OriginFor<T>,
+			application_index: ApplicationIndex,
+			new_provider_id: ProviderId,
+		) -> DispatchResult {
+			let provider_key = ensure_signed(origin)?;
+			let provider_msa_id = Self::ensure_valid_msa_key(&provider_key)?;
+			ensure!(
+				Self::is_registered_provider(provider_msa_id),
+				Error::<T>::ProviderNotRegistered
+			);
+			let provider_id = ProviderId(provider_msa_id);
+			ensure!(
+				new_provider_id != provider_id,
+				Error::<T>::InvalidApplicationTransferRecipient
+			);
+			ensure!(
+				Self::is_registered_provider(new_provider_id.into()),
+				Error::<T>::ProviderNotRegistered
+			);
+			Self::ensure_active_application(provider_id, application_index)?;
+			PendingApplicationTransfers::<T>::insert(
+				provider_id,
+				application_index,
+				new_provider_id,
+			);
+			let event = Event::ApplicationTransferOffered {
+				provider_id,
+				application_id: application_index,
+				new_provider_id,
+			};
+			Self::add_event_to_offchain_index(Some(&event), provider_id.into());
+			Self::deposit_event(event);
+			Ok(())
+		}
+
+		/// Accepts an application transfer offered to the calling provider. The application is
+		/// moved to the next application index of the calling provider.
+		///
+		/// # Events
+		/// * [`Event::ApplicationTransferred`]
+		///
+		/// # Errors
+		/// * [`Error::NoKeyExists`] - If there is not MSA for `origin`.
+		/// * [`Error::ProviderNotRegistered`] - If the provider is not registered.
+		/// * [`Error::ApplicationTransferNotFound`] - If the application was not offered to the calling provider.
+		/// * [`Error::ApplicationNotFound`] - If the application is not registered.
+		/// * [`Error::ApplicationSuspended`] - If the application is suspended.
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::accept_application_transfer())]
+		pub fn accept_application_transfer(
+			origin: OriginFor<T>,
+			provider_id: ProviderId,
+			application_index: ApplicationIndex,
+		) -> DispatchResult {
+			let new_provider_key = ensure_signed(origin)?;
+			let new_provider_msa_id = Self::ensure_valid_msa_key(&new_provider_key)?;
+			ensure!(
+				Self::is_registered_provider(new_provider_msa_id),
+				Error::<T>::ProviderNotRegistered
+			);
+			let new_provider_id = ProviderId(new_provider_msa_id);
+			ensure!(
+				PendingApplicationTransfers::<T>::get(provider_id, application_index) ==
+					Some(new_provider_id),
+				Error::<T>::ApplicationTransferNotFound
+			);
+			Self::ensure_active_application(provider_id, application_index)?;
+			let new_application_id =
+				Self::transfer_application_for(provider_id, application_index, new_provider_id)?;
+			let event = Event::ApplicationTransferred {
+				provider_id,
+				application_id: application_index,
+				new_provider_id,
+				new_application_id,
+			};
+			Self::add_event_to_offchain_index(Some(&event), provider_id.into());
+			Self::add_event_to_offchain_index(Some(&event), new_provider_id.into());
+			Self::deposit_event(event);
+			Ok(())
+		}
 	}
 }
 
@@ -1981,6 +2387,19 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Returns the account id controlled by `passkey_public_key` once it is added to an MSA
+	///
+	/// # Errors
+	/// * [`Error::InvalidPasskeyPublicKey`]
+	///
+	pub fn passkey_account_id(
+		passkey_public_key: &PasskeyPublicKey,
+	) -> Result<T::AccountId, DispatchError> {
+		let account_id32 = passkey_public_key.to_account_id32();
+		T::AccountId::decode(&mut TrailingZeroInput::new(account_id32.as_ref()))
+			.map_err(|_| Error::<T>::InvalidPasskeyPublicKey.into())
+	}
+
 	/// Check that Intent ids are all valid
 	///
 	/// # Errors
@@ -2241,6 +2660,76 @@ impl<T: Config> Pallet<T> {
 		.map(|_| total_logos_removed)
 	}
 
+	/// Removes an application from `ProviderToApplicationRegistry` together with its approved logos,
+	/// suspension and pending transfer. Returns the number of logos removed.
+	///
+	/// # Errors
+	/// * [`Error::ApplicationNotFound`]
+	pub fn retire_application_for(
+		provider_msa_id: ProviderId,
+		application_index: ApplicationIndex,
+	) -> Result<u32, DispatchError> {
+		let application =
+			ProviderToApplicationRegistry::<T>::take(provider_msa_id, application_index)
+				.ok_or(Error::<T>::ApplicationNotFound)?;
+		let total_logos_removed = Self::remove_logo_storage(Some(&application))?;
+		SuspendedApplications::<T>::remove(provider_msa_id, application_index);
+		PendingApplicationTransfers::<T>::remove(provider_msa_id, application_index);
+		Ok(total_logos_removed)
+	}
+
+	/// Moves an application from one provider to the next application index of another provider
+	/// and clears the pending transfer. Returns the new application index.
+	///
+	/// # Errors
+	/// * [`Error::ApplicationNotFound`]
+	/// * [`Error::DuplicateApplicationRegistryEntry`]
+	pub fn transfer_application_for(
+		provider_msa_id: ProviderId,
+		application_index: ApplicationIndex,
+		new_provider_msa_id: ProviderId,
+	) -> Result<ApplicationIndex, DispatchError> {
+		let application =
+			ProviderToApplicationRegistry::<T>::take(provider_msa_id, application_index)
+				.ok_or(Error::<T>::ApplicationNotFound)?;
+		let new_application_index = NextApplicationIndex::<T>::get(new_provider_msa_id);
+		ensure!(
+			!ProviderToApplicationRegistry::<T>::contains_key(
+				new_provider_msa_id,
+				new_application_index
+			),
+			Error::<T>::DuplicateApplicationRegistryEntry
+		);
+		ProviderToApplicationRegistry::<T>::insert(
+			new_provider_msa_id,
+			new_application_index,
+			application,
+		);
+		NextApplicationIndex::<T>::insert(new_provider_msa_id, new_application_index + 1);
+		PendingApplicationTransfers::<T>::remove(provider_msa_id, application_index);
+		Ok(new_application_index)
+	}
+
+	/// Checks that the application exists and is not suspended
+	///
+	/// # Errors
+	/// * [`Error::ApplicationNotFound`]
+	/// * [`Error::ApplicationSuspended`]
+	fn ensure_active_application(
+		provider_msa_id: ProviderId,
+		application_index: ApplicationIndex,
+	) -> DispatchResult {
+		ensure!(
+			ProviderToApplicationRegistry::<T>::contains_key(provider_msa_id, application_index),
+			Error::<T>::ApplicationNotFound
+		);
+		ensure!(
+			!SuspendedApplications::<T>::contains_key(provider_msa_id, application_index),
+			Error::<T>::ApplicationSuspended
+		);
+		Ok(())
+	}
+
 	/// Mutates the delegation relationship storage item only when the supplied function returns an 'Ok()' result.
 	/// The callback function 'f' takes the value (a delegation) and a reference to a boolean variable. This callback
 	/// sets the boolean variable to 'true' if the value is to be inserted and to 'false' if it is to be updated.
@@ -2622,23 +3111,59 @@ impl<T: Config> Pallet<T> {
 	) -> DispatchResult {
 		// store default logo CID if any
 		if !payload.default_logo_250_100_png_cid.is_empty() {
-			ApprovedLogos::<T>::insert(
-				payload.default_logo_250_100_png_cid.clone(),
-				BoundedVec::new(),
-			);
+			Self::approve_logo(&payload.default_logo_250_100_png_cid);
 		}
 
 		// store localized logos CIDs if any
 		for (_, localized_cid) in &payload.localized_logo_250_100_png_cids {
 			if !localized_cid.is_empty() {
-				ApprovedLogos::<T>::insert(localized_cid, BoundedVec::new());
+				Self::approve_logo(localized_cid);
 			}
 		}
 
 		Ok(())
 	}
 
-	/// Remove default logo and localized logos from storage `ApprovedLogos`
+	/// Adds a reference to a logo, approving it for upload if nothing referenced it yet
+	fn approve_logo(logo_cid: &LogoCid<T>) {
+		let references = ApprovedLogoReferences::<T>::get(logo_cid);
+		if references == 0 {
+			ApprovedLogos::<T>::insert(logo_cid, BoundedVec::new());
+			ApprovedLogoMetadata::<T>::remove(logo_cid);
+		}
+		ApprovedLogoReferences::<T>::insert(logo_cid, references.saturating_add(1));
+	}
+
+	/// Drops a reference to a logo and removes it once nothing references it
+	fn release_logo(logo_cid: &LogoCid<T>) {
+		let references = ApprovedLogoReferences::<T>::get(logo_cid);
+		if references > 1 {
+			ApprovedLogoReferences::<T>::insert(logo_cid, references - 1);
+			return
+		}
+		// logos approved before references were counted have no entry and are removed
+		ApprovedLogoReferences::<T>::remove(logo_cid);
+		ApprovedLogos::<T>::remove(logo_cid);
+		ApprovedLogoMetadata::<T>::remove(logo_cid);
+	}
+
+	/// Reads the logo image header and checks its dimensions against the configured bounds
+	fn ensure_valid_logo_image(logo_bytes: &[u8]) -> Result<ImageMetadata, DispatchError> {
+		let metadata = parse_image_metadata(logo_bytes).map_err(|e| match e {
+			ImageError::SvgNotSupported | ImageError::UnsupportedFormat =>
+				Error::<T>::UnsupportedLogoFormat,
+			ImageError::MalformedHeader => Error::<T>::InvalidLogoImage,
+		})?;
+		let bounds = T::MinLogoDimension::get()..=T::MaxLogoDimension::get();
+		ensure!(
+			bounds.contains(&metadata.width) && bounds.contains(&metadata.height),
+			Error::<T>::InvalidLogoDimensions
+		);
+		Ok(metadata)
+	}
+
+	/// Remove default logo and localized logos from storage `ApprovedLogos` unless another
+	/// registry entry still references them
 	fn remove_logo_storage(
 		existing_payload: Option<
 			&ProviderRegistryEntry<
@@ -2654,13 +3179,13 @@ impl<T: Config> Pallet<T> {
 			// remove default logo CID if any
 			if !payload.default_logo_250_100_png_cid.is_empty() {
 				total_logo_removed += 1;
-				ApprovedLogos::<T>::remove(&payload.default_logo_250_100_png_cid);
+				Self::release_logo(&payload.default_logo_250_100_png_cid);
 			}
 			// remove localized logos CIDs if any
 			for (_, localized_cid) in &payload.localized_logo_250_100_png_cids {
 				if !localized_cid.is_empty() {
 					total_logo_removed += 1;
-					ApprovedLogos::<T>::remove(localized_cid);
+					Self::release_logo(localized_cid);
 				}
 			}
 		}
@@ -2735,6 +3260,17 @@ impl<T: Config> Pallet<T> {
 		application_id: Option<ApplicationIndex>,
 		locale: Option<Vec<u8>>,
 	) -> Option<ProviderApplicationContext> {
+		Self::get_provider_application_context_v2(provider_id, application_id, locale)
+			.map(|context| context.into())
+	}
+
+	/// Retrieves the provider or application context including logos with their format and
+	/// dimensions, localized name if any and whether the application is suspended
+	pub fn get_provider_application_context_v2(
+		provider_id: ProviderId,
+		application_id: Option<ApplicationIndex>,
+		locale: Option<Vec<u8>>,
+	) -> Option<ProviderApplicationContextV2> {
 		let bounded_locale = locale.and_then(|loc| BoundedVec::try_from(loc).ok());
 		let provider_or_application_registry = match application_id {
 			Some(app_id) => ProviderToApplicationRegistry::<T>::get(provider_id, app_id)?,
@@ -2744,8 +3280,10 @@ impl<T: Config> Pallet<T> {
 		let default_logo_cid = provider_or_application_registry.default_logo_250_100_png_cid;
 		// Default logo bytes
 		let default_logo_250_100_png_bytes: Option<Vec<u8>> =
-			ApprovedLogos::<T>::get(default_logo_cid).map(|bv| bv.to_vec());
+			ApprovedLogos::<T>::get(&default_logo_cid).map(|bv| bv.to_vec());
+		let default_logo_metadata = ApprovedLogoMetadata::<T>::get(&default_logo_cid);
 		let mut localized_name: Option<Vec<u8>> = None;
+		let mut localized_logo_metadata: Option<ImageMetadata> = None;
 		// Localized logo bytes if any
 		let localized_logo_250_100_png_bytes: Option<Vec<u8>> = bounded_locale.and_then(|locale| {
 			// Localized name if any
@@ -2754,19 +3292,158 @@ impl<T: Config> Pallet<T> {
 				.get(&locale)
 				.map(|bv| bv.to_vec());
 
-			provider_or_application_registry
-				.localized_logo_250_100_png_cids
-				.get(&locale)
-				.and_then(|cid| ApprovedLogos::<T>::get(cid).map(|bv| bv.to_vec()))
+			let localized_cid =
+				provider_or_application_registry.localized_logo_250_100_png_cids.get(&locale)?;
+			localized_logo_metadata = ApprovedLogoMetadata::<T>::get(localized_cid);
+			ApprovedLogos::<T>::get(localized_cid).map(|bv| bv.to_vec())
 		});
 
-		Some(ProviderApplicationContext {
+		let is_suspended = application_id
+			.is_some_and(|app_id| SuspendedApplications::<T>::contains_key(provider_id, app_id));
+
+		Some(ProviderApplicationContextV2 {
 			default_name,
 			provider_id,
 			application_id,
 			default_logo_250_100_png_bytes,
 			localized_logo_250_100_png_bytes,
 			localized_name,
+			default_logo_metadata,
+			localized_logo_metadata,
+			is_suspended,
+		})
+	}
+
+	/// Retrieves a page of the provider and application directory.
+	/// Providers are examined in storage order starting after `request.start_after`, each provider
+	/// is followed by its applications in index order. Every provider and application index counts
+	/// towards the page size, including retired applications, so a page can end part way through
+	/// the applications of a provider. Only logo CIDs are returned, use
+	/// [`Self::get_approved_logo`] to fetch the logo bytes.
+	pub fn get_provider_directory(request: ProviderDirectoryRequest) -> ProviderDirectoryResponse {
+		let mut response = ProviderDirectoryResponse::default();
+		if !request.validate() {
+			return response;
+		}
+		let bounded_locale: Option<BoundedVec<u8, T::MaxLanguageCodeSize>> = match request.locale {
+			Some(locale) => match BoundedVec::try_from(locale) {
+				Ok(bounded) => Some(bounded),
+				// no entry can have a localized name for an invalid locale
+				Err(_) => return response,
+			},
+			None => None,
+		};
+
+		let mut providers = match request.start_after {
+			Some(cursor) => ProviderToRegistryEntryV2::<T>::iter_from(
+				ProviderToRegistryEntryV2::<T>::hashed_key_for(cursor.provider_id),
+			),
+			None => ProviderToRegistryEntryV2::<T>::iter(),
+		};
+		// the provider whose applications are examined next, and the first index to examine
+		let mut pending_applications = request.start_after.map(|cursor| {
+			(cursor.provider_id, cursor.application_id.map_or(0, |id| id.saturating_add(1)))
+		});
+		let mut last_examined = request.start_after;
+		let mut examined = 0u32;
+
+		loop {
+			if let Some((provider_id, first_application_id)) = pending_applications.take() {
+				for application_id in
+					first_application_id..NextApplicationIndex::<T>::get(provider_id)
+				{
+					if examined == request.page_size {
+						response.next_start_after = last_examined;
+						return response;
+					}
+					examined += 1;
+					last_examined = Some(ProviderDirectoryCursor {
+						provider_id,
+						application_id: Some(application_id),
+					});
+					if let Some(application) =
+						ProviderToApplicationRegistry::<T>::get(provider_id, application_id)
+					{
+						response.entries.extend(Self::to_provider_directory_entry(
+							provider_id,
+							Some(application_id),
+							&application,
+							bounded_locale.as_ref(),
+							request.approved_logo_only,
+						));
+					}
+				}
+			}
+
+			let Some((provider_id, provider_entry)) = providers.next() else { break };
+			if examined == request.page_size {
+				response.next_start_after = last_examined;
+				break;
+			}
+			examined += 1;
+			last_examined = Some(ProviderDirectoryCursor { provider_id, application_id: None });
+			response.entries.extend(Self::to_provider_directory_entry(
+				provider_id,
+				None,
+				&provider_entry,
+				bounded_locale.as_ref(),
+				request.approved_logo_only,
+			));
+			pending_applications = Some((provider_id, 0));
+		}
+		response
+	}
+
+	/// Retrieves the uploaded bytes of an approved logo by its CID
+	pub fn get_approved_logo(logo_cid: Vec<u8>) -> Option<Vec<u8>> {
+		let bounded_cid: LogoCid<T> = BoundedVec::try_from(logo_cid).ok()?;
+		ApprovedLogos::<T>::get(bounded_cid)
+			.filter(|logo_bytes| !logo_bytes.is_empty())
+			.map(|logo_bytes| logo_bytes.into_inner())
+	}
+
+	/// Maps a provider or application registry entry to a directory entry
+	/// Returns `None` if the entry does not match the locale or approved logo filters
+	fn to_provider_directory_entry(
+		provider_id: ProviderId,
+		application_id: Option<ApplicationIndex>,
+		entry: &ProviderRegistryEntry<
+			T::MaxProviderNameSize,
+			T::MaxLanguageCodeSize,
+			T::MaxLogoCidSize,
+			T::MaxLocaleCount,
+		>,
+		locale: Option<&BoundedVec<u8, T::MaxLanguageCodeSize>>,
+		approved_logo_only: bool,
+	) -> Option<ProviderDirectoryEntry> {
+		let localized_name = match locale {
+			Some(locale) => Some(entry.localized_names.get(locale)?.to_vec()),
+			None => None,
+		};
+		let approved_cid = |cid: &LogoCid<T>| {
+			(!cid.is_empty() && ApprovedLogos::<T>::contains_key(cid)).then(|| cid.to_vec())
+		};
+		let default_logo_250_100_png_cid = approved_cid(&entry.default_logo_250_100_png_cid);
+		let localized_logo_250_100_png_cid = locale
+			.and_then(|locale| entry.localized_logo_250_100_png_cids.get(locale))
+			.and_then(approved_cid);
+		if approved_logo_only &&
+			default_logo_250_100_png_cid.is_none() &&
+			localized_logo_250_100_png_cid.is_none()
+		{
+			return None;
+		}
+		let is_suspended = application_id
+			.is_some_and(|app_id| SuspendedApplications::<T>::contains_key(provider_id, app_id));
+
+		Some(ProviderDirectoryEntry {
+			provider_id,
+			application_id,
+			default_name: entry.default_name.to_vec(),
+			localized_name,
+			default_logo_250_100_png_cid,
+			localized_logo_250_100_png_cid,
+			is_suspended,
 		})
 	}
 
@@ -1,9 +1,12 @@
 use crate::{pallet::OffchainIndexEventCount, Config, Event, Pallet, PublicKeyToMsaId};
 pub use common_primitives::msa::MessageSourceId;
 /// Offchain Storage for MSA
-use common_primitives::offchain::{
-	self as offchain_common, get_msa_account_lock_name, get_msa_account_storage_key_name,
-	LockStatus, MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS,
+use common_primitives::{
+	msa::{ApplicationIndex, ProviderId},
+	offchain::{
+		self as offchain_common, get_msa_account_lock_name, get_msa_account_storage_key_name,
+		LockStatus, MSA_ACCOUNT_LOCK_TIMEOUT_EXPIRATION_MS,
+	},
 };
 use frame_support::{RuntimeDebugNoBound, Twox128};
 use frame_system::pallet_prelude::BlockNumberFor;
@@ -263,6 +266,47 @@ pub enum IndexedEvent<T: Config> {
 		/// The MSA for the Event
 		msa_id: MessageSourceId,
 	},
+	/// An application was retired by its provider
+	IndexedApplicationRetired {
+		/// The provider of the application
+		provider_id: ProviderId,
+		/// The retired application
+		application_id: ApplicationIndex,
+	},
+	/// An application was suspended via governance
+	IndexedApplicationSuspended {
+		/// The provider of the application
+		provider_id: ProviderId,
+		/// The suspended application
+		application_id: ApplicationIndex,
+	},
+	/// A suspended application was resumed via governance
+	IndexedApplicationResumed {
+		/// The provider of the application
+		provider_id: ProviderId,
+		/// The resumed application
+		application_id: ApplicationIndex,
+	},
+	/// An application was offered for transfer to another provider
+	IndexedApplicationTransferOffered {
+		/// The provider offering the application
+		provider_id: ProviderId,
+		/// The offered application
+		application_id: ApplicationIndex,
+		/// The provider the application is offered to
+		new_provider_id: ProviderId,
+	},
+	/// An application was transferred to another provider
+	IndexedApplicationTransferred {
+		/// The previous provider of the application
+		provider_id: ProviderId,
+		/// The application index under the previous provider
+		application_id: ApplicationIndex,
+		/// The new provider of the application
+		new_provider_id: ProviderId,
+		/// The application index under the new provider
+		new_application_id: ApplicationIndex,
+	},
 }
 
 impl<T: Config> IndexedEvent<T> {
@@ -275,6 +319,41 @@ impl<T: Config> IndexedEvent<T> {
 				Some(Self::IndexedPublicKeyAdded { msa_id: *msa_id, key: key.clone() }),
 			Some(Event::PublicKeyDeleted { key }) =>
 				Some(Self::IndexedPublicKeyDeleted { msa_id: event_msa_id, key: key.clone() }),
+			Some(Event::ApplicationRetired { provider_id, application_id }) =>
+				Some(Self::IndexedApplicationRetired {
+					provider_id: *provider_id,
+					application_id: *application_id,
+				}),
+			Some(Event::ApplicationSuspended { provider_id, application_id }) =>
+				Some(Self::IndexedApplicationSuspended {
+					provider_id: *provider_id,
+					application_id: *application_id,
+				}),
+			Some(Event::ApplicationResumed { provider_id, application_id }) =>
+				Some(Self::IndexedApplicationResumed {
+					provider_id: *provider_id,
+					application_id: *application_id,
+				}),
+			Some(Event::ApplicationTransferOffered {
+				provider_id,
+				application_id,
+				new_provider_id,
+			}) => Some(Self::IndexedApplicationTransferOffered {
+				provider_id: *provider_id,
+				application_id: *application_id,
+				new_provider_id: *new_provider_id,
+			}),
+			Some(Event::ApplicationTransferred {
+				provider_id,
+				application_id,
+				new_provider_id,
+				new_application_id,
+			}) => Some(Self::IndexedApplicationTransferred {
+				provider_id: *provider_id,
+				application_id: *application_id,
+				new_provider_id: *new_provider_id,
+				new_application_id: *new_application_id,
+			}),
 			None => Some(Self::MsaIndexInvalidated { msa_id: event_msa_id }),
 			_ => None,
 		}
@@ -359,6 +438,12 @@ fn reverse_map_msa_keys<T: Config>(block_number: BlockNumberFor<T>) -> bool {
 				let events = events_by_msa_id.entry(*msa_id).or_default();
 				events.push(event.clone());
 			},
+			// application lifecycle events do not change the keys of an MSA
+			IndexedEvent::IndexedApplicationRetired { .. } |
+			IndexedEvent::IndexedApplicationSuspended { .. } |
+			IndexedEvent::IndexedApplicationResumed { .. } |
+			IndexedEvent::IndexedApplicationTransferOffered { .. } |
+			IndexedEvent::IndexedApplicationTransferred { .. } => {},
 		}
 	}
 
@@ -418,6 +503,12 @@ fn process_offchain_events<T: Config>(msa_id: MessageSourceId, events: Vec<Index
 			IndexedEvent::MsaIndexInvalidated { .. } => {
 				// nothing to do since we take care of removing extra keys for all events anyway
 			},
+			// application lifecycle events are never grouped by MSA in `reverse_map_msa_keys`
+			IndexedEvent::IndexedApplicationRetired { .. } |
+			IndexedEvent::IndexedApplicationSuspended { .. } |
+			IndexedEvent::IndexedApplicationResumed { .. } |
+			IndexedEvent::IndexedApplicationTransferOffered { .. } |
+			IndexedEvent::IndexedApplicationTransferred { .. } => {},
 		}
 	}
 
@@ -13,7 +13,7 @@ use common_helpers::rpc::map_rpc_result;
 use common_primitives::{
 	msa::{
 		DelegationGrant, DelegationResponse, DelegatorId, KeyInfoResponse, MessageSourceId,
-		ProviderId,
+		ProviderDirectoryRequest, ProviderDirectoryResponse, ProviderId,
 	},
 	node::BlockNumber,
 	offchain::get_msa_account_storage_key_name,
@@ -102,6 +102,17 @@ pub trait MsaApi<BlockHash, AccountId> {
 		public_keys: Vec<AccountId>,
 		signature_hex: String,
 	) -> RpcResult<()>;
+
+	/// Retrieve a page of registered providers and their applications
+	#[method(name = "msa_getProviderDirectory")]
+	fn get_provider_directory(
+		&self,
+		request: ProviderDirectoryRequest,
+	) -> RpcResult<ProviderDirectoryResponse>;
+
+	/// Retrieve the uploaded bytes of an approved logo by its CID
+	#[method(name = "msa_getApprovedLogo")]
+	fn get_approved_logo(&self, logo_cid: String) -> RpcResult<Option<Vec<u8>>>;
 }
 
 /// The client handler for the API used by Frequency Service RPC with `jsonrpsee`
@@ -132,6 +143,8 @@ pub enum MsaOffchainRpcError {
 	OffchainIndexingNotEnabled,
 	/// Invalid signature
 	InvalidSignature,
+	/// Invalid pagination request
+	InvalidPaginationRequest,
 }
 
 impl From<MsaOffchainRpcError> for ErrorObjectOwned {
@@ -144,6 +157,7 @@ impl From<MsaOffchainRpcError> for ErrorObjectOwned {
 			MsaOffchainRpcError::OffchainIndexingNotEnabled =>
 				ErrorObject::owned(3, msg, None::<()>),
 			MsaOffchainRpcError::InvalidSignature => ErrorObject::owned(4, msg, None::<()>),
+			MsaOffchainRpcError::InvalidPaginationRequest => ErrorObject::owned(5, msg, None::<()>),
 		}
 	}
 }
@@ -269,4 +283,24 @@ where
 
 		Err(MsaOffchainRpcError::InvalidSignature.into())
 	}
+
+	fn get_provider_directory(
+		&self,
+		request: ProviderDirectoryRequest,
+	) -> RpcResult<ProviderDirectoryResponse> {
+		if !request.validate() {
+			return Err(MsaOffchainRpcError::InvalidPaginationRequest.into())
+		}
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		let runtime_api_result = api.get_provider_directory(at, request);
+		map_rpc_result(runtime_api_result)
+	}
+
+	fn get_approved_logo(&self, logo_cid: String) -> RpcResult<Option<Vec<u8>>> {
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		let runtime_api_result = api.get_approved_logo(at, logo_cid.into_bytes());
+		map_rpc_result(runtime_api_result)
+	}
 }
@@ -3,7 +3,10 @@ mod rpc_mock;
 use super::*;
 use rpc_mock::*;
 
-use common_primitives::node::{AccountId, Block, BlockNumber};
+use common_primitives::{
+	msa::{ProviderDirectoryCursor, ProviderDirectoryEntry},
+	node::{AccountId, Block, BlockNumber},
+};
 use pallet_msa_runtime_api::MsaRuntimeApi;
 use parity_scale_codec::Encode;
 use sp_core::offchain::{testing::TestPersistentOffchainDB, OffchainStorage};
@@ -19,6 +22,7 @@ const SCHEMA_FOR_A: u16 = 1;
 const SCHEMA_FOR_A_AND_B: u16 = 2;
 const SCHEMA_FOR_B: u16 = 3;
 const NOT_EXIST_MSA: u64 = 100;
+const LOGO_CID: &str = "bafkreidgvpkjawlxz6sffxzwgooowe5yt7i6wsyg236mfoks77nywkptdq";
 
 sp_api::mock_impl_runtime_apis! {
 	impl MsaRuntimeApi<Block, AccountId> for TestRuntimeApi {
@@ -57,6 +61,30 @@ sp_api::mock_impl_runtime_apis! {
 				_ => vec![],
 			}
 		}
+
+		/// Get a page of the provider directory
+		fn get_provider_directory(request: ProviderDirectoryRequest) -> ProviderDirectoryResponse {
+			match request.start_after {
+				None => ProviderDirectoryResponse {
+					entries: vec![ProviderDirectoryEntry {
+						provider_id: PROVIDER_WITH_DELEGATE_A,
+						default_name: b"Provider A".to_vec(),
+						default_logo_250_100_png_cid: Some(LOGO_CID.as_bytes().to_vec()),
+						..Default::default()
+					}],
+					next_start_after: Some(ProviderDirectoryCursor {
+						provider_id: PROVIDER_WITH_DELEGATE_A,
+						application_id: None,
+					}),
+				},
+				Some(_) => ProviderDirectoryResponse::default(),
+			}
+		}
+
+		/// Get the bytes of an approved logo
+		fn get_approved_logo(logo_cid: Vec<u8>) -> Option<Vec<u8>> {
+			(logo_cid == LOGO_CID.as_bytes()).then(|| vec![1, 2, 3])
+		}
 	}
 }
 
@@ -286,3 +314,47 @@ async fn get_keys_by_msa_id_with_value_should_work() {
 	assert!(response.is_some());
 	assert_eq!(KeyInfoResponse { msa_id, msa_keys: accounts }, response.unwrap());
 }
+
+#[tokio::test]
+async fn get_provider_directory_should_work() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api
+		.get_provider_directory(ProviderDirectoryRequest { page_size: 10, ..Default::default() });
+
+	assert!(result.is_ok());
+	let response = result.unwrap();
+	assert_eq!(1, response.entries.len());
+	assert_eq!(PROVIDER_WITH_DELEGATE_A, response.entries[0].provider_id);
+	assert_eq!(
+		Some(ProviderDirectoryCursor {
+			provider_id: PROVIDER_WITH_DELEGATE_A,
+			application_id: None
+		}),
+		response.next_start_after
+	);
+}
+
+#[tokio::test]
+async fn get_provider_directory_with_invalid_page_size_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	let result = api.get_provider_directory(ProviderDirectoryRequest {
+		page_size: ProviderDirectoryRequest::MAX_PAGE_SIZE + 1,
+		..Default::default()
+	});
+
+	assert!(result.is_err());
+	assert_eq!("InvalidPaginationRequest", result.unwrap_err().message());
+}
+
+#[tokio::test]
+async fn get_approved_logo_should_work() {
+	let client = Arc::new(TestApi {});
+	let api = MsaHandler::<TestApi, Block, TestPersistentOffchainDB>::new(client, None);
+
+	assert_eq!(Some(vec![1, 2, 3]), api.get_approved_logo(LOGO_CID.to_string()).unwrap());
+	assert_eq!(None, api.get_approved_logo("unknown".to_string()).unwrap());
+}
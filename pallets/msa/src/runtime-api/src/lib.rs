@@ -63,5 +63,18 @@ sp_api::decl_runtime_apis! {
 		/// Get the provider application context for a given provider and application
 		#[api_version(4)]
 		fn get_provider_application_context(provider_id: ProviderId, application_id: Option<ApplicationIndex>, locale: Option<Vec<u8>>) -> Option<ProviderApplicationContext>;
+
+		/// Get a page of registered providers and their applications with logo CIDs
+		#[api_version(5)]
+		fn get_provider_directory(request: ProviderDirectoryRequest) -> ProviderDirectoryResponse;
+
+		/// Get the uploaded bytes of an approved logo by its CID
+		#[api_version(5)]
+		fn get_approved_logo(logo_cid: Vec<u8>) -> Option<Vec<u8>>;
+
+		/// Get the provider application context for a given provider and application including
+		/// the logo formats and whether the application is suspended
+		#[api_version(6)]
+		fn get_provider_application_context_v2(provider_id: ProviderId, application_id: Option<ApplicationIndex>, locale: Option<Vec<u8>>) -> Option<ProviderApplicationContextV2>;
 	}
 }
@@ -0,0 +1,71 @@
+///
+/// Passkey constants used both in benchmarks and tests
+///
+#[allow(unused)]
+pub mod constants {
+	/// client data json in base64-url format, the challenged is replaced with `#rplc#`
+	pub const REPLACED_CLIENT_DATA_JSON: &str = "eyJ0eXBlIjoid2ViYXV0aG4uZ2V0IiwiY2hhbGxlbmdlIjoiI3JwbGMjIiwib3JpZ2luIjoiaHR0cHM6Ly9wYXNza2V5LmFtcGxpY2EuaW86ODA4MCIsImNyb3NzT3JpZ2luIjpmYWxzZSwiYWxnIjoiSFMyNTYifQ";
+	/// authenticator data in base64-url format
+	pub const AUTHENTICATOR_DATA: &str = "WJ8JTNbivTWn-433ubs148A7EgWowi4SAcYBjLWfo1EdAAAAAA";
+}
+
+/// Passkey utility functions to be used across tests and benchmarks
+#[allow(unused)]
+pub mod utilities {
+	use super::constants::{AUTHENTICATOR_DATA, REPLACED_CLIENT_DATA_JSON};
+	use common_primitives::passkey::{
+		PasskeyPublicKey, PasskeySignature, VerifiablePasskeySignature, CHALLENGE_PLACEHOLDER,
+	};
+	use p256::{
+		ecdsa::{signature::Signer, SigningKey},
+		elliptic_curve::sec1::ToEncodedPoint,
+	};
+	use sp_io::hashing::sha2_256;
+
+	/// a deterministic P256 secret key
+	pub fn get_p256_secret() -> p256::SecretKey {
+		p256::SecretKey::from_slice(&[
+			1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8,
+		])
+		.expect("valid secret")
+	}
+
+	/// get PasskeyPublicKey from Secret key
+	pub fn get_p256_public_key(secret: &p256::SecretKey) -> PasskeyPublicKey {
+		let encoded = secret.public_key().to_encoded_point(true);
+		encoded.try_into().expect("valid compressed point")
+	}
+
+	/// creates a self-contained passkey signature of `payload`
+	pub fn passkey_sign(secret: &p256::SecretKey, payload: &[u8]) -> VerifiablePasskeySignature {
+		let client_data_json =
+			base64_url::decode(REPLACED_CLIENT_DATA_JSON).expect("valid client data");
+		let authenticator_data =
+			base64_url::decode(AUTHENTICATOR_DATA).expect("valid authenticator data");
+
+		let signing_key: SigningKey = secret.into();
+		let calculated_challenge = sha2_256(payload);
+		let calculated_challenge_base64url = base64_url::encode(&calculated_challenge);
+
+		// inject challenge inside clientJsonData
+		let str_of_json = core::str::from_utf8(&client_data_json).expect("valid utf8");
+		let original_client_data_json =
+			str_of_json.replace(CHALLENGE_PLACEHOLDER, &calculated_challenge_base64url);
+
+		// prepare signing payload which is [authenticator || sha256(client_data_json)]
+		let mut passkey_signature_payload = authenticator_data.clone();
+		passkey_signature_payload
+			.extend_from_slice(&sha2_256(original_client_data_json.as_bytes()));
+
+		let (signature, _) = signing_key.try_sign(&passkey_signature_payload).expect("should sign");
+		let der_sig = p256::ecdsa::DerSignature::from(signature);
+		let signature: PasskeySignature =
+			der_sig.as_bytes().to_vec().try_into().expect("valid signature size");
+
+		VerifiablePasskeySignature {
+			signature,
+			authenticator_data: authenticator_data.try_into().expect("valid size"),
+			client_data_json: client_data_json.try_into().expect("valid size"),
+		}
+	}
+}
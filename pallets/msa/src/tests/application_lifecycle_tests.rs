@@ -0,0 +1,401 @@
+use common_primitives::msa::{ApplicationContext, ProviderId};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use frame_system::RawOrigin;
+use sp_core::Pair;
+use sp_runtime::DispatchError::BadOrigin;
+
+use pretty_assertions::assert_eq;
+
+use crate::{
+	tests::mock::*, types::compute_cid, ApprovedLogoMetadata, ApprovedLogoReferences,
+	ApprovedLogos, Error, Event, NextApplicationIndex, PendingApplicationTransfers,
+	ProviderToApplicationRegistry, SuspendedApplications,
+};
+
+fn create_application(provider_msa_id: u64, provider_key: sp_core::sr25519::Public) -> u16 {
+	let application_id = NextApplicationIndex::<Test>::get(ProviderId(provider_msa_id));
+	assert_ok!(Msa::create_application(
+		RuntimeOrigin::signed(provider_key.into()),
+		ApplicationContext::default()
+	));
+	application_id
+}
+
+#[test]
+fn retire_application_happy_path() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let logo_cid: BoundedVec<u8, _> =
+			BoundedVec::try_from(compute_cid(b"app_logo")).expect("Logo CID should fit in bounds");
+		let mut entry = ApplicationContext::default();
+		entry.default_logo_250_100_png_cid = logo_cid.clone();
+		assert_ok!(Msa::create_application(RuntimeOrigin::signed(provider_key.into()), entry));
+		assert!(ApprovedLogos::<Test>::contains_key(&logo_cid));
+
+		assert_ok!(Msa::retire_application(RuntimeOrigin::signed(provider_key.into()), 0));
+
+		System::assert_last_event(
+			Event::ApplicationRetired { provider_id, application_id: 0 }.into(),
+		);
+		assert!(ProviderToApplicationRegistry::<Test>::get(provider_id, 0).is_none());
+		assert!(!ApprovedLogos::<Test>::contains_key(&logo_cid));
+		assert!(Msa::get_provider_application_context(provider_id, Some(0), None).is_none());
+		// application indexes are not reused
+		assert_eq!(create_application(provider_msa_id, provider_key), 1);
+	})
+}
+
+#[test]
+fn retire_application_keeps_logo_referenced_by_another_application() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("AppProvider");
+		let logo_data = include_bytes!("../../../../e2e/msa/frequency.png");
+		let cid = common_primitives::cid::compute_cid_v1(logo_data).expect("Failed to compute CID");
+		let encoded = multibase::encode(multibase::Base::Base58Btc, cid);
+		let logo_cid: BoundedVec<u8, _> = BoundedVec::try_from(encoded.as_bytes().to_vec())
+			.expect("Logo CID should fit in bounds");
+		let logo_bytes: BoundedVec<u8, _> =
+			BoundedVec::try_from(logo_data.to_vec()).expect("Logo should fit in bounds");
+		let entry = ApplicationContext {
+			default_logo_250_100_png_cid: logo_cid.clone(),
+			..Default::default()
+		};
+		assert_ok!(Msa::create_application(
+			RuntimeOrigin::signed(provider_key.into()),
+			entry.clone()
+		));
+		assert_ok!(Msa::upload_logo(
+			RuntimeOrigin::signed(provider_key.into()),
+			logo_cid.clone(),
+			logo_bytes.clone()
+		));
+		// approving the logo for a second application keeps the uploaded bytes
+		assert_ok!(Msa::create_application(RuntimeOrigin::signed(provider_key.into()), entry));
+		assert_eq!(ApprovedLogos::<Test>::get(&logo_cid), Some(logo_bytes.clone()));
+		assert_eq!(ApprovedLogoReferences::<Test>::get(&logo_cid), 2);
+
+		assert_ok!(Msa::retire_application(RuntimeOrigin::signed(provider_key.into()), 0));
+
+		assert_eq!(ApprovedLogos::<Test>::get(&logo_cid), Some(logo_bytes));
+		assert!(ApprovedLogoMetadata::<Test>::contains_key(&logo_cid));
+		assert_eq!(ApprovedLogoReferences::<Test>::get(&logo_cid), 1);
+
+		assert_ok!(Msa::retire_application(RuntimeOrigin::signed(provider_key.into()), 1));
+
+		assert!(!ApprovedLogos::<Test>::contains_key(&logo_cid));
+		assert!(!ApprovedLogoMetadata::<Test>::contains_key(&logo_cid));
+		assert!(!ApprovedLogoReferences::<Test>::contains_key(&logo_cid));
+	})
+}
+
+#[test]
+fn retire_application_fails_for_unknown_application() {
+	new_test_ext().execute_with(|| {
+		let (_, provider_key) = create_provider_with_name("AppProvider");
+
+		assert_noop!(
+			Msa::retire_application(RuntimeOrigin::signed(provider_key.into()), 0),
+			Error::<Test>::ApplicationNotFound
+		);
+	})
+}
+
+#[test]
+fn retire_application_fails_for_non_provider() {
+	new_test_ext().execute_with(|| {
+		let (_, key_pair) = create_account();
+
+		assert_noop!(
+			Msa::retire_application(RuntimeOrigin::signed(key_pair.public().into()), 0),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn retire_application_clears_suspension_and_pending_transfer() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let (new_provider_msa_id, _) = create_provider_with_name("NewProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+		assert_ok!(Msa::offer_application_transfer(
+			RuntimeOrigin::signed(provider_key.into()),
+			application_id,
+			ProviderId(new_provider_msa_id)
+		));
+		SuspendedApplications::<Test>::insert(provider_id, application_id, 1);
+
+		assert_ok!(Msa::retire_application(
+			RuntimeOrigin::signed(provider_key.into()),
+			application_id
+		));
+
+		assert!(!SuspendedApplications::<Test>::contains_key(provider_id, application_id));
+		assert!(!PendingApplicationTransfers::<Test>::contains_key(provider_id, application_id));
+	})
+}
+
+#[test]
+fn suspend_application_via_governance_happy_path() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+
+		assert_ok!(Msa::suspend_application_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(1, 1)),
+			provider_id,
+			application_id
+		));
+
+		System::assert_last_event(
+			Event::ApplicationSuspended { provider_id, application_id }.into(),
+		);
+		let context =
+			Msa::get_provider_application_context_v2(provider_id, Some(application_id), None)
+				.expect("application context should exist");
+		assert!(context.is_suspended);
+		// the provider itself is not reported as suspended
+		let provider_context = Msa::get_provider_application_context_v2(provider_id, None, None)
+			.expect("provider context should exist");
+		assert!(!provider_context.is_suspended);
+	})
+}
+
+#[test]
+fn suspend_application_via_governance_requires_governance_origin() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let application_id = create_application(provider_msa_id, provider_key);
+
+		assert_noop!(
+			Msa::suspend_application_via_governance(
+				RuntimeOrigin::signed(provider_key.into()),
+				ProviderId(provider_msa_id),
+				application_id
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn suspend_application_via_governance_fails_for_unknown_or_suspended_application() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let provider_id = ProviderId(provider_msa_id);
+
+		assert_noop!(
+			Msa::suspend_application_via_governance(RawOrigin::Root.into(), provider_id, 0),
+			Error::<Test>::ApplicationNotFound
+		);
+
+		let application_id = create_application(provider_msa_id, provider_key);
+		assert_ok!(Msa::suspend_application_via_governance(
+			RawOrigin::Root.into(),
+			provider_id,
+			application_id
+		));
+		assert_noop!(
+			Msa::suspend_application_via_governance(
+				RawOrigin::Root.into(),
+				provider_id,
+				application_id
+			),
+			Error::<Test>::ApplicationSuspended
+		);
+	})
+}
+
+#[test]
+fn resume_application_via_governance_happy_path() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+		assert_ok!(Msa::suspend_application_via_governance(
+			RawOrigin::Root.into(),
+			provider_id,
+			application_id
+		));
+
+		assert_ok!(Msa::resume_application_via_governance(
+			RawOrigin::Root.into(),
+			provider_id,
+			application_id
+		));
+
+		System::assert_last_event(Event::ApplicationResumed { provider_id, application_id }.into());
+		let context =
+			Msa::get_provider_application_context_v2(provider_id, Some(application_id), None)
+				.expect("application context should exist");
+		assert!(!context.is_suspended);
+	})
+}
+
+#[test]
+fn resume_application_via_governance_fails_when_not_suspended() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let application_id = create_application(provider_msa_id, provider_key);
+
+		assert_noop!(
+			Msa::resume_application_via_governance(
+				RawOrigin::Root.into(),
+				ProviderId(provider_msa_id),
+				application_id
+			),
+			Error::<Test>::ApplicationNotSuspended
+		);
+	})
+}
+
+#[test]
+fn application_transfer_happy_path() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let (new_provider_msa_id, new_provider_key) = create_provider_with_name("NewProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let new_provider_id = ProviderId(new_provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+		// the receiving provider already has an application
+		create_application(new_provider_msa_id, new_provider_key);
+		let application = ProviderToApplicationRegistry::<Test>::get(provider_id, application_id);
+
+		assert_ok!(Msa::offer_application_transfer(
+			RuntimeOrigin::signed(provider_key.into()),
+			application_id,
+			new_provider_id
+		));
+		System::assert_last_event(
+			Event::ApplicationTransferOffered { provider_id, application_id, new_provider_id }
+				.into(),
+		);
+		// nothing moves until the receiving provider accepts
+		assert!(ProviderToApplicationRegistry::<Test>::contains_key(provider_id, application_id));
+
+		assert_ok!(Msa::accept_application_transfer(
+			RuntimeOrigin::signed(new_provider_key.into()),
+			provider_id,
+			application_id
+		));
+
+		System::assert_last_event(
+			Event::ApplicationTransferred {
+				provider_id,
+				application_id,
+				new_provider_id,
+				new_application_id: 1,
+			}
+			.into(),
+		);
+		assert!(!ProviderToApplicationRegistry::<Test>::contains_key(provider_id, application_id));
+		assert_eq!(ProviderToApplicationRegistry::<Test>::get(new_provider_id, 1), application);
+		assert_eq!(NextApplicationIndex::<Test>::get(new_provider_id), 2);
+		assert!(!PendingApplicationTransfers::<Test>::contains_key(provider_id, application_id));
+	})
+}
+
+#[test]
+fn accept_application_transfer_fails_without_offer() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let (_, new_provider_key) = create_provider_with_name("NewProvider");
+		let (other_provider_msa_id, _) = create_provider_with_name("OtherProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+
+		assert_noop!(
+			Msa::accept_application_transfer(
+				RuntimeOrigin::signed(new_provider_key.into()),
+				provider_id,
+				application_id
+			),
+			Error::<Test>::ApplicationTransferNotFound
+		);
+
+		// an offer to a different provider cannot be accepted
+		assert_ok!(Msa::offer_application_transfer(
+			RuntimeOrigin::signed(provider_key.into()),
+			application_id,
+			ProviderId(other_provider_msa_id)
+		));
+		assert_noop!(
+			Msa::accept_application_transfer(
+				RuntimeOrigin::signed(new_provider_key.into()),
+				provider_id,
+				application_id
+			),
+			Error::<Test>::ApplicationTransferNotFound
+		);
+	})
+}
+
+#[test]
+fn offer_application_transfer_fails_for_invalid_recipient() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let (non_provider_msa_id, _) = create_account();
+		let application_id = create_application(provider_msa_id, provider_key);
+
+		assert_noop!(
+			Msa::offer_application_transfer(
+				RuntimeOrigin::signed(provider_key.into()),
+				application_id,
+				ProviderId(provider_msa_id)
+			),
+			Error::<Test>::InvalidApplicationTransferRecipient
+		);
+		assert_noop!(
+			Msa::offer_application_transfer(
+				RuntimeOrigin::signed(provider_key.into()),
+				application_id,
+				ProviderId(non_provider_msa_id)
+			),
+			Error::<Test>::ProviderNotRegistered
+		);
+	})
+}
+
+#[test]
+fn suspended_application_cannot_be_transferred() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("AppProvider");
+		let (new_provider_msa_id, new_provider_key) = create_provider_with_name("NewProvider");
+		let provider_id = ProviderId(provider_msa_id);
+		let new_provider_id = ProviderId(new_provider_msa_id);
+		let application_id = create_application(provider_msa_id, provider_key);
+		assert_ok!(Msa::offer_application_transfer(
+			RuntimeOrigin::signed(provider_key.into()),
+			application_id,
+			new_provider_id
+		));
+
+		assert_ok!(Msa::suspend_application_via_governance(
+			RawOrigin::Root.into(),
+			provider_id,
+			application_id
+		));
+
+		// suspension cancels the pending offer
+		assert!(!PendingApplicationTransfers::<Test>::contains_key(provider_id, application_id));
+		assert_noop!(
+			Msa::accept_application_transfer(
+				RuntimeOrigin::signed(new_provider_key.into()),
+				provider_id,
+				application_id
+			),
+			Error::<Test>::ApplicationTransferNotFound
+		);
+		assert_noop!(
+			Msa::offer_application_transfer(
+				RuntimeOrigin::signed(provider_key.into()),
+				application_id,
+				new_provider_id
+			),
+			Error::<Test>::ApplicationSuspended
+		);
+	})
+}
@@ -1,5 +1,12 @@
-use common_primitives::msa::{ApplicationContext, ProviderId};
-use frame_support::{assert_noop, assert_ok, traits::ChangeMembers, BoundedBTreeMap, BoundedVec};
+use common_primitives::{
+	image::{ImageMetadata, ImageMimeType},
+	msa::{ApplicationContext, ProviderId},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{ChangeMembers, Get},
+	BoundedBTreeMap, BoundedVec,
+};
 
 use pallet_collective::ProposalOf;
 use sp_core::{Encode, Pair};
@@ -8,7 +15,9 @@ use sp_weights::Weight;
 use pretty_assertions::assert_eq;
 
 use crate::{
-	tests::mock::*, types::compute_cid, ApprovedLogos, Error, Event, NextApplicationIndex,
+	tests::mock::*,
+	types::{compute_cid, png_logo_bytes},
+	ApprovedLogoMetadata, ApprovedLogos, Error, Event, NextApplicationIndex,
 	ProviderToApplicationRegistry,
 };
 
@@ -256,7 +265,8 @@ fn create_application_via_governance_fails_for_duplicate_application() {
 fn upload_logo_happy_path() {
 	new_test_ext().execute_with(|| {
 		let (_, key_pair) = create_provider_with_name("LogoProvider");
-		let logo_cid = compute_cid(b"test_logo_data");
+		let logo_bytes = png_logo_bytes(250, 100, 64);
+		let logo_cid = compute_cid(&logo_bytes);
 		let input_bounded_cid = BoundedVec::try_from(logo_cid).unwrap();
 
 		// assuming logo was approved via governance
@@ -265,14 +275,17 @@ fn upload_logo_happy_path() {
 		assert_ok!(Msa::upload_logo(
 			RuntimeOrigin::signed(key_pair.into()),
 			BoundedVec::try_from(input_bounded_cid.clone()).expect("Logo CID should fit in bounds"),
-			BoundedVec::try_from(b"test_logo_data".to_vec())
-				.expect("Logo data should fit in bounds")
+			BoundedVec::try_from(logo_bytes.clone()).expect("Logo data should fit in bounds")
 		));
 
 		let stored_logo_bytes = ApprovedLogos::<Test>::get(&input_bounded_cid);
-		let expect_logo_bytes = BoundedVec::try_from(b"test_logo_data".to_vec())
-			.expect("Logo data should fit in bounds");
+		let expect_logo_bytes =
+			BoundedVec::try_from(logo_bytes).expect("Logo data should fit in bounds");
 		assert_eq!(stored_logo_bytes, Some(expect_logo_bytes));
+		assert_eq!(
+			ApprovedLogoMetadata::<Test>::get(&input_bounded_cid),
+			Some(ImageMetadata { mime_type: ImageMimeType::Png, width: 250, height: 100 })
+		);
 	});
 }
 
@@ -356,10 +369,69 @@ fn upload_logo_fails_mismatch_logo_data() {
 	});
 }
 
+fn assert_upload_logo_fails(logo_bytes: Vec<u8>, expected_error: Error<Test>) {
+	let (_, key_pair) = create_provider_with_name("LogoProvider");
+	let input_bounded_cid = BoundedVec::try_from(compute_cid(&logo_bytes)).unwrap();
+
+	// assuming logo was approved via governance
+	ApprovedLogos::<Test>::insert(&input_bounded_cid, BoundedVec::new());
+
+	assert_noop!(
+		Msa::upload_logo(
+			RuntimeOrigin::signed(key_pair.into()),
+			input_bounded_cid,
+			BoundedVec::try_from(logo_bytes).expect("Logo data should fit in bounds")
+		),
+		expected_error
+	);
+}
+
+#[test]
+fn upload_logo_fails_for_svg() {
+	new_test_ext().execute_with(|| {
+		assert_upload_logo_fails(
+			b"<svg xmlns=\"http://www.w3.org/2000/svg\"><script>alert(1)</script></svg>".to_vec(),
+			Error::<Test>::UnsupportedLogoFormat,
+		);
+	});
+}
+
+#[test]
+fn upload_logo_fails_for_unrecognised_format() {
+	new_test_ext().execute_with(|| {
+		assert_upload_logo_fails(b"test_logo_data".to_vec(), Error::<Test>::UnsupportedLogoFormat);
+	});
+}
+
+#[test]
+fn upload_logo_fails_for_malformed_header() {
+	new_test_ext().execute_with(|| {
+		let mut logo_bytes = png_logo_bytes(250, 100, 0);
+		logo_bytes.truncate(20);
+		assert_upload_logo_fails(logo_bytes, Error::<Test>::InvalidLogoImage);
+	});
+}
+
+#[test]
+fn upload_logo_fails_for_out_of_bounds_dimensions() {
+	new_test_ext().execute_with(|| {
+		let too_small = MsaMinLogoDimension::get() - 1;
+		let too_large = MsaMaxLogoDimension::get() + 1;
+		assert_upload_logo_fails(
+			png_logo_bytes(too_small, 100, 0),
+			Error::<Test>::InvalidLogoDimensions,
+		);
+		assert_upload_logo_fails(
+			png_logo_bytes(250, too_large, 0),
+			Error::<Test>::InvalidLogoDimensions,
+		);
+	});
+}
+
 #[test]
 fn create_application_and_upload_logo() {
 	new_test_ext().execute_with(|| {
-		let (_, key_pair) = create_provider_with_name("LogoProvider");
+		let (provider_msa_id, key_pair) = create_provider_with_name("LogoProvider");
 		let logo_data = include_bytes!("../../../../e2e/msa/frequency.png");
 		let cid = common_primitives::cid::compute_cid_v1(logo_data).expect("Failed to compute CID");
 		let encoded = multibase::encode(multibase::Base::Base58Btc, cid);
@@ -390,6 +462,14 @@ fn create_application_and_upload_logo() {
 		let stored_bytes =
 			ApprovedLogos::<Test>::get(&input_bounded_cid).expect("Logo should be approved");
 		assert_eq!(stored_bytes, bounded_logo_data);
+
+		let context =
+			Msa::get_provider_application_context_v2(ProviderId(provider_msa_id), Some(0), None)
+				.expect("application context should exist");
+		assert_eq!(
+			context.default_logo_metadata,
+			Some(ImageMetadata { mime_type: ImageMimeType::Png, width: 251, height: 66 })
+		);
 	});
 }
 
@@ -49,7 +49,7 @@ frame_support::construct_runtime!(
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
 		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
 		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>},
-		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>},
+		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Config<T,I>, Storage, Event<T>, Origin<T>},
 		Handles: pallet_handles::{Pallet, Call, Storage, Event<T>},
 	}
@@ -134,7 +134,7 @@ impl pallet_balances::Config for Test {
 	type WeightInfo = ();
 	type FreezeIdentifier = RuntimeFreezeReason;
 	type MaxFreezes = ConstU32<2>;
-	type RuntimeHoldReason = ();
+	type RuntimeHoldReason = RuntimeHoldReason;
 	type RuntimeFreezeReason = ();
 	type DoneSlashHandler = ();
 }
@@ -156,6 +156,13 @@ impl pallet_schemas::Config for Test {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type NamespaceEntityDeposit = ConstU64<100>;
+	type MsaInfoProvider = Msa;
+	type MaxIntentsWithRetention = ConstU32<10>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = ();
 }
 
 impl pallet_handles::Config for Test {
@@ -239,6 +246,8 @@ impl pallet_msa::Config for Test {
 	type MaxLogoCidSize = MsaMaxLogoCidSize;
 	type MaxLogoSize = MsaMaxLogoSize;
 	type MaxLocaleCount = MsaMaxLocaleCount;
+	type MinLogoDimension = MsaMinLogoDimension;
+	type MaxLogoDimension = MsaMaxLogoDimension;
 	type SchemaValidator = Schemas;
 	type HandleProvider = Handles;
 	type MortalityWindowSize = ConstU32<100>;
@@ -1,5 +1,6 @@
 pub mod mock;
 
+mod application_lifecycle_tests;
 mod application_tests;
 mod creation_tests;
 mod delegation_tests;
@@ -9,7 +10,9 @@ mod migration_tests;
 mod msa_token_tests;
 mod offchain_tests;
 mod other_tests;
+mod passkey_tests;
 mod permission_tests;
+mod provider_directory_tests;
 mod public_key_tests;
 mod recovery_tests;
 mod replay_tests;
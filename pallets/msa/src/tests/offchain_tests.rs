@@ -1,7 +1,7 @@
 use frame_support::{assert_ok, pallet_prelude::Hooks, traits::OriginTrait};
 
 use crate::{
-	get_bucket_number, tests::mock::*, Config, FinalizedBlockResponse, IndexedEvent,
+	get_bucket_number, tests::mock::*, Config, Event, FinalizedBlockResponse, IndexedEvent,
 	MsaOffchainReplayEvent, OffchainReplayEvent, Pallet, LAST_PROCESSED_BLOCK_STORAGE_NAME,
 	MSA_INITIAL_INDEXED_STORAGE_NAME, RPC_FINALIZED_BLOCK_REQUEST_BODY,
 	RPC_FINALIZED_BLOCK_REQUEST_URL,
@@ -10,7 +10,7 @@ use frame_system::pallet_prelude::BlockNumberFor;
 use parity_scale_codec::{Decode, Encode};
 
 use common_primitives::{
-	msa::MessageSourceId,
+	msa::{MessageSourceId, ProviderId},
 	node::AccountId,
 	offchain::{get_index_value, get_msa_account_storage_key_name},
 };
@@ -223,6 +223,68 @@ fn get_bucket_number_should_return_pseudo_random_value() {
 	});
 }
 
+#[test]
+fn indexed_event_map_should_map_application_lifecycle_events() {
+	new_test_ext().execute_with(|| {
+		let provider_id = ProviderId(1);
+		let new_provider_id = ProviderId(2);
+
+		assert_eq!(
+			IndexedEvent::<Test>::map(
+				Some(&Event::ApplicationRetired { provider_id, application_id: 3 }),
+				provider_id.into()
+			),
+			Some(IndexedEvent::IndexedApplicationRetired { provider_id, application_id: 3 })
+		);
+		assert_eq!(
+			IndexedEvent::<Test>::map(
+				Some(&Event::ApplicationSuspended { provider_id, application_id: 3 }),
+				provider_id.into()
+			),
+			Some(IndexedEvent::IndexedApplicationSuspended { provider_id, application_id: 3 })
+		);
+		assert_eq!(
+			IndexedEvent::<Test>::map(
+				Some(&Event::ApplicationResumed { provider_id, application_id: 3 }),
+				provider_id.into()
+			),
+			Some(IndexedEvent::IndexedApplicationResumed { provider_id, application_id: 3 })
+		);
+		assert_eq!(
+			IndexedEvent::<Test>::map(
+				Some(&Event::ApplicationTransferOffered {
+					provider_id,
+					application_id: 3,
+					new_provider_id
+				}),
+				provider_id.into()
+			),
+			Some(IndexedEvent::IndexedApplicationTransferOffered {
+				provider_id,
+				application_id: 3,
+				new_provider_id
+			})
+		);
+		assert_eq!(
+			IndexedEvent::<Test>::map(
+				Some(&Event::ApplicationTransferred {
+					provider_id,
+					application_id: 3,
+					new_provider_id,
+					new_application_id: 0
+				}),
+				new_provider_id.into()
+			),
+			Some(IndexedEvent::IndexedApplicationTransferred {
+				provider_id,
+				application_id: 3,
+				new_provider_id,
+				new_application_id: 0
+			})
+		);
+	});
+}
+
 #[test]
 fn reindex_offchain_should_always_succeed() {
 	new_test_ext().execute_with(|| {
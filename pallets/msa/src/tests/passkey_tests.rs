@@ -0,0 +1,176 @@
+use frame_support::{assert_noop, assert_ok};
+
+use sp_core::{crypto::AccountId32, sr25519, Encode, Pair};
+use sp_runtime::MultiSignature;
+
+use crate::{
+	test_common::utilities::{get_p256_public_key, get_p256_secret, passkey_sign},
+	tests::mock::*,
+	types::AddPasskeyData,
+	Error, Event, PublicKeyToMsaId,
+};
+
+use common_primitives::{
+	msa::MessageSourceId,
+	passkey::{PasskeyPublicKey, VerifiablePasskeySignature},
+	utils::wrap_binary_data,
+};
+
+fn create_and_sign_add_passkey_payload(
+	msa_id: MessageSourceId,
+	owner_key_pair: &sr25519::Pair,
+) -> (AddPasskeyData<Test>, MultiSignature, VerifiablePasskeySignature, PasskeyPublicKey) {
+	let secret = get_p256_secret();
+	let passkey_public_key = get_p256_public_key(&secret);
+	let add_passkey_payload = AddPasskeyData::<Test> {
+		msa_id,
+		expiration: 10,
+		new_passkey_public_key: passkey_public_key.clone(),
+	};
+	let owner_signature: MultiSignature =
+		owner_key_pair.sign(&wrap_binary_data(add_passkey_payload.encode())).into();
+	let passkey_signature = passkey_sign(&secret, &add_passkey_payload.encode());
+	(add_passkey_payload, owner_signature, passkey_signature, passkey_public_key)
+}
+
+#[test]
+fn add_passkey_to_msa_should_register_the_passkey_account() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (payload, owner_signature, passkey_signature, passkey_public_key) =
+			create_and_sign_add_passkey_payload(msa_id, &owner_key_pair);
+
+		assert_ok!(Msa::add_passkey_to_msa(
+			test_origin_signed(1),
+			owner_key_pair.public().into(),
+			owner_signature,
+			passkey_signature,
+			payload
+		));
+
+		let passkey_account: AccountId32 = passkey_public_key.to_account_id32();
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&passkey_account), Some(msa_id));
+		System::assert_last_event(
+			Event::PasskeyAdded { msa_id, key: passkey_account, passkey_public_key }.into(),
+		);
+	});
+}
+
+#[test]
+fn add_passkey_to_msa_with_invalid_passkey_signature_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (payload, owner_signature, _, _) =
+			create_and_sign_add_passkey_payload(msa_id, &owner_key_pair);
+		let wrong_passkey_signature = passkey_sign(&get_p256_secret(), b"another payload");
+
+		assert_noop!(
+			Msa::add_passkey_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				wrong_passkey_signature,
+				payload
+			),
+			Error::<Test>::NewKeyOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn add_passkey_to_msa_with_invalid_owner_signature_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (fake_owner_pair, _) = sr25519::Pair::generate();
+		let (payload, _, passkey_signature, _) =
+			create_and_sign_add_passkey_payload(msa_id, &owner_key_pair);
+		let fake_owner_signature: MultiSignature =
+			fake_owner_pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			Msa::add_passkey_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				fake_owner_signature,
+				passkey_signature,
+				payload
+			),
+			Error::<Test>::MsaOwnershipInvalidSignature
+		);
+	});
+}
+
+#[test]
+fn add_passkey_to_msa_for_a_different_msa_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (_, owner_key_pair) = create_account();
+		let (other_msa_id, _) = create_account();
+		let (payload, owner_signature, passkey_signature, _) =
+			create_and_sign_add_passkey_payload(other_msa_id, &owner_key_pair);
+
+		assert_noop!(
+			Msa::add_passkey_to_msa(
+				test_origin_signed(1),
+				owner_key_pair.public().into(),
+				owner_signature,
+				passkey_signature,
+				payload
+			),
+			Error::<Test>::NotMsaOwner
+		);
+	});
+}
+
+#[test]
+fn add_passkey_to_msa_when_passkey_is_already_registered_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (payload, owner_signature, passkey_signature, _) =
+			create_and_sign_add_passkey_payload(msa_id, &owner_key_pair);
+		assert_ok!(Msa::add_passkey_to_msa(
+			test_origin_signed(1),
+			owner_key_pair.public().into(),
+			owner_signature,
+			passkey_signature,
+			payload
+		));
+
+		let (other_msa_id, other_owner_key_pair) = create_account();
+		let (payload, owner_signature, passkey_signature, _) =
+			create_and_sign_add_passkey_payload(other_msa_id, &other_owner_key_pair);
+		assert_noop!(
+			Msa::add_passkey_to_msa(
+				test_origin_signed(1),
+				other_owner_key_pair.public().into(),
+				owner_signature,
+				passkey_signature,
+				payload
+			),
+			Error::<Test>::KeyAlreadyRegistered
+		);
+	});
+}
+
+#[test]
+fn delete_msa_public_key_should_remove_a_registered_passkey() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, owner_key_pair) = create_account();
+		let (payload, owner_signature, passkey_signature, passkey_public_key) =
+			create_and_sign_add_passkey_payload(msa_id, &owner_key_pair);
+		assert_ok!(Msa::add_passkey_to_msa(
+			test_origin_signed(1),
+			owner_key_pair.public().into(),
+			owner_signature,
+			passkey_signature,
+			payload
+		));
+		let passkey_account: AccountId32 = passkey_public_key.to_account_id32();
+
+		assert_ok!(Msa::delete_msa_public_key(
+			RuntimeOrigin::signed(owner_key_pair.public().into()),
+			passkey_account.clone()
+		));
+
+		assert_eq!(PublicKeyToMsaId::<Test>::get(&passkey_account), None);
+	});
+}
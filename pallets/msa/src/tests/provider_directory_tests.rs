@@ -0,0 +1,198 @@
+use common_primitives::msa::{
+	ApplicationContext, ProviderDirectoryCursor, ProviderDirectoryRequest, ProviderId,
+	ProviderRegistryEntry,
+};
+use frame_support::{assert_ok, BoundedBTreeMap, BoundedVec};
+use frame_system::RawOrigin;
+use sp_core::Pair;
+
+use pretty_assertions::assert_eq;
+
+use crate::{tests::mock::*, types::compute_cid, ApprovedLogos};
+
+fn create_provider(name: &str, locale: Option<&str>, logo: Option<&[u8]>) -> ProviderId {
+	let (provider_msa_id, key_pair) = create_account();
+	let mut entry = ProviderRegistryEntry::default();
+	entry.default_name = BoundedVec::try_from(name.as_bytes().to_vec()).unwrap();
+	if let Some(locale) = locale {
+		let mut localized_names = BoundedBTreeMap::new();
+		localized_names
+			.try_insert(
+				BoundedVec::try_from(locale.as_bytes().to_vec()).unwrap(),
+				entry.default_name.clone(),
+			)
+			.unwrap();
+		entry.localized_names = localized_names;
+	}
+	if let Some(logo) = logo {
+		entry.default_logo_250_100_png_cid = BoundedVec::try_from(compute_cid(logo)).unwrap();
+	}
+	assert_ok!(Msa::create_provider_via_governance_v2(
+		RawOrigin::Root.into(),
+		key_pair.public().into(),
+		entry
+	));
+	assert_ok!(Msa::create_application(
+		RuntimeOrigin::signed(key_pair.public().into()),
+		ApplicationContext::default()
+	));
+	ProviderId(provider_msa_id)
+}
+
+fn request(page_size: u32) -> ProviderDirectoryRequest {
+	ProviderDirectoryRequest { page_size, ..Default::default() }
+}
+
+#[test]
+fn get_provider_directory_returns_providers_and_applications() {
+	new_test_ext().execute_with(|| {
+		let provider_id = create_provider("Provider", None, Some(b"logo"));
+
+		let response = Msa::get_provider_directory(request(10));
+
+		assert_eq!(response.entries.len(), 2);
+		assert_eq!(response.next_start_after, None);
+		let provider_entry = &response.entries[0];
+		assert_eq!(provider_entry.provider_id, provider_id);
+		assert_eq!(provider_entry.application_id, None);
+		assert_eq!(provider_entry.default_name, b"Provider".to_vec());
+		assert_eq!(provider_entry.default_logo_250_100_png_cid, Some(compute_cid(b"logo")));
+		let application_entry = &response.entries[1];
+		assert_eq!(application_entry.provider_id, provider_id);
+		assert_eq!(application_entry.application_id, Some(0));
+		assert_eq!(application_entry.default_logo_250_100_png_cid, None);
+	})
+}
+
+#[test]
+fn get_provider_directory_pages_over_all_providers() {
+	new_test_ext().execute_with(|| {
+		let mut expected: Vec<ProviderId> =
+			(0..5).map(|_| create_provider("Provider", None, None)).collect();
+		expected.sort_by_key(|provider_id| provider_id.0);
+
+		let mut seen = vec![];
+		let mut next_request = request(2);
+		loop {
+			let response = Msa::get_provider_directory(next_request.clone());
+			assert!(response.entries.len() <= 2);
+			seen.extend(
+				response
+					.entries
+					.iter()
+					.filter(|entry| entry.application_id.is_none())
+					.map(|entry| entry.provider_id),
+			);
+			match response.next_start_after {
+				Some(start_after) => next_request.start_after = Some(start_after),
+				None => break,
+			}
+		}
+		seen.sort_by_key(|provider_id| provider_id.0);
+
+		assert_eq!(seen, expected);
+	})
+}
+
+#[test]
+fn get_provider_directory_pages_over_applications_of_a_provider() {
+	new_test_ext().execute_with(|| {
+		let (provider_msa_id, provider_key) = create_provider_with_name("Provider");
+		let provider_id = ProviderId(provider_msa_id);
+		for _ in 0..3 {
+			assert_ok!(Msa::create_application(
+				RuntimeOrigin::signed(provider_key.into()),
+				ApplicationContext::default()
+			));
+		}
+		assert_ok!(Msa::retire_application(RuntimeOrigin::signed(provider_key.into()), 1));
+
+		let first_page = Msa::get_provider_directory(request(2));
+		assert_eq!(
+			first_page.entries.iter().map(|entry| entry.application_id).collect::<Vec<_>>(),
+			vec![None, Some(0)]
+		);
+		assert_eq!(
+			first_page.next_start_after,
+			Some(ProviderDirectoryCursor { provider_id, application_id: Some(0) })
+		);
+
+		// the retired application still counts towards the page size
+		let second_page = Msa::get_provider_directory(ProviderDirectoryRequest {
+			start_after: first_page.next_start_after,
+			..request(2)
+		});
+		assert_eq!(
+			second_page.entries.iter().map(|entry| entry.application_id).collect::<Vec<_>>(),
+			vec![Some(2)]
+		);
+		assert_eq!(second_page.next_start_after, None);
+	})
+}
+
+#[test]
+fn get_provider_directory_filters_by_locale() {
+	new_test_ext().execute_with(|| {
+		let localized_provider_id = create_provider("Localized", Some("en"), None);
+		create_provider("NotLocalized", None, None);
+
+		let response = Msa::get_provider_directory(ProviderDirectoryRequest {
+			page_size: 10,
+			locale: Some(b"en".to_vec()),
+			..Default::default()
+		});
+
+		assert_eq!(response.entries.len(), 1);
+		assert_eq!(response.entries[0].provider_id, localized_provider_id);
+		assert_eq!(response.entries[0].localized_name, Some(b"Localized".to_vec()));
+	})
+}
+
+#[test]
+fn get_provider_directory_filters_by_approved_logo() {
+	new_test_ext().execute_with(|| {
+		let provider_with_logo = create_provider("WithLogo", None, Some(b"logo"));
+		create_provider("WithoutLogo", None, None);
+
+		let response = Msa::get_provider_directory(ProviderDirectoryRequest {
+			page_size: 10,
+			approved_logo_only: true,
+			..Default::default()
+		});
+
+		assert_eq!(response.entries.len(), 1);
+		assert_eq!(response.entries[0].provider_id, provider_with_logo);
+	})
+}
+
+#[test]
+fn get_provider_directory_rejects_invalid_page_size() {
+	new_test_ext().execute_with(|| {
+		create_provider("Provider", None, None);
+
+		assert!(Msa::get_provider_directory(request(0)).entries.is_empty());
+		assert!(Msa::get_provider_directory(request(ProviderDirectoryRequest::MAX_PAGE_SIZE + 1))
+			.entries
+			.is_empty());
+	})
+}
+
+#[test]
+fn get_approved_logo_returns_uploaded_bytes_only() {
+	new_test_ext().execute_with(|| {
+		let logo_cid = compute_cid(b"logo");
+		let bounded_cid = BoundedVec::try_from(logo_cid.clone()).unwrap();
+
+		assert_eq!(Msa::get_approved_logo(logo_cid.clone()), None);
+
+		// approved but not uploaded yet
+		ApprovedLogos::<Test>::insert(&bounded_cid, BoundedVec::new());
+		assert_eq!(Msa::get_approved_logo(logo_cid.clone()), None);
+
+		ApprovedLogos::<Test>::insert(
+			&bounded_cid,
+			BoundedVec::try_from(b"logo".to_vec()).unwrap(),
+		);
+		assert_eq!(Msa::get_approved_logo(logo_cid), Some(b"logo".to_vec()));
+	})
+}
@@ -12,6 +12,7 @@ pub use common_primitives::msa::{
 use common_primitives::node::BlockNumber;
 
 use common_primitives::{
+	passkey::PasskeyPublicKey,
 	signatures::{get_eip712_encoding_prefix, AccountAddressMapper, EthereumAddressMapper},
 	utils::to_abi_compatible_number,
 };
@@ -68,6 +69,51 @@ impl<T: Config> EIP712Encode for AddKeyData<T> {
 	}
 }
 
+/// A type definition for the payload for the following operation:
+/// -  Adding a passkey to an MSA - `pallet_msa::add_passkey_to_msa`
+#[derive(
+	TypeInfo, RuntimeDebugNoBound, Clone, Decode, DecodeWithMemTracking, Encode, PartialEq, Eq,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct AddPasskeyData<T: Config> {
+	/// Message Source Account identifier
+	pub msa_id: MessageSourceId,
+	/// The block number at which a signed proof of this payload expires.
+	pub expiration: BlockNumberFor<T>,
+	/// The P256 passkey public key to be added.
+	pub new_passkey_public_key: PasskeyPublicKey,
+}
+
+impl<T: Config> EIP712Encode for AddPasskeyData<T> {
+	fn encode_eip_712(&self, chain_id: u32) -> Box<[u8]> {
+		lazy_static! {
+			// signed payload
+			static ref MAIN_TYPE_HASH: [u8; 32] = sp_io::hashing::keccak_256(
+				b"AddPasskeyData(uint64 msaId,uint32 expiration,bytes newPasskeyPublicKey)",
+			);
+		}
+		// get prefix and domain separator
+		let prefix_domain_separator: Box<[u8]> =
+			get_eip712_encoding_prefix("0xcccccccccccccccccccccccccccccccccccccccc", chain_id);
+		let coded_owner_msa_id = to_abi_compatible_number(self.msa_id);
+		let expiration: U256 = self.expiration.into();
+		let coded_expiration = to_abi_compatible_number(expiration.as_u128());
+		let coded_passkey_public_key =
+			sp_io::hashing::keccak_256(self.new_passkey_public_key.0.as_slice());
+		let message = sp_io::hashing::keccak_256(
+			&[
+				MAIN_TYPE_HASH.as_slice(),
+				&coded_owner_msa_id,
+				&coded_expiration,
+				&coded_passkey_public_key,
+			]
+			.concat(),
+		);
+		let combined = [prefix_domain_separator.as_ref(), &message].concat();
+		combined.into_boxed_slice()
+	}
+}
+
 /// Type discriminator enum for signed payloads
 #[derive(
 	Encode, Decode, DecodeWithMemTracking, Clone, Copy, PartialEq, Eq, RuntimeDebugNoBound, TypeInfo,
@@ -297,3 +343,17 @@ pub fn compute_cid(bytes: &[u8]) -> Vec<u8> {
 	let encoded = multibase::encode(multibase::Base::Base58Btc, cid);
 	encoded.into_bytes()
 }
+
+/// Helper function to build a PNG logo of the given dimensions padded to `len` bytes.
+/// Only the header is valid which is all `upload_logo` inspects.
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub fn png_logo_bytes(width: u32, height: u32, len: usize) -> Vec<u8> {
+	let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0D, 0x0A, 0x1A, 0x0A];
+	bytes.extend_from_slice(&13u32.to_be_bytes());
+	bytes.extend_from_slice(b"IHDR");
+	bytes.extend_from_slice(&width.to_be_bytes());
+	bytes.extend_from_slice(&height.to_be_bytes());
+	bytes.extend_from_slice(&[8, 6, 0, 0, 0]);
+	bytes.resize(len.max(bytes.len()), 0);
+	bytes
+}
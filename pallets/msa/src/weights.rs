@@ -62,6 +62,12 @@ pub trait WeightInfo {
 	fn update_application_via_governance(n: u32, m: u32, ) -> Weight;
 	fn propose_to_update_application(n: u32, m: u32, ) -> Weight;
 	fn update_provider_via_governance(n: u32, m: u32, ) -> Weight;
+	fn add_passkey_to_msa() -> Weight;
+	fn retire_application() -> Weight;
+	fn suspend_application_via_governance() -> Weight;
+	fn resume_application_via_governance() -> Weight;
+	fn offer_application_transfer() -> Weight;
+	fn accept_application_transfer() -> Weight;
 }
 
 /// Weights for `pallet_msa` using the Substrate node and recommended hardware.
@@ -467,14 +473,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::ApprovedLogos` (r:1 w:1)
 	/// Proof: `Msa::ApprovedLogos` (`max_values`: None, `max_size`: Some(131150), added: 133625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ApprovedLogoMetadata` (r:0 w:1)
+	/// Proof: `Msa::ApprovedLogoMetadata` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	fn upload_logo() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123`
 		//  Estimated: `135110`
-		// Minimum execution time: 303_240_000 picoseconds.
-		Weight::from_parts(309_062_000, 135110)
+		// Minimum execution time: 305_118_000 picoseconds.
+		Weight::from_parts(311_406_000, 135110)
 			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -576,6 +584,107 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2_u64))
 			.saturating_add(T::DbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:2 w:2)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn add_passkey_to_msa() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(198_412_000, 6531)
+			.saturating_add(T::DbWeight::get().reads(6_u64))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ApprovedLogos` (r:0 w:11)
+	/// Proof: `Msa::ApprovedLogos` (`max_values`: None, `max_size`: Some(131150), added: 133625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:0 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn retire_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4232`
+		//  Estimated: `7732`
+		// Minimum execution time: 43_063_000 picoseconds.
+		Weight::from_parts(45_812_000, 7732)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn suspend_application_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3737`
+		//  Estimated: `7237`
+		// Minimum execution time: 16_171_000 picoseconds.
+		Weight::from_parts(17_204_000, 7237)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	fn resume_application_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1747`
+		//  Estimated: `3495`
+		// Minimum execution time: 9_284_000 picoseconds.
+		Weight::from_parts(9_877_000, 3495)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:0)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn offer_application_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10964`
+		//  Estimated: `14464`
+		// Minimum execution time: 26_819_000 picoseconds.
+		Weight::from_parts(28_531_000, 14464)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:1 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:2 w:2)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:0)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::NextApplicationIndex` (r:1 w:1)
+	/// Proof: `Msa::NextApplicationIndex` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	fn accept_application_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9984`
+		//  Estimated: `13484`
+		// Minimum execution time: 36_722_000 picoseconds.
+		Weight::from_parts(39_066_000, 13484)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -980,14 +1089,16 @@ impl WeightInfo for () {
 	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::ApprovedLogos` (r:1 w:1)
 	/// Proof: `Msa::ApprovedLogos` (`max_values`: None, `max_size`: Some(131150), added: 133625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ApprovedLogoMetadata` (r:0 w:1)
+	/// Proof: `Msa::ApprovedLogoMetadata` (`max_values`: None, `max_size`: Some(82), added: 2557, mode: `MaxEncodedLen`)
 	fn upload_logo() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `123`
 		//  Estimated: `135110`
-		// Minimum execution time: 303_240_000 picoseconds.
-		Weight::from_parts(309_062_000, 135110)
+		// Minimum execution time: 305_118_000 picoseconds.
+		Weight::from_parts(311_406_000, 135110)
 			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
@@ -1089,6 +1200,107 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
 			.saturating_add(RocksDbWeight::get().writes(2_u64))
 	}
+	/// Storage: `Msa::PayloadSignatureRegistryList` (r:2 w:2)
+	/// Proof: `Msa::PayloadSignatureRegistryList` (`max_values`: Some(50000), `max_size`: Some(144), added: 2124, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PayloadSignatureRegistryPointer` (r:1 w:1)
+	/// Proof: `Msa::PayloadSignatureRegistryPointer` (`max_values`: Some(1), `max_size`: Some(140), added: 635, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:1)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyCountForMsaId` (r:1 w:1)
+	/// Proof: `Msa::PublicKeyCountForMsaId` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	fn add_passkey_to_msa() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(198_412_000, 6531)
+			.saturating_add(RocksDbWeight::get().reads(6_u64))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:1)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ApprovedLogos` (r:0 w:11)
+	/// Proof: `Msa::ApprovedLogos` (`max_values`: None, `max_size`: Some(131150), added: 133625, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:0 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn retire_application() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `4232`
+		//  Estimated: `7732`
+		// Minimum execution time: 43_063_000 picoseconds.
+		Weight::from_parts(45_812_000, 7732)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(14_u64))
+	}
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn suspend_application_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3737`
+		//  Estimated: `7237`
+		// Minimum execution time: 16_171_000 picoseconds.
+		Weight::from_parts(17_204_000, 7237)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:1)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	fn resume_application_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1747`
+		//  Estimated: `3495`
+		// Minimum execution time: 9_284_000 picoseconds.
+		Weight::from_parts(9_877_000, 3495)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:2 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:1 w:0)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:0)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:0 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn offer_application_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10964`
+		//  Estimated: `14464`
+		// Minimum execution time: 26_819_000 picoseconds.
+		Weight::from_parts(28_531_000, 14464)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToRegistryEntryV2` (r:1 w:0)
+	/// Proof: `Msa::ProviderToRegistryEntryV2` (`max_values`: None, `max_size`: Some(3762), added: 6237, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PendingApplicationTransfers` (r:1 w:1)
+	/// Proof: `Msa::PendingApplicationTransfers` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::ProviderToApplicationRegistry` (r:2 w:2)
+	/// Proof: `Msa::ProviderToApplicationRegistry` (`max_values`: None, `max_size`: Some(3772), added: 6247, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::SuspendedApplications` (r:1 w:0)
+	/// Proof: `Msa::SuspendedApplications` (`max_values`: None, `max_size`: Some(30), added: 2505, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::NextApplicationIndex` (r:1 w:1)
+	/// Proof: `Msa::NextApplicationIndex` (`max_values`: None, `max_size`: Some(26), added: 2501, mode: `MaxEncodedLen`)
+	fn accept_application_transfer() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `9984`
+		//  Estimated: `13484`
+		// Minimum execution time: 36_722_000 picoseconds.
+		Weight::from_parts(39_066_000, 13484)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
 
 
@@ -1438,4 +1650,76 @@ mod tests {
 				> 7722
 		);
 	}
+	#[test]
+	fn test_add_passkey_to_msa() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6531
+		);
+	}
+	#[test]
+	fn test_retire_application() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 7732
+		);
+	}
+	#[test]
+	fn test_suspend_application_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 7237
+		);
+	}
+	#[test]
+	fn test_resume_application_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3495
+		);
+	}
+	#[test]
+	fn test_offer_application_transfer() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 14464
+		);
+	}
+	#[test]
+	fn test_accept_application_transfer() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 13484
+		);
+	}
 }
@@ -18,7 +18,7 @@
 )]
 // allowing deprecated until moving to Extrinsic V5 structure
 #![allow(deprecated)]
-use common_primitives::node::EIP712Encode;
+use common_primitives::{msa::MsaLookup, node::EIP712Encode};
 use common_runtime::{
 	extensions::check_nonce::{prepare_nonce, validate_nonce},
 	signature::check_signature,
@@ -55,6 +55,8 @@ mod mock;
 mod tests;
 #[cfg(test)]
 mod tests_v2;
+#[cfg(test)]
+mod tests_v3;
 
 pub mod weights;
 pub use weights::*;
@@ -106,6 +108,9 @@ pub mod module {
 		/// Filters the inner calls for passkey which is set in runtime
 		type PasskeyCallFilter: Contains<<Self as Config>::RuntimeCall>;
 
+		/// A type that will supply MSA related information
+		type MsaInfoProvider: MsaLookup<AccountId = Self::AccountId>;
+
 		/// Helper Currency method for benchmarking
 		#[cfg(feature = "runtime-benchmarks")]
 		type Currency: Mutate<Self::AccountId>;
@@ -168,18 +173,29 @@ pub mod module {
 			payload: PasskeyPayloadV2<T>,
 		) -> DispatchResultWithPostInfo {
 			ensure_none(origin)?;
-			let transaction_account_id = payload.passkey_call.account_id.clone();
-			let main_origin = T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(
-				transaction_account_id.clone(),
-			));
-			let result = payload.passkey_call.call.dispatch(main_origin);
-			if let Ok(_inner) = result {
-				// all post-dispatch logic should be included in here
-				Self::deposit_event(Event::TransactionExecutionSuccess {
-					account_id: transaction_account_id,
-				});
-			}
-			result
+			Self::dispatch_passkey_call(payload.passkey_call)
+		}
+
+		/// Proxies an extrinsic call for a passkey that is registered as a key of an MSA.
+		/// The account id inside the payload must be the account controlled by the passkey, so
+		/// unlike `proxy_v2` no account ownership proof is required.
+		/// Since this is an unsigned extrinsic all the verification checks are performed inside
+		/// `validate_unsigned` and `pre_dispatch` hooks.
+		#[pallet::call_index(2)]
+		#[pallet::weight({
+			let dispatch_info = payload.passkey_call.call.get_dispatch_info();
+			let overhead = <T as Config>::WeightInfo::pre_dispatch()
+				.saturating_add(T::DbWeight::get().reads(1));
+			let total = overhead.saturating_add(dispatch_info.call_weight);
+			(total, dispatch_info.class)
+		})]
+		#[allow(clippy::useless_conversion)]
+		pub fn proxy_v3(
+			origin: OriginFor<T>,
+			payload: PasskeyPayloadV3<T>,
+		) -> DispatchResultWithPostInfo {
+			ensure_none(origin)?;
+			Self::dispatch_passkey_call(payload.passkey_call)
 		}
 	}
 
@@ -197,23 +213,20 @@ pub mod module {
 		/// The majority of these checks are the same as `SignedExtra` list in defined in runtime
 		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
 			let valid_tx = ValidTransaction::default();
-			let (payload, is_legacy_call) = Self::filter_valid_calls(call)?;
+			let signature_check = Self::filter_valid_calls(call)?;
+			let passkey_call = signature_check.passkey_call.clone();
 
 			let frame_system_validity =
-				FrameSystemChecks(payload.passkey_call.account_id.clone(), call.clone())
-					.validate()?;
-			let nonce_validity = PasskeyNonceCheck::new(payload.passkey_call.clone()).validate()?;
+				FrameSystemChecks(passkey_call.account_id.clone(), call.clone()).validate()?;
+			let nonce_validity = PasskeyNonceCheck::new(passkey_call.clone()).validate()?;
 			let weight_validity =
-				PasskeyWeightCheck::new(payload.passkey_call.account_id.clone(), call.clone())
+				PasskeyWeightCheck::new(passkey_call.account_id.clone(), call.clone())
+					.validate()?;
+			let tx_payment_validity =
+				ChargeTransactionPayment::<T>(passkey_call.account_id.clone(), call.clone())
 					.validate()?;
-			let tx_payment_validity = ChargeTransactionPayment::<T>(
-				payload.passkey_call.account_id.clone(),
-				call.clone(),
-			)
-			.validate()?;
 			// this is the last since it is the heaviest
-			let signature_validity =
-				PasskeySignatureCheck::new(payload.clone(), is_legacy_call).validate()?;
+			let signature_validity = signature_check.validate()?;
 
 			let valid_tx = valid_tx
 				.combine_with(frame_system_validity)
@@ -227,16 +240,16 @@ pub mod module {
 		/// Checking and executing a list of operations pre_dispatch
 		/// The majority of these checks are the same as `SignedExtra` list in defined in runtime
 		fn pre_dispatch(call: &Self::Call) -> Result<(), TransactionValidityError> {
-			let (payload, is_legacy_call) = Self::filter_valid_calls(call)?;
-			FrameSystemChecks(payload.passkey_call.account_id.clone(), call.clone())
+			let signature_check = Self::filter_valid_calls(call)?;
+			let passkey_call = signature_check.passkey_call.clone();
+			FrameSystemChecks(passkey_call.account_id.clone(), call.clone()).pre_dispatch()?;
+			PasskeyNonceCheck::new(passkey_call.clone()).pre_dispatch()?;
+			PasskeyWeightCheck::new(passkey_call.account_id.clone(), call.clone())
 				.pre_dispatch()?;
-			PasskeyNonceCheck::new(payload.passkey_call.clone()).pre_dispatch()?;
-			PasskeyWeightCheck::new(payload.passkey_call.account_id.clone(), call.clone())
-				.pre_dispatch()?;
-			ChargeTransactionPayment::<T>(payload.passkey_call.account_id.clone(), call.clone())
+			ChargeTransactionPayment::<T>(passkey_call.account_id.clone(), call.clone())
 				.pre_dispatch()?;
 			// this is the last since it is the heaviest
-			PasskeySignatureCheck::new(payload.clone(), is_legacy_call).pre_dispatch()
+			signature_check.pre_dispatch()
 		}
 	}
 }
@@ -247,23 +260,42 @@ where
 	<T as frame_system::Config>::RuntimeCall:
 		From<Call<T>> + Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
 {
-	/// Filtering the valid calls and extracting the Payload V2 from inside the call and returning if this
-	/// is a legacy call or not
+	/// Filtering the valid calls and building the signature check for the payload inside the call
 	fn filter_valid_calls(
 		call: &Call<T>,
-	) -> Result<(PasskeyPayloadV2<T>, bool), TransactionValidityError> {
+	) -> Result<PasskeySignatureCheck<T>, TransactionValidityError> {
 		match call {
 			Call::proxy { payload }
 				if T::PasskeyCallFilter::contains(&payload.clone().passkey_call.call) =>
-				Ok((payload.clone().into(), true)),
+				Ok(PasskeySignatureCheck::from_v2(payload.clone().into(), true)),
 			Call::proxy_v2 { payload }
 				if T::PasskeyCallFilter::contains(&payload.clone().passkey_call.call) =>
-				Ok((payload.clone(), false)),
+				Ok(PasskeySignatureCheck::from_v2(payload.clone(), false)),
+			Call::proxy_v3 { payload }
+				if T::PasskeyCallFilter::contains(&payload.clone().passkey_call.call) =>
+				Ok(PasskeySignatureCheck::from_v3(payload.clone())),
 			_ => Err(InvalidTransaction::Call.into()),
 		}
 	}
 }
 
+impl<T: Config> Pallet<T> {
+	/// Dispatches the inner call with the origin set to the account inside the passkey call
+	fn dispatch_passkey_call(passkey_call: PasskeyCallV2<T>) -> DispatchResultWithPostInfo {
+		let transaction_account_id = passkey_call.account_id.clone();
+		let main_origin =
+			T::RuntimeOrigin::from(frame_system::RawOrigin::Signed(transaction_account_id.clone()));
+		let result = passkey_call.call.dispatch(main_origin);
+		if let Ok(_inner) = result {
+			// all post-dispatch logic should be included in here
+			Self::deposit_event(Event::TransactionExecutionSuccess {
+				account_id: transaction_account_id,
+			});
+		}
+		result
+	}
+}
+
 /// Passkey specific nonce check which is a wrapper around `CheckNonce` extension
 #[derive(Encode, Decode, Clone, TypeInfo)]
 #[scale_info(skip_type_params(T))]
@@ -294,37 +326,88 @@ where
 	}
 }
 
-/// Passkey signatures check which verifies 2 signatures
-/// 1. Account signature of the P256 public key
-/// 2. Passkey P256 signature of the account public key
+/// How the account inside a passkey call is proven to be controlled by the passkey
+#[derive(Encode, Decode, Clone, TypeInfo)]
+enum AccountOwnership {
+	/// Account signature over the passkey public key (legacy payload encoding)
+	LegacyProof(MultiSignature),
+	/// Account signature over the passkey public key
+	Proof(MultiSignature),
+	/// The account is derived from the passkey public key and registered to an MSA
+	RegisteredPasskey,
+}
+
+/// Passkey signatures check which verifies
+/// 1. Ownership of the account: either an account signature of the P256 public key or
+///    the account being a passkey account registered to an MSA
+/// 2. Passkey P256 signature of the passkey call
 #[derive(Encode, Decode, Clone, TypeInfo)]
 #[scale_info(skip_type_params(T))]
 struct PasskeySignatureCheck<T: Config> {
-	payload: PasskeyPayloadV2<T>,
-	is_legacy_payload: bool,
+	passkey_public_key: PasskeyPublicKey,
+	verifiable_passkey_signature: VerifiablePasskeySignature,
+	passkey_call: PasskeyCallV2<T>,
+	account_ownership: AccountOwnership,
 }
 
 impl<T: Config> PasskeySignatureCheck<T> {
-	pub fn new(passkey_payload: PasskeyPayloadV2<T>, is_legacy_payload: bool) -> Self {
-		Self { payload: passkey_payload, is_legacy_payload }
+	pub fn from_v2(passkey_payload: PasskeyPayloadV2<T>, is_legacy_payload: bool) -> Self {
+		let account_ownership = match is_legacy_payload {
+			true => AccountOwnership::LegacyProof(passkey_payload.account_ownership_proof),
+			false => AccountOwnership::Proof(passkey_payload.account_ownership_proof),
+		};
+		Self {
+			passkey_public_key: passkey_payload.passkey_public_key,
+			verifiable_passkey_signature: passkey_payload.verifiable_passkey_signature,
+			passkey_call: passkey_payload.passkey_call,
+			account_ownership,
+		}
 	}
 
-	pub fn validate(&self) -> TransactionValidity {
-		// checking account signature to verify ownership of the account used
-		let signed_data = self.payload.passkey_public_key.clone();
-		let signature = self.payload.account_ownership_proof.clone();
-		let signer = &self.payload.passkey_call.account_id;
+	pub fn from_v3(passkey_payload: PasskeyPayloadV3<T>) -> Self {
+		Self {
+			passkey_public_key: passkey_payload.passkey_public_key,
+			verifiable_passkey_signature: passkey_payload.verifiable_passkey_signature,
+			passkey_call: passkey_payload.passkey_call,
+			account_ownership: AccountOwnership::RegisteredPasskey,
+		}
+	}
 
-		Self::check_account_signature(signer, &signed_data, &signature)
-			.map_err(|_e| TransactionValidityError::Invalid(InvalidTransaction::BadSigner))?;
+	pub fn validate(&self) -> TransactionValidity {
+		// checking ownership of the account used
+		let signer = &self.passkey_call.account_id;
+		let p256_signed_data = match &self.account_ownership {
+			AccountOwnership::LegacyProof(signature) => {
+				Self::check_account_signature(signer, &self.passkey_public_key, signature)
+					.map_err(|_e| {
+						TransactionValidityError::Invalid(InvalidTransaction::BadSigner)
+					})?;
+				PasskeyCall::<T> {
+					account_id: signer.clone(),
+					account_nonce: self.passkey_call.account_nonce,
+					account_ownership_proof: signature.clone(),
+					call: self.passkey_call.call.clone(),
+				}
+				.encode()
+			},
+			AccountOwnership::Proof(signature) => {
+				Self::check_account_signature(signer, &self.passkey_public_key, signature)
+					.map_err(|_e| {
+						TransactionValidityError::Invalid(InvalidTransaction::BadSigner)
+					})?;
+				self.passkey_call.encode()
+			},
+			AccountOwnership::RegisteredPasskey => {
+				Self::check_registered_passkey(signer, &self.passkey_public_key).map_err(|_e| {
+					TransactionValidityError::Invalid(InvalidTransaction::BadSigner)
+				})?;
+				self.passkey_call.encode()
+			},
+		};
 
 		// checking the passkey signature to ensure access to the passkey
-		let p256_signed_data = match self.is_legacy_payload {
-			true => PasskeyPayload::from(self.payload.clone()).passkey_call.encode(),
-			false => self.payload.passkey_call.encode(),
-		};
-		let p256_signature = self.payload.verifiable_passkey_signature.clone();
-		let p256_signer = self.payload.passkey_public_key.clone();
+		let p256_signature = self.verifiable_passkey_signature.clone();
+		let p256_signer = self.passkey_public_key.clone();
 
 		p256_signature
 			.try_verify(&p256_signed_data, &p256_signer)
@@ -368,6 +451,22 @@ impl<T: Config> PasskeySignatureCheck<T> {
 
 		Ok(())
 	}
+
+	/// Check that the account id is the account controlled by the passkey public key and
+	/// that it is registered as a key of an MSA
+	/// Returns Err(InvalidAccountSignature) otherwise
+	fn check_registered_passkey(
+		account_id: &T::AccountId,
+		passkey_public_key: &PasskeyPublicKey,
+	) -> DispatchResult {
+		let key = T::ConvertIntoAccountId32::convert(account_id.clone());
+		ensure!(key == passkey_public_key.to_account_id32(), Error::<T>::InvalidAccountSignature);
+		ensure!(
+			T::MsaInfoProvider::get_msa_id(account_id).is_some(),
+			Error::<T>::InvalidAccountSignature
+		);
+		Ok(())
+	}
 }
 
 /// Passkey related tx payment
@@ -13,7 +13,11 @@ use sp_runtime::{
 	BuildStorage, SaturatedConversion,
 };
 
-use common_primitives::node::AccountId;
+use common_primitives::{
+	msa::{MessageSourceId, MsaLookup},
+	node::AccountId,
+};
+use core::cell::RefCell;
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -94,12 +98,39 @@ impl pallet_transaction_payment::Config for Test {
 	type WeightInfo = ();
 }
 
+thread_local! {
+	static MSA_KEYS: RefCell<Vec<(AccountId, MessageSourceId)>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Mock MSA lookup backed by a thread local list of registered keys
+pub struct MockMsaLookup;
+
+impl MockMsaLookup {
+	/// Registers the key to the given MSA
+	pub fn add_key(key: AccountId, msa_id: MessageSourceId) {
+		MSA_KEYS.with(|keys| keys.borrow_mut().push((key, msa_id)));
+	}
+}
+
+impl MsaLookup for MockMsaLookup {
+	type AccountId = AccountId;
+
+	fn get_msa_id(key: &Self::AccountId) -> Option<MessageSourceId> {
+		MSA_KEYS.with(|keys| keys.borrow().iter().find(|(k, _)| k == key).map(|(_, id)| *id))
+	}
+
+	fn get_max_msa_id() -> MessageSourceId {
+		MSA_KEYS.with(|keys| keys.borrow().iter().map(|(_, id)| *id).max().unwrap_or_default())
+	}
+}
+
 impl pallet_passkey::Config for Test {
 	type RuntimeEvent = RuntimeEvent;
 	type WeightInfo = ();
 	type RuntimeCall = RuntimeCall;
 	type ConvertIntoAccountId32 = ConvertInto;
 	type PasskeyCallFilter = MockPasskeyCallFilter;
+	type MsaInfoProvider = MockMsaLookup;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Currency = Balances;
 }
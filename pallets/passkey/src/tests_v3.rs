@@ -0,0 +1,205 @@
+//! Unit tests for passkeys registered as MSA keys.
+use super::*;
+use crate::mock::Passkey;
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin};
+use frame_system::Call as SystemCall;
+use mock::*;
+
+use crate::test_common::{
+	constants::{AUTHENTICATOR_DATA, REPLACED_CLIENT_DATA_JSON},
+	utilities::*,
+};
+use sp_runtime::DispatchError::BadOrigin;
+
+struct TestPasskeyPayloadV3Builder {
+	secret: p256::SecretKey,
+	account_id: Option<AccountId32>,
+	call: <Test as Config>::RuntimeCall,
+	invalid_passkey_signature: bool,
+}
+
+impl TestPasskeyPayloadV3Builder {
+	pub fn new() -> Self {
+		Self {
+			secret: p256::SecretKey::from_slice(&[
+				1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8, 1, 2, 3, 4, 5, 6, 7, 8,
+			])
+			.unwrap(),
+			account_id: None,
+			call: RuntimeCall::System(SystemCall::remark { remark: vec![1, 2, 3u8] }),
+			invalid_passkey_signature: false,
+		}
+	}
+
+	pub fn passkey_account(&self) -> AccountId32 {
+		get_p256_public_key(&self.secret).unwrap().to_account_id32()
+	}
+
+	pub fn with_account(mut self, account_id: AccountId32) -> Self {
+		self.account_id = Some(account_id);
+		self
+	}
+
+	pub fn with_registered_passkey(self) -> Self {
+		MockMsaLookup::add_key(self.passkey_account(), 1);
+		self
+	}
+
+	pub fn with_invalid_passkey_signature(mut self) -> Self {
+		self.invalid_passkey_signature = true;
+		self
+	}
+
+	pub fn with_funded_account(self, amount: u64) -> Self {
+		let account = self.account_id.clone().unwrap_or_else(|| self.passkey_account());
+		assert_ok!(Balances::force_set_balance(RawOrigin::Root.into(), account, amount));
+		self
+	}
+
+	pub fn build(&self) -> PasskeyPayloadV3<Test> {
+		let client_data = base64_url::decode(REPLACED_CLIENT_DATA_JSON).unwrap();
+		let authenticator = base64_url::decode(AUTHENTICATOR_DATA).unwrap();
+		let bad_authenticator = b"bad_auth".to_vec();
+		let call: PasskeyCallV2<Test> = PasskeyCallV2 {
+			account_id: self.account_id.clone().unwrap_or_else(|| self.passkey_account()),
+			account_nonce: 0u64,
+			call: Box::new(self.call.clone()),
+		};
+		let passkey_signature = passkey_sign(
+			&self.secret,
+			&call.encode(),
+			&client_data,
+			match self.invalid_passkey_signature {
+				true => &bad_authenticator,
+				false => &authenticator,
+			},
+		)
+		.unwrap();
+		PasskeyPayloadV3 {
+			passkey_public_key: get_p256_public_key(&self.secret).unwrap(),
+			verifiable_passkey_signature: VerifiablePasskeySignature {
+				signature: passkey_signature,
+				client_data_json: client_data.try_into().unwrap(),
+				authenticator_data: authenticator.try_into().unwrap(),
+			},
+			passkey_call: call,
+		}
+	}
+}
+
+#[test]
+fn proxy_v3_with_signed_origin_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let builder = TestPasskeyPayloadV3Builder::new().with_registered_passkey();
+		let payload = builder.build();
+
+		// assert
+		assert_noop!(
+			Passkey::proxy_v3(RuntimeOrigin::signed(builder.passkey_account()), payload),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn proxy_v3_with_unsigned_origin_should_work() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let payload = TestPasskeyPayloadV3Builder::new().with_registered_passkey().build();
+
+		// assert
+		assert_ok!(Passkey::proxy_v3(RuntimeOrigin::none(), payload));
+	});
+}
+
+#[test]
+fn validate_unsigned_v3_with_registered_passkey_should_pass() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let payload = TestPasskeyPayloadV3Builder::new()
+			.with_registered_passkey()
+			.with_funded_account(10000000000)
+			.build();
+
+		// act
+		let res =
+			Passkey::validate_unsigned(TransactionSource::InBlock, &Call::proxy_v3 { payload });
+
+		// assert
+		assert!(res.is_ok());
+	});
+}
+
+#[test]
+fn validate_unsigned_v3_with_unregistered_passkey_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let payload = TestPasskeyPayloadV3Builder::new().with_funded_account(10000000000).build();
+
+		// act
+		let res =
+			Passkey::validate_unsigned(TransactionSource::InBlock, &Call::proxy_v3 { payload });
+
+		// assert
+		assert_eq!(res, InvalidTransaction::BadSigner.into());
+	});
+}
+
+#[test]
+fn validate_unsigned_v3_with_account_not_controlled_by_passkey_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let other_account = AccountId32::new([7u8; 32]);
+		MockMsaLookup::add_key(other_account.clone(), 1);
+		let payload = TestPasskeyPayloadV3Builder::new()
+			.with_registered_passkey()
+			.with_account(other_account)
+			.with_funded_account(10000000000)
+			.build();
+
+		// act
+		let res =
+			Passkey::validate_unsigned(TransactionSource::InBlock, &Call::proxy_v3 { payload });
+
+		// assert
+		assert_eq!(res, InvalidTransaction::BadSigner.into());
+	});
+}
+
+#[test]
+fn validate_unsigned_v3_with_bad_passkey_signature_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let payload = TestPasskeyPayloadV3Builder::new()
+			.with_registered_passkey()
+			.with_funded_account(10000000000)
+			.with_invalid_passkey_signature()
+			.build();
+
+		// act
+		let res =
+			Passkey::validate_unsigned(TransactionSource::InBlock, &Call::proxy_v3 { payload });
+
+		// assert
+		assert_eq!(res, InvalidTransaction::BadSigner.into());
+	});
+}
+
+#[test]
+fn pre_dispatch_v3_with_registered_passkey_should_increment_nonce() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let builder = TestPasskeyPayloadV3Builder::new()
+			.with_registered_passkey()
+			.with_funded_account(10000000000);
+		let payload = builder.build();
+
+		// act
+		let res = Passkey::pre_dispatch(&Call::proxy_v3 { payload });
+
+		// assert
+		assert!(res.is_ok());
+		assert_eq!(System::account(builder.passkey_account()).nonce, 1);
+	});
+}
@@ -3,35 +3,55 @@ extern crate alloc;
 use super::*;
 use crate::Pallet as SchemasPallet;
 use alloc::vec;
-use common_primitives::schema::IntentSetting;
+use common_primitives::schema::{IntentSetting, StorageQuota};
 use frame_benchmarking::{v2::*, whitelisted_caller};
 use frame_support::{assert_ok, ensure, BoundedVec};
 use frame_system::RawOrigin;
 use numtoa::NumToA;
+use sp_runtime::Saturating;
 
 fn assert_last_event<T: Config>(generic_event: <T as Config>::RuntimeEvent) {
 	frame_system::Pallet::<T>::assert_last_event(generic_event.into());
 }
 
+/// Generates a valid Avro record with as many fields as fit in `size` bytes
 fn generate_schema<T: Config>(
 	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	generate_record::<T>(size, b"\",\"type\":\"long\"},")
+}
+
+/// Generates a valid Avro record with as many fields with defaults as fit in `size` bytes, so
+/// that records of any size are fully compatible with each other
+fn generate_compatible_schema<T: Config>(
+	size: usize,
+) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
+	generate_record::<T>(size, b"\",\"type\":\"long\",\"default\":0},")
+}
+
+/// Generates an Avro record of fields named `f0`, `f1`, ... each followed by `field_suffix`
+fn generate_record<T: Config>(
+	size: usize,
+	field_suffix: &[u8],
 ) -> BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> {
 	let mut json: Vec<u8> = vec![];
-	json.extend(b"{");
+	json.extend(b"{\"type\":\"record\",\"name\":\"r\",\"fields\":[");
 	for i in 0..size {
 		let mut item: Vec<u8> = vec![];
-		item.extend(b"\"key");
+		item.extend(b"{\"name\":\"f");
 		let mut buff = [0u8; 30];
 		item.extend(i.numtoa(10, &mut buff));
-		item.extend(b"\":\"val\",");
-		if item.len() + json.len() < size {
+		item.extend(field_suffix);
+		if item.len() + json.len() + 2 <= size {
 			json.extend(item);
 		} else {
 			break;
 		}
 	}
-	json.pop(); // removing last ,
-	json.extend(b"}");
+	if json.last() == Some(&b',') {
+		json.pop(); // removing last ,
+	}
+	json.extend(b"]}");
 	json.try_into().unwrap()
 }
 
@@ -69,6 +89,58 @@ fn generate_intents<T: Config>(size: usize) -> BoundedVec<IntentId, T::MaxIntent
 	intents.try_into().unwrap()
 }
 
+/// Creates an Intent whose messages can be pruned
+fn generate_onchain_intent<T: Config>() -> IntentId {
+	let name_payload = BoundedVec::try_from(b"onchain.retained".to_vec()).expect("should convert");
+	let (intent_id, _) = SchemasPallet::<T>::create_intent_for(
+		name_payload,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+	)
+	.expect("should create intent");
+	intent_id
+}
+
+/// Creates an Intent whose stateful storage can be limited
+fn generate_paginated_intent<T: Config>() -> IntentId {
+	let name_payload = BoundedVec::try_from(b"paginated.quota".to_vec()).expect("should convert");
+	let (intent_id, _) = SchemasPallet::<T>::create_intent_for(
+		name_payload,
+		PayloadLocation::Paginated,
+		BoundedVec::default(),
+	)
+	.expect("should create intent");
+	intent_id
+}
+
+/// The namespace owned by the benchmark caller
+fn provider_namespace() -> SchemaNamePayload {
+	BoundedVec::try_from(b"bbb".to_vec()).expect("should convert")
+}
+
+/// A name in the namespace owned by the benchmark caller
+fn provider_name(descriptor: &[u8]) -> SchemaNamePayload {
+	let name: Vec<u8> = b"bbb.".iter().chain(descriptor.iter()).copied().collect();
+	BoundedVec::try_from(name).expect("should convert")
+}
+
+/// Funds the caller, gives it an MSA and makes that MSA the owner of [`provider_namespace`]
+fn set_up_namespace_owner<T: Config>(caller: &T::AccountId) -> Result<(), BenchmarkError> {
+	T::Currency::set_balance(
+		caller,
+		T::Currency::minimum_balance()
+			.saturating_add(T::NamespaceEntityDeposit::get().saturating_mul(100u32.into())),
+	);
+	T::MsaBenchmarkHelper::create_msa(caller.clone())?;
+	let msa_id = T::MsaInfoProvider::get_msa_id(caller).ok_or("caller should have an MSA")?;
+	assert_ok!(SchemasPallet::<T>::set_namespace_owner_via_governance(
+		RawOrigin::Root.into(),
+		provider_namespace(),
+		Some(ProviderId(msa_id))
+	));
+	Ok(())
+}
+
 #[benchmarks]
 mod benchmarks {
 	use super::*;
@@ -79,6 +151,10 @@ mod benchmarks {
 			{ T::MinSchemaModelSizeBytes::get() + 8 },
 			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
 		>,
+		p: Linear<
+			{ T::MinSchemaModelSizeBytes::get() + 8 },
+			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
+		>,
 	) -> Result<(), BenchmarkError> {
 		let sender: T::AccountId = whitelisted_caller();
 		let model_type = ModelType::AvroBinary;
@@ -86,8 +162,19 @@ mod benchmarks {
 			RawOrigin::Root.into(),
 			T::SchemaModelMaxBytesBoundedVecLimit::get()
 		));
-		let schema_input = generate_schema::<T>(m as usize);
+		let schema_input = generate_compatible_schema::<T>(m as usize);
 		let intent_id = generate_intents::<T>(1)[0];
+		// Worst case: the new schema is checked in both directions against a previous schema
+		SchemasPallet::<T>::create_schema_for(
+			intent_id,
+			generate_compatible_schema::<T>(p as usize),
+			model_type,
+		)?;
+		assert_ok!(SchemasPallet::<T>::set_intent_compatibility_policy_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			CompatibilityPolicy::Full
+		));
 
 		#[extrinsic_call]
 		create_schema_v4(RawOrigin::Signed(sender), intent_id, schema_input, model_type);
@@ -96,7 +183,7 @@ mod benchmarks {
 			CurrentSchemaIdentifierMaximum::<T>::get() > 0,
 			"Created schema count should be > 0"
 		);
-		ensure!(SchemaInfos::<T>::get(1).is_some(), "Created schema should exist");
+		ensure!(SchemaInfos::<T>::get(2).is_some(), "Created schema should exist");
 		Ok(())
 	}
 
@@ -122,6 +209,10 @@ mod benchmarks {
 			{ T::MinSchemaModelSizeBytes::get() + 8 },
 			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
 		>,
+		p: Linear<
+			{ T::MinSchemaModelSizeBytes::get() + 8 },
+			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
+		>,
 	) -> Result<(), BenchmarkError> {
 		let sender: T::AccountId = whitelisted_caller();
 		let model_type = ModelType::AvroBinary;
@@ -129,8 +220,19 @@ mod benchmarks {
 			RawOrigin::Root.into(),
 			T::SchemaModelMaxBytesBoundedVecLimit::get()
 		));
-		let schema_input = generate_schema::<T>(m as usize);
+		let schema_input = generate_compatible_schema::<T>(m as usize);
 		let intent_id = generate_intents::<T>(1)[0];
+		// Worst case: the new schema is checked in both directions against a previous schema
+		SchemasPallet::<T>::create_schema_for(
+			intent_id,
+			generate_compatible_schema::<T>(p as usize),
+			model_type,
+		)?;
+		assert_ok!(SchemasPallet::<T>::set_intent_compatibility_policy_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			CompatibilityPolicy::Full
+		));
 
 		#[extrinsic_call]
 		create_schema_via_governance_v3(
@@ -145,7 +247,7 @@ mod benchmarks {
 			CurrentSchemaIdentifierMaximum::<T>::get() > 0,
 			"Created schema count should be > 0"
 		);
-		ensure!(SchemaInfos::<T>::get(1).is_some(), "Created schema should exist");
+		ensure!(SchemaInfos::<T>::get(2).is_some(), "Created schema should exist");
 		Ok(())
 	}
 
@@ -444,6 +546,355 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn update_schema_status_via_governance() -> Result<(), BenchmarkError> {
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(
+			RawOrigin::Root.into(),
+			T::SchemaModelMaxBytesBoundedVecLimit::get()
+		));
+		let intent_id = generate_intents::<T>(1)[0];
+		// Worst case: the schema is unlinked from supported schemas before and after it
+		let [_, schema_id, _] = [0; 3].map(|_| {
+			SchemasPallet::<T>::create_schema_for(
+				intent_id,
+				generate_schema::<T>(T::MinSchemaModelSizeBytes::get() as usize + 8),
+				ModelType::AvroBinary,
+			)
+		});
+		let schema_id = schema_id?;
+
+		#[extrinsic_call]
+		update_schema_status_via_governance(RawOrigin::Root, schema_id, SchemaStatus::Unsupported);
+
+		assert_last_event::<T>(
+			Event::<T>::SchemaStatusUpdated { schema_id, status: SchemaStatus::Unsupported }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_to_update_schema_status() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(
+			RawOrigin::Root.into(),
+			T::SchemaModelMaxBytesBoundedVecLimit::get()
+		));
+		let intent_id = generate_intents::<T>(1)[0];
+		let schema_id = SchemasPallet::<T>::create_schema_for(
+			intent_id,
+			generate_schema::<T>(T::MinSchemaModelSizeBytes::get() as usize + 8),
+			ModelType::AvroBinary,
+		)?;
+
+		#[extrinsic_call]
+		propose_to_update_schema_status(
+			RawOrigin::Signed(sender),
+			schema_id,
+			SchemaStatus::Deprecated,
+		);
+
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_intent_compatibility_policy_via_governance() -> Result<(), BenchmarkError> {
+		let intent_id = generate_intents::<T>(1)[0];
+
+		#[extrinsic_call]
+		set_intent_compatibility_policy_via_governance(
+			RawOrigin::Root,
+			intent_id,
+			CompatibilityPolicy::Full,
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::IntentCompatibilityPolicyUpdated {
+				intent_id,
+				policy: CompatibilityPolicy::Full,
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_to_set_intent_compatibility_policy() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		let intent_id = generate_intents::<T>(1)[0];
+
+		#[extrinsic_call]
+		propose_to_set_intent_compatibility_policy(
+			RawOrigin::Signed(sender),
+			intent_id,
+			CompatibilityPolicy::Full,
+		);
+
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_namespace_owner_via_governance() -> Result<(), BenchmarkError> {
+		let namespace = provider_namespace();
+
+		#[extrinsic_call]
+		set_namespace_owner_via_governance(RawOrigin::Root, namespace.clone(), Some(ProviderId(1)));
+
+		assert_last_event::<T>(
+			Event::<T>::NamespaceOwnerUpdated {
+				namespace: namespace.into_inner(),
+				owner: Some(ProviderId(1)),
+			}
+			.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_to_set_namespace_owner() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+
+		#[extrinsic_call]
+		propose_to_set_namespace_owner(
+			RawOrigin::Signed(sender),
+			provider_namespace(),
+			Some(ProviderId(1)),
+		);
+
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_provider_intent(
+		m: Linear<0, { T::MaxSchemaSettingsPerSchema::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		set_up_namespace_owner::<T>(&sender)?;
+		let settings = generate_settings::<T>(m as usize);
+
+		#[extrinsic_call]
+		create_provider_intent(
+			RawOrigin::Signed(sender.clone()),
+			provider_name(b"intent"),
+			PayloadLocation::Paginated,
+			settings,
+		);
+
+		let intent_id = CurrentIntentIdentifierMaximum::<T>::get();
+		ensure!(
+			EntityDeposits::<T>::contains_key(NamespaceEntity::Intent(intent_id)),
+			"Deposit should be held for the Intent"
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_provider_intent_group(
+		m: Linear<0, { T::MaxIntentsPerIntentGroup::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		set_up_namespace_owner::<T>(&sender)?;
+		let intent_ids = generate_intents::<T>(m as usize);
+
+		#[extrinsic_call]
+		create_provider_intent_group(
+			RawOrigin::Signed(sender.clone()),
+			provider_name(b"group"),
+			intent_ids,
+		);
+
+		let intent_group_id = CurrentIntentGroupIdentifierMaximum::<T>::get();
+		ensure!(
+			EntityDeposits::<T>::contains_key(NamespaceEntity::IntentGroup(intent_group_id)),
+			"Deposit should be held for the IntentGroup"
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn create_provider_schema(
+		m: Linear<
+			{ T::MinSchemaModelSizeBytes::get() + 8 },
+			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
+		>,
+		p: Linear<
+			{ T::MinSchemaModelSizeBytes::get() + 8 },
+			{ T::SchemaModelMaxBytesBoundedVecLimit::get() - 1 },
+		>,
+	) -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		let model_type = ModelType::AvroBinary;
+		set_up_namespace_owner::<T>(&sender)?;
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(
+			RawOrigin::Root.into(),
+			T::SchemaModelMaxBytesBoundedVecLimit::get()
+		));
+		let schema_input = generate_compatible_schema::<T>(m as usize);
+		let (intent_id, _) = SchemasPallet::<T>::create_intent_for(
+			provider_name(b"intent"),
+			PayloadLocation::Paginated,
+			BoundedVec::default(),
+		)?;
+		// Worst case: the new schema is checked in both directions against a previous schema
+		SchemasPallet::<T>::create_schema_for(
+			intent_id,
+			generate_compatible_schema::<T>(p as usize),
+			model_type,
+		)?;
+		assert_ok!(SchemasPallet::<T>::set_intent_compatibility_policy_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			CompatibilityPolicy::Full
+		));
+
+		#[extrinsic_call]
+		create_provider_schema(
+			RawOrigin::Signed(sender),
+			provider_name(b"intent"),
+			schema_input,
+			model_type,
+		);
+
+		ensure!(
+			EntityDeposits::<T>::contains_key(NamespaceEntity::Schema(2)),
+			"Deposit should be held for the Schema"
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_provider_intent() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		set_up_namespace_owner::<T>(&sender)?;
+		SchemasPallet::<T>::create_provider_intent(
+			RawOrigin::Signed(sender.clone()).into(),
+			provider_name(b"intent"),
+			PayloadLocation::Paginated,
+			BoundedVec::default(),
+		)?;
+		let intent_id = CurrentIntentIdentifierMaximum::<T>::get();
+
+		#[extrinsic_call]
+		remove_provider_intent(RawOrigin::Signed(sender.clone()), provider_name(b"intent"));
+
+		assert_last_event::<T>(Event::<T>::IntentRemoved { key: sender, intent_id }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn remove_provider_intent_group(
+		m: Linear<0, { T::MaxIntentsPerIntentGroup::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		set_up_namespace_owner::<T>(&sender)?;
+		let intent_ids = generate_intents::<T>(m as usize);
+		SchemasPallet::<T>::create_provider_intent_group(
+			RawOrigin::Signed(sender.clone()).into(),
+			provider_name(b"group"),
+			intent_ids,
+		)?;
+		let intent_group_id = CurrentIntentGroupIdentifierMaximum::<T>::get();
+
+		#[extrinsic_call]
+		remove_provider_intent_group(RawOrigin::Signed(sender.clone()), provider_name(b"group"));
+
+		assert_last_event::<T>(
+			Event::<T>::IntentGroupRemoved { key: sender, intent_group_id }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn update_provider_schema_status() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		set_up_namespace_owner::<T>(&sender)?;
+		assert_ok!(SchemasPallet::<T>::set_max_schema_model_bytes(
+			RawOrigin::Root.into(),
+			T::SchemaModelMaxBytesBoundedVecLimit::get()
+		));
+		SchemasPallet::<T>::create_intent_for(
+			provider_name(b"intent"),
+			PayloadLocation::Paginated,
+			BoundedVec::default(),
+		)?;
+		for _ in 0..3 {
+			SchemasPallet::<T>::create_provider_schema(
+				RawOrigin::Signed(sender.clone()).into(),
+				provider_name(b"intent"),
+				generate_schema::<T>(T::MinSchemaModelSizeBytes::get() as usize + 8),
+				ModelType::AvroBinary,
+			)?;
+		}
+		let schema_id = CurrentSchemaIdentifierMaximum::<T>::get() - 1;
+
+		// Worst case: the schema becomes Unsupported, its deposit is released and it is unlinked
+		// from supported schemas before and after it
+		#[extrinsic_call]
+		update_provider_schema_status(
+			RawOrigin::Signed(sender),
+			provider_name(b"intent"),
+			schema_id,
+			SchemaStatus::Unsupported,
+		);
+
+		assert_last_event::<T>(
+			Event::<T>::SchemaStatusUpdated { schema_id, status: SchemaStatus::Unsupported }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_intent_retention_via_governance() -> Result<(), BenchmarkError> {
+		let intent_id = generate_onchain_intent::<T>();
+
+		#[extrinsic_call]
+		set_intent_retention_via_governance(RawOrigin::Root, intent_id, Some(100));
+
+		assert_last_event::<T>(
+			Event::<T>::IntentRetentionUpdated { intent_id, retention: Some(100) }.into(),
+		);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_to_set_intent_retention() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		let intent_id = generate_onchain_intent::<T>();
+
+		#[extrinsic_call]
+		propose_to_set_intent_retention(RawOrigin::Signed(sender), intent_id, Some(100));
+
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_intent_storage_quota_via_governance() -> Result<(), BenchmarkError> {
+		let intent_id = generate_paginated_intent::<T>();
+		let quota = Some(StorageQuota { max_bytes: 1024, max_pages: 4 });
+
+		#[extrinsic_call]
+		set_intent_storage_quota_via_governance(RawOrigin::Root, intent_id, quota);
+
+		assert_last_event::<T>(Event::<T>::IntentStorageQuotaUpdated { intent_id, quota }.into());
+		Ok(())
+	}
+
+	#[benchmark]
+	fn propose_to_set_intent_storage_quota() -> Result<(), BenchmarkError> {
+		let sender: T::AccountId = whitelisted_caller();
+		let intent_id = generate_paginated_intent::<T>();
+		let quota = Some(StorageQuota { max_bytes: 1024, max_pages: 4 });
+
+		#[extrinsic_call]
+		propose_to_set_intent_storage_quota(RawOrigin::Signed(sender), intent_id, quota);
+
+		assert_eq!(T::ProposalProvider::proposal_count(), 1);
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		SchemasPallet,
 		crate::tests::mock::new_test_ext(),
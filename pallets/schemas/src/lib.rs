@@ -26,23 +26,36 @@
 )]
 
 extern crate alloc;
-use alloc::{boxed::Box, vec, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeSet, vec, vec::Vec};
 use common_primitives::{
-	node::ProposalProvider,
-	parquet::ParquetModel,
+	avro::{check_avro_compatibility, parse_avro_schema, validate_avro_datum},
+	msa::{MsaLookup, ProviderId},
+	node::{BlockNumber, ProposalProvider},
+	parquet::{check_parquet_compatibility, parse_parquet_model, ParquetModel},
 	schema::{
-		IntentGroupId, IntentGroupResponse, IntentId, IntentResponse, IntentSetting,
-		IntentSettings, MappedEntityIdentifier, ModelType, NameLookupResponse, PayloadLocation,
-		SchemaId, SchemaInfoResponse, SchemaProvider, SchemaStatus, SchemaValidator,
-		SchemaVersionResponse,
+		CompatibilityPolicy, EntityPaginationRequest, EntityPaginationResponse, IntentGroupId,
+		IntentGroupResponse, IntentId, IntentResponse, IntentResponseV2, IntentRetention,
+		IntentSchemaResponse, IntentSetting, IntentSettings, MappedEntityIdentifier, ModelType,
+		NameLookupResponse, PayloadLocation, PayloadValidationError, SchemaId, SchemaInfoResponse,
+		SchemaProvider, SchemaStatus, SchemaValidator, SchemaVersionResponse,
+		SchemaVersionResponseV2, StorageQuota,
 	},
 };
 use frame_support::{
 	dispatch::{DispatchResult, PostDispatchInfo},
 	ensure,
-	traits::{BuildGenesisConfig, Get},
+	traits::{
+		tokens::{
+			fungible::{Inspect as InspectFungible, Mutate, MutateHold},
+			Precision,
+		},
+		BuildGenesisConfig, Get,
+	},
+};
+use sp_runtime::{
+	traits::{Dispatchable, SaturatedConversion},
+	BoundedVec, DispatchError,
 };
-use sp_runtime::{traits::Dispatchable, BoundedVec, DispatchError};
 
 #[cfg(test)]
 mod tests;
@@ -50,7 +63,7 @@ mod tests;
 #[cfg(feature = "runtime-benchmarks")]
 mod benchmarking;
 #[cfg(feature = "runtime-benchmarks")]
-use common_primitives::benchmarks::SchemaBenchmarkHelper;
+use common_primitives::benchmarks::{MsaBenchmarkHelper, SchemaBenchmarkHelper};
 use common_primitives::schema::SchemaResponseV2;
 
 mod types;
@@ -62,7 +75,6 @@ pub use weights::*;
 
 /// Storage migrations
 pub mod migration;
-mod serde;
 
 #[frame_support::pallet]
 pub mod pallet {
@@ -106,6 +118,37 @@ pub mod pallet {
 		/// Maximum number of schema settings that can be registered per schema (if any)
 		#[pallet::constant]
 		type MaxSchemaSettingsPerSchema: Get<u32>;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Functions that allow a fungible balance to be held for namespace entity deposits.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ Mutate<Self::AccountId>
+			+ InspectFungible<Self::AccountId>;
+
+		/// The deposit held from a namespace owner for each Intent, IntentGroup or Schema it creates
+		#[pallet::constant]
+		type NamespaceEntityDeposit: Get<BalanceOf<Self>>;
+
+		/// A type that will supply MSA related information
+		type MsaInfoProvider: MsaLookup<AccountId = Self::AccountId>;
+
+		/// Maximum number of Intents that can have a message retention period
+		#[pallet::constant]
+		type MaxIntentsWithRetention: Get<u32>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type MsaBenchmarkHelper: MsaBenchmarkHelper<Self::AccountId>;
+	}
+
+	/// A reason for holding funds
+	/// Creates a hold reason for this pallet that is aggregated by `construct_runtime`.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds held as a deposit for an entity created in an owned namespace
+		NamespaceEntityDeposit,
 	}
 
 	#[pallet::event]
@@ -166,6 +209,69 @@ pub mod pallet {
 			/// IntentGroupId of the IntentGroup that was updated
 			intent_group_id: IntentGroupId,
 		},
+
+		/// Emitted when the status of a schema is changed
+		SchemaStatusUpdated {
+			/// Schema ID of the updated schema
+			schema_id: SchemaId,
+
+			/// The new status of the schema
+			status: SchemaStatus,
+		},
+
+		/// Emitted when the compatibility policy of an Intent is changed
+		IntentCompatibilityPolicyUpdated {
+			/// Intent ID of the updated Intent
+			intent_id: IntentId,
+
+			/// The new compatibility policy of the Intent
+			policy: CompatibilityPolicy,
+		},
+
+		/// Emitted when the owner of a namespace is set or cleared
+		NamespaceOwnerUpdated {
+			/// The namespace (ASCII bytes)
+			namespace: Vec<u8>,
+
+			/// The provider that owns the namespace, if any
+			owner: Option<ProviderId>,
+		},
+
+		/// Emitted when an Intent is removed by the owner of its namespace
+		IntentRemoved {
+			/// Account ID that removed the Intent
+			key: T::AccountId,
+
+			/// IntentId of the removed Intent
+			intent_id: IntentId,
+		},
+
+		/// Emitted when an IntentGroup is removed by the owner of its namespace
+		IntentGroupRemoved {
+			/// Account ID that removed the IntentGroup
+			key: T::AccountId,
+
+			/// IntentGroupId of the removed IntentGroup
+			intent_group_id: IntentGroupId,
+		},
+
+		/// Emitted when the message retention period of an Intent is set or cleared
+		IntentRetentionUpdated {
+			/// Intent ID of the updated Intent
+			intent_id: IntentId,
+
+			/// The new retention period in blocks; `None` if messages are retained forever
+			retention: Option<BlockNumber>,
+		},
+
+		/// Emitted when the stateful storage quota of an Intent is set or cleared
+		IntentStorageQuotaUpdated {
+			/// Intent ID of the updated Intent
+			intent_id: IntentId,
+
+			/// The new quota; `None` if the storage of the Intent is not limited
+			quota: Option<StorageQuota>,
+		},
 	}
 
 	#[derive(PartialEq, Eq)] // for testing
@@ -236,6 +342,129 @@ pub mod pallet {
 
 		/// Too many intents in the group
 		TooManyIntentsInGroup,
+
+		/// A schema can only move forward from `Active` to `Deprecated` to `Unsupported`
+		InvalidSchemaStatusTransition,
+
+		/// Avro model is not valid JSON
+		InvalidAvroJson,
+
+		/// Avro schema is not a JSON string, object or array, or has an invalid `type`
+		InvalidAvroType,
+
+		/// Avro type name does not refer to a primitive or a previously defined named type
+		UnknownAvroType,
+
+		/// Avro schema is missing a required attribute
+		MissingAvroAttribute,
+
+		/// Avro attribute has the wrong JSON type or an unsupported value
+		InvalidAvroAttribute,
+
+		/// Avro name, namespace or alias does not follow the Avro naming rules
+		InvalidAvroName,
+
+		/// Avro named type is defined more than once
+		DuplicateAvroName,
+
+		/// Avro record has two fields with the same name
+		DuplicateAvroFieldName,
+
+		/// Avro enum symbols are not unique valid names
+		InvalidAvroEnumSymbols,
+
+		/// Avro fixed size is not a non-negative integer
+		InvalidAvroFixedSize,
+
+		/// Avro union is empty, nested or contains the same type more than once
+		InvalidAvroUnion,
+
+		/// Avro default value does not match its type
+		InvalidAvroDefault,
+
+		/// Avro types are nested too deeply
+		AvroNestingTooDeep,
+
+		/// New schema has a different model type than the previous schema of the Intent
+		IncompatibleSchemaModelType,
+
+		/// Avro type can not be resolved against the previous schema, even with type promotion
+		IncompatibleAvroType,
+
+		/// Avro named type has a different name than in the previous schema
+		IncompatibleAvroName,
+
+		/// Avro record field is missing from one schema and has no default in the other
+		IncompatibleAvroFieldDefault,
+
+		/// Avro enum symbol is missing from one schema and the other has no default symbol
+		IncompatibleAvroEnumSymbol,
+
+		/// Avro fixed type has a different size than in the previous schema
+		IncompatibleAvroFixedSize,
+
+		/// Avro type matches no branch of a union in the other schema
+		IncompatibleAvroUnion,
+
+		/// Required Parquet column is missing from one model
+		IncompatibleParquetMissingColumn,
+
+		/// Parquet column has a different type than in the previous model
+		IncompatibleParquetColumnType,
+
+		/// Required Parquet column is optional in the other model
+		IncompatibleParquetOptionalColumn,
+
+		/// Parquet model has no columns or too many columns
+		InvalidParquetColumnCount,
+
+		/// Parquet column name is empty or contains reserved characters
+		InvalidParquetColumnName,
+
+		/// Parquet column name is used more than once
+		DuplicateParquetColumnName,
+
+		/// Parquet integer bit width is not 8, 16, 32 or 64
+		InvalidParquetIntegerWidth,
+
+		/// Parquet decimal precision is zero or smaller than its scale
+		InvalidParquetDecimal,
+
+		/// Parquet type can not be written as declared
+		UnsupportedParquetType,
+
+		/// Parquet bloom filter is not supported for the column type
+		UnsupportedParquetBloomFilter,
+
+		/// Parquet compression codec is not supported
+		UnsupportedParquetCompressionCodec,
+
+		/// The caller's MSA does not own the namespace
+		NamespaceNotOwned,
+
+		/// The entity was not created by a namespace owner and can not be removed
+		EntityNotRemovable,
+
+		/// The Intent can not be removed because it has schemas
+		IntentHasSchemas,
+
+		/// The Intent can not be removed because it belongs to an IntentGroup
+		IntentInIntentGroup,
+
+		/// The retention period must be at least one block
+		InvalidRetentionPeriod,
+
+		/// Only Intents with an `OnChain` or `IPFS` payload location can have a retention period
+		RetentionNotSupported,
+
+		/// The maximum number of Intents with a retention period has been reached
+		TooManyIntentsWithRetention,
+
+		/// A storage quota must allow at least one page and one byte
+		InvalidStorageQuota,
+
+		/// Only Intents with an `Itemized` or `Paginated` payload location can have a storage quota
+		StorageQuotaNotSupported,
 	}
 
 	#[pallet::pallet]
@@ -312,6 +541,75 @@ pub mod pallet {
 	pub(super) type IntentGroups<T: Config> =
 		StorageMap<_, Twox64Concat, IntentGroupId, IntentGroup<T>, OptionQuery>;
 
+	/// Storage for the compatibility policy of Intents; Intents without an entry are not checked
+	/// - Key: [`IntentId`]
+	/// - Value: [`CompatibilityPolicy`]
+	#[pallet::storage]
+	pub(super) type IntentCompatibilityPolicies<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, CompatibilityPolicy, ValueQuery>;
+
+	/// Storage for the message retention period of Intents, in blocks; messages of Intents
+	/// without an entry are retained forever
+	/// - Key: [`IntentId`]
+	/// - Value: [`IntentRetention`]
+	#[pallet::storage]
+	pub(super) type IntentRetentionPeriods<T: Config> =
+		CountedStorageMap<_, Twox64Concat, IntentId, IntentRetention, OptionQuery>;
+
+	/// Storage for the stateful storage quota of Intents, applied to each MSA separately;
+	/// the storage of Intents without an entry is not limited
+	/// - Key: [`IntentId`]
+	/// - Value: [`StorageQuota`]
+	#[pallet::storage]
+	pub(super) type IntentStorageQuotas<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, StorageQuota, OptionQuery>;
+
+	/// Storage for the most recently registered schema of each Intent
+	/// - Key: [`IntentId`]
+	/// - Value: [`SchemaId`]
+	#[pallet::storage]
+	pub(super) type IntentLatestSchemaIds<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, SchemaId, OptionQuery>;
+
+	/// Storage for the most recent `Active` or `Deprecated` schema of each Intent, against which
+	/// new schemas are checked for compatibility
+	/// - Key: [`IntentId`]
+	/// - Value: [`SchemaId`]
+	#[pallet::storage]
+	pub(super) type IntentLatestSupportedSchemaIds<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, SchemaId, OptionQuery>;
+
+	/// Storage linking the `Active` and `Deprecated` schemas of each Intent in order, so that the
+	/// latest of them is known again once it becomes `Unsupported`
+	/// - Key: [`SchemaId`]
+	/// - Value: [`SupportedSchemaLink`]
+	#[pallet::storage]
+	pub(super) type SupportedSchemaLinks<T: Config> =
+		StorageMap<_, Twox64Concat, SchemaId, SupportedSchemaLink, OptionQuery>;
+
+	/// Storage for the provider that owns each namespace
+	/// - Key: Protocol Name
+	/// - Value: [`ProviderId`]
+	#[pallet::storage]
+	pub(super) type NamespaceOwners<T: Config> =
+		StorageMap<_, Blake2_128Concat, SchemaProtocolName, ProviderId, OptionQuery>;
+
+	/// Storage for the deposits held for entities created by namespace owners
+	/// - Key: [`NamespaceEntity`]
+	/// - Value: [`EntityDeposit`]
+	#[pallet::storage]
+	pub(super) type EntityDeposits<T: Config> =
+		StorageMap<_, Twox64Concat, NamespaceEntity, EntityDeposit<T>, OptionQuery>;
+
+	/// Storage for the number of IntentGroups each Intent belongs to
+	/// Counts are kept from the first write of each IntentGroup after this storage was added, which
+	/// is exact for all Intents created by namespace owners
+	/// - Key: [`IntentId`]
+	/// - Value: Number of IntentGroups
+	#[pallet::storage]
+	pub(super) type IntentGroupMemberships<T: Config> =
+		StorageMap<_, Twox64Concat, IntentId, u16, ValueQuery>;
+
 	#[pallet::genesis_config]
 	pub struct GenesisConfig<T: Config> {
 		/// Maximum schema identifier at genesis
@@ -785,23 +1083,31 @@ pub mod pallet {
 		/// # Errors
 		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
 		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
-		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
+		/// * [`Error::InvalidSchema`] - Parquet model is malformed in some way
+		/// * [`Error::InvalidAvroJson`] and the other `Avro` errors - Avro model is malformed
+		/// * [`Error::IncompatibleSchemaModelType`] and the other `Incompatible` errors - The model is incompatible with the previous schema under the Intent's [`CompatibilityPolicy`]
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		///
 		#[pallet::call_index(20)]
-		#[pallet::weight(T::WeightInfo::create_schema_via_governance_v3(model.len() as u32))]
+		#[pallet::weight(T::WeightInfo::create_schema_via_governance_v3(
+			model.len() as u32,
+			T::SchemaModelMaxBytesBoundedVecLimit::get(),
+		))]
 		pub fn create_schema_via_governance_v3(
 			origin: OriginFor<T>,
 			creator_key: T::AccountId,
 			intent_id: IntentId,
 			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
 			model_type: ModelType,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			let model_len = model.len() as u32;
+			let previous_model_len = Self::get_compatibility_model_len(intent_id);
 			let schema_id = Self::create_schema_for(intent_id, model, model_type)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: creator_key, schema_id });
-			Ok(())
+			Ok(Some(T::WeightInfo::create_schema_via_governance_v3(model_len, previous_model_len))
+				.into())
 		}
 
 		/// Adds a given schema to storage. (testnet)
@@ -818,133 +1124,665 @@ pub mod pallet {
 		/// # Errors
 		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
 		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
-		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
+		/// * [`Error::InvalidSchema`] - Parquet model is malformed in some way
+		/// * [`Error::InvalidAvroJson`] and the other `Avro` errors - Avro model is malformed
+		/// * [`Error::IncompatibleSchemaModelType`] and the other `Incompatible` errors - The model is incompatible with the previous schema under the Intent's [`CompatibilityPolicy`]
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		///
 		#[pallet::call_index(21)]
-		#[pallet::weight(T::WeightInfo::create_schema_v4(model.len() as u32))]
+		#[pallet::weight(T::WeightInfo::create_schema_v4(
+			model.len() as u32,
+			T::SchemaModelMaxBytesBoundedVecLimit::get(),
+		))]
 		pub fn create_schema_v4(
 			origin: OriginFor<T>,
 			intent_id: IntentId,
 			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
 			model_type: ModelType,
-		) -> DispatchResult {
+		) -> DispatchResultWithPostInfo {
 			let sender = ensure_signed(origin)?;
 
+			let model_len = model.len() as u32;
+			let previous_model_len = Self::get_compatibility_model_len(intent_id);
 			let schema_id = Self::create_schema_for(intent_id, model, model_type)?;
 
 			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
-			Ok(())
-		}
-	}
-
-	impl<T: Config> Pallet<T> {
-		/// Set the maximum schema id to the supplied value.
-		#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
-		pub fn set_schema_count(n: SchemaId) {
-			<CurrentSchemaIdentifierMaximum<T>>::set(n);
+			Ok(Some(T::WeightInfo::create_schema_v4(model_len, previous_model_len)).into())
 		}
 
-		/// Set the maximum intent id to the supplied value.
-		#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
-		pub fn set_intent_count(n: IntentId) {
-			<CurrentIntentIdentifierMaximum<T>>::set(n);
+		/// Update the status of a schema by means of council approval.
+		/// Writes to a `Deprecated` schema are still accepted but emit a warning event, writes to an
+		/// `Unsupported` schema are rejected.
+		///
+		/// # Events
+		/// * [`Event::SchemaStatusUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema does not exist
+		/// * [`Error::InvalidSchemaStatusTransition`] - The schema cannot move to the new status
+		///
+		#[pallet::call_index(22)]
+		#[pallet::weight(T::WeightInfo::update_schema_status_via_governance())]
+		pub fn update_schema_status_via_governance(
+			origin: OriginFor<T>,
+			schema_id: SchemaId,
+			status: SchemaStatus,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			Self::update_schema_status(schema_id, status)
 		}
 
-		/// Lowest-level insertion function for a [`SchemaInfo`] and [`SchemaPayload`] into storage,
-		/// using an already-allocated [`SchemaId`]
-		pub fn store_schema_info_and_payload(
+		/// Propose to update the status of a schema. Creates a proposal for council approval to
+		/// deprecate or retire a schema.
+		///
+		/// # Errors
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema does not exist
+		/// * [`Error::InvalidSchemaStatusTransition`] - The schema cannot move to the new status
+		///
+		#[pallet::call_index(23)]
+		#[pallet::weight(T::WeightInfo::propose_to_update_schema_status())]
+		pub fn propose_to_update_schema_status(
+			origin: OriginFor<T>,
 			schema_id: SchemaId,
-			schema_info: SchemaInfo,
-			schema_payload: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
-		) -> Result<(), DispatchError> {
-			<SchemaInfos<T>>::insert(schema_id, schema_info);
-			<SchemaPayloads<T>>::insert(schema_id, schema_payload);
+			status: SchemaStatus,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			let schema_info =
+				SchemaInfos::<T>::get(schema_id).ok_or(Error::<T>::SchemaIdDoesNotExist)?;
+			ensure!(
+				schema_info.status.can_transition_to(status),
+				Error::<T>::InvalidSchemaStatusTransition
+			);
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::update_schema_status_via_governance { schema_id, status }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
 			Ok(())
 		}
 
-		/// Inserts both the [`SchemaInfo`] and Schema Payload into storage
-		/// Updates the [`CurrentSchemaIdentifierMaximum`] storage
-		pub fn add_schema(
+		/// Set the compatibility policy of an Intent by means of council approval.
+		/// With a policy other than `None`, each new schema of the Intent is checked against the
+		/// Intent's previous schema when it is created.
+		///
+		/// # Events
+		/// * [`Event::IntentCompatibilityPolicyUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		///
+		#[pallet::call_index(24)]
+		#[pallet::weight(T::WeightInfo::set_intent_compatibility_policy_via_governance())]
+		pub fn set_intent_compatibility_policy_via_governance(
+			origin: OriginFor<T>,
 			intent_id: IntentId,
-			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
-			model_type: ModelType,
-			payload_location: PayloadLocation,
-			settings: IntentSettings,
-			status: SchemaStatus,
-		) -> Result<SchemaId, DispatchError> {
-			let schema_id = Self::get_next_schema_id()?;
-			let schema_info =
-				SchemaInfo { intent_id, model_type, payload_location, settings, status };
-			<CurrentSchemaIdentifierMaximum<T>>::set(schema_id);
-			Self::store_schema_info_and_payload(schema_id, schema_info, model)?;
+			policy: CompatibilityPolicy,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			ensure!(IntentInfos::<T>::contains_key(intent_id), Error::<T>::InvalidIntentId);
+			IntentCompatibilityPolicies::<T>::insert(intent_id, policy);
 
-			Ok(schema_id)
+			Self::deposit_event(Event::IntentCompatibilityPolicyUpdated { intent_id, policy });
+			Ok(())
 		}
 
-		/// Lowest-level insertion function for a [`IntentInfo`] into storage,
-		/// using an already-allocated [`IntentId`]
-		pub fn store_intent_info(
+		/// Propose to set the compatibility policy of an Intent. Creates a proposal for council
+		/// approval.
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		///
+		#[pallet::call_index(25)]
+		#[pallet::weight(T::WeightInfo::propose_to_set_intent_compatibility_policy())]
+		pub fn propose_to_set_intent_compatibility_policy(
+			origin: OriginFor<T>,
 			intent_id: IntentId,
-			intent_info: IntentInfo,
-			intent_name: &SchemaName,
-		) -> Result<(), DispatchError> {
-			NameToMappedEntityIds::<T>::insert(
-				&intent_name.namespace,
-				&intent_name.descriptor,
-				MappedEntityIdentifier::Intent(intent_id),
-			);
-			<IntentInfos<T>>::insert(intent_id, intent_info);
+			policy: CompatibilityPolicy,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			ensure!(IntentInfos::<T>::contains_key(intent_id), Error::<T>::InvalidIntentId);
 
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::set_intent_compatibility_policy_via_governance { intent_id, policy })
+					.into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
 			Ok(())
 		}
 
-		/// Inserts the [`IntentInfo`] into storage
-		/// Updates the [`CurrentIntentIdentifierMaximum`] storage
-		/// Does little validation, as this is an internal method intended to be called
-		/// by higher-level extrinsics that perform various validations.
+		/// Set or clear the provider that owns a namespace by means of council approval.
+		/// The owner of a namespace may create Intents, IntentGroups and Schemas in it without
+		/// council approval, holding a deposit for each of them.
+		///
+		/// # Events
+		/// * [`Event::NamespaceOwnerUpdated`]
 		///
 		/// # Errors
-		/// * [`Error::IntentCountOverflow`]
-		pub fn add_intent(
-			payload_location: PayloadLocation,
-			settings: BoundedVec<IntentSetting, T::MaxSchemaSettingsPerSchema>,
-			intent_name: &SchemaName,
-		) -> Result<IntentId, DispatchError> {
-			let intent_id = Self::get_next_intent_id()?;
-			let mut set_settings = IntentSettings::all_disabled();
-			if !settings.is_empty() {
-				for i in settings.into_inner() {
-					set_settings.set(i);
-				}
-			}
-
-			let intent_info = IntentInfo { payload_location, settings: set_settings };
-			<CurrentIntentIdentifierMaximum<T>>::set(intent_id);
-			Self::store_intent_info(intent_id, intent_info, intent_name)?;
+		/// * [`Error::InvalidSchemaNameStructure`] - The namespace is not a bare protocol name
+		/// * [`Error::InvalidSchemaNameEncoding`] - The namespace has an invalid encoding
+		/// * [`Error::InvalidSchemaNameCharacters`] - The namespace contains invalid characters
+		/// * [`Error::InvalidSchemaNamespaceLength`] - The namespace exceeds the max allowed length
+		///
+		#[pallet::call_index(26)]
+		#[pallet::weight(T::WeightInfo::set_namespace_owner_via_governance())]
+		pub fn set_namespace_owner_via_governance(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+			owner: Option<ProviderId>,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			let namespace = Self::parse_namespace(namespace)?;
+			NamespaceOwners::<T>::set(&namespace, owner);
 
-			Ok(intent_id)
+			Self::deposit_event(Event::NamespaceOwnerUpdated {
+				namespace: namespace.into_inner(),
+				owner,
+			});
+			Ok(())
 		}
 
-		/// Lowest-level insertion function for a [`IntentGroup`] into storage,
-		/// using an already-allocated [`IntentGroupId`]
-		pub fn store_intent_group(
-			intent_group_id: IntentGroupId,
-			intent_ids: BoundedVec<IntentId, T::MaxIntentsPerIntentGroup>,
-			intent_group_name: &SchemaName,
-		) -> Result<(), DispatchError> {
-			NameToMappedEntityIds::<T>::insert(
-				&intent_group_name.namespace,
-				&intent_group_name.descriptor,
-				MappedEntityIdentifier::IntentGroup(intent_group_id),
-			);
-			Self::update_intent_group_storage(intent_group_id, intent_ids)?;
+		/// Propose to set or clear the owner of a namespace. Creates a proposal for council
+		/// approval.
+		///
+		/// # Errors
+		/// * [`Error::InvalidSchemaNameStructure`] - The namespace is not a bare protocol name
+		/// * [`Error::InvalidSchemaNameEncoding`] - The namespace has an invalid encoding
+		/// * [`Error::InvalidSchemaNameCharacters`] - The namespace contains invalid characters
+		/// * [`Error::InvalidSchemaNamespaceLength`] - The namespace exceeds the max allowed length
+		///
+		#[pallet::call_index(27)]
+		#[pallet::weight(T::WeightInfo::propose_to_set_namespace_owner())]
+		pub fn propose_to_set_namespace_owner(
+			origin: OriginFor<T>,
+			namespace: SchemaNamePayload,
+			owner: Option<ProviderId>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			Self::parse_namespace(namespace.clone())?;
 
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::set_namespace_owner_via_governance { namespace, owner }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
 			Ok(())
 		}
 
-		/// Inserts a list of [`IntentId`]s with a new [`IntentGroupId`] in storage, and adds.
-		/// a new name mapping to the [`IntentGroupId`].
+		/// Creates a new Intent in a namespace owned by the caller's MSA, holding
+		/// [`Config::NamespaceEntityDeposit`] from the caller until the Intent is removed
+		///
+		/// # Events
+		/// * [`Event::IntentCreated`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace
+		/// * [`Error::IntentCountOverflow`] - The Intent count has exceeded its bounds
+		/// * [`Error::InvalidSetting`] - Invalid setting is provided
+		/// * [`Error::NameAlreadyExists`] - The name already exists
+		/// * [`Error::InvalidSchemaNameEncoding`] and the other name errors - The name is invalid
+		///
+		#[pallet::call_index(28)]
+		#[pallet::weight(T::WeightInfo::create_provider_intent(settings.len() as u32))]
+		pub fn create_provider_intent(
+			origin: OriginFor<T>,
+			intent_name: SchemaNamePayload,
+			payload_location: PayloadLocation,
+			settings: BoundedVec<IntentSetting, T::MaxSchemaSettingsPerSchema>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_name = SchemaName::try_parse::<T>(intent_name.clone(), true)?;
+			Self::ensure_namespace_owner(&sender, &parsed_name.namespace)?;
+
+			let (intent_id, parsed_name) =
+				Self::create_intent_for(intent_name, payload_location, settings)?;
+			Self::hold_entity_deposit(&sender, NamespaceEntity::Intent(intent_id))?;
+
+			Self::deposit_event(Event::IntentCreated {
+				key: sender,
+				intent_id,
+				intent_name: parsed_name.get_combined_name(),
+			});
+			Ok(())
+		}
+
+		/// Creates a new IntentGroup in a namespace owned by the caller's MSA, holding
+		/// [`Config::NamespaceEntityDeposit`] from the caller until the IntentGroup is removed
+		///
+		/// # Events
+		/// * [`Event::IntentGroupCreated`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace
+		/// * [`Error::IntentGroupCountOverflow`] - The Intent Group count has exceeded its bounds
+		/// * [`Error::NameAlreadyExists`] - The name already exists
+		/// * [`Error::InvalidSchemaNameEncoding`] and the other name errors - The name is invalid
+		/// * [`Error::InvalidIntentId`] - At least one of the specified [`IntentId`]s does not exist
+		///
+		#[pallet::call_index(29)]
+		#[pallet::weight(T::WeightInfo::create_provider_intent_group(intent_ids.len() as u32))]
+		pub fn create_provider_intent_group(
+			origin: OriginFor<T>,
+			intent_group_name: SchemaNamePayload,
+			intent_ids: BoundedVec<IntentId, T::MaxIntentsPerIntentGroup>,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let parsed_name = SchemaName::try_parse::<T>(intent_group_name.clone(), true)?;
+			Self::ensure_namespace_owner(&sender, &parsed_name.namespace)?;
+
+			let (intent_group_id, parsed_name) =
+				Self::create_intent_group_for(intent_group_name, intent_ids)?;
+			Self::hold_entity_deposit(&sender, NamespaceEntity::IntentGroup(intent_group_id))?;
+
+			Self::deposit_event(Event::IntentGroupCreated {
+				key: sender,
+				intent_group_id,
+				intent_group_name: parsed_name.get_combined_name(),
+			});
+			Ok(())
+		}
+
+		/// Creates a new Schema for an Intent in a namespace owned by the caller's MSA, holding
+		/// [`Config::NamespaceEntityDeposit`] from the caller until the Schema becomes
+		/// `Unsupported`
+		///
+		/// # Events
+		/// * [`Event::SchemaCreated`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace
+		/// * [`Error::InvalidIntentId`] - The name does not refer to an Intent
+		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
+		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
+		/// * [`Error::InvalidSchema`] - Parquet model is malformed in some way
+		/// * [`Error::InvalidAvroJson`] and the other `Avro` errors - Avro model is malformed
+		/// * [`Error::IncompatibleSchemaModelType`] and the other `Incompatible` errors - The model is incompatible with the previous schema under the Intent's [`CompatibilityPolicy`]
+		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
+		///
+		#[pallet::call_index(30)]
+		#[pallet::weight(T::WeightInfo::create_provider_schema(
+			model.len() as u32,
+			T::SchemaModelMaxBytesBoundedVecLimit::get(),
+		))]
+		pub fn create_provider_schema(
+			origin: OriginFor<T>,
+			intent_name: SchemaNamePayload,
+			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			model_type: ModelType,
+		) -> DispatchResultWithPostInfo {
+			let sender = ensure_signed(origin)?;
+			let intent_id = match Self::get_owned_entity(&sender, intent_name)? {
+				(_, MappedEntityIdentifier::Intent(intent_id)) => intent_id,
+				_ => return Err(Error::<T>::InvalidIntentId.into()),
+			};
+
+			let model_len = model.len() as u32;
+			let previous_model_len = Self::get_compatibility_model_len(intent_id);
+			let schema_id = Self::create_schema_for(intent_id, model, model_type)?;
+			Self::hold_entity_deposit(&sender, NamespaceEntity::Schema(schema_id))?;
+
+			Self::deposit_event(Event::SchemaCreated { key: sender, schema_id });
+			Ok(Some(T::WeightInfo::create_provider_schema(model_len, previous_model_len)).into())
+		}
+
+		/// Removes an Intent created by the owner of its namespace and releases its deposit.
+		/// Only Intents without schemas that do not belong to any IntentGroup can be removed.
+		///
+		/// # Events
+		/// * [`Event::IntentRemoved`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace
+		/// * [`Error::InvalidIntentId`] - The name does not refer to an Intent
+		/// * [`Error::EntityNotRemovable`] - The Intent was not created by a namespace owner
+		/// * [`Error::IntentHasSchemas`] - The Intent has schemas
+		/// * [`Error::IntentInIntentGroup`] - The Intent belongs to an IntentGroup
+		///
+		#[pallet::call_index(31)]
+		#[pallet::weight(T::WeightInfo::remove_provider_intent())]
+		pub fn remove_provider_intent(
+			origin: OriginFor<T>,
+			intent_name: SchemaNamePayload,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (parsed_name, intent_id) = match Self::get_owned_entity(&sender, intent_name)? {
+				(parsed_name, MappedEntityIdentifier::Intent(intent_id)) =>
+					(parsed_name, intent_id),
+				_ => return Err(Error::<T>::InvalidIntentId.into()),
+			};
+			let entity = NamespaceEntity::Intent(intent_id);
+			ensure!(EntityDeposits::<T>::contains_key(entity), Error::<T>::EntityNotRemovable);
+			ensure!(
+				!IntentLatestSchemaIds::<T>::contains_key(intent_id),
+				Error::<T>::IntentHasSchemas
+			);
+			ensure!(
+				IntentGroupMemberships::<T>::get(intent_id) == 0,
+				Error::<T>::IntentInIntentGroup
+			);
+
+			NameToMappedEntityIds::<T>::remove(&parsed_name.namespace, &parsed_name.descriptor);
+			IntentInfos::<T>::remove(intent_id);
+			IntentCompatibilityPolicies::<T>::remove(intent_id);
+			IntentRetentionPeriods::<T>::remove(intent_id);
+			IntentStorageQuotas::<T>::remove(intent_id);
+			Self::release_entity_deposit(entity)?;
+
+			Self::deposit_event(Event::IntentRemoved { key: sender, intent_id });
+			Ok(())
+		}
+
+		/// Removes an IntentGroup created by the owner of its namespace and releases its deposit.
+		///
+		/// # Events
+		/// * [`Event::IntentGroupRemoved`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace
+		/// * [`Error::InvalidIntentGroupId`] - The name does not refer to an IntentGroup
+		/// * [`Error::EntityNotRemovable`] - The IntentGroup was not created by a namespace owner
+		///
+		#[pallet::call_index(32)]
+		#[pallet::weight(T::WeightInfo::remove_provider_intent_group(
+			T::MaxIntentsPerIntentGroup::get()
+		))]
+		pub fn remove_provider_intent_group(
+			origin: OriginFor<T>,
+			intent_group_name: SchemaNamePayload,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let (parsed_name, intent_group_id) =
+				match Self::get_owned_entity(&sender, intent_group_name)? {
+					(parsed_name, MappedEntityIdentifier::IntentGroup(intent_group_id)) =>
+						(parsed_name, intent_group_id),
+					_ => return Err(Error::<T>::InvalidIntentGroupId.into()),
+				};
+			let entity = NamespaceEntity::IntentGroup(intent_group_id);
+			ensure!(EntityDeposits::<T>::contains_key(entity), Error::<T>::EntityNotRemovable);
+
+			NameToMappedEntityIds::<T>::remove(&parsed_name.namespace, &parsed_name.descriptor);
+			Self::remove_intent_group_storage(intent_group_id);
+			Self::release_entity_deposit(entity)?;
+
+			Self::deposit_event(Event::IntentGroupRemoved { key: sender, intent_group_id });
+			Ok(())
+		}
+
+		/// Update the status of a schema of an Intent in a namespace owned by the caller's MSA.
+		/// The deposit held for the schema is released once it becomes `Unsupported`.
+		///
+		/// # Events
+		/// * [`Event::SchemaStatusUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The caller's MSA does not own the namespace, or the
+		///   schema does not belong to the named Intent
+		/// * [`Error::InvalidIntentId`] - The name does not refer to an Intent
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema does not exist
+		/// * [`Error::InvalidSchemaStatusTransition`] - The schema cannot move to the new status
+		///
+		#[pallet::call_index(33)]
+		#[pallet::weight(T::WeightInfo::update_provider_schema_status())]
+		pub fn update_provider_schema_status(
+			origin: OriginFor<T>,
+			intent_name: SchemaNamePayload,
+			schema_id: SchemaId,
+			status: SchemaStatus,
+		) -> DispatchResult {
+			let sender = ensure_signed(origin)?;
+			let intent_id = match Self::get_owned_entity(&sender, intent_name)? {
+				(_, MappedEntityIdentifier::Intent(intent_id)) => intent_id,
+				_ => return Err(Error::<T>::InvalidIntentId.into()),
+			};
+			let schema_info =
+				SchemaInfos::<T>::get(schema_id).ok_or(Error::<T>::SchemaIdDoesNotExist)?;
+			ensure!(schema_info.intent_id == intent_id, Error::<T>::NamespaceNotOwned);
+
+			Self::update_schema_status(schema_id, status)
+		}
+
+		/// Set or clear the message retention period of an Intent by means of council approval.
+		/// Messages of the Intent are pruned once they are older than the retention period.
+		///
+		/// # Events
+		/// * [`Event::IntentRetentionUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidRetentionPeriod`] - The retention period is zero
+		/// * [`Error::RetentionNotSupported`] - The Intent does not have an `OnChain` or `IPFS` payload location
+		/// * [`Error::TooManyIntentsWithRetention`] - [`Config::MaxIntentsWithRetention`] Intents already have a retention period
+		///
+		#[pallet::call_index(34)]
+		#[pallet::weight(T::WeightInfo::set_intent_retention_via_governance())]
+		pub fn set_intent_retention_via_governance(
+			origin: OriginFor<T>,
+			intent_id: IntentId,
+			retention: Option<BlockNumber>,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_retention(intent_id, retention)?;
+			match retention {
+				Some(period) => {
+					let existing = IntentRetentionPeriods::<T>::get(intent_id);
+					ensure!(
+						existing.is_some() ||
+							IntentRetentionPeriods::<T>::count() <
+								T::MaxIntentsWithRetention::get(),
+						Error::<T>::TooManyIntentsWithRetention
+					);
+					// Pruning starts from the block the retention was first set in, so changing
+					// the period keeps the original start
+					let since = existing.map(|r| r.since).unwrap_or_else(|| {
+						frame_system::Pallet::<T>::block_number().saturated_into()
+					});
+					IntentRetentionPeriods::<T>::insert(
+						intent_id,
+						IntentRetention { period, since },
+					);
+				},
+				None => IntentRetentionPeriods::<T>::remove(intent_id),
+			}
+
+			Self::deposit_event(Event::IntentRetentionUpdated { intent_id, retention });
+			Ok(())
+		}
+
+		/// Propose to set or clear the message retention period of an Intent. Creates a proposal
+		/// for council approval.
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidRetentionPeriod`] - The retention period is zero
+		/// * [`Error::RetentionNotSupported`] - The Intent does not have an `OnChain` or `IPFS` payload location
+		///
+		#[pallet::call_index(35)]
+		#[pallet::weight(T::WeightInfo::propose_to_set_intent_retention())]
+		pub fn propose_to_set_intent_retention(
+			origin: OriginFor<T>,
+			intent_id: IntentId,
+			retention: Option<BlockNumber>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			Self::ensure_valid_retention(intent_id, retention)?;
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::set_intent_retention_via_governance { intent_id, retention }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
+			Ok(())
+		}
+
+		/// Set or clear the stateful storage quota of an Intent by means of council approval.
+		/// Writes that would take the storage of an MSA for the Intent over the quota fail.
+		///
+		/// # Events
+		/// * [`Event::IntentStorageQuotaUpdated`]
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidStorageQuota`] - The quota allows no pages or no bytes
+		/// * [`Error::StorageQuotaNotSupported`] - The Intent does not have an `Itemized` or `Paginated` payload location
+		///
+		#[pallet::call_index(36)]
+		#[pallet::weight(T::WeightInfo::set_intent_storage_quota_via_governance())]
+		pub fn set_intent_storage_quota_via_governance(
+			origin: OriginFor<T>,
+			intent_id: IntentId,
+			quota: Option<StorageQuota>,
+		) -> DispatchResult {
+			T::CreateSchemaViaGovernanceOrigin::ensure_origin(origin)?;
+			Self::ensure_valid_storage_quota(intent_id, quota)?;
+			match quota {
+				Some(quota) => IntentStorageQuotas::<T>::insert(intent_id, quota),
+				None => IntentStorageQuotas::<T>::remove(intent_id),
+			}
+
+			Self::deposit_event(Event::IntentStorageQuotaUpdated { intent_id, quota });
+			Ok(())
+		}
+
+		/// Propose to set or clear the stateful storage quota of an Intent. Creates a proposal
+		/// for council approval.
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidStorageQuota`] - The quota allows no pages or no bytes
+		/// * [`Error::StorageQuotaNotSupported`] - The Intent does not have an `Itemized` or `Paginated` payload location
+		///
+		#[pallet::call_index(37)]
+		#[pallet::weight(T::WeightInfo::propose_to_set_intent_storage_quota())]
+		pub fn propose_to_set_intent_storage_quota(
+			origin: OriginFor<T>,
+			intent_id: IntentId,
+			quota: Option<StorageQuota>,
+		) -> DispatchResult {
+			let proposer = ensure_signed(origin)?;
+			Self::ensure_valid_storage_quota(intent_id, quota)?;
+
+			let proposal: Box<T::Proposal> = Box::new(
+				(Call::<T>::set_intent_storage_quota_via_governance { intent_id, quota }).into(),
+			);
+			T::ProposalProvider::propose_with_simple_majority(proposer, proposal)?;
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Set the maximum schema id to the supplied value.
+		#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
+		pub fn set_schema_count(n: SchemaId) {
+			<CurrentSchemaIdentifierMaximum<T>>::set(n);
+		}
+
+		/// Set the maximum intent id to the supplied value.
+		#[cfg(any(feature = "std", feature = "runtime-benchmarks", test))]
+		pub fn set_intent_count(n: IntentId) {
+			<CurrentIntentIdentifierMaximum<T>>::set(n);
+		}
+
+		/// Lowest-level insertion function for a [`SchemaInfo`] and [`SchemaPayload`] into storage,
+		/// using an already-allocated [`SchemaId`]
+		/// Updates the [`IntentLatestSchemaIds`] storage, and links `Active` and `Deprecated`
+		/// schemas into the [`SupportedSchemaLinks`] of their Intent
+		pub fn store_schema_info_and_payload(
+			schema_id: SchemaId,
+			schema_info: SchemaInfo,
+			schema_payload: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+		) -> Result<(), DispatchError> {
+			IntentLatestSchemaIds::<T>::mutate(schema_info.intent_id, |latest| {
+				if latest.is_none_or(|latest_id| latest_id < schema_id) {
+					*latest = Some(schema_id);
+				}
+			});
+			if schema_info.status != SchemaStatus::Unsupported {
+				Self::link_supported_schema(schema_info.intent_id, schema_id);
+			}
+			<SchemaInfos<T>>::insert(schema_id, schema_info);
+			<SchemaPayloads<T>>::insert(schema_id, schema_payload);
+			Ok(())
+		}
+
+		/// Inserts both the [`SchemaInfo`] and Schema Payload into storage
+		/// Updates the [`CurrentSchemaIdentifierMaximum`] storage
+		pub fn add_schema(
+			intent_id: IntentId,
+			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
+			model_type: ModelType,
+			payload_location: PayloadLocation,
+			settings: IntentSettings,
+			status: SchemaStatus,
+		) -> Result<SchemaId, DispatchError> {
+			let schema_id = Self::get_next_schema_id()?;
+			let schema_info =
+				SchemaInfo { intent_id, model_type, payload_location, settings, status };
+			<CurrentSchemaIdentifierMaximum<T>>::set(schema_id);
+			Self::store_schema_info_and_payload(schema_id, schema_info, model)?;
+
+			Ok(schema_id)
+		}
+
+		/// Lowest-level insertion function for a [`IntentInfo`] into storage,
+		/// using an already-allocated [`IntentId`]
+		pub fn store_intent_info(
+			intent_id: IntentId,
+			intent_info: IntentInfo,
+			intent_name: &SchemaName,
+		) -> Result<(), DispatchError> {
+			NameToMappedEntityIds::<T>::insert(
+				&intent_name.namespace,
+				&intent_name.descriptor,
+				MappedEntityIdentifier::Intent(intent_id),
+			);
+			<IntentInfos<T>>::insert(intent_id, intent_info);
+
+			Ok(())
+		}
+
+		/// Inserts the [`IntentInfo`] into storage
+		/// Updates the [`CurrentIntentIdentifierMaximum`] storage
+		/// Does little validation, as this is an internal method intended to be called
+		/// by higher-level extrinsics that perform various validations.
+		///
+		/// # Errors
+		/// * [`Error::IntentCountOverflow`]
+		pub fn add_intent(
+			payload_location: PayloadLocation,
+			settings: BoundedVec<IntentSetting, T::MaxSchemaSettingsPerSchema>,
+			intent_name: &SchemaName,
+		) -> Result<IntentId, DispatchError> {
+			let intent_id = Self::get_next_intent_id()?;
+			let mut set_settings = IntentSettings::all_disabled();
+			if !settings.is_empty() {
+				for i in settings.into_inner() {
+					set_settings.set(i);
+				}
+			}
+
+			let intent_info = IntentInfo { payload_location, settings: set_settings };
+			<CurrentIntentIdentifierMaximum<T>>::set(intent_id);
+			Self::store_intent_info(intent_id, intent_info, intent_name)?;
+
+			Ok(intent_id)
+		}
+
+		/// Lowest-level insertion function for a [`IntentGroup`] into storage,
+		/// using an already-allocated [`IntentGroupId`]
+		pub fn store_intent_group(
+			intent_group_id: IntentGroupId,
+			intent_ids: BoundedVec<IntentId, T::MaxIntentsPerIntentGroup>,
+			intent_group_name: &SchemaName,
+		) -> Result<(), DispatchError> {
+			NameToMappedEntityIds::<T>::insert(
+				&intent_group_name.namespace,
+				&intent_group_name.descriptor,
+				MappedEntityIdentifier::IntentGroup(intent_group_id),
+			);
+			Self::update_intent_group_storage(intent_group_id, intent_ids)?;
+
+			Ok(())
+		}
+
+		/// Inserts a list of [`IntentId`]s with a new [`IntentGroupId`] in storage, and adds.
+		/// a new name mapping to the [`IntentGroupId`].
 		/// Updates the [`CurrentIntentGroupIdentifierMaximum`] storage.
 		/// Does little validation, as this is an internal method intended to be called by.
 		/// higher-level extrinsics that perform the validations.
@@ -966,14 +1804,33 @@ pub mod pallet {
 		/// Does little validation, as this is an internal method intended to be called by
 		/// higher-level extrinsics that perform the validations.
 		///
+		/// Updates the [`IntentGroupMemberships`] storage
 		pub fn update_intent_group_storage(
 			intent_group_id: IntentGroupId,
 			intent_ids: BoundedVec<IntentId, T::MaxIntentsPerIntentGroup>,
 		) -> DispatchResult {
+			Self::remove_intent_group_storage(intent_group_id);
+			for intent_id in intent_ids.iter() {
+				IntentGroupMemberships::<T>::mutate(intent_id, |count| {
+					*count = count.saturating_add(1)
+				});
+			}
 			IntentGroups::<T>::set(intent_group_id, Some(IntentGroup { intent_ids }));
 			Ok(())
 		}
 
+		/// Removes an [`IntentGroup`] from storage
+		/// Updates the [`IntentGroupMemberships`] storage
+		fn remove_intent_group_storage(intent_group_id: IntentGroupId) {
+			if let Some(intent_group) = IntentGroups::<T>::take(intent_group_id) {
+				for intent_id in intent_group.intent_ids.iter() {
+					IntentGroupMemberships::<T>::mutate_exists(intent_id, |count| {
+						*count = count.and_then(|c| c.checked_sub(1)).filter(|c| *c > 0);
+					});
+				}
+			}
+		}
+
 		/// Retrieve a schema by id
 		pub fn get_schema_by_id(schema_id: SchemaId) -> Option<SchemaResponseV2> {
 			match (SchemaInfos::<T>::get(schema_id), SchemaPayloads::<T>::get(schema_id)) {
@@ -1016,27 +1873,43 @@ pub mod pallet {
 
 		/// Retrieve an Intent by its ID
 		pub fn get_intent_by_id(intent_id: IntentId) -> Option<IntentResponse> {
-			IntentInfos::<T>::get(intent_id).map(|intent_info| IntentResponse {
+			Self::get_intent_by_id_v2(intent_id).map(|intent| intent.into())
+		}
+
+		/// Retrieve an Intent by its ID in the response form that can include the status of its
+		/// Schemas
+		pub fn get_intent_by_id_v2(intent_id: IntentId) -> Option<IntentResponseV2> {
+			IntentInfos::<T>::get(intent_id).map(|intent_info| IntentResponseV2 {
 				intent_id,
 				payload_location: intent_info.payload_location,
 				settings: intent_info.settings.0.iter().collect::<Vec<IntentSetting>>(),
 				schema_ids: None,
+				schemas: None,
 			})
 		}
 
 		/// Retrieve an Intent by its ID, with a list of implementing SchemaIDs in sorted order
+		/// along with the status of every implementing Schema
 		/// NOTE: This must not be called on-chain due to inability to determine weight
-		pub fn get_intent_by_id_with_schemas(intent_id: IntentId) -> Option<IntentResponse> {
-			let response = Self::get_intent_by_id(intent_id);
+		pub fn get_intent_by_id_with_schemas(intent_id: IntentId) -> Option<IntentResponseV2> {
+			let response = Self::get_intent_by_id_v2(intent_id);
 			response.map(|mut intent| {
-				let mut schema_ids = SchemaInfos::<T>::iter()
-					.filter(|(_, info)| {
-						info.intent_id == intent_id && info.status != SchemaStatus::Unsupported
+				let mut schemas = SchemaInfos::<T>::iter()
+					.filter(|(_, info)| info.intent_id == intent_id)
+					.map(|(schema_id, info)| IntentSchemaResponse {
+						schema_id,
+						status: info.status,
 					})
-					.map(|(id, _)| id)
-					.collect::<Vec<SchemaId>>();
-				schema_ids.sort();
-				intent.schema_ids = Some(schema_ids);
+					.collect::<Vec<IntentSchemaResponse>>();
+				schemas.sort_by_key(|schema| schema.schema_id);
+				intent.schema_ids = Some(
+					schemas
+						.iter()
+						.filter(|schema| schema.status != SchemaStatus::Unsupported)
+						.map(|schema| schema.schema_id)
+						.collect(),
+				);
+				intent.schemas = Some(schemas);
 				intent
 			})
 		}
@@ -1054,11 +1927,115 @@ pub mod pallet {
 			None
 		}
 
-		/// Ensures that a given u8 Vector conforms to a recognized Parquet shape
+		/// Retrieve a page of Intents in order of IntentId, filtered by namespace prefix and
+		/// payload location
+		/// Warning: Must only get called from RPC, since the number of DB accesses is not deterministic
+		pub fn list_intents(
+			request: EntityPaginationRequest,
+		) -> EntityPaginationResponse<IntentResponseV2> {
+			let named = Self::get_named_entity_ids(&request);
+			Self::paginate(&request, CurrentIntentIdentifierMaximum::<T>::get(), |intent_id| {
+				if named.as_ref().is_some_and(|(intents, _)| !intents.contains(&intent_id)) {
+					return None;
+				}
+				Self::get_intent_by_id_v2(intent_id).filter(|intent| {
+					request
+						.payload_location
+						.is_none_or(|location| location == intent.payload_location)
+				})
+			})
+		}
+
+		/// Retrieve a page of Schemas in order of SchemaId, filtered by the namespace prefix of their
+		/// Intent, payload location and status
+		/// Warning: Must only get called from RPC, since the number of DB accesses is not deterministic
+		pub fn list_schemas(
+			request: EntityPaginationRequest,
+		) -> EntityPaginationResponse<SchemaInfoResponse> {
+			let named = Self::get_named_entity_ids(&request);
+			Self::paginate(&request, CurrentSchemaIdentifierMaximum::<T>::get(), |schema_id| {
+				Self::get_schema_info_by_id(schema_id).filter(|schema| {
+					named.as_ref().is_none_or(|(intents, _)| intents.contains(&schema.intent_id)) &&
+						request
+							.payload_location
+							.is_none_or(|location| location == schema.payload_location) &&
+						request.status.is_none_or(|status| status == schema.status)
+				})
+			})
+		}
+
+		/// Retrieve a page of IntentGroups in order of IntentGroupId, filtered by namespace prefix
+		/// Warning: Must only get called from RPC, since the number of DB accesses is not deterministic
+		pub fn list_intent_groups(
+			request: EntityPaginationRequest,
+		) -> EntityPaginationResponse<IntentGroupResponse> {
+			let named = Self::get_named_entity_ids(&request);
+			Self::paginate(
+				&request,
+				CurrentIntentGroupIdentifierMaximum::<T>::get(),
+				|intent_group_id| {
+					if named.as_ref().is_some_and(|(_, groups)| !groups.contains(&intent_group_id))
+					{
+						return None;
+					}
+					Self::get_intent_group_by_id(intent_group_id)
+				},
+			)
+		}
+
+		/// The ids of the Intents and IntentGroups whose name starts with the namespace prefix of the
+		/// request, or `None` if the request has no namespace prefix
+		fn get_named_entity_ids(
+			request: &EntityPaginationRequest,
+		) -> Option<(BTreeSet<IntentId>, BTreeSet<IntentGroupId>)> {
+			let prefix = request.namespace_prefix.as_ref()?.to_ascii_lowercase();
+			let mut intents = BTreeSet::new();
+			let mut intent_groups = BTreeSet::new();
+			for (namespace, descriptor, entity_id) in NameToMappedEntityIds::<T>::iter() {
+				let name = SchemaName { namespace, descriptor }.get_combined_name();
+				if !name.starts_with(&prefix) {
+					continue;
+				}
+				match entity_id {
+					MappedEntityIdentifier::Intent(intent_id) => intents.insert(intent_id),
+					MappedEntityIdentifier::IntentGroup(intent_group_id) =>
+						intent_groups.insert(intent_group_id),
+				};
+			}
+			Some((intents, intent_groups))
+		}
+
+		/// Collects the entities returned by `fetch` for ids from `request.from_id` up to `max_id`
+		/// until the page is full. The next page starts at the next id that `fetch` returns.
+		fn paginate<R>(
+			request: &EntityPaginationRequest,
+			max_id: u16,
+			mut fetch: impl FnMut(u16) -> Option<R>,
+		) -> EntityPaginationResponse<R> {
+			let mut response = EntityPaginationResponse::new();
+			if !request.validate() {
+				return response;
+			}
+			for id in request.from_id.max(1)..=max_id {
+				if let Some(entity) = fetch(id) {
+					if response.content.len() == request.page_size as usize {
+						response.has_next = true;
+						response.next_id = Some(id);
+						break;
+					}
+					response.content.push(entity);
+				}
+			}
+			response
+		}
+
+		/// Ensures that a given u8 Vector conforms to a recognized Parquet shape, or is a valid
+		/// Avro schema
 		///
 		/// # Errors
-		/// * [`Error::InvalidSchema`]
-		/// * [`Error::SchemaCountOverflow`]
+		/// * [`Error::InvalidSchema`] - Parquet model is not a list of columns
+		/// * [`Error::InvalidParquetColumnCount`] and the other `Parquet` errors - Parquet model can not be written
+		/// * [`Error::InvalidAvroJson`] and the other `Avro` errors - Avro model is malformed
 		///
 		pub fn ensure_valid_model(
 			model_type: &ModelType,
@@ -1066,15 +2043,118 @@ pub mod pallet {
 		) -> DispatchResult {
 			match *model_type {
 				ModelType::Parquet => {
-					serde_json::from_slice::<ParquetModel>(model)
+					parse_parquet_model(model).map_err(Error::<T>::from)?;
+				},
+				ModelType::AvroBinary => {
+					parse_avro_schema(model).map_err(Error::<T>::from)?;
+				},
+			};
+			Ok(())
+		}
+
+		/// Returns the length of the model that a new schema of the Intent is checked against for
+		/// compatibility, or 0 when no check is made. Creating a schema is charged for it, as the
+		/// previous model is parsed and resolved against the new one.
+		pub fn get_compatibility_model_len(intent_id: IntentId) -> u32 {
+			if IntentCompatibilityPolicies::<T>::get(intent_id) == CompatibilityPolicy::None {
+				return 0
+			}
+			IntentLatestSupportedSchemaIds::<T>::get(intent_id)
+				.and_then(SchemaPayloads::<T>::decode_len)
+				.unwrap_or_default() as u32
+		}
+
+		/// Checks a new, already validated, schema model against the latest `Active` or `Deprecated`
+		/// schema of the Intent as required by the Intent's [`CompatibilityPolicy`].
+		/// `Unsupported` schemas are passed over since no more data is written with them, so a new
+		/// schema must still be compatible with the latest schema data can be written with.
+		/// The check is skipped when the Intent has no `Active` or `Deprecated` schema, and when
+		/// the previous model predates model validation and can not be parsed.
+		///
+		/// # Errors
+		/// * [`Error::IncompatibleSchemaModelType`] - The model type differs from the previous schema
+		/// * [`Error::IncompatibleAvroType`] and the other `IncompatibleAvro` errors - Avro schema resolution fails
+		/// * [`Error::IncompatibleParquetMissingColumn`] and the other `IncompatibleParquet` errors - Parquet columns do not match
+		pub fn ensure_compatible_with_previous_schema(
+			intent_id: IntentId,
+			model_type: &ModelType,
+			model: &[u8],
+		) -> DispatchResult {
+			let policy = IntentCompatibilityPolicies::<T>::get(intent_id);
+			if policy == CompatibilityPolicy::None {
+				return Ok(())
+			}
+			let Some(previous_schema_id) = IntentLatestSupportedSchemaIds::<T>::get(intent_id)
+			else {
+				return Ok(())
+			};
+			let (Some(previous_info), Some(previous_model)) = (
+				SchemaInfos::<T>::get(previous_schema_id),
+				SchemaPayloads::<T>::get(previous_schema_id),
+			) else {
+				return Ok(())
+			};
+			ensure!(
+				previous_info.model_type == *model_type,
+				Error::<T>::IncompatibleSchemaModelType
+			);
+
+			match *model_type {
+				ModelType::Parquet => {
+					let Ok(previous) = serde_json::from_slice::<ParquetModel>(&previous_model)
+					else {
+						return Ok(())
+					};
+					let new = serde_json::from_slice::<ParquetModel>(model)
 						.map_err(|_| Error::<T>::InvalidSchema)?;
+					if policy.requires_backward() {
+						check_parquet_compatibility(&new, &previous).map_err(Error::<T>::from)?;
+					}
+					if policy.requires_forward() {
+						check_parquet_compatibility(&previous, &new).map_err(Error::<T>::from)?;
+					}
+				},
+				ModelType::AvroBinary => {
+					let Ok(previous) = parse_avro_schema(&previous_model) else { return Ok(()) };
+					let new = parse_avro_schema(model).map_err(Error::<T>::from)?;
+					if policy.requires_backward() {
+						check_avro_compatibility(&new, &previous).map_err(Error::<T>::from)?;
+					}
+					if policy.requires_forward() {
+						check_avro_compatibility(&previous, &new).map_err(Error::<T>::from)?;
+					}
 				},
-				ModelType::AvroBinary => serde::validate_json_model(model.clone().into_inner())
-					.map_err(|_| Error::<T>::InvalidSchema)?,
 			};
 			Ok(())
 		}
 
+		/// Decodes a payload against the Avro model of a schema, rejecting data that does not
+		/// conform to it. Only `AvroBinary` schemas can be validated.
+		///
+		/// # Errors
+		/// * [`PayloadValidationError::SchemaNotFound`] - The schema does not exist
+		/// * [`PayloadValidationError::UnsupportedModelType`] - The schema is not an Avro schema
+		/// * [`PayloadValidationError::InvalidModel`] - The stored model can not be parsed
+		/// * [`PayloadValidationError::InvalidPayload`] - The payload does not match the model
+		pub fn validate_payload(
+			schema_id: SchemaId,
+			payload: &[u8],
+		) -> Result<(), PayloadValidationError> {
+			let (Some(schema_info), Some(model)) =
+				(SchemaInfos::<T>::get(schema_id), SchemaPayloads::<T>::get(schema_id))
+			else {
+				return Err(PayloadValidationError::SchemaNotFound)
+			};
+			ensure!(
+				schema_info.model_type == ModelType::AvroBinary,
+				PayloadValidationError::UnsupportedModelType
+			);
+			let definition =
+				parse_avro_schema(&model).map_err(|_| PayloadValidationError::InvalidModel)?;
+			validate_avro_datum(&definition, payload)
+				.map_err(PayloadValidationError::InvalidPayload)
+		}
+
 		/// Get the next available schema id
 		///
 		/// # Errors
@@ -1122,7 +2202,9 @@ pub mod pallet {
 		/// # Errors
 		/// * [`Error::LessThanMinSchemaModelBytes`] - The schema's length is less than the minimum schema length
 		/// * [`Error::ExceedsMaxSchemaModelBytes`] - The schema's length is greater than the maximum schema length
-		/// * [`Error::InvalidSchema`] - Schema is malformed in some way
+		/// * [`Error::InvalidSchema`] - Parquet model is malformed in some way
+		/// * [`Error::InvalidAvroJson`] and the other `Avro` errors - Avro model is malformed
+		/// * [`Error::IncompatibleSchemaModelType`] and the other `Incompatible` errors - The model is incompatible with the previous schema under the Intent's [`CompatibilityPolicy`]
 		/// * [`Error::SchemaCountOverflow`] - The schema count has exceeded its bounds
 		/// * [`Error::InvalidSetting`] - Invalid setting is provided
 		pub fn create_schema_for(
@@ -1130,7 +2212,6 @@ pub mod pallet {
 			model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit>,
 			model_type: ModelType,
 		) -> Result<SchemaId, DispatchError> {
-			Self::ensure_valid_model(&model_type, &model)?;
 			ensure!(
 				model.len() >= T::MinSchemaModelSizeBytes::get() as usize,
 				Error::<T>::LessThanMinSchemaModelBytes
@@ -1139,8 +2220,16 @@ pub mod pallet {
 				model.len() <= GovernanceSchemaModelMaxBytes::<T>::get() as usize,
 				Error::<T>::ExceedsMaxSchemaModelBytes
 			);
+			Self::ensure_valid_model(&model_type, &model)?;
 			let intent_info =
 				IntentInfos::<T>::get(intent_id).ok_or(Error::<T>::InvalidIntentId)?;
+			// ValidatePayload requires a model that payloads can be decoded against
+			ensure!(
+				!intent_info.settings.contains(&IntentSetting::ValidatePayload) ||
+					model_type == ModelType::AvroBinary,
+				Error::<T>::InvalidSetting
+			);
+			Self::ensure_compatible_with_previous_schema(intent_id, &model_type, &model)?;
 			let schema_id = Self::add_schema(
 				intent_id,
 				model,
@@ -1177,6 +2266,12 @@ pub mod pallet {
 					payload_location == PayloadLocation::Itemized,
 				Error::<T>::InvalidSetting
 			);
+			// AllowUpdates only relaxes AppendOnly
+			ensure!(
+				!settings.contains(&IntentSetting::AllowUpdates) ||
+					settings.contains(&IntentSetting::AppendOnly),
+				Error::<T>::InvalidSetting
+			);
 			// SignatureRequired is only valid for Itemized and Paginated payload locations
 			ensure!(
 				!settings.contains(&IntentSetting::SignatureRequired) ||
@@ -1184,6 +2279,14 @@ pub mod pallet {
 					payload_location == PayloadLocation::Paginated,
 				Error::<T>::InvalidSetting
 			);
+			// ValidatePayload is only valid for OnChain, Itemized and Paginated payload locations
+			ensure!(
+				!settings.contains(&IntentSetting::ValidatePayload) ||
+					payload_location == PayloadLocation::OnChain ||
+					payload_location == PayloadLocation::Itemized ||
+					payload_location == PayloadLocation::Paginated,
+				Error::<T>::InvalidSetting
+			);
 			let parsed_name = Self::parse_and_verify_new_name(&intent_name_payload)?;
 			let intent_id = Self::add_intent(payload_location, settings, &parsed_name)?;
 			Ok((intent_id, parsed_name))
@@ -1242,6 +2345,8 @@ pub mod pallet {
 			let max_intent_id = CurrentIntentIdentifierMaximum::<T>::get();
 			intent_ids.iter().try_for_each::<_, _>(|intent_id| {
 				ensure!(*intent_id <= max_intent_id, Error::<T>::InvalidIntentId);
+				// Intents removed by their namespace owner leave gaps below the maximum
+				ensure!(IntentInfos::<T>::contains_key(intent_id), Error::<T>::InvalidIntentId);
 				Ok::<(), DispatchError>(())
 			})?;
 			Ok(())
@@ -1250,27 +2355,39 @@ pub mod pallet {
 		/// a method to return all versions of a schema name with their schemaIds
 		/// Warning: Must only get called from RPC, since the number of DB accesses is not deterministic
 		pub fn get_schema_versions(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponse>> {
+			Self::get_schema_versions_v2(schema_name)
+				.map(|versions| versions.into_iter().map(|version| version.into()).collect())
+		}
+
+		/// a method to return all versions of a schema name with their schemaIds and status
+		/// Warning: Must only get called from RPC, since the number of DB accesses is not deterministic
+		pub fn get_schema_versions_v2(
+			schema_name: Vec<u8>,
+		) -> Option<Vec<SchemaVersionResponseV2>> {
 			if let Some(mut entities) = Self::get_intent_or_group_ids_by_name(schema_name.clone()) {
 				entities.retain(|e| matches!(e.entity_id, MappedEntityIdentifier::Intent(_)));
 				return Some(
 					entities
 						.iter()
 						.flat_map(|e| {
-							let schema_ids: Vec<SchemaId> = SchemaInfos::<T>::iter()
-								.filter_map(|(id, info)| match e.entity_id {
-									MappedEntityIdentifier::Intent(intent_id) => {
-										if info.intent_id == intent_id {
-											Some(id)
-										} else {
-											None
-										}
-									},
-									_ => None,
-								})
-								.collect();
-							schema_ids.convert_to_response(&e.name)
+							let mut schemas: Vec<(SchemaId, SchemaStatus)> =
+								SchemaInfos::<T>::iter()
+									.filter_map(|(id, info)| match e.entity_id {
+										MappedEntityIdentifier::Intent(intent_id) => {
+											if info.intent_id == intent_id {
+												Some((id, info.status))
+											} else {
+												None
+											}
+										},
+										_ => None,
+									})
+									.collect();
+							// versions are numbered in order of creation
+							schemas.sort_by_key(|(id, _)| *id);
+							schemas.convert_to_response(&e.name)
 						})
-						.collect::<Vec<SchemaVersionResponse>>(),
+						.collect::<Vec<SchemaVersionResponseV2>>(),
 				);
 			}
 			None
@@ -1305,6 +2422,240 @@ pub mod pallet {
 			(!responses.is_empty()).then_some(responses)
 		}
 
+		/// Updates the status of a schema, releasing the deposit held for it, if any, and unlinking
+		/// it from the supported schemas of its Intent once it becomes `Unsupported`
+		///
+		/// # Errors
+		/// * [`Error::SchemaIdDoesNotExist`] - The schema does not exist
+		/// * [`Error::InvalidSchemaStatusTransition`] - The schema cannot move to the new status
+		fn update_schema_status(schema_id: SchemaId, status: SchemaStatus) -> DispatchResult {
+			let intent_id = SchemaInfos::<T>::try_mutate(
+				schema_id,
+				|maybe_schema_info| -> Result<IntentId, DispatchError> {
+					let schema_info =
+						maybe_schema_info.as_mut().ok_or(Error::<T>::SchemaIdDoesNotExist)?;
+					ensure!(
+						schema_info.status.can_transition_to(status),
+						Error::<T>::InvalidSchemaStatusTransition
+					);
+					schema_info.status = status;
+					Ok(schema_info.intent_id)
+				},
+			)?;
+			if status == SchemaStatus::Unsupported {
+				Self::unlink_supported_schema(intent_id, schema_id);
+				Self::release_entity_deposit(NamespaceEntity::Schema(schema_id))?;
+			}
+
+			Self::deposit_event(Event::SchemaStatusUpdated { schema_id, status });
+			Ok(())
+		}
+
+		/// Links an `Active` or `Deprecated` schema into the [`SupportedSchemaLinks`] of its Intent,
+		/// making it the latest supported schema unless a later one is already linked
+		fn link_supported_schema(intent_id: IntentId, schema_id: SchemaId) {
+			let mut previous = IntentLatestSupportedSchemaIds::<T>::get(intent_id);
+			let mut next = None;
+			// Only schemas stored out of order, as in genesis, walk back from the latest one
+			while let Some(previous_id) = previous.filter(|previous_id| *previous_id > schema_id) {
+				next = Some(previous_id);
+				previous =
+					SupportedSchemaLinks::<T>::get(previous_id).and_then(|link| link.previous);
+			}
+			if let Some(previous_id) = previous {
+				SupportedSchemaLinks::<T>::mutate(previous_id, |maybe_link| {
+					if let Some(link) = maybe_link {
+						link.next = Some(schema_id);
+					}
+				});
+			}
+			match next {
+				Some(next_id) => SupportedSchemaLinks::<T>::mutate(next_id, |maybe_link| {
+					if let Some(link) = maybe_link {
+						link.previous = Some(schema_id);
+					}
+				}),
+				None => IntentLatestSupportedSchemaIds::<T>::insert(intent_id, schema_id),
+			}
+			SupportedSchemaLinks::<T>::insert(schema_id, SupportedSchemaLink { previous, next });
+		}
+
+		/// Unlinks a schema that became `Unsupported` from the [`SupportedSchemaLinks`] of its
+		/// Intent, making the previous supported schema the latest one when it was the latest
+		fn unlink_supported_schema(intent_id: IntentId, schema_id: SchemaId) {
+			let Some(SupportedSchemaLink { previous, next }) =
+				SupportedSchemaLinks::<T>::take(schema_id)
+			else {
+				return
+			};
+			if let Some(previous_id) = previous {
+				SupportedSchemaLinks::<T>::mutate(previous_id, |maybe_link| {
+					if let Some(link) = maybe_link {
+						link.next = next;
+					}
+				});
+			}
+			match (next, previous) {
+				(Some(next_id), _) => SupportedSchemaLinks::<T>::mutate(next_id, |maybe_link| {
+					if let Some(link) = maybe_link {
+						link.previous = previous;
+					}
+				}),
+				(None, Some(previous_id)) =>
+					IntentLatestSupportedSchemaIds::<T>::insert(intent_id, previous_id),
+				(None, None) => IntentLatestSupportedSchemaIds::<T>::remove(intent_id),
+			}
+		}
+
+		/// Retrieve the message retention of at most `limit` Intents that have one, in storage
+		/// order continuing after the Intent `start_after`
+		pub fn get_intent_retention_periods(
+			start_after: Option<IntentId>,
+			limit: u32,
+		) -> Vec<(IntentId, IntentRetention)> {
+			match start_after {
+				Some(intent_id) => IntentRetentionPeriods::<T>::iter_from(
+					IntentRetentionPeriods::<T>::hashed_key_for(intent_id),
+				)
+				.take(limit as usize)
+				.collect(),
+				None => IntentRetentionPeriods::<T>::iter().take(limit as usize).collect(),
+			}
+		}
+
+		/// Retrieve the stateful storage quota of an Intent, if it has one
+		pub fn get_intent_storage_quota(intent_id: IntentId) -> Option<StorageQuota> {
+			IntentStorageQuotas::<T>::get(intent_id)
+		}
+
+		/// Checks that a storage quota can be set for an Intent
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidStorageQuota`] - The quota allows no pages or no bytes
+		/// * [`Error::StorageQuotaNotSupported`] - The Intent does not have an `Itemized` or `Paginated` payload location
+		fn ensure_valid_storage_quota(
+			intent_id: IntentId,
+			quota: Option<StorageQuota>,
+		) -> DispatchResult {
+			let intent = IntentInfos::<T>::get(intent_id).ok_or(Error::<T>::InvalidIntentId)?;
+			if let Some(quota) = quota {
+				ensure!(
+					quota.max_bytes > 0 && quota.max_pages > 0,
+					Error::<T>::InvalidStorageQuota
+				);
+				ensure!(
+					matches!(
+						intent.payload_location,
+						PayloadLocation::Itemized | PayloadLocation::Paginated
+					),
+					Error::<T>::StorageQuotaNotSupported
+				);
+			}
+			Ok(())
+		}
+
+		/// Checks that a retention period can be set for an Intent
+		///
+		/// # Errors
+		/// * [`Error::InvalidIntentId`] - The Intent does not exist
+		/// * [`Error::InvalidRetentionPeriod`] - The retention period is zero
+		/// * [`Error::RetentionNotSupported`] - The Intent does not have an `OnChain` or `IPFS` payload location
+		fn ensure_valid_retention(
+			intent_id: IntentId,
+			retention: Option<BlockNumber>,
+		) -> DispatchResult {
+			let intent = IntentInfos::<T>::get(intent_id).ok_or(Error::<T>::InvalidIntentId)?;
+			if let Some(period) = retention {
+				ensure!(period > 0, Error::<T>::InvalidRetentionPeriod);
+				ensure!(
+					matches!(
+						intent.payload_location,
+						PayloadLocation::OnChain | PayloadLocation::IPFS
+					),
+					Error::<T>::RetentionNotSupported
+				);
+			}
+			Ok(())
+		}
+
+		/// Parses a bare namespace, such as `acme`, that owns all names of the form `acme.*`
+		///
+		/// # Errors
+		/// * [`Error::InvalidSchemaNameStructure`] - The namespace has a descriptor
+		/// * [`Error::InvalidSchemaNameEncoding`] and the other name errors - The namespace is invalid
+		fn parse_namespace(
+			namespace: SchemaNamePayload,
+		) -> Result<SchemaProtocolName, DispatchError> {
+			let parsed_name = SchemaName::try_parse::<T>(namespace, false)?;
+			ensure!(!parsed_name.descriptor_exists(), Error::<T>::InvalidSchemaNameStructure);
+			Ok(parsed_name.namespace)
+		}
+
+		/// Ensures that the MSA of a key owns a namespace
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The key has no MSA or its MSA does not own the namespace
+		fn ensure_namespace_owner(
+			key: &T::AccountId,
+			namespace: &SchemaProtocolName,
+		) -> DispatchResult {
+			let msa_id =
+				T::MsaInfoProvider::get_msa_id(key).ok_or(Error::<T>::NamespaceNotOwned)?;
+			ensure!(
+				NamespaceOwners::<T>::get(namespace) == Some(ProviderId(msa_id)),
+				Error::<T>::NamespaceNotOwned
+			);
+			Ok(())
+		}
+
+		/// Resolves a name in a namespace owned by the MSA of a key
+		///
+		/// # Errors
+		/// * [`Error::NamespaceNotOwned`] - The key has no MSA or its MSA does not own the namespace
+		/// * [`Error::InvalidSchemaNameEncoding`] and the other name errors - The name is invalid
+		/// * [`Error::InvalidIntentId`] - The name is not registered
+		fn get_owned_entity(
+			key: &T::AccountId,
+			name: SchemaNamePayload,
+		) -> Result<(SchemaName, MappedEntityIdentifier), DispatchError> {
+			let parsed_name = SchemaName::try_parse::<T>(name, true)?;
+			Self::ensure_namespace_owner(key, &parsed_name.namespace)?;
+			let entity_id = NameToMappedEntityIds::<T>::try_get(
+				&parsed_name.namespace,
+				&parsed_name.descriptor,
+			)
+			.map_err(|_| Error::<T>::InvalidIntentId)?;
+			Ok((parsed_name, entity_id))
+		}
+
+		/// Holds [`Config::NamespaceEntityDeposit`] from a depositor for an entity
+		fn hold_entity_deposit(
+			depositor: &T::AccountId,
+			entity: NamespaceEntity,
+		) -> DispatchResult {
+			let amount = T::NamespaceEntityDeposit::get();
+			T::Currency::hold(&HoldReason::NamespaceEntityDeposit.into(), depositor, amount)?;
+			EntityDeposits::<T>::insert(
+				entity,
+				EntityDeposit { depositor: depositor.clone(), amount },
+			);
+			Ok(())
+		}
+
+		/// Releases the deposit held for an entity, if any
+		fn release_entity_deposit(entity: NamespaceEntity) -> DispatchResult {
+			if let Some(deposit) = EntityDeposits::<T>::take(entity) {
+				T::Currency::release(
+					&HoldReason::NamespaceEntityDeposit.into(),
+					&deposit.depositor,
+					deposit.amount,
+					Precision::BestEffort,
+				)?;
+			}
+			Ok(())
+		}
+
 		/// Parses and validates a new name and makes sure it does not already exist
 		/// # Errors
 		/// * [`Error::NameAlreadyExists`] - The name already exists
@@ -1355,12 +2706,15 @@ impl<T: Config> SchemaBenchmarkHelper for Pallet<T> {
 		let model: BoundedVec<u8, T::SchemaModelMaxBytesBoundedVecLimit> =
 			model.try_into().unwrap();
 		Self::ensure_valid_model(&model_type, &model)?;
+		let settings = IntentInfos::<T>::get(intent_id)
+			.map(|intent| intent.settings)
+			.unwrap_or_default();
 		let schema_id = Self::add_schema(
 			intent_id,
 			model,
 			model_type,
 			payload_location,
-			IntentSettings::default(),
+			settings,
 			SchemaStatus::Active,
 		)?;
 		Ok(schema_id)
@@ -1377,6 +2731,15 @@ impl<T: Config> SchemaBenchmarkHelper for Pallet<T> {
 			Self::create_intent_for(name, payload_location, settings.try_into().unwrap())?;
 		Ok(intent_id)
 	}
+
+	/// Sets the message retention period of an Intent
+	fn set_intent_retention_period(intent_id: IntentId, retention: BlockNumber) {
+		let since = frame_system::Pallet::<T>::block_number().saturated_into();
+		IntentRetentionPeriods::<T>::insert(
+			intent_id,
+			IntentRetention { period: retention, since },
+		);
+	}
 }
 
 impl<T: Config> SchemaValidator<SchemaId> for Pallet<T> {
@@ -1408,4 +2771,19 @@ impl<T: Config> SchemaProvider<SchemaId> for Pallet<T> {
 	fn get_intent_by_id(intent_id: IntentId) -> Option<IntentResponse> {
 		Self::get_intent_by_id(intent_id)
 	}
+
+	fn validate_payload(schema_id: SchemaId, payload: &[u8]) -> Result<(), PayloadValidationError> {
+		Self::validate_payload(schema_id, payload)
+	}
+
+	fn get_intent_retention_periods(
+		start_after: Option<IntentId>,
+		limit: u32,
+	) -> Vec<(IntentId, IntentRetention)> {
+		Self::get_intent_retention_periods(start_after, limit)
+	}
+
+	fn get_intent_storage_quota(intent_id: IntentId) -> Option<StorageQuota> {
+		Self::get_intent_storage_quota(intent_id)
+	}
 }
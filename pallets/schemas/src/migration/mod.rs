@@ -1,5 +1,8 @@
 /// Types and storage aliases for V5 storage
 mod v4;
-/// Migration module for migrating from V5 to V6
+/// Migration module for migrating from V4 to V5
 pub mod v5;
 pub use v5::MigrateV4ToV5;
+/// Migration module for migrating from V5 to V6
+pub mod v6;
+pub use v6::MigrateV5ToV6;
@@ -0,0 +1,136 @@
+use crate::{
+	pallet::{
+		IntentLatestSchemaIds, IntentLatestSupportedSchemaIds, SchemaInfos, SupportedSchemaLinks,
+	},
+	Config, Pallet, SupportedSchemaLink, SCHEMA_STORAGE_VERSION,
+};
+use alloc::{collections::BTreeMap, vec::Vec};
+use common_primitives::schema::{IntentId, SchemaId, SchemaStatus};
+use core::marker::PhantomData;
+#[cfg(feature = "try-runtime")]
+use frame_support::ensure;
+use frame_support::{
+	pallet_prelude::{Get, GetStorageVersion, Weight},
+	traits::UncheckedOnRuntimeUpgrade,
+};
+#[cfg(feature = "try-runtime")]
+use parity_scale_codec::{Decode, Encode};
+#[cfg(feature = "try-runtime")]
+use sp_runtime::TryRuntimeError;
+
+const LOG_TARGET: &str = "pallet::schemas::migration::v6";
+
+/// Migrate from v5 to v6: record the latest schema of each Intent, and link the `Active` and
+/// `Deprecated` schemas of each Intent.
+pub struct InnerMigrateV5ToV6<T: Config>(PhantomData<T>);
+
+impl<T: Config> UncheckedOnRuntimeUpgrade for InnerMigrateV5ToV6<T> {
+	fn on_runtime_upgrade() -> Weight {
+		let onchain_version = Pallet::<T>::on_chain_storage_version();
+		let current_version = Pallet::<T>::in_code_storage_version();
+		log::info!(target: LOG_TARGET, "onchain_version={onchain_version:?}, current_version={current_version:?}");
+		if SCHEMA_STORAGE_VERSION != current_version {
+			log::error!(target: LOG_TARGET, "storage version mismatch: expected {SCHEMA_STORAGE_VERSION:?}, found {current_version:?}");
+			return T::DbWeight::get().reads(1)
+		}
+		if onchain_version < current_version {
+			log::info!(target: LOG_TARGET, "Migrating from v5 to v6");
+			let mut reads = 0;
+			let mut writes = 0;
+			let mut supported_schema_ids = BTreeMap::<IntentId, Vec<SchemaId>>::new();
+
+			SchemaInfos::<T>::iter().for_each(|(schema_id, schema_info)| {
+				reads += 2;
+				writes += 1;
+				IntentLatestSchemaIds::<T>::mutate(schema_info.intent_id, |latest| {
+					if latest.is_none_or(|latest_id| latest_id < schema_id) {
+						*latest = Some(schema_id);
+					}
+				});
+				if schema_info.status != SchemaStatus::Unsupported {
+					supported_schema_ids.entry(schema_info.intent_id).or_default().push(schema_id);
+				}
+			});
+
+			for (intent_id, mut schema_ids) in supported_schema_ids {
+				schema_ids.sort();
+				for (index, schema_id) in schema_ids.iter().enumerate() {
+					let link = SupportedSchemaLink {
+						previous: index.checked_sub(1).map(|previous| schema_ids[previous]),
+						next: schema_ids.get(index + 1).copied(),
+					};
+					SupportedSchemaLinks::<T>::insert(schema_id, link);
+					writes += 1;
+				}
+				if let Some(latest_id) = schema_ids.last() {
+					IntentLatestSupportedSchemaIds::<T>::insert(intent_id, latest_id);
+					writes += 1;
+				}
+			}
+
+			log::info!(target: LOG_TARGET, "Migration complete. schemas_read={:?}", reads / 2);
+
+			// Set storage version to current version
+			SCHEMA_STORAGE_VERSION.put::<Pallet<T>>();
+			let weight = T::DbWeight::get().reads_writes(reads, writes);
+			log::info!(target: LOG_TARGET,
+				"Schemas storage migrated to version {current_version:?}. reads={reads:?}, writes={writes:?}, proof_size={:?}, ref_time={:?}",
+				weight.proof_size(), weight.ref_time());
+
+			weight
+		} else {
+			log::info!(target: LOG_TARGET,
+			"Migration did not execute; storage version is already up to date. \
+			onchain_version={onchain_version:?}, current_version={current_version:?}"
+			);
+			T::DbWeight::get().reads(1)
+		}
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn pre_upgrade() -> Result<Vec<u8>, TryRuntimeError> {
+		let mut intent_ids =
+			SchemaInfos::<T>::iter_values().map(|info| info.intent_id).collect::<Vec<_>>();
+		intent_ids.sort();
+		intent_ids.dedup();
+		Ok((intent_ids.len() as u32).encode())
+	}
+
+	#[cfg(feature = "try-runtime")]
+	fn post_upgrade(state: Vec<u8>) -> Result<(), TryRuntimeError> {
+		let intents_with_schemas = u32::decode(&mut &state[..])
+			.map_err(|_| TryRuntimeError::Other("unable to decode intent count"))?;
+		let latest_count = IntentLatestSchemaIds::<T>::iter_values().count() as u32;
+
+		ensure!(intents_with_schemas == latest_count, "latest schema count mismatch");
+		ensure!(
+			IntentLatestSchemaIds::<T>::iter().all(|(intent_id, schema_id)| {
+				SchemaInfos::<T>::get(schema_id).is_some_and(|info| info.intent_id == intent_id)
+			}),
+			"latest schema does not belong to its intent"
+		);
+		ensure!(
+			IntentLatestSupportedSchemaIds::<T>::iter().all(|(intent_id, schema_id)| {
+				SchemaInfos::<T>::get(schema_id).is_some_and(|info| {
+					info.intent_id == intent_id && info.status != SchemaStatus::Unsupported
+				}) && SupportedSchemaLinks::<T>::get(schema_id)
+					.is_some_and(|link| link.next.is_none())
+			}),
+			"latest supported schema is not the last supported schema of its intent"
+		);
+		Ok(())
+	}
+}
+
+/// [`UncheckedOnRuntimeUpgrade`] implementation [`InnerMigrateV5ToV6`] wrapped in a
+/// [`VersionedMigration`](frame_support::migrations::VersionedMigration), which ensures that:
+/// - The migration only runs once when the on-chain storage version is 5
+/// - The on-chain storage version is updated to `6` after the migration executes
+/// - Reads/Writes from checking/settings the on-chain storage version are accounted for
+pub type MigrateV5ToV6<T> = frame_support::migrations::VersionedMigration<
+	5, // The migration will only execute when the on-chain storage version is 5
+	6, // The on-chain storage version will be set to 6 after the migration is complete
+	InnerMigrateV5ToV6<T>,
+	crate::pallet::Pallet<T>,
+	<T as frame_system::Config>::DbWeight,
+>;
@@ -13,6 +13,7 @@ use common_primitives::schema::*;
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
 	proc_macros::rpc,
+	types::{ErrorObject, ErrorObjectOwned},
 };
 use pallet_schemas_runtime_api::SchemasRuntimeApi;
 use sp_api::ProvideRuntimeApi;
@@ -30,6 +31,43 @@ pub trait SchemasApi<BlockHash> {
 	/// retrieving schema by schema id
 	#[method(name = "schemas_getBySchemaId")]
 	fn get_by_schema_id(&self, schema_id: SchemaId) -> RpcResult<Option<SchemaResponse>>;
+
+	/// List Intents in order of IntentId, filtered by namespace prefix and payload location
+	#[method(name = "schemas_listIntents")]
+	fn list_intents(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<IntentResponseV2>>;
+
+	/// List Schemas in order of SchemaId, filtered by namespace prefix, payload location and status
+	#[method(name = "schemas_listSchemas")]
+	fn list_schemas(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<SchemaInfoResponse>>;
+
+	/// List IntentGroups in order of IntentGroupId, filtered by namespace prefix
+	#[method(name = "schemas_listIntentGroups")]
+	fn list_intent_groups(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<IntentGroupResponse>>;
+}
+
+/// Errors that occur on the client RPC
+#[derive(Debug)]
+pub enum SchemaRpcError {
+	/// Pagination request is bad
+	InvalidPaginationRequest,
+}
+
+impl From<SchemaRpcError> for ErrorObjectOwned {
+	fn from(e: SchemaRpcError) -> Self {
+		let msg = format!("{e:?}");
+		match e {
+			SchemaRpcError::InvalidPaginationRequest => ErrorObject::owned(1, msg, None::<()>),
+		}
+	}
 }
 
 /// The client handler for the API used by Frequency Service RPC with `jsonrpsee`
@@ -59,4 +97,40 @@ where
 		let schema_api_result = api.get_by_schema_id(at, schema_id);
 		map_rpc_result(schema_api_result)
 	}
+
+	fn list_intents(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<IntentResponseV2>> {
+		if !pagination.validate() {
+			return Err(SchemaRpcError::InvalidPaginationRequest.into());
+		}
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		map_rpc_result(api.list_intents(at, pagination))
+	}
+
+	fn list_schemas(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<SchemaInfoResponse>> {
+		if !pagination.validate() {
+			return Err(SchemaRpcError::InvalidPaginationRequest.into());
+		}
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		map_rpc_result(api.list_schemas(at, pagination))
+	}
+
+	fn list_intent_groups(
+		&self,
+		pagination: EntityPaginationRequest,
+	) -> RpcResult<EntityPaginationResponse<IntentGroupResponse>> {
+		if !pagination.validate() {
+			return Err(SchemaRpcError::InvalidPaginationRequest.into());
+		}
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		map_rpc_result(api.list_intent_groups(at, pagination))
+	}
 }
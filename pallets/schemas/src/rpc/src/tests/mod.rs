@@ -23,6 +23,19 @@ sp_api::mock_impl_runtime_apis! {
 				_ => None,
 			}
 		}
+
+		fn list_schemas(request: EntityPaginationRequest) -> EntityPaginationResponse<SchemaInfoResponse> {
+			let mut response = EntityPaginationResponse::new();
+			response.content.push(SchemaInfoResponse {
+				schema_id: request.from_id,
+				model_type: ModelType::AvroBinary,
+				payload_location: request.payload_location.unwrap_or(PayloadLocation::OnChain),
+				settings: Vec::new(),
+				intent_id: 1,
+				status: request.status.unwrap_or(SchemaStatus::Active),
+			});
+			response
+		}
 	}
 }
 
@@ -56,3 +69,43 @@ async fn get_schema_with_success() {
 	assert_eq!(ModelType::AvroBinary, response.model_type);
 	assert_eq!(PayloadLocation::OnChain, response.payload_location);
 }
+
+type SchemaListResult =
+	Result<EntityPaginationResponse<SchemaInfoResponse>, jsonrpsee::types::ErrorObjectOwned>;
+
+#[tokio::test]
+async fn list_schemas_with_invalid_page_size_should_fail() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	for page_size in [0, EntityPaginationRequest::MAX_PAGE_SIZE + 1] {
+		let result: SchemaListResult = api.list_schemas(EntityPaginationRequest {
+			from_id: 1,
+			page_size,
+			..Default::default()
+		});
+
+		assert!(result.is_err());
+	}
+}
+
+#[tokio::test]
+async fn list_schemas_with_success() {
+	let client = Arc::new(TestApi {});
+	let api = SchemasHandler::new(client);
+
+	let result: SchemaListResult = api.list_schemas(EntityPaginationRequest {
+		from_id: 5,
+		page_size: 10,
+		payload_location: Some(PayloadLocation::Paginated),
+		status: Some(SchemaStatus::Deprecated),
+		..Default::default()
+	});
+
+	let response = result.unwrap();
+	assert_eq!(1, response.content.len());
+	assert_eq!(5, response.content[0].schema_id);
+	assert_eq!(PayloadLocation::Paginated, response.content[0].payload_location);
+	assert_eq!(SchemaStatus::Deprecated, response.content[0].status);
+	assert!(!response.has_next);
+}
@@ -56,5 +56,29 @@ sp_api::decl_runtime_apis! {
 		/// Fetch the IntentGroup by id
 		#[api_version(3)]
 		fn get_intent_group_by_id(intent_group_id: IntentGroupId) -> Option<IntentGroupResponse>;
+
+		/// Check a payload against the Avro model of a schema without submitting it
+		#[api_version(4)]
+		fn validate_payload(schema_id: SchemaId, payload: Vec<u8>) -> Result<(), PayloadValidationError>;
+
+		/// List Intents in order of IntentId, filtered by namespace prefix and payload location
+		#[api_version(5)]
+		fn list_intents(request: EntityPaginationRequest) -> EntityPaginationResponse<IntentResponseV2>;
+
+		/// List Schemas in order of SchemaId, filtered by namespace prefix, payload location and status
+		#[api_version(5)]
+		fn list_schemas(request: EntityPaginationRequest) -> EntityPaginationResponse<SchemaInfoResponse>;
+
+		/// List IntentGroups in order of IntentGroupId, filtered by namespace prefix
+		#[api_version(5)]
+		fn list_intent_groups(request: EntityPaginationRequest) -> EntityPaginationResponse<IntentGroupResponse>;
+
+		/// Fetch the Intent by id, optionally with the status of every implementing Schema
+		#[api_version(6)]
+		fn get_intent_by_id_v2(intent_id: IntentId, include_schemas: bool) -> Option<IntentResponseV2>;
+
+		/// Fetch the schema versions by name with the status of every Schema
+		#[api_version(6)]
+		fn get_schema_versions_by_name_v2(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponseV2>>;
 	}
 }
@@ -0,0 +1,383 @@
+use super::mock::*;
+use crate::{
+	pallet::{
+		CurrentSchemaIdentifierMaximum, IntentCompatibilityPolicies,
+		IntentLatestSupportedSchemaIds, SupportedSchemaLinks,
+	},
+	Error, Event as AnnouncementEvent, SchemaNamePayload, SupportedSchemaLink, WeightInfo,
+};
+use common_primitives::schema::{
+	CompatibilityPolicy, IntentId, ModelType, PayloadLocation, SchemaId, SchemaStatus,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+use pallet_collective::ProposalOf;
+use sp_runtime::{DispatchError::BadOrigin, DispatchResult};
+
+const RECORD_V1: &str = r#"{"type":"record","name":"Post","fields":[{"name":"id","type":"int"}]}"#;
+const RECORD_WIDENED: &str =
+	r#"{"type":"record","name":"Post","fields":[{"name":"id","type":"long"}]}"#;
+const RECORD_WITH_DEFAULT: &str = r#"{"type":"record","name":"Post","fields":[
+	{"name":"id","type":"int"},{"name":"text","type":"string","default":""}]}"#;
+const RECORD_WITH_REQUIRED: &str = r#"{"type":"record","name":"Post","fields":[
+	{"name":"id","type":"int"},{"name":"text","type":"string"}]}"#;
+const PARQUET_MODEL: &str =
+	r#"[{"name":"a","column_type":"BOOLEAN","compression":"SNAPPY","bloom_filter":false}]"#;
+
+fn create_intent_with_policy(
+	policy: CompatibilityPolicy,
+	model_type: ModelType,
+	model: &str,
+) -> (IntentId, SchemaId) {
+	assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 500));
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(b"protocol.descriptor".to_vec()).expect("should convert");
+	let (intent_id, _) = SchemasPallet::create_intent_for(
+		intent_name,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+	)
+	.expect("should have created an intent");
+	let schema_id =
+		SchemasPallet::create_schema_for(intent_id, create_bounded_schema_vec(model), model_type)
+			.expect("should create schema");
+	assert_ok!(SchemasPallet::set_intent_compatibility_policy_via_governance(
+		RawOrigin::Root.into(),
+		intent_id,
+		policy
+	));
+	(intent_id, schema_id)
+}
+
+fn create_schema(intent_id: IntentId, model_type: ModelType, model: &str) -> DispatchResult {
+	SchemasPallet::create_schema_v4(
+		test_origin_signed(1),
+		intent_id,
+		create_bounded_schema_vec(model),
+		model_type,
+	)
+	.map(|_| ())
+	.map_err(|e| e.error)
+}
+
+#[test]
+fn set_intent_compatibility_policy_via_governance_sets_policy() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::None, ModelType::AvroBinary, RECORD_V1);
+
+		assert_ok!(SchemasPallet::set_intent_compatibility_policy_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			intent_id,
+			CompatibilityPolicy::Full
+		));
+		System::assert_last_event(
+			AnnouncementEvent::IntentCompatibilityPolicyUpdated {
+				intent_id,
+				policy: CompatibilityPolicy::Full,
+			}
+			.into(),
+		);
+		assert_eq!(IntentCompatibilityPolicies::<Test>::get(intent_id), CompatibilityPolicy::Full);
+	})
+}
+
+#[test]
+fn set_intent_compatibility_policy_via_governance_requires_governance_and_intent() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::None, ModelType::AvroBinary, RECORD_V1);
+
+		assert_noop!(
+			SchemasPallet::set_intent_compatibility_policy_via_governance(
+				test_origin_signed(1),
+				intent_id,
+				CompatibilityPolicy::Full
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_compatibility_policy_via_governance(
+				RawOrigin::Root.into(),
+				intent_id + 1,
+				CompatibilityPolicy::Full
+			),
+			Error::<Test>::InvalidIntentId
+		);
+	})
+}
+
+#[test]
+fn propose_to_set_intent_compatibility_policy_creates_proposal() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::None, ModelType::AvroBinary, RECORD_V1);
+
+		assert_ok!(SchemasPallet::propose_to_set_intent_compatibility_policy(
+			test_origin_signed(5),
+			intent_id,
+			CompatibilityPolicy::Backward
+		));
+
+		let proposal_hash = System::events()
+			.iter()
+			.find_map(|event| match event.event {
+				RuntimeEvent::Council(pallet_collective::Event::Proposed {
+					proposal_hash, ..
+				}) => Some(proposal_hash),
+				_ => None,
+			})
+			.expect("proposal should be created");
+		assert_eq!(
+			ProposalOf::<Test, CouncilCollective>::get(proposal_hash),
+			Some(RuntimeCall::SchemasPallet(
+				crate::Call::set_intent_compatibility_policy_via_governance {
+					intent_id,
+					policy: CompatibilityPolicy::Backward,
+				}
+			))
+		);
+	})
+}
+
+#[test]
+fn create_schema_without_policy_is_not_checked() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::None, ModelType::AvroBinary, RECORD_V1);
+
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, r#""string""#));
+		assert_ok!(create_schema(intent_id, ModelType::Parquet, PARQUET_MODEL));
+	})
+}
+
+#[test]
+fn create_schema_with_backward_policy_requires_reading_previous_data() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) = create_intent_with_policy(
+			CompatibilityPolicy::Backward,
+			ModelType::AvroBinary,
+			RECORD_V1,
+		);
+
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_REQUIRED),
+			Error::<Test>::IncompatibleAvroFieldDefault
+		);
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, r#""string""#),
+			Error::<Test>::IncompatibleAvroType
+		);
+		assert_noop!(
+			create_schema(intent_id, ModelType::Parquet, PARQUET_MODEL),
+			Error::<Test>::IncompatibleSchemaModelType
+		);
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_DEFAULT));
+
+		// Checked against the latest schema, which writes the field that has no default
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_REQUIRED));
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WIDENED));
+	})
+}
+
+#[test]
+fn create_schema_with_forward_policy_requires_previous_schema_to_read_new_data() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) = create_intent_with_policy(
+			CompatibilityPolicy::Forward,
+			ModelType::AvroBinary,
+			RECORD_V1,
+		);
+
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, RECORD_WIDENED),
+			Error::<Test>::IncompatibleAvroType
+		);
+		assert_noop!(
+			create_schema(
+				intent_id,
+				ModelType::AvroBinary,
+				r#"{"type":"record","name":"Comment","fields":[{"name":"id","type":"int"}]}"#
+			),
+			Error::<Test>::IncompatibleAvroName
+		);
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_REQUIRED));
+	})
+}
+
+#[test]
+fn create_schema_with_full_policy_checks_both_directions_against_latest_schema() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::Full, ModelType::AvroBinary, RECORD_V1);
+
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_REQUIRED),
+			Error::<Test>::IncompatibleAvroFieldDefault
+		);
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, RECORD_WIDENED),
+			Error::<Test>::IncompatibleAvroType
+		);
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_DEFAULT));
+	})
+}
+
+#[test]
+fn create_schema_charges_for_previous_model_only_when_checked() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::Full, ModelType::AvroBinary, RECORD_V1);
+		let model = create_bounded_schema_vec(RECORD_WITH_DEFAULT);
+
+		let post_info = SchemasPallet::create_schema_v4(
+			test_origin_signed(1),
+			intent_id,
+			model.clone(),
+			ModelType::AvroBinary,
+		)
+		.expect("should create schema");
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::create_schema_v4(model.len() as u32, RECORD_V1.len() as u32))
+		);
+
+		assert_ok!(SchemasPallet::set_intent_compatibility_policy_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			CompatibilityPolicy::None
+		));
+		let post_info = SchemasPallet::create_schema_v4(
+			test_origin_signed(1),
+			intent_id,
+			model.clone(),
+			ModelType::AvroBinary,
+		)
+		.expect("should create schema");
+		assert_eq!(
+			post_info.actual_weight,
+			Some(<() as WeightInfo>::create_schema_v4(model.len() as u32, 0))
+		);
+	})
+}
+
+#[test]
+fn create_schema_skips_check_against_unsupported_schema() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, schema_id) =
+			create_intent_with_policy(CompatibilityPolicy::Full, ModelType::AvroBinary, RECORD_V1);
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			schema_id,
+			SchemaStatus::Unsupported
+		));
+
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, r#""string""#));
+	})
+}
+
+#[test]
+fn create_schema_checks_against_latest_supported_schema_when_latest_is_unsupported() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) =
+			create_intent_with_policy(CompatibilityPolicy::Full, ModelType::AvroBinary, RECORD_V1);
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_DEFAULT));
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			CurrentSchemaIdentifierMaximum::<Test>::get(),
+			SchemaStatus::Unsupported
+		));
+
+		assert_noop!(
+			create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_REQUIRED),
+			Error::<Test>::IncompatibleAvroFieldDefault
+		);
+		assert_ok!(create_schema(intent_id, ModelType::AvroBinary, RECORD_WITH_DEFAULT));
+	})
+}
+
+#[test]
+fn unsupported_schemas_are_unlinked_from_supported_schemas_of_intent() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, first_id) =
+			create_intent_with_policy(CompatibilityPolicy::None, ModelType::AvroBinary, RECORD_V1);
+		let [second_id, third_id] = [0; 2].map(|_| {
+			SchemasPallet::create_schema_for(
+				intent_id,
+				create_bounded_schema_vec(RECORD_V1),
+				ModelType::AvroBinary,
+			)
+			.expect("should create schema")
+		});
+		assert_eq!(IntentLatestSupportedSchemaIds::<Test>::get(intent_id), Some(third_id));
+		assert_eq!(
+			SupportedSchemaLinks::<Test>::get(second_id),
+			Some(SupportedSchemaLink { previous: Some(first_id), next: Some(third_id) })
+		);
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			second_id,
+			SchemaStatus::Unsupported
+		));
+		assert_eq!(SupportedSchemaLinks::<Test>::get(second_id), None);
+		assert_eq!(IntentLatestSupportedSchemaIds::<Test>::get(intent_id), Some(third_id));
+		assert_eq!(
+			SupportedSchemaLinks::<Test>::get(third_id),
+			Some(SupportedSchemaLink { previous: Some(first_id), next: None })
+		);
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			third_id,
+			SchemaStatus::Unsupported
+		));
+		assert_eq!(IntentLatestSupportedSchemaIds::<Test>::get(intent_id), Some(first_id));
+		assert_eq!(
+			SupportedSchemaLinks::<Test>::get(first_id),
+			Some(SupportedSchemaLink::default())
+		);
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			first_id,
+			SchemaStatus::Unsupported
+		));
+		assert_eq!(IntentLatestSupportedSchemaIds::<Test>::get(intent_id), None);
+		assert_eq!(SupportedSchemaLinks::<Test>::get(first_id), None);
+	})
+}
+
+#[test]
+fn create_schema_checks_parquet_columns() {
+	new_test_ext().execute_with(|| {
+		let (intent_id, _) = create_intent_with_policy(
+			CompatibilityPolicy::Backward,
+			ModelType::Parquet,
+			PARQUET_MODEL,
+		);
+
+		assert_noop!(
+			create_schema(
+				intent_id,
+				ModelType::Parquet,
+				r#"[{"name":"a","column_type":"INT32","compression":"SNAPPY","bloom_filter":false}]"#
+			),
+			Error::<Test>::IncompatibleParquetColumnType
+		);
+		assert_noop!(
+			create_schema(
+				intent_id,
+				ModelType::Parquet,
+				r#"[{"name":"a","column_type":"BOOLEAN","compression":"SNAPPY","bloom_filter":false},
+				{"name":"b","column_type":"BOOLEAN","compression":"SNAPPY","bloom_filter":false}]"#
+			),
+			Error::<Test>::IncompatibleParquetMissingColumn
+		);
+		assert_ok!(create_schema(
+			intent_id,
+			ModelType::Parquet,
+			r#"[{"name":"a","column_type":"BOOLEAN","compression":"GZIP","bloom_filter":false},
+			{"name":"b","column_type":"BOOLEAN","compression":"SNAPPY","bloom_filter":false,"optional":true}]"#
+		));
+	})
+}
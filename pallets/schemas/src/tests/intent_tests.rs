@@ -130,6 +130,37 @@ fn create_intent_via_governance_with_append_only_setting_and_non_itemized_should
 		}
 	})
 }
+#[test]
+fn create_intent_via_governance_with_allow_updates_without_append_only_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let sender: AccountId = test_public(1);
+		let name = "namespace.descriptor";
+		let intent_name: SchemaNamePayload =
+			BoundedVec::try_from(name.to_string().into_bytes()).expect("should convert");
+
+		// act and assert
+		assert_noop!(
+			SchemasPallet::create_intent_via_governance(
+				RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+				sender.clone(),
+				PayloadLocation::Itemized,
+				BoundedVec::try_from(vec![IntentSetting::AllowUpdates]).unwrap(),
+				intent_name.clone(),
+			),
+			Error::<Test>::InvalidSetting
+		);
+		assert_ok!(SchemasPallet::create_intent_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			sender,
+			PayloadLocation::Itemized,
+			BoundedVec::try_from(vec![IntentSetting::AppendOnly, IntentSetting::AllowUpdates])
+				.unwrap(),
+			intent_name,
+		));
+	})
+}
+
 #[test]
 fn create_intent_via_governance_with_signature_required_setting_and_wrong_location_should_fail() {
 	new_test_ext().execute_with(|| {
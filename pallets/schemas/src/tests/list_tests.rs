@@ -0,0 +1,149 @@
+use super::mock::*;
+use crate::SchemaNamePayload;
+use common_primitives::schema::{
+	EntityPaginationRequest, IntentId, ModelType, PayloadLocation, SchemaId, SchemaStatus,
+};
+use frame_support::{assert_ok, dispatch::RawOrigin, BoundedVec};
+
+const AVRO_MODEL: &str = r#""string""#;
+
+fn create_intent(name: &str, payload_location: PayloadLocation) -> IntentId {
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(name.as_bytes().to_vec()).expect("should convert");
+	let (intent_id, _) =
+		SchemasPallet::create_intent_for(intent_name, payload_location, BoundedVec::default())
+			.expect("should have created an intent");
+	intent_id
+}
+
+fn create_schema(intent_id: IntentId) -> SchemaId {
+	SchemasPallet::create_schema_for(
+		intent_id,
+		create_bounded_schema_vec(AVRO_MODEL),
+		ModelType::AvroBinary,
+	)
+	.expect("should create schema")
+}
+
+fn request(from_id: u16, page_size: u16) -> EntityPaginationRequest {
+	EntityPaginationRequest { from_id, page_size, ..Default::default() }
+}
+
+/// Creates `dsnp.public-key` (Itemized, 2 schemas), `dsnp.broadcast` (OnChain, 1 schema),
+/// `other.broadcast` (OnChain, 1 schema) and the IntentGroups `dsnp.all` and `other.all`
+fn create_entities() {
+	assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 500));
+	let keys = create_intent("dsnp.public-key", PayloadLocation::Itemized);
+	let broadcast = create_intent("dsnp.broadcast", PayloadLocation::OnChain);
+	let other = create_intent("other.broadcast", PayloadLocation::OnChain);
+	create_schema(keys);
+	create_schema(keys);
+	create_schema(broadcast);
+	create_schema(other);
+	for (name, intent_ids) in [("dsnp.all", vec![keys, broadcast]), ("other.all", vec![other])] {
+		assert_ok!(SchemasPallet::create_intent_group_for(
+			BoundedVec::try_from(name.as_bytes().to_vec()).expect("should convert"),
+			BoundedVec::try_from(intent_ids).expect("should convert"),
+		));
+	}
+}
+
+#[test]
+fn list_intents_should_paginate_in_order_of_id() {
+	new_test_ext().execute_with(|| {
+		create_entities();
+
+		let first_page = SchemasPallet::list_intents(request(0, 2));
+		let ids: Vec<IntentId> = first_page.content.iter().map(|i| i.intent_id).collect();
+		assert_eq!(ids, vec![1, 2]);
+		assert!(first_page.has_next);
+		assert_eq!(first_page.next_id, Some(3));
+
+		let last_page = SchemasPallet::list_intents(request(3, 2));
+		let ids: Vec<IntentId> = last_page.content.iter().map(|i| i.intent_id).collect();
+		assert_eq!(ids, vec![3]);
+		assert!(!last_page.has_next);
+		assert_eq!(last_page.next_id, None);
+	})
+}
+
+#[test]
+fn list_intents_should_filter_by_namespace_prefix_and_payload_location() {
+	new_test_ext().execute_with(|| {
+		create_entities();
+
+		let dsnp = SchemasPallet::list_intents(EntityPaginationRequest {
+			namespace_prefix: Some(b"DSNP.".to_vec()),
+			..request(1, 10)
+		});
+		let ids: Vec<IntentId> = dsnp.content.iter().map(|i| i.intent_id).collect();
+		assert_eq!(ids, vec![1, 2]);
+
+		let on_chain = SchemasPallet::list_intents(EntityPaginationRequest {
+			payload_location: Some(PayloadLocation::OnChain),
+			..request(1, 10)
+		});
+		let ids: Vec<IntentId> = on_chain.content.iter().map(|i| i.intent_id).collect();
+		assert_eq!(ids, vec![2, 3]);
+	})
+}
+
+#[test]
+fn list_schemas_should_filter_by_intent_namespace_payload_location_and_status() {
+	new_test_ext().execute_with(|| {
+		create_entities();
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			1,
+			SchemaStatus::Deprecated,
+		));
+
+		let all = SchemasPallet::list_schemas(request(1, 10));
+		let ids: Vec<SchemaId> = all.content.iter().map(|s| s.schema_id).collect();
+		assert_eq!(ids, vec![1, 2, 3, 4]);
+
+		let dsnp_on_chain = SchemasPallet::list_schemas(EntityPaginationRequest {
+			namespace_prefix: Some(b"dsnp.".to_vec()),
+			payload_location: Some(PayloadLocation::OnChain),
+			..request(1, 10)
+		});
+		let ids: Vec<SchemaId> = dsnp_on_chain.content.iter().map(|s| s.schema_id).collect();
+		assert_eq!(ids, vec![3]);
+
+		let active_keys = SchemasPallet::list_schemas(EntityPaginationRequest {
+			namespace_prefix: Some(b"dsnp.public".to_vec()),
+			status: Some(SchemaStatus::Active),
+			..request(1, 10)
+		});
+		let ids: Vec<SchemaId> = active_keys.content.iter().map(|s| s.schema_id).collect();
+		assert_eq!(ids, vec![2]);
+	})
+}
+
+#[test]
+fn list_intent_groups_should_filter_by_namespace_prefix() {
+	new_test_ext().execute_with(|| {
+		create_entities();
+
+		let other = SchemasPallet::list_intent_groups(EntityPaginationRequest {
+			namespace_prefix: Some(b"other".to_vec()),
+			..request(1, 10)
+		});
+		assert_eq!(other.content.len(), 1);
+		assert_eq!(other.content[0].intent_group_id, 2);
+		assert_eq!(other.content[0].intent_ids, vec![3]);
+	})
+}
+
+#[test]
+fn list_with_invalid_page_size_should_return_empty_page() {
+	new_test_ext().execute_with(|| {
+		create_entities();
+
+		for page_size in [0, EntityPaginationRequest::MAX_PAGE_SIZE + 1] {
+			let response = SchemasPallet::list_intents(request(1, page_size));
+			assert!(response.content.is_empty());
+			assert!(!response.has_next);
+		}
+	})
+}
@@ -1,14 +1,17 @@
 use frame_support::{
 	assert_ok,
 	dispatch::RawOrigin,
-	traits::{ConstU16, ConstU32, EitherOfDiverse},
+	traits::{ConstU16, ConstU32, ConstU64, EitherOfDiverse},
 	weights::Weight,
 	BoundedVec,
 };
 use frame_system::EnsureRoot;
 use parity_scale_codec::MaxEncodedLen;
 
-use common_primitives::node::AccountId;
+use common_primitives::{
+	msa::{MessageSourceId, MsaLookup},
+	node::AccountId,
+};
 use common_runtime::constants::DAYS;
 use pallet_collective::{self, Members};
 use sp_core::{parameter_types, Encode, H256};
@@ -25,7 +28,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		SchemasPallet: pallet_schemas::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		SchemasPallet: pallet_schemas::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Council: pallet_collective::<Instance1>::{Pallet, Call, Config<T,I>, Storage, Event<T>, Origin<T>},
 	}
 );
@@ -38,6 +42,7 @@ parameter_types! {
 	frame_system::limits::BlockWeights::simple_max(Weight::MAX);
 	pub MaxProposalWeight: frame_support::weights::Weight  = sp_runtime::Perbill::from_percent(50) * BlockWeights::get().max_block;
 	pub const SchemaModelMaxBytesBoundedVecLimit :u32 = 65_500;
+	pub const NamespaceEntityDeposit: u64 = 100;
 }
 
 impl Encode for SchemaModelMaxBytesBoundedVecLimit {}
@@ -68,6 +73,61 @@ impl pallet_collective::Config<CouncilCollective> for Test {
 
 pub type MaxIntentsPerIntentGroup = ConstU32<10>;
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<1>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type DoneSlashHandler = ();
+}
+
+/// Keys created by [`test_public`] with a non-zero integer belong to the MSA with that id
+pub struct MsaInfoHandler;
+
+impl MsaLookup for MsaInfoHandler {
+	type AccountId = AccountId;
+
+	fn get_msa_id(key: &AccountId) -> Option<MessageSourceId> {
+		let bytes: &[u8; 32] = key.as_ref();
+		match bytes[0] {
+			0 => None,
+			n => Some(n as MessageSourceId),
+		}
+	}
+
+	fn get_max_msa_id() -> MessageSourceId {
+		u8::MAX as MessageSourceId
+	}
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl common_primitives::benchmarks::MsaBenchmarkHelper<AccountId> for MsaInfoHandler {
+	fn set_delegation_relationship(
+		_provider: common_primitives::msa::ProviderId,
+		_delegator: common_primitives::msa::DelegatorId,
+		_schemas: Vec<common_primitives::schema::SchemaId>,
+	) -> sp_runtime::DispatchResult {
+		Ok(())
+	}
+
+	fn add_key(_msa_id: MessageSourceId, _key: AccountId) -> sp_runtime::DispatchResult {
+		Ok(())
+	}
+
+	fn create_msa(key: AccountId) -> Result<MessageSourceId, DispatchError> {
+		Self::get_msa_id(&key).ok_or(DispatchError::Other("key has no MSA"))
+	}
+}
+
 /// Interface to collective pallet to propose a proposal.
 pub struct CouncilProposalProvider;
 
@@ -117,10 +177,17 @@ impl pallet_schemas::Config for Test {
 		EnsureRoot<AccountId>,
 		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
 	>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type NamespaceEntityDeposit = NamespaceEntityDeposit;
+	type MsaInfoProvider = MsaInfoHandler;
+	type MaxIntentsWithRetention = ConstU32<2>;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = MsaInfoHandler;
 }
 
 impl frame_system::Config for Test {
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
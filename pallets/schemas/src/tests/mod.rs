@@ -1,5 +1,12 @@
+mod compatibility_tests;
 mod intent_group_tests;
 mod intent_tests;
+mod list_tests;
 pub mod mock;
+mod namespace_tests;
 mod other_tests;
+mod payload_validation_tests;
+mod retention_tests;
+mod schema_status_tests;
 mod serde_tests;
+mod storage_quota_tests;
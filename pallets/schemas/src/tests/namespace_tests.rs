@@ -0,0 +1,465 @@
+use super::mock::*;
+use crate::{
+	EntityDeposits, Error, Event as AnnouncementEvent, HoldReason, IntentGroupMemberships,
+	NamespaceEntity, NamespaceOwners, SchemaNamePayload,
+};
+use common_primitives::{
+	msa::ProviderId,
+	schema::{IntentId, MappedEntityIdentifier, ModelType, PayloadLocation, SchemaStatus},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	dispatch::RawOrigin,
+	traits::fungible::{Inspect, InspectHold},
+	BoundedVec,
+};
+use pallet_collective::ProposalOf;
+use sp_runtime::DispatchError::BadOrigin;
+
+const AVRO_MODEL: &str = r#""string""#;
+
+fn name(value: &str) -> SchemaNamePayload {
+	BoundedVec::try_from(value.as_bytes().to_vec()).expect("should convert")
+}
+
+/// Makes MSA `n` the owner of `namespace` and funds the key `test_public(n)`
+fn set_up_owner(n: u8, namespace: &str) {
+	assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 500));
+	assert_ok!(SchemasPallet::set_namespace_owner_via_governance(
+		RawOrigin::Root.into(),
+		name(namespace),
+		Some(ProviderId(n as u64))
+	));
+	assert_ok!(Balances::force_set_balance(RawOrigin::Root.into(), test_public(n), 1_000));
+}
+
+fn held_deposit(n: u8) -> u64 {
+	Balances::balance_on_hold(&HoldReason::NamespaceEntityDeposit.into(), &test_public(n))
+}
+
+fn create_provider_intent(n: u8, intent_name: &str) -> IntentId {
+	assert_ok!(SchemasPallet::create_provider_intent(
+		test_origin_signed(n),
+		name(intent_name),
+		PayloadLocation::OnChain,
+		BoundedVec::default()
+	));
+	match SchemasPallet::get_intent_or_group_ids_by_name(intent_name.as_bytes().to_vec())
+		.expect("should resolve name")[0]
+		.entity_id
+	{
+		MappedEntityIdentifier::Intent(intent_id) => intent_id,
+		_ => panic!("name should refer to an Intent"),
+	}
+}
+
+#[test]
+fn set_namespace_owner_via_governance_should_set_and_clear_owner() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SchemasPallet::set_namespace_owner_via_governance(
+			RawOrigin::Root.into(),
+			name("Acme"),
+			Some(ProviderId(1))
+		));
+		assert_eq!(NamespaceOwners::<Test>::get(name("acme")), Some(ProviderId(1)));
+		System::assert_last_event(
+			AnnouncementEvent::NamespaceOwnerUpdated {
+				namespace: b"acme".to_vec(),
+				owner: Some(ProviderId(1)),
+			}
+			.into(),
+		);
+
+		assert_ok!(SchemasPallet::set_namespace_owner_via_governance(
+			RawOrigin::Root.into(),
+			name("acme"),
+			None
+		));
+		assert_eq!(NamespaceOwners::<Test>::get(name("acme")), None);
+	})
+}
+
+#[test]
+fn set_namespace_owner_via_governance_should_reject_invalid_namespaces_and_origins() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SchemasPallet::set_namespace_owner_via_governance(
+				RawOrigin::Root.into(),
+				name("acme.intent"),
+				Some(ProviderId(1))
+			),
+			Error::<Test>::InvalidSchemaNameStructure
+		);
+		assert_noop!(
+			SchemasPallet::set_namespace_owner_via_governance(
+				test_origin_signed(1),
+				name("acme"),
+				Some(ProviderId(1))
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn propose_to_set_namespace_owner_creates_proposal() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SchemasPallet::propose_to_set_namespace_owner(
+			test_origin_signed(5),
+			name("acme"),
+			Some(ProviderId(5))
+		));
+
+		let proposal_hash = System::events()
+			.iter()
+			.find_map(|event| match event.event {
+				RuntimeEvent::Council(pallet_collective::Event::Proposed {
+					proposal_hash, ..
+				}) => Some(proposal_hash),
+				_ => None,
+			})
+			.expect("proposal should be created");
+		assert_eq!(
+			ProposalOf::<Test, CouncilCollective>::get(proposal_hash),
+			Some(RuntimeCall::SchemasPallet(crate::Call::set_namespace_owner_via_governance {
+				namespace: name("acme"),
+				owner: Some(ProviderId(5)),
+			}))
+		);
+	})
+}
+
+#[test]
+fn create_provider_intent_should_hold_deposit() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+
+		let intent_id = create_provider_intent(1, "acme.intent");
+
+		assert_eq!(held_deposit(1), NamespaceEntityDeposit::get());
+		assert_eq!(
+			EntityDeposits::<Test>::get(NamespaceEntity::Intent(intent_id)).map(|d| d.depositor),
+			Some(test_public(1))
+		);
+		System::assert_last_event(
+			AnnouncementEvent::IntentCreated {
+				key: test_public(1),
+				intent_id,
+				intent_name: b"acme.intent".to_vec(),
+			}
+			.into(),
+		);
+	})
+}
+
+#[test]
+fn create_provider_intent_should_fail_if_namespace_not_owned() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		assert_ok!(Balances::force_set_balance(RawOrigin::Root.into(), test_public(2), 1_000));
+
+		// another MSA
+		assert_noop!(
+			SchemasPallet::create_provider_intent(
+				test_origin_signed(2),
+				name("acme.intent"),
+				PayloadLocation::OnChain,
+				BoundedVec::default()
+			),
+			Error::<Test>::NamespaceNotOwned
+		);
+		// a namespace without owner
+		assert_noop!(
+			SchemasPallet::create_provider_intent(
+				test_origin_signed(1),
+				name("other.intent"),
+				PayloadLocation::OnChain,
+				BoundedVec::default()
+			),
+			Error::<Test>::NamespaceNotOwned
+		);
+		// a key without MSA
+		assert_noop!(
+			SchemasPallet::create_provider_intent(
+				test_origin_signed(0),
+				name("acme.intent"),
+				PayloadLocation::OnChain,
+				BoundedVec::default()
+			),
+			Error::<Test>::NamespaceNotOwned
+		);
+	})
+}
+
+#[test]
+fn create_provider_intent_should_fail_without_funds_for_deposit() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		assert_ok!(Balances::force_set_balance(
+			RawOrigin::Root.into(),
+			test_public(1),
+			NamespaceEntityDeposit::get() / 2
+		));
+
+		assert!(SchemasPallet::create_provider_intent(
+			test_origin_signed(1),
+			name("acme.intent"),
+			PayloadLocation::OnChain,
+			BoundedVec::default()
+		)
+		.is_err());
+		assert_eq!(SchemasPallet::get_intent_or_group_ids_by_name(b"acme.intent".to_vec()), None);
+	})
+}
+
+#[test]
+fn create_provider_schema_should_hold_deposit_until_unsupported() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		create_provider_intent(1, "acme.intent");
+
+		assert_ok!(SchemasPallet::create_provider_schema(
+			test_origin_signed(1),
+			name("acme.intent"),
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary
+		));
+		let schema_id = 1;
+		assert_eq!(held_deposit(1), 2 * NamespaceEntityDeposit::get());
+
+		assert_ok!(SchemasPallet::update_provider_schema_status(
+			test_origin_signed(1),
+			name("acme.intent"),
+			schema_id,
+			SchemaStatus::Deprecated
+		));
+		assert_eq!(held_deposit(1), 2 * NamespaceEntityDeposit::get());
+
+		assert_ok!(SchemasPallet::update_provider_schema_status(
+			test_origin_signed(1),
+			name("acme.intent"),
+			schema_id,
+			SchemaStatus::Unsupported
+		));
+		assert_eq!(held_deposit(1), NamespaceEntityDeposit::get());
+		assert!(!EntityDeposits::<Test>::contains_key(NamespaceEntity::Schema(schema_id)));
+		assert_eq!(Balances::balance(&test_public(1)), 1_000 - NamespaceEntityDeposit::get());
+	})
+}
+
+#[test]
+fn update_schema_status_via_governance_should_release_schema_deposit() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		create_provider_intent(1, "acme.intent");
+		assert_ok!(SchemasPallet::create_provider_schema(
+			test_origin_signed(1),
+			name("acme.intent"),
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary
+		));
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			1,
+			SchemaStatus::Unsupported
+		));
+
+		assert_eq!(held_deposit(1), NamespaceEntityDeposit::get());
+	})
+}
+
+#[test]
+fn provider_schema_extrinsics_should_fail_for_names_that_are_not_intents() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		assert_ok!(SchemasPallet::create_provider_intent_group(
+			test_origin_signed(1),
+			name("acme.group"),
+			BoundedVec::default()
+		));
+
+		assert_noop!(
+			SchemasPallet::create_provider_schema(
+				test_origin_signed(1),
+				name("acme.group"),
+				create_bounded_schema_vec(AVRO_MODEL),
+				ModelType::AvroBinary
+			),
+			Error::<Test>::InvalidIntentId
+		);
+		assert_noop!(
+			SchemasPallet::create_provider_schema(
+				test_origin_signed(1),
+				name("acme.missing"),
+				create_bounded_schema_vec(AVRO_MODEL),
+				ModelType::AvroBinary
+			),
+			Error::<Test>::InvalidIntentId
+		);
+	})
+}
+
+#[test]
+fn update_provider_schema_status_should_fail_for_schema_of_another_intent() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		create_provider_intent(1, "acme.intent");
+		let (other_intent_id, _) = SchemasPallet::create_intent_for(
+			name("other.intent"),
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+		)
+		.expect("should create intent");
+		let schema_id = SchemasPallet::create_schema_for(
+			other_intent_id,
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary,
+		)
+		.expect("should create schema");
+
+		assert_noop!(
+			SchemasPallet::update_provider_schema_status(
+				test_origin_signed(1),
+				name("acme.intent"),
+				schema_id,
+				SchemaStatus::Deprecated
+			),
+			Error::<Test>::NamespaceNotOwned
+		);
+	})
+}
+
+#[test]
+fn remove_provider_intent_should_refund_deposit_and_free_name() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		let intent_id = create_provider_intent(1, "acme.intent");
+
+		assert_ok!(SchemasPallet::remove_provider_intent(
+			test_origin_signed(1),
+			name("acme.intent")
+		));
+
+		assert_eq!(held_deposit(1), 0);
+		assert_eq!(Balances::balance(&test_public(1)), 1_000);
+		assert_eq!(SchemasPallet::get_intent_by_id(intent_id), None);
+		assert_eq!(SchemasPallet::get_intent_or_group_ids_by_name(b"acme.intent".to_vec()), None);
+		System::assert_last_event(
+			AnnouncementEvent::IntentRemoved { key: test_public(1), intent_id }.into(),
+		);
+
+		// the name can be registered again
+		create_provider_intent(1, "acme.intent");
+	})
+}
+
+#[test]
+fn remove_provider_intent_should_fail_if_intent_is_in_use() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		let intent_id = create_provider_intent(1, "acme.grouped");
+		assert_ok!(SchemasPallet::create_provider_intent_group(
+			test_origin_signed(1),
+			name("acme.group"),
+			BoundedVec::try_from(vec![intent_id]).expect("should convert")
+		));
+		create_provider_intent(1, "acme.with-schema");
+		assert_ok!(SchemasPallet::create_provider_schema(
+			test_origin_signed(1),
+			name("acme.with-schema"),
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary
+		));
+
+		assert_noop!(
+			SchemasPallet::remove_provider_intent(test_origin_signed(1), name("acme.grouped")),
+			Error::<Test>::IntentInIntentGroup
+		);
+		assert_noop!(
+			SchemasPallet::remove_provider_intent(test_origin_signed(1), name("acme.with-schema")),
+			Error::<Test>::IntentHasSchemas
+		);
+		assert_noop!(
+			SchemasPallet::remove_provider_intent(test_origin_signed(1), name("acme.group")),
+			Error::<Test>::InvalidIntentId
+		);
+	})
+}
+
+#[test]
+fn remove_provider_intent_should_fail_for_intent_without_deposit() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		SchemasPallet::create_intent_for(
+			name("acme.governed"),
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+		)
+		.expect("should create intent");
+
+		assert_noop!(
+			SchemasPallet::remove_provider_intent(test_origin_signed(1), name("acme.governed")),
+			Error::<Test>::EntityNotRemovable
+		);
+	})
+}
+
+#[test]
+fn remove_provider_intent_group_should_refund_deposit_and_release_intents() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		let intent_id = create_provider_intent(1, "acme.intent");
+		assert_ok!(SchemasPallet::create_provider_intent_group(
+			test_origin_signed(1),
+			name("acme.group"),
+			BoundedVec::try_from(vec![intent_id]).expect("should convert")
+		));
+		assert_eq!(IntentGroupMemberships::<Test>::get(intent_id), 1);
+		assert_eq!(held_deposit(1), 2 * NamespaceEntityDeposit::get());
+
+		assert_ok!(SchemasPallet::remove_provider_intent_group(
+			test_origin_signed(1),
+			name("acme.group")
+		));
+
+		assert_eq!(held_deposit(1), NamespaceEntityDeposit::get());
+		assert_eq!(IntentGroupMemberships::<Test>::get(intent_id), 0);
+		assert_eq!(SchemasPallet::get_intent_group_by_id(1), None);
+		System::assert_last_event(
+			AnnouncementEvent::IntentGroupRemoved { key: test_public(1), intent_group_id: 1 }
+				.into(),
+		);
+		assert_ok!(SchemasPallet::remove_provider_intent(
+			test_origin_signed(1),
+			name("acme.intent")
+		));
+	})
+}
+
+#[test]
+fn update_intent_group_should_track_memberships() {
+	new_test_ext().execute_with(|| {
+		set_up_owner(1, "acme");
+		let first = create_provider_intent(1, "acme.first");
+		let second = create_provider_intent(1, "acme.second");
+		let (intent_group_id, _) = SchemasPallet::create_intent_group_for(
+			name("acme.group"),
+			BoundedVec::try_from(vec![first]).expect("should convert"),
+		)
+		.expect("should create intent group");
+
+		assert_ok!(SchemasPallet::update_intent_group(
+			test_origin_signed(1),
+			intent_group_id,
+			BoundedVec::try_from(vec![second]).expect("should convert")
+		));
+
+		assert_eq!(IntentGroupMemberships::<Test>::get(first), 0);
+		assert_eq!(IntentGroupMemberships::<Test>::get(second), 1);
+		assert_ok!(SchemasPallet::remove_provider_intent(
+			test_origin_signed(1),
+			name("acme.first")
+		));
+	})
+}
@@ -1,7 +1,7 @@
 use super::mock::*;
 use crate::{
 	CurrentSchemaIdentifierMaximum, Error, Event as AnnouncementEvent,
-	GovernanceSchemaModelMaxBytes, SchemaDescriptor, SchemaInfo, SchemaName, SchemaNamePayload,
+	GovernanceSchemaModelMaxBytes, SchemaDescriptor, SchemaName, SchemaNamePayload,
 	SchemaProtocolName, SchemaVersionId, MAX_NUMBER_OF_VERSIONS,
 };
 use common_primitives::{
@@ -14,8 +14,9 @@ use common_primitives::{
 		ParquetModel,
 	},
 	schema::{
-		MappedEntityIdentifier, ModelType, NameLookupResponse, PayloadLocation, SchemaId,
-		SchemaResponseV2, SchemaStatus, SchemaVersion, SchemaVersionResponse,
+		IntentSchemaResponse, MappedEntityIdentifier, ModelType, NameLookupResponse,
+		PayloadLocation, SchemaId, SchemaResponseV2, SchemaStatus, SchemaVersion,
+		SchemaVersionResponse,
 	},
 };
 use frame_support::{
@@ -75,7 +76,8 @@ fn get_non_existing_schema_by_id_should_return_none() {
 #[test]
 fn validate_schema_is_acceptable() {
 	new_test_ext().execute_with(|| {
-		let test_str_raw = r#"{"name":"John Doe"}"#;
+		let test_str_raw =
+			r#"{"type":"record","name":"Person","fields":[{"name":"name","type":"string"}]}"#;
 		let result = SchemasPallet::ensure_valid_model(
 			&ModelType::AvroBinary,
 			&create_bounded_schema_vec(test_str_raw),
@@ -92,11 +94,40 @@ fn reject_null_json_schema() {
 				&ModelType::AvroBinary,
 				&create_bounded_schema_vec("")
 			),
-			Error::<Test>::InvalidSchema
+			Error::<Test>::InvalidAvroJson
 		);
 	})
 }
 
+#[test]
+fn reject_invalid_avro_schema_with_specific_error() {
+	new_test_ext().execute_with(|| {
+		for (model, expected) in [
+			("null", Error::<Test>::InvalidAvroType),
+			(r#"{"name": "Doe", "type": "lost"}"#, Error::<Test>::UnknownAvroType),
+			(r#"{"type": "record", "name": "Bond"}"#, Error::<Test>::MissingAvroAttribute),
+			(
+				r#"{"type": "record", "name": "Bond-007", "fields": []}"#,
+				Error::<Test>::InvalidAvroName,
+			),
+			(
+				r#"{"type": "enum", "name": "E", "symbols": ["A", "A"]}"#,
+				Error::<Test>::InvalidAvroEnumSymbols,
+			),
+			(r#"{"type": "fixed", "name": "F", "size": -1}"#, Error::<Test>::InvalidAvroFixedSize),
+			(r#"["null", "null"]"#, Error::<Test>::InvalidAvroUnion),
+		] {
+			assert_noop!(
+				SchemasPallet::ensure_valid_model(
+					&ModelType::AvroBinary,
+					&create_bounded_schema_vec(model)
+				),
+				expected
+			);
+		}
+	})
+}
+
 #[test]
 fn serialize_parquet_column() {
 	new_test_ext().execute_with(|| {
@@ -117,7 +148,7 @@ fn serialize_parquet_column() {
 #[test]
 fn validate_parquet_model() {
 	new_test_ext().execute_with(|| {
-		let test_str_raw = r#"[{"name": "Foo", "column_type": "BOOLEAN", "compression": "UNCOMPRESSED", "bloom_filter": true}]"#;
+		let test_str_raw = r#"[{"name": "Foo", "column_type": "BOOLEAN", "compression": "UNCOMPRESSED", "bloom_filter": false}]"#;
 		let result = SchemasPallet::ensure_valid_model(&ModelType::Parquet, &create_bounded_schema_vec(test_str_raw));
 		assert_ok!(result);
 	});
@@ -137,6 +168,53 @@ fn reject_incorrect_parquet_model() {
 	})
 }
 
+#[test]
+fn reject_parquet_model_that_can_not_be_written() {
+	new_test_ext().execute_with(|| {
+		let test_cases = [
+			(r#"[]"#, Error::<Test>::InvalidParquetColumnCount),
+			(
+				r#"[{"name":"a;b","column_type":"INT32","compression":"GZIP","bloom_filter":false}]"#,
+				Error::<Test>::InvalidParquetColumnName,
+			),
+			(
+				r#"[{"name":"a","column_type":"INT32","compression":"GZIP","bloom_filter":false},
+				{"name":"a","column_type":"INT64","compression":"GZIP","bloom_filter":false}]"#,
+				Error::<Test>::DuplicateParquetColumnName,
+			),
+			(
+				r#"[{"name":"a","column_type":{"INTEGER":{"bit_width":24,"sign":true}},"compression":"GZIP","bloom_filter":false}]"#,
+				Error::<Test>::InvalidParquetIntegerWidth,
+			),
+			(
+				r#"[{"name":"a","column_type":{"DECIMAL":{"scale":3,"precision":0}},"compression":"GZIP","bloom_filter":false}]"#,
+				Error::<Test>::InvalidParquetDecimal,
+			),
+			(
+				r#"[{"name":"a","column_type":"FIXED_LEN_BYTE_ARRAY","compression":"GZIP","bloom_filter":false}]"#,
+				Error::<Test>::UnsupportedParquetType,
+			),
+			(
+				r#"[{"name":"a","column_type":"BOOLEAN","compression":"GZIP","bloom_filter":true}]"#,
+				Error::<Test>::UnsupportedParquetBloomFilter,
+			),
+			(
+				r#"[{"name":"a","column_type":"STRING","compression":"LZO","bloom_filter":false}]"#,
+				Error::<Test>::UnsupportedParquetCompressionCodec,
+			),
+		];
+		for (model, expected) in test_cases {
+			assert_noop!(
+				SchemasPallet::ensure_valid_model(
+					&ModelType::Parquet,
+					&create_bounded_schema_vec(model)
+				),
+				expected
+			);
+		}
+	})
+}
+
 #[test]
 fn serialize_parquet_model() {
 	new_test_ext().execute_with(|| {
@@ -227,7 +305,7 @@ fn dsnp_broadcast() {
 		{
 			"name": "url",
 			"column_type": "STRING",
-			"compression": "LZO",
+			"compression": "GZIP",
 			"bloom_filter": false
 		}
 	]
@@ -450,12 +528,12 @@ fn schema_version_id_add_should_work() {
 				SchemaVersionResponse {
 					schema_id: schema_id_1,
 					schema_version: 1,
-					schema_name: schema_name.clone().get_combined_name()
+					schema_name: schema_name.clone().get_combined_name(),
 				},
 				SchemaVersionResponse {
 					schema_id: schema_id_2,
 					schema_version: 2,
-					schema_name: schema_name.get_combined_name()
+					schema_name: schema_name.get_combined_name(),
 				},
 			]
 		);
@@ -512,6 +590,29 @@ fn create_schema_v4_requires_valid_schema_size() {
 	})
 }
 
+#[test]
+fn create_schema_v4_rejects_invalid_avro_model() {
+	new_test_ext().execute_with(|| {
+		sudo_set_max_schema_size();
+		let (intent_id, _) = SchemasPallet::create_intent_for(
+			create_bounded_schema_vec(r#"test.intent"#),
+			PayloadLocation::OnChain,
+			BoundedVec::default(),
+		)
+		.expect("should have created an intent");
+
+		assert_noop!(
+			SchemasPallet::create_schema_v4(
+				RuntimeOrigin::signed(test_public(1)),
+				intent_id,
+				create_bounded_schema_vec(r#"{"type": "array", "item": "long"}"#),
+				ModelType::AvroBinary
+			),
+			Error::<Test>::MissingAvroAttribute
+		);
+	})
+}
+
 #[test]
 fn create_schema_v4_happy_path() {
 	new_test_ext().execute_with(|| {
@@ -541,7 +642,7 @@ fn create_schema_v4_happy_path() {
 			assert_ok!(SchemasPallet::create_schema_v4(
 				RuntimeOrigin::signed(sender.clone()),
 				intent_id,
-				create_bounded_schema_vec(r#"{"name": "Doe", "type": "lost"}"#),
+				create_bounded_schema_vec(r#"{"name": "Doe", "type": "long"}"#),
 				ModelType::AvroBinary,
 			));
 			let res = SchemasPallet::get_schema_by_id(expected_schema_id);
@@ -584,9 +685,9 @@ fn create_schema_v4_increments_schema_id() {
 
 		// act and assert
 		for fields in [
-			r#"{"Name": "Bond", "Code": "007"}"#,
-			r#"{"type": "num","minimum": -90,"maximum": 90}"#,
-			r#"{"latitude": 48.858093,"longitude": 2.294694}"#,
+			r#"{"type": "record", "name": "Bond", "fields": []}"#,
+			r#"{"type": "int","minimum": -90,"maximum": 90}"#,
+			r#"{"type": "double","latitude": 48.858093}"#,
 		] {
 			let expected_schema_id = last_schema_id + 1;
 			assert_ok!(SchemasPallet::create_schema_v4(
@@ -771,7 +872,7 @@ fn create_schema_via_governance_v3_happy_path() {
 			BoundedVec::default(),
 		)
 		.expect("should have created an intent");
-		let model = create_bounded_schema_vec(r#"{"name": "Doe", "type": "lost"}"#);
+		let model = create_bounded_schema_vec(r#"{"name": "Doe", "type": "long"}"#);
 
 		// act
 		assert_ok!(SchemasPallet::create_schema_via_governance_v3(
@@ -805,7 +906,7 @@ fn propose_to_create_schema_v3_happy_path() {
 	new_test_ext().execute_with(|| {
 		sudo_set_max_schema_size();
 
-		let test_model = r#"{"foo": "bar", "bar": "buzz"}"#;
+		let test_model = r#"{"type": "bytes", "bar": "buzz"}"#;
 		let serialized_fields = Vec::from(test_model.as_bytes());
 		let intent_name =
 			SchemaNamePayload::try_from("namespace.descriptor".to_string().into_bytes())
@@ -997,7 +1098,8 @@ fn get_intent_with_schemas_should_return_sorted_schemas() {
 		)
 		.expect("should have created an intent");
 
-		let model = create_bounded_schema_vec(r#"{"Name": "Bond", "Code": "007"}"#);
+		let model =
+			create_bounded_schema_vec(r#"{"type": "record", "name": "Bond", "fields": []}"#);
 
 		const MAX_SCHEMAS: u16 = 100;
 		for _ in 0..MAX_SCHEMAS {
@@ -1032,7 +1134,8 @@ fn get_intent_with_schemas_should_not_return_unsupported_schemas() {
 		)
 		.expect("should have created an intent");
 
-		let model = create_bounded_schema_vec(r#"{"Name": "Bond", "Code": "007"}"#);
+		let model =
+			create_bounded_schema_vec(r#"{"type": "record", "name": "Bond", "fields": []}"#);
 
 		const MAX_SCHEMAS: usize = 100;
 		for _ in 0..MAX_SCHEMAS {
@@ -1041,20 +1144,12 @@ fn get_intent_with_schemas_should_not_return_unsupported_schemas() {
 		}
 
 		// Set one of the schemas to Unsupported
-		// TODO: rework once the pallet supports updating schema status
 		let unsupported_schema_id: SchemaId = 1;
-		let mut schema_repsonse = SchemasPallet::get_schema_by_id(unsupported_schema_id)
-			.expect("should get schema by id");
-		schema_repsonse.status = SchemaStatus::Unsupported;
-		let info = SchemaInfo {
-			intent_id,
-			model_type: ModelType::AvroBinary,
-			payload_location: PayloadLocation::OnChain,
-			settings: Default::default(),
-			status: SchemaStatus::Unsupported,
-		};
-		SchemasPallet::store_schema_info_and_payload(unsupported_schema_id, info, model)
-			.expect("should store schema");
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			unsupported_schema_id,
+			SchemaStatus::Unsupported
+		));
 
 		let response = SchemasPallet::get_intent_by_id_with_schemas(intent_id)
 			.expect("should get intent by id");
@@ -1064,5 +1159,15 @@ fn get_intent_with_schemas_should_not_return_unsupported_schemas() {
 			!schema_ids.contains(&unsupported_schema_id),
 			"Returned schemas should not contain unsupported schemas"
 		);
+		// the status of every schema is still surfaced
+		let schemas = response.schemas.expect("should return schemas");
+		assert_eq!(schemas.len(), MAX_SCHEMAS);
+		assert_eq!(
+			schemas[0],
+			IntentSchemaResponse {
+				schema_id: unsupported_schema_id,
+				status: SchemaStatus::Unsupported
+			}
+		);
 	})
 }
@@ -0,0 +1,124 @@
+use super::mock::*;
+use crate::{Error, SchemaNamePayload};
+use common_primitives::{
+	avro::AvroDecodeError,
+	node::AccountId,
+	schema::{
+		IntentId, IntentSetting, ModelType, PayloadLocation, PayloadValidationError, SchemaProvider,
+	},
+};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+
+const AVRO_MODEL: &str = r#"{"type":"record","name":"Post","fields":[{"name":"id","type":"int"},
+	{"name":"text","type":"string"}]}"#;
+const PARQUET_MODEL: &str =
+	r#"[{"name":"a","column_type":"BOOLEAN","compression":"SNAPPY","bloom_filter":false}]"#;
+
+fn create_intent(payload_location: PayloadLocation, settings: Vec<IntentSetting>) -> IntentId {
+	assert_ok!(SchemasPallet::set_max_schema_model_bytes(RawOrigin::Root.into(), 500));
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(b"protocol.descriptor".to_vec()).expect("should convert");
+	let (intent_id, _) = SchemasPallet::create_intent_for(
+		intent_name,
+		payload_location,
+		BoundedVec::try_from(settings).expect("should convert"),
+	)
+	.expect("should have created an intent");
+	intent_id
+}
+
+#[test]
+fn create_intent_with_validate_payload_setting_and_wrong_location_should_fail() {
+	new_test_ext().execute_with(|| {
+		let sender: AccountId = test_public(1);
+		let intent_name: SchemaNamePayload =
+			BoundedVec::try_from(b"protocol.descriptor".to_vec()).expect("should convert");
+
+		for location in [PayloadLocation::IPFS, PayloadLocation::OffChain] {
+			assert_noop!(
+				SchemasPallet::create_intent_via_governance(
+					RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+					sender.clone(),
+					location,
+					BoundedVec::try_from(vec![IntentSetting::ValidatePayload]).unwrap(),
+					intent_name.clone(),
+				),
+				Error::<Test>::InvalidSetting
+			);
+		}
+	})
+}
+
+#[test]
+fn create_schema_with_validate_payload_setting_requires_avro_model() {
+	new_test_ext().execute_with(|| {
+		let intent_id =
+			create_intent(PayloadLocation::Paginated, vec![IntentSetting::ValidatePayload]);
+
+		assert_noop!(
+			SchemasPallet::create_schema_v4(
+				test_origin_signed(1),
+				intent_id,
+				create_bounded_schema_vec(PARQUET_MODEL),
+				ModelType::Parquet,
+			),
+			Error::<Test>::InvalidSetting
+		);
+		assert_ok!(SchemasPallet::create_schema_v4(
+			test_origin_signed(1),
+			intent_id,
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary,
+		));
+	})
+}
+
+#[test]
+fn validate_payload_decodes_payload_against_schema_model() {
+	new_test_ext().execute_with(|| {
+		let intent_id =
+			create_intent(PayloadLocation::OnChain, vec![IntentSetting::ValidatePayload]);
+		let schema_id = SchemasPallet::create_schema_for(
+			intent_id,
+			create_bounded_schema_vec(AVRO_MODEL),
+			ModelType::AvroBinary,
+		)
+		.expect("should create schema");
+
+		// id 1, followed by the string "hi"
+		assert_ok!(<SchemasPallet as SchemaProvider<_>>::validate_payload(
+			schema_id,
+			&[0x02, 0x04, b'h', b'i']
+		));
+		assert_eq!(
+			SchemasPallet::validate_payload(schema_id, &[0x02, 0x04, b'h']),
+			Err(PayloadValidationError::InvalidPayload(AvroDecodeError::InvalidLength))
+		);
+		assert_eq!(
+			SchemasPallet::validate_payload(schema_id, &[0x02, 0x00, 0x00]),
+			Err(PayloadValidationError::InvalidPayload(AvroDecodeError::TrailingBytes))
+		);
+		assert_eq!(
+			SchemasPallet::validate_payload(schema_id + 1, &[0x02, 0x00]),
+			Err(PayloadValidationError::SchemaNotFound)
+		);
+	})
+}
+
+#[test]
+fn validate_payload_rejects_parquet_schemas() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(PayloadLocation::IPFS, Vec::new());
+		let schema_id = SchemasPallet::create_schema_for(
+			intent_id,
+			create_bounded_schema_vec(PARQUET_MODEL),
+			ModelType::Parquet,
+		)
+		.expect("should create schema");
+
+		assert_eq!(
+			SchemasPallet::validate_payload(schema_id, &[0x00]),
+			Err(PayloadValidationError::UnsupportedModelType)
+		);
+	})
+}
@@ -0,0 +1,219 @@
+use super::mock::*;
+use crate::{pallet::IntentRetentionPeriods, Error, Event as AnnouncementEvent, SchemaNamePayload};
+use common_primitives::schema::{IntentId, IntentRetention, PayloadLocation};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+use pallet_collective::ProposalOf;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn create_intent(name: &[u8], payload_location: PayloadLocation) -> IntentId {
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(name.to_vec()).expect("should convert");
+	let (intent_id, _) =
+		SchemasPallet::create_intent_for(intent_name, payload_location, BoundedVec::default())
+			.expect("should have created an intent");
+	intent_id
+}
+
+#[test]
+fn set_intent_retention_via_governance_sets_and_clears_retention() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.retained", PayloadLocation::OnChain);
+		System::set_block_number(5);
+
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			intent_id,
+			Some(100)
+		));
+		System::assert_last_event(
+			AnnouncementEvent::IntentRetentionUpdated { intent_id, retention: Some(100) }.into(),
+		);
+		let retention = IntentRetention { period: 100, since: 5 };
+		assert_eq!(IntentRetentionPeriods::<Test>::get(intent_id), Some(retention));
+		assert_eq!(
+			SchemasPallet::get_intent_retention_periods(None, 10),
+			vec![(intent_id, retention)]
+		);
+
+		// changing the period keeps the block the retention started in
+		System::set_block_number(8);
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			Some(50)
+		));
+		assert_eq!(
+			IntentRetentionPeriods::<Test>::get(intent_id),
+			Some(IntentRetention { period: 50, since: 5 })
+		);
+
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			None
+		));
+		System::assert_last_event(
+			AnnouncementEvent::IntentRetentionUpdated { intent_id, retention: None }.into(),
+		);
+		assert_eq!(IntentRetentionPeriods::<Test>::get(intent_id), None);
+		assert_eq!(IntentRetentionPeriods::<Test>::count(), 0);
+
+		// setting it again starts the retention over
+		System::set_block_number(9);
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			Some(100)
+		));
+		assert_eq!(
+			IntentRetentionPeriods::<Test>::get(intent_id),
+			Some(IntentRetention { period: 100, since: 9 })
+		);
+	})
+}
+
+#[test]
+fn get_intent_retention_periods_should_page_through_intents() {
+	new_test_ext().execute_with(|| {
+		let first = create_intent(b"protocol.first", PayloadLocation::OnChain);
+		let second = create_intent(b"protocol.second", PayloadLocation::OnChain);
+		for intent_id in [first, second] {
+			assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+				RawOrigin::Root.into(),
+				intent_id,
+				Some(10)
+			));
+		}
+
+		let page = SchemasPallet::get_intent_retention_periods(None, 1);
+		assert_eq!(page.len(), 1);
+		let next = SchemasPallet::get_intent_retention_periods(Some(page[0].0), 1);
+		assert_eq!(next.len(), 1);
+		assert_ne!(next[0].0, page[0].0);
+		assert!(SchemasPallet::get_intent_retention_periods(Some(next[0].0), 1).is_empty());
+		assert_eq!(SchemasPallet::get_intent_retention_periods(None, 10).len(), 2);
+	})
+}
+
+#[test]
+fn set_intent_retention_via_governance_with_invalid_input_should_fail() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.retained", PayloadLocation::IPFS);
+		let itemized_intent_id = create_intent(b"protocol.itemized", PayloadLocation::Itemized);
+
+		assert_noop!(
+			SchemasPallet::set_intent_retention_via_governance(
+				test_origin_signed(1),
+				intent_id,
+				Some(100)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_retention_via_governance(
+				RawOrigin::Root.into(),
+				itemized_intent_id + 1,
+				Some(100)
+			),
+			Error::<Test>::InvalidIntentId
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_retention_via_governance(
+				RawOrigin::Root.into(),
+				intent_id,
+				Some(0)
+			),
+			Error::<Test>::InvalidRetentionPeriod
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_retention_via_governance(
+				RawOrigin::Root.into(),
+				itemized_intent_id,
+				Some(100)
+			),
+			Error::<Test>::RetentionNotSupported
+		);
+	})
+}
+
+#[test]
+fn set_intent_retention_via_governance_over_limit_should_fail() {
+	new_test_ext().execute_with(|| {
+		let first = create_intent(b"protocol.first", PayloadLocation::OnChain);
+		let second = create_intent(b"protocol.second", PayloadLocation::OnChain);
+		let third = create_intent(b"protocol.third", PayloadLocation::OnChain);
+
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			first,
+			Some(10)
+		));
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			second,
+			Some(10)
+		));
+		assert_noop!(
+			SchemasPallet::set_intent_retention_via_governance(
+				RawOrigin::Root.into(),
+				third,
+				Some(10)
+			),
+			Error::<Test>::TooManyIntentsWithRetention
+		);
+
+		// updating an existing entry is still allowed at the limit
+		assert_ok!(SchemasPallet::set_intent_retention_via_governance(
+			RawOrigin::Root.into(),
+			second,
+			Some(20)
+		));
+		assert_eq!(IntentRetentionPeriods::<Test>::get(second).map(|r| r.period), Some(20));
+	})
+}
+
+#[test]
+fn propose_to_set_intent_retention_creates_proposal() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.retained", PayloadLocation::OnChain);
+
+		assert_ok!(SchemasPallet::propose_to_set_intent_retention(
+			test_origin_signed(5),
+			intent_id,
+			Some(100)
+		));
+
+		let proposal_hash = System::events()
+			.iter()
+			.find_map(|event| match event.event {
+				RuntimeEvent::Council(pallet_collective::Event::Proposed {
+					proposal_hash, ..
+				}) => Some(proposal_hash),
+				_ => None,
+			})
+			.expect("proposal should be created");
+		assert_eq!(
+			ProposalOf::<Test, CouncilCollective>::get(proposal_hash),
+			Some(RuntimeCall::SchemasPallet(crate::Call::set_intent_retention_via_governance {
+				intent_id,
+				retention: Some(100),
+			}))
+		);
+	})
+}
+
+#[test]
+fn propose_to_set_intent_retention_with_invalid_period_should_fail() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.retained", PayloadLocation::OnChain);
+
+		assert_noop!(
+			SchemasPallet::propose_to_set_intent_retention(
+				test_origin_signed(5),
+				intent_id,
+				Some(0)
+			),
+			Error::<Test>::InvalidRetentionPeriod
+		);
+	})
+}
@@ -0,0 +1,216 @@
+use super::mock::*;
+use crate::{Error, Event as AnnouncementEvent, SchemaNamePayload};
+use common_primitives::schema::{
+	IntentId, ModelType, PayloadLocation, SchemaId, SchemaStatus, SchemaVersionResponse,
+	SchemaVersionResponseV2,
+};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+use pallet_collective::ProposalOf;
+use sp_runtime::DispatchError::BadOrigin;
+
+fn create_intent_with_schemas(name: &str, count: usize) -> (IntentId, Vec<SchemaId>) {
+	sudo_set_max_schema_size();
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(name.to_string().into_bytes()).expect("should convert");
+	let (intent_id, _) = SchemasPallet::create_intent_for(
+		intent_name,
+		PayloadLocation::OnChain,
+		BoundedVec::default(),
+	)
+	.expect("should have created an intent");
+	let model = create_bounded_schema_vec(r#"{"type": "record", "name": "Bond", "fields": []}"#);
+	let schema_ids = (0..count)
+		.map(|_| {
+			SchemasPallet::create_schema_for(intent_id, model.clone(), ModelType::AvroBinary)
+				.expect("should create schema")
+		})
+		.collect();
+	(intent_id, schema_ids)
+}
+
+fn schema_status(schema_id: SchemaId) -> SchemaStatus {
+	SchemasPallet::get_schema_info_by_id(schema_id)
+		.expect("schema should exist")
+		.status
+}
+
+#[test]
+fn update_schema_status_via_governance_moves_schema_through_lifecycle() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 1);
+		let schema_id = schema_ids[0];
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			schema_id,
+			SchemaStatus::Deprecated
+		));
+		System::assert_last_event(
+			AnnouncementEvent::SchemaStatusUpdated { schema_id, status: SchemaStatus::Deprecated }
+				.into(),
+		);
+		assert_eq!(schema_status(schema_id), SchemaStatus::Deprecated);
+
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			schema_id,
+			SchemaStatus::Unsupported
+		));
+		assert_eq!(schema_status(schema_id), SchemaStatus::Unsupported);
+	})
+}
+
+#[test]
+fn update_schema_status_via_governance_rejects_backward_transitions() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 1);
+		let schema_id = schema_ids[0];
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			schema_id,
+			SchemaStatus::Unsupported
+		));
+
+		assert_noop!(
+			SchemasPallet::update_schema_status_via_governance(
+				RawOrigin::Root.into(),
+				schema_id,
+				SchemaStatus::Active
+			),
+			Error::<Test>::InvalidSchemaStatusTransition
+		);
+		assert_noop!(
+			SchemasPallet::update_schema_status_via_governance(
+				RawOrigin::Root.into(),
+				schema_id,
+				SchemaStatus::Unsupported
+			),
+			Error::<Test>::InvalidSchemaStatusTransition
+		);
+	})
+}
+
+#[test]
+fn update_schema_status_via_governance_fails_for_unknown_schema() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			SchemasPallet::update_schema_status_via_governance(
+				RawOrigin::Root.into(),
+				1,
+				SchemaStatus::Deprecated
+			),
+			Error::<Test>::SchemaIdDoesNotExist
+		);
+	})
+}
+
+#[test]
+fn update_schema_status_via_governance_requires_governance_origin() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 1);
+
+		assert_noop!(
+			SchemasPallet::update_schema_status_via_governance(
+				test_origin_signed(1),
+				schema_ids[0],
+				SchemaStatus::Deprecated
+			),
+			BadOrigin
+		);
+	})
+}
+
+#[test]
+fn propose_to_update_schema_status_creates_proposal() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 1);
+
+		assert_ok!(SchemasPallet::propose_to_update_schema_status(
+			test_origin_signed(5),
+			schema_ids[0],
+			SchemaStatus::Deprecated
+		));
+
+		let proposal_hash = System::events()
+			.iter()
+			.find_map(|event| match event.event {
+				RuntimeEvent::Council(pallet_collective::Event::Proposed {
+					proposal_hash, ..
+				}) => Some(proposal_hash),
+				_ => None,
+			})
+			.expect("proposal should be created");
+		assert_eq!(
+			ProposalOf::<Test, CouncilCollective>::get(proposal_hash),
+			Some(RuntimeCall::SchemasPallet(crate::Call::update_schema_status_via_governance {
+				schema_id: schema_ids[0],
+				status: SchemaStatus::Deprecated,
+			}))
+		);
+	})
+}
+
+#[test]
+fn propose_to_update_schema_status_rejects_invalid_transition() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 1);
+
+		assert_noop!(
+			SchemasPallet::propose_to_update_schema_status(
+				test_origin_signed(5),
+				schema_ids[0],
+				SchemaStatus::Active
+			),
+			Error::<Test>::InvalidSchemaStatusTransition
+		);
+	})
+}
+
+#[test]
+fn get_schema_versions_v2_surfaces_schema_status() {
+	new_test_ext().execute_with(|| {
+		let (_, schema_ids) = create_intent_with_schemas("protocol.descriptor", 2);
+		assert_ok!(SchemasPallet::update_schema_status_via_governance(
+			RawOrigin::Root.into(),
+			schema_ids[0],
+			SchemaStatus::Deprecated
+		));
+
+		let versions = SchemasPallet::get_schema_versions_v2(b"protocol.descriptor".to_vec())
+			.expect("should return versions");
+
+		assert_eq!(
+			versions,
+			vec![
+				SchemaVersionResponseV2 {
+					schema_name: b"protocol.descriptor".to_vec(),
+					schema_version: 1,
+					schema_id: schema_ids[0],
+					status: SchemaStatus::Deprecated,
+				},
+				SchemaVersionResponseV2 {
+					schema_name: b"protocol.descriptor".to_vec(),
+					schema_version: 2,
+					schema_id: schema_ids[1],
+					status: SchemaStatus::Active,
+				},
+			]
+		);
+		// the original response form is unchanged
+		assert_eq!(
+			SchemasPallet::get_schema_versions(b"protocol.descriptor".to_vec()),
+			Some(vec![
+				SchemaVersionResponse {
+					schema_name: b"protocol.descriptor".to_vec(),
+					schema_version: 1,
+					schema_id: schema_ids[0],
+				},
+				SchemaVersionResponse {
+					schema_name: b"protocol.descriptor".to_vec(),
+					schema_version: 2,
+					schema_id: schema_ids[1],
+				},
+			])
+		);
+	})
+}
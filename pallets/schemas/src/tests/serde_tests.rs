@@ -1,41 +1,53 @@
-use crate::{serde::*, tests::mock::*};
-use frame_support::assert_noop;
+use crate::{tests::mock::*, Error};
+use common_primitives::schema::ModelType;
+use frame_support::{assert_noop, assert_ok, dispatch::DispatchResult};
 
-fn create_schema_vec(from_string: &str) -> Vec<u8> {
-	Vec::from(from_string.as_bytes())
+fn validate_avro_model(from_string: &str) -> DispatchResult {
+	SchemasPallet::ensure_valid_model(
+		&ModelType::AvroBinary,
+		&create_bounded_schema_vec(from_string),
+	)
 }
 
 #[test]
 fn serde_helper_valid_schema() {
-	for test_str_raw in [
-		r#"{"name":"John Doe"}"#,
-		r#"{"minimum": -90,"maximum": 90}"#,
-		r#"{"a":0}"#,
-		r#"{"fruits":[ "apple",{"fruitName": "orange","fruitLike": true }]}"#,
-		r#"{ "links": {
+	new_test_ext().execute_with(|| {
+		for test_str_raw in [
+			r#"{"type":"record","name":"Person","fields":[{"name":"name","type":"string","default":"John Doe"}]}"#,
+			r#"{"type":"int","minimum": -90,"maximum": 90}"#,
+			r#"{"type":"int","a":0}"#,
+			r#"{"type":"array","items":[ "string",{"type":"record","name":"Fruit","fields":[{"name":"fruitName","type":"string"},{"name":"fruitLike","type":"boolean"}]}]}"#,
+			r#"{ "type": "map", "values": "string", "links": {
 			"self": "http://example.com/articles?page[number]=3&page[size]=1",
 			"first": "http://example.com/articles?page[number]=1&page[size]=1"
 			}}"#,
-		r#"{ "alias": "0xd8f3" }"#,
-	] {
-		assert!(validate_json_model(create_schema_vec(test_str_raw)).is_ok());
-	}
+			r#"{ "type": "string", "alias": "0xd8f3" }"#,
+			r#""long""#,
+		] {
+			assert_ok!(validate_avro_model(test_str_raw));
+		}
+	});
 }
 
 #[test]
 fn serde_helper_invalid_schema() {
-	for test_str_raw in [
-		"true",
-		"567",
-		r#"string"#,
-		"",
-		r#"["this","is","a","weird","array"],
+	new_test_ext().execute_with(|| {
+		for test_str_raw in [
+			"true",
+			"567",
+			r#"string"#,
+			"",
+			r#"["this","is","a","weird","array"],
 			r#"{ "name", "John Doe" }"#,
-		r#"{ "minimum": -90, 90 }"#,
-		r#"{ "fruits": [ "apple", {"fruitName": "orange" "fruitLike": true }}"#,
-	] {
-		assert!(validate_json_model(create_schema_vec(test_str_raw)).is_err());
-	}
+			r#"{ "minimum": -90, 90 }"#,
+			r#"{ "fruits": [ "apple", {"fruitName": "orange" "fruitLike": true }}"#,
+			// JSON objects that are not Avro schemas
+			r#"{"name":"John Doe"}"#,
+			r#"{"a":0}"#,
+		] {
+			assert!(validate_avro_model(test_str_raw).is_err());
+		}
+	});
 }
 
 #[test]
@@ -48,10 +60,7 @@ fn serde_helper_deserialzer_error() {
 			r#"{ "unicode code point": "\ud83f" }"#,  // InvalidUnicodeCodePoint
 			                                          // r#"{ "v": 300e715100 }"#,              // NumberOutOfRange
 		] {
-			assert_noop!(
-				validate_json_model(create_schema_vec(test_str_raw)),
-				SerdeError::DeserializationError
-			);
+			assert_noop!(validate_avro_model(test_str_raw), Error::<Test>::InvalidAvroJson);
 		}
 	});
 }
@@ -59,13 +68,14 @@ fn serde_helper_deserialzer_error() {
 #[test]
 fn serde_helper_null_schema() {
 	new_test_ext().execute_with(|| {
-		assert_noop!(validate_json_model(create_schema_vec("null")), SerdeError::InvalidNullSchema);
+		assert_noop!(validate_avro_model("null"), Error::<Test>::InvalidAvroType);
 	});
 }
 
 #[test]
 fn serde_helper_utf8_encoding_schema() {
-	let utf8_schema = r#"{"a":"Espíritu navideño"}"#;
-	let result = validate_json_model(create_schema_vec(utf8_schema));
-	assert!(result.is_ok());
+	new_test_ext().execute_with(|| {
+		let utf8_schema = r#"{"type":"string","doc":"Espíritu navideño"}"#;
+		assert_ok!(validate_avro_model(utf8_schema));
+	});
 }
@@ -0,0 +1,121 @@
+use super::mock::*;
+use crate::{pallet::IntentStorageQuotas, Error, Event as AnnouncementEvent, SchemaNamePayload};
+use common_primitives::schema::{IntentId, PayloadLocation, SchemaProvider, StorageQuota};
+use frame_support::{assert_noop, assert_ok, dispatch::RawOrigin, BoundedVec};
+use pallet_collective::ProposalOf;
+use sp_runtime::DispatchError::BadOrigin;
+
+const QUOTA: StorageQuota = StorageQuota { max_bytes: 2048, max_pages: 4 };
+
+fn create_intent(name: &[u8], payload_location: PayloadLocation) -> IntentId {
+	let intent_name: SchemaNamePayload =
+		BoundedVec::try_from(name.to_vec()).expect("should convert");
+	let (intent_id, _) =
+		SchemasPallet::create_intent_for(intent_name, payload_location, BoundedVec::default())
+			.expect("should have created an intent");
+	intent_id
+}
+
+#[test]
+fn set_intent_storage_quota_via_governance_sets_and_clears_quota() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.limited", PayloadLocation::Paginated);
+
+		assert_ok!(SchemasPallet::set_intent_storage_quota_via_governance(
+			RuntimeOrigin::from(pallet_collective::RawOrigin::Members(2, 3)),
+			intent_id,
+			Some(QUOTA)
+		));
+		System::assert_last_event(
+			AnnouncementEvent::IntentStorageQuotaUpdated { intent_id, quota: Some(QUOTA) }.into(),
+		);
+		assert_eq!(IntentStorageQuotas::<Test>::get(intent_id), Some(QUOTA));
+		assert_eq!(
+			<SchemasPallet as SchemaProvider<_>>::get_intent_storage_quota(intent_id),
+			Some(QUOTA)
+		);
+
+		assert_ok!(SchemasPallet::set_intent_storage_quota_via_governance(
+			RawOrigin::Root.into(),
+			intent_id,
+			None
+		));
+		System::assert_last_event(
+			AnnouncementEvent::IntentStorageQuotaUpdated { intent_id, quota: None }.into(),
+		);
+		assert_eq!(IntentStorageQuotas::<Test>::get(intent_id), None);
+	})
+}
+
+#[test]
+fn set_intent_storage_quota_via_governance_with_invalid_input_should_fail() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.limited", PayloadLocation::Itemized);
+		let onchain_intent_id = create_intent(b"protocol.onchain", PayloadLocation::OnChain);
+
+		assert_noop!(
+			SchemasPallet::set_intent_storage_quota_via_governance(
+				test_origin_signed(1),
+				intent_id,
+				Some(QUOTA)
+			),
+			BadOrigin
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_storage_quota_via_governance(
+				RawOrigin::Root.into(),
+				onchain_intent_id + 1,
+				Some(QUOTA)
+			),
+			Error::<Test>::InvalidIntentId
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_storage_quota_via_governance(
+				RawOrigin::Root.into(),
+				intent_id,
+				Some(StorageQuota { max_bytes: 2048, max_pages: 0 })
+			),
+			Error::<Test>::InvalidStorageQuota
+		);
+		assert_noop!(
+			SchemasPallet::set_intent_storage_quota_via_governance(
+				RawOrigin::Root.into(),
+				onchain_intent_id,
+				Some(QUOTA)
+			),
+			Error::<Test>::StorageQuotaNotSupported
+		);
+	})
+}
+
+#[test]
+fn propose_to_set_intent_storage_quota_creates_proposal() {
+	new_test_ext().execute_with(|| {
+		let intent_id = create_intent(b"protocol.limited", PayloadLocation::Paginated);
+
+		assert_ok!(SchemasPallet::propose_to_set_intent_storage_quota(
+			test_origin_signed(5),
+			intent_id,
+			Some(QUOTA)
+		));
+
+		let proposal_hash = System::events()
+			.iter()
+			.find_map(|event| match event.event {
+				RuntimeEvent::Council(pallet_collective::Event::Proposed {
+					proposal_hash, ..
+				}) => Some(proposal_hash),
+				_ => None,
+			})
+			.expect("proposal should be created");
+		assert_eq!(
+			ProposalOf::<Test, CouncilCollective>::get(proposal_hash),
+			Some(RuntimeCall::SchemasPallet(
+				crate::Call::set_intent_storage_quota_via_governance {
+					intent_id,
+					quota: Some(QUOTA),
+				}
+			))
+		);
+	})
+}
@@ -1,12 +1,21 @@
 //! Types for the Schema Pallet
 use crate::{Config, Error};
-use common_primitives::schema::{
-	IntentGroupId, IntentId, IntentSetting, IntentSettings, MappedEntityIdentifier, ModelType,
-	NameLookupResponse, PayloadLocation, SchemaId, SchemaStatus, SchemaVersion,
-	SchemaVersionResponse,
+use common_primitives::{
+	avro::{AvroError, AvroIncompatibility},
+	parquet::{ParquetError, ParquetIncompatibility},
+	schema::{
+		IntentGroupId, IntentId, IntentSetting, IntentSettings, MappedEntityIdentifier, ModelType,
+		NameLookupResponse, PayloadLocation, SchemaId, SchemaStatus, SchemaVersion,
+		SchemaVersionResponse, SchemaVersionResponseV2,
+	},
 };
 use core::fmt::Debug;
-use frame_support::{ensure, pallet_prelude::ConstU32, traits::StorageVersion, BoundedVec};
+use frame_support::{
+	ensure,
+	pallet_prelude::ConstU32,
+	traits::{tokens::fungible::Inspect as InspectFungible, StorageVersion},
+	BoundedVec,
+};
 use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
 use scale_info::TypeInfo;
 use sp_runtime::DispatchError;
@@ -15,7 +24,7 @@ use alloc::{string::String, vec, vec::Vec};
 use frame_support::traits::Len;
 
 /// Current storage version of the schemas pallet.
-pub const SCHEMA_STORAGE_VERSION: StorageVersion = StorageVersion::new(5);
+pub const SCHEMA_STORAGE_VERSION: StorageVersion = StorageVersion::new(6);
 
 /// The maximum size of a fully qualified name, including all parts and separators
 pub const SCHEMA_NAME_BYTES_MAX: u32 = 32; // Hard limit of 32 bytes
@@ -41,6 +50,10 @@ pub const SEPARATOR_CHAR: char = '.';
 /// -1 is to avoid overflow when converting the (index + 1) to `SchemaVersion` in `SchemaVersionId`
 pub const MAX_NUMBER_OF_VERSIONS: u32 = SchemaVersion::MAX as u32 - 1;
 
+/// Type alias for the balance of the pallet's currency
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
+
 /// Type alias for SchemaPayload
 #[allow(type_alias_bounds)]
 pub type SchemaPayload<T: Config> =
@@ -136,6 +149,37 @@ pub struct SchemaInfo {
 	pub status: SchemaStatus,
 }
 
+#[derive(Clone, Copy, Default, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+/// The neighbours of an `Active` or `Deprecated` schema among the `Active` and `Deprecated`
+/// schemas of its Intent, in order of [`SchemaId`]
+pub struct SupportedSchemaLink {
+	/// The previous `Active` or `Deprecated` schema of the Intent
+	pub previous: Option<SchemaId>,
+	/// The next `Active` or `Deprecated` schema of the Intent
+	pub next: Option<SchemaId>,
+}
+
+#[derive(Clone, Copy, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+/// An entity created by a namespace owner, for which a deposit is held
+pub enum NamespaceEntity {
+	/// An Intent
+	Intent(IntentId),
+	/// An IntentGroup
+	IntentGroup(IntentGroupId),
+	/// A Schema
+	Schema(SchemaId),
+}
+
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+/// A deposit held for a [`NamespaceEntity`]
+pub struct EntityDeposit<T: Config> {
+	/// The account the deposit is held from
+	pub depositor: T::AccountId,
+	/// The amount held
+	pub amount: BalanceOf<T>,
+}
+
 #[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
 /// A structure defining name of a registered entity in this pallet.
 /// Names consist of a `protocol` (namespace) and a `descriptor`. Currently, names may be registered
@@ -308,14 +352,15 @@ pub trait ConvertToResponse<I, R> {
 	fn convert_to_response(&self, name: &I) -> R;
 }
 
-impl ConvertToResponse<Vec<u8>, Vec<SchemaVersionResponse>> for Vec<SchemaId> {
-	fn convert_to_response(&self, schema_name: &Vec<u8>) -> Vec<SchemaVersionResponse> {
+impl ConvertToResponse<Vec<u8>, Vec<SchemaVersionResponseV2>> for Vec<(SchemaId, SchemaStatus)> {
+	fn convert_to_response(&self, schema_name: &Vec<u8>) -> Vec<SchemaVersionResponseV2> {
 		self.iter()
 			.enumerate()
-			.map(|(index, id)| SchemaVersionResponse {
+			.map(|(index, (id, status))| SchemaVersionResponseV2 {
 				schema_name: schema_name.clone(),
 				schema_id: *id,
 				schema_version: (index + 1) as SchemaVersion,
+				status: *status,
 			})
 			.collect()
 	}
@@ -326,3 +371,63 @@ impl ConvertToResponse<SchemaName, NameLookupResponse> for MappedEntityIdentifie
 		NameLookupResponse { name: name.get_combined_name(), entity_id: *self }
 	}
 }
+
+impl<T: Config> From<AvroError> for Error<T> {
+	fn from(error: AvroError) -> Self {
+		match error {
+			AvroError::InvalidJson => Error::<T>::InvalidAvroJson,
+			AvroError::InvalidType => Error::<T>::InvalidAvroType,
+			AvroError::UnknownType => Error::<T>::UnknownAvroType,
+			AvroError::MissingAttribute => Error::<T>::MissingAvroAttribute,
+			AvroError::InvalidAttribute => Error::<T>::InvalidAvroAttribute,
+			AvroError::InvalidName => Error::<T>::InvalidAvroName,
+			AvroError::DuplicateName => Error::<T>::DuplicateAvroName,
+			AvroError::DuplicateFieldName => Error::<T>::DuplicateAvroFieldName,
+			AvroError::InvalidEnumSymbols => Error::<T>::InvalidAvroEnumSymbols,
+			AvroError::InvalidFixedSize => Error::<T>::InvalidAvroFixedSize,
+			AvroError::InvalidUnion => Error::<T>::InvalidAvroUnion,
+			AvroError::InvalidDefault => Error::<T>::InvalidAvroDefault,
+			AvroError::NestingTooDeep => Error::<T>::AvroNestingTooDeep,
+		}
+	}
+}
+
+impl<T: Config> From<AvroIncompatibility> for Error<T> {
+	fn from(incompatibility: AvroIncompatibility) -> Self {
+		match incompatibility {
+			AvroIncompatibility::TypeMismatch => Error::<T>::IncompatibleAvroType,
+			AvroIncompatibility::NameMismatch => Error::<T>::IncompatibleAvroName,
+			AvroIncompatibility::MissingFieldDefault => Error::<T>::IncompatibleAvroFieldDefault,
+			AvroIncompatibility::MissingEnumSymbol => Error::<T>::IncompatibleAvroEnumSymbol,
+			AvroIncompatibility::FixedSizeMismatch => Error::<T>::IncompatibleAvroFixedSize,
+			AvroIncompatibility::UnionBranchMismatch => Error::<T>::IncompatibleAvroUnion,
+		}
+	}
+}
+
+impl<T: Config> From<ParquetIncompatibility> for Error<T> {
+	fn from(incompatibility: ParquetIncompatibility) -> Self {
+		match incompatibility {
+			ParquetIncompatibility::MissingColumn => Error::<T>::IncompatibleParquetMissingColumn,
+			ParquetIncompatibility::ColumnTypeMismatch => Error::<T>::IncompatibleParquetColumnType,
+			ParquetIncompatibility::OptionalColumn => Error::<T>::IncompatibleParquetOptionalColumn,
+		}
+	}
+}
+
+impl<T: Config> From<ParquetError> for Error<T> {
+	fn from(error: ParquetError) -> Self {
+		match error {
+			ParquetError::InvalidJson => Error::<T>::InvalidSchema,
+			ParquetError::InvalidColumnCount => Error::<T>::InvalidParquetColumnCount,
+			ParquetError::InvalidColumnName => Error::<T>::InvalidParquetColumnName,
+			ParquetError::DuplicateColumnName => Error::<T>::DuplicateParquetColumnName,
+			ParquetError::InvalidIntegerWidth => Error::<T>::InvalidParquetIntegerWidth,
+			ParquetError::InvalidDecimal => Error::<T>::InvalidParquetDecimal,
+			ParquetError::UnsupportedType => Error::<T>::UnsupportedParquetType,
+			ParquetError::UnsupportedBloomFilter => Error::<T>::UnsupportedParquetBloomFilter,
+			ParquetError::UnsupportedCompressionCodec =>
+				Error::<T>::UnsupportedParquetCompressionCodec,
+		}
+	}
+}
@@ -33,9 +33,9 @@ use core::marker::PhantomData;
 
 /// Weight functions needed for `pallet_schemas`.
 pub trait WeightInfo {
-	fn create_schema_v4(m: u32, ) -> Weight;
+	fn create_schema_v4(m: u32, p: u32, ) -> Weight;
 	fn set_max_schema_model_bytes() -> Weight;
-	fn create_schema_via_governance_v3(m: u32, ) -> Weight;
+	fn create_schema_via_governance_v3(m: u32, p: u32, ) -> Weight;
 	fn propose_to_create_schema_v3() -> Weight;
 	fn create_intent(m: u32, ) -> Weight;
 	fn create_intent_via_governance(m: u32, ) -> Weight;
@@ -46,6 +46,22 @@ pub trait WeightInfo {
 	fn update_intent_group(m: u32, ) -> Weight;
 	fn update_intent_group_via_governance(m: u32, ) -> Weight;
 	fn propose_to_update_intent_group() -> Weight;
+	fn update_schema_status_via_governance() -> Weight;
+	fn propose_to_update_schema_status() -> Weight;
+	fn set_intent_compatibility_policy_via_governance() -> Weight;
+	fn propose_to_set_intent_compatibility_policy() -> Weight;
+	fn set_namespace_owner_via_governance() -> Weight;
+	fn propose_to_set_namespace_owner() -> Weight;
+	fn create_provider_intent(m: u32, ) -> Weight;
+	fn create_provider_intent_group(m: u32, ) -> Weight;
+	fn create_provider_schema(m: u32, p: u32, ) -> Weight;
+	fn remove_provider_intent() -> Weight;
+	fn remove_provider_intent_group(m: u32, ) -> Weight;
+	fn update_provider_schema_status() -> Weight;
+	fn set_intent_retention_via_governance() -> Weight;
+	fn propose_to_set_intent_retention() -> Weight;
+	fn set_intent_storage_quota_via_governance() -> Weight;
+	fn propose_to_set_intent_storage_quota() -> Weight;
 }
 
 /// Weights for `pallet_schemas` using the Substrate node and recommended hardware.
@@ -55,23 +71,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
 	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
-	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
 	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[16, 65499]`.
-	fn create_schema_v4(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `480`
-		//  Estimated: `3973`
-		// Minimum execution time: 24_871_000 picoseconds.
-		Weight::from_parts(9_614_500, 3973)
-			// Standard Error: 95
-			.saturating_add(Weight::from_parts(35_757, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_schema_v4(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(14_203_100, 68979)
+			.saturating_add(Weight::from_parts(83_540, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(83_540, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:0 w:1)
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -87,23 +109,29 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
 	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
-	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
 	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[16, 65499]`.
-	fn create_schema_via_governance_v3(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `480`
-		//  Estimated: `3973`
-		// Minimum execution time: 25_040_000 picoseconds.
-		Weight::from_parts(15_196_760, 3973)
-			// Standard Error: 66
-			.saturating_add(Weight::from_parts(35_674, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
-			.saturating_add(T::DbWeight::get().writes(3_u64))
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_schema_via_governance_v3(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(19_870_340, 68979)
+			.saturating_add(Weight::from_parts(83_392, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(83_392, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -183,8 +211,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
 	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::IntentGroups` (r:0 w:1)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn create_intent_group(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -194,8 +226,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(26_536_727, 4055)
 			// Standard Error: 21_451
 			.saturating_add(Weight::from_parts(493_689, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
 	}
 	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
 	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
@@ -203,8 +238,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
 	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::IntentGroups` (r:0 w:1)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 2]`.
 	fn create_intent_group_via_governance(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -214,8 +253,11 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		Weight::from_parts(25_560_557, 4055)
 			// Standard Error: 61_735
 			.saturating_add(Weight::from_parts(1_238_295, 0).saturating_mul(m.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(3_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -240,6 +282,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:20 w:20)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn update_intent_group(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -250,12 +296,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 12_114
 			.saturating_add(Weight::from_parts(8_164, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 7462).saturating_mul(m.into()))
 	}
 	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:20 w:20)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 2]`.
 	fn update_intent_group_via_governance(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -266,7 +319,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 39_869
 			.saturating_add(Weight::from_parts(218_711, 0).saturating_mul(m.into()))
 			.saturating_add(T::DbWeight::get().reads(2_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(m.into())))
 			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 7462).saturating_mul(m.into()))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -287,6 +343,356 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4_u64))
 			.saturating_add(T::DbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:3 w:3)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:0 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_schema_status_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(11_820_000, 3593)
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_update_schema_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_163_000 picoseconds.
+		Weight::from_parts(22_514_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:0 w:1)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_intent_compatibility_policy_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(11_420_000, 3478)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_compatibility_policy() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(23_870_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::NamespaceOwners` (r:0 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	fn set_namespace_owner_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_259_000 picoseconds.
+		Weight::from_parts(9_850_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_namespace_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_065_000 picoseconds.
+		Weight::from_parts(22_410_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:0 w:1)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 2]`.
+	fn create_provider_intent(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 57_565_000 picoseconds.
+		Weight::from_parts(61_240_000, 3613)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_parts(212_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
+	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10]`.
+	fn create_provider_intent_group(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 60_977_000 picoseconds.
+		Weight::from_parts(64_870_000, 3613)
+			// Standard Error: 181_000
+			.saturating_add(Weight::from_parts(1_815_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(7_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:0)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_provider_schema(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(83_310_000, 69479)
+			.saturating_add(Weight::from_parts(27_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(27_000, 0).saturating_mul(p.into()))
+			.saturating_add(T::DbWeight::get().reads(15_u64))
+			.saturating_add(T::DbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:0)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:1 w:0)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:0 w:1)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:0 w:1)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CounterForIntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::CounterForIntentRetentionPeriods` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:0 w:1)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn remove_provider_intent() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(63_120_000, 3613)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
+	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10]`.
+	fn remove_provider_intent_group(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 56_522_000 picoseconds.
+		Weight::from_parts(60_130_000, 3613)
+			// Standard Error: 204_000
+			.saturating_add(Weight::from_parts(2_044_000, 0).saturating_mul(m.into()))
+			.saturating_add(T::DbWeight::get().reads(7_u64))
+			.saturating_add(T::DbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(T::DbWeight::get().writes(5_u64))
+			.saturating_add(T::DbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2487).saturating_mul(m.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:0)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:3 w:3)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:0 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_provider_schema_status() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(57_650_000, 3613)
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CounterForIntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::CounterForIntentRetentionPeriods` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_intent_retention_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(15_210_000, 3479)
+			.saturating_add(T::DbWeight::get().reads(3_u64))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_retention() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(23_920_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:0 w:1)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn set_intent_storage_quota_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3478`
+		// Minimum execution time: 12_408_000 picoseconds.
+		Weight::from_parts(13_200_000, 3478)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_storage_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_714_000 picoseconds.
+		Weight::from_parts(23_100_000, 4126)
+			.saturating_add(T::DbWeight::get().reads(5_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+	}
 }
 
 // For backwards compatibility and tests.
@@ -295,23 +701,29 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
 	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
-	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
 	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[16, 65499]`.
-	fn create_schema_v4(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `480`
-		//  Estimated: `3973`
-		// Minimum execution time: 24_871_000 picoseconds.
-		Weight::from_parts(9_614_500, 3973)
-			// Standard Error: 95
-			.saturating_add(Weight::from_parts(35_757, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_schema_v4(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(14_203_100, 68979)
+			.saturating_add(Weight::from_parts(83_540, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(83_540, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:0 w:1)
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
@@ -327,23 +739,29 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
 	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
-	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaInfos` (r:0 w:1)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::SchemaPayloads` (r:0 w:1)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
 	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[16, 65499]`.
-	fn create_schema_via_governance_v3(m: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `480`
-		//  Estimated: `3973`
-		// Minimum execution time: 25_040_000 picoseconds.
-		Weight::from_parts(15_196_760, 3973)
-			// Standard Error: 66
-			.saturating_add(Weight::from_parts(35_674, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
-			.saturating_add(RocksDbWeight::get().writes(3_u64))
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_schema_via_governance_v3(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(19_870_340, 68979)
+			.saturating_add(Weight::from_parts(83_392, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(83_392, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -423,8 +841,12 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
 	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::IntentGroups` (r:0 w:1)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn create_intent_group(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -434,8 +856,11 @@ impl WeightInfo for () {
 		Weight::from_parts(26_536_727, 4055)
 			// Standard Error: 21_451
 			.saturating_add(Weight::from_parts(493_689, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
 	}
 	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
 	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
@@ -443,8 +868,12 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
 	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
-	/// Storage: `Schemas::IntentGroups` (r:0 w:1)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 2]`.
 	fn create_intent_group_via_governance(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -454,8 +883,11 @@ impl WeightInfo for () {
 		Weight::from_parts(25_560_557, 4055)
 			// Standard Error: 61_735
 			.saturating_add(Weight::from_parts(1_238_295, 0).saturating_mul(m.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(3_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -480,6 +912,10 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:20 w:20)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 10]`.
 	fn update_intent_group(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -490,12 +926,19 @@ impl WeightInfo for () {
 			// Standard Error: 12_114
 			.saturating_add(Weight::from_parts(8_164, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 7462).saturating_mul(m.into()))
 	}
 	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
 	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
 	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:20 w:20)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
 	/// The range of component `m` is `[0, 2]`.
 	fn update_intent_group_via_governance(m: u32, ) -> Weight {
 		// Proof Size summary in bytes:
@@ -506,7 +949,10 @@ impl WeightInfo for () {
 			// Standard Error: 39_869
 			.saturating_add(Weight::from_parts(218_711, 0).saturating_mul(m.into()))
 			.saturating_add(RocksDbWeight::get().reads(2_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(m.into())))
 			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 7462).saturating_mul(m.into()))
 	}
 	/// Storage: `Council::Members` (r:1 w:0)
 	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
@@ -527,6 +973,356 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4_u64))
 			.saturating_add(RocksDbWeight::get().writes(4_u64))
 	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:3 w:3)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:0 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_schema_status_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(11_820_000, 3593)
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_update_schema_status() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_163_000 picoseconds.
+		Weight::from_parts(22_514_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:0 w:1)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	fn set_intent_compatibility_policy_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(11_420_000, 3478)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_compatibility_policy() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(23_870_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::NamespaceOwners` (r:0 w:1)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	fn set_namespace_owner_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 9_259_000 picoseconds.
+		Weight::from_parts(9_850_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_namespace_owner() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_065_000 picoseconds.
+		Weight::from_parts(22_410_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:0 w:1)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 2]`.
+	fn create_provider_intent(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 57_565_000 picoseconds.
+		Weight::from_parts(61_240_000, 3613)
+			// Standard Error: 21_000
+			.saturating_add(Weight::from_parts(212_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentIdentifierMaximum` (r:1 w:0)
+	/// Proof: `Schemas::CurrentIntentIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentIntentGroupIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentIntentGroupIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
+	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:10 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10]`.
+	fn create_provider_intent_group(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 60_977_000 picoseconds.
+		Weight::from_parts(64_870_000, 3613)
+			// Standard Error: 181_000
+			.saturating_add(Weight::from_parts(1_815_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((2_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(7_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 4975).saturating_mul(m.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:0)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::GovernanceSchemaModelMaxBytes` (r:1 w:0)
+	/// Proof: `Schemas::GovernanceSchemaModelMaxBytes` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:1 w:0)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:1 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:1 w:2)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:1)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CurrentSchemaIdentifierMaximum` (r:1 w:1)
+	/// Proof: `Schemas::CurrentSchemaIdentifierMaximum` (`max_values`: Some(1), `max_size`: Some(2), added: 497, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:0 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[16, 65499]`.
+	/// The range of component `p` is `[16, 65499]`.
+	fn create_provider_schema(m: u32, p: u32, ) -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(83_310_000, 69479)
+			.saturating_add(Weight::from_parts(27_000, 0).saturating_mul(m.into()))
+			.saturating_add(Weight::from_parts(27_000, 0).saturating_mul(p.into()))
+			.saturating_add(RocksDbWeight::get().reads(15_u64))
+			.saturating_add(RocksDbWeight::get().writes(11_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSchemaIds` (r:1 w:0)
+	/// Proof: `Schemas::IntentLatestSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:1 w:0)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:0 w:1)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentCompatibilityPolicies` (r:0 w:1)
+	/// Proof: `Schemas::IntentCompatibilityPolicies` (`max_values`: None, `max_size`: Some(11), added: 2486, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CounterForIntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::CounterForIntentRetentionPeriods` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:0 w:1)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn remove_provider_intent() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(63_120_000, 3613)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(10_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:1)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroups` (r:1 w:1)
+	/// Proof: `Schemas::IntentGroups` (`max_values`: None, `max_size`: Some(31), added: 2506, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentGroupMemberships` (r:10 w:10)
+	/// Proof: `Schemas::IntentGroupMemberships` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `m` is `[0, 10]`.
+	fn remove_provider_intent_group(m: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `113`
+		//  Estimated: `3613`
+		// Minimum execution time: 56_522_000 picoseconds.
+		Weight::from_parts(60_130_000, 3613)
+			// Standard Error: 204_000
+			.saturating_add(Weight::from_parts(2_044_000, 0).saturating_mul(m.into()))
+			.saturating_add(RocksDbWeight::get().reads(7_u64))
+			.saturating_add(RocksDbWeight::get().reads((1_u64).saturating_mul(m.into())))
+			.saturating_add(RocksDbWeight::get().writes(5_u64))
+			.saturating_add(RocksDbWeight::get().writes((1_u64).saturating_mul(m.into())))
+			.saturating_add(Weight::from_parts(0, 2487).saturating_mul(m.into()))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NamespaceOwners` (r:1 w:0)
+	/// Proof: `Schemas::NamespaceOwners` (`max_values`: None, `max_size`: Some(55), added: 2530, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::NameToMappedEntityIds` (r:1 w:0)
+	/// Proof: `Schemas::NameToMappedEntityIds` (`max_values`: None, `max_size`: Some(95), added: 2570, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:1)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SupportedSchemaLinks` (r:3 w:3)
+	/// Proof: `Schemas::SupportedSchemaLinks` (`max_values`: None, `max_size`: Some(16), added: 2491, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentLatestSupportedSchemaIds` (r:0 w:1)
+	/// Proof: `Schemas::IntentLatestSupportedSchemaIds` (`max_values`: None, `max_size`: Some(12), added: 2487, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::EntityDeposits` (r:1 w:1)
+	/// Proof: `Schemas::EntityDeposits` (`max_values`: None, `max_size`: Some(59), added: 2534, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(85), added: 2560, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn update_provider_schema_status() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(57_650_000, 3613)
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(8_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::IntentRetentionPeriods` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::CounterForIntentRetentionPeriods` (r:1 w:1)
+	/// Proof: `Schemas::CounterForIntentRetentionPeriods` (`max_values`: Some(1), `max_size`: Some(4), added: 499, mode: `MaxEncodedLen`)
+	fn set_intent_retention_via_governance() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(15_210_000, 3479)
+			.saturating_add(RocksDbWeight::get().reads(3_u64))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_retention() -> Weight {
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(23_920_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:0 w:1)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	fn set_intent_storage_quota_via_governance() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1739`
+		//  Estimated: `3478`
+		// Minimum execution time: 12_408_000 picoseconds.
+		Weight::from_parts(13_200_000, 3478)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Schemas::IntentInfos` (r:1 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(13), added: 2488, mode: `MaxEncodedLen`)
+	/// Storage: `Council::Members` (r:1 w:0)
+	/// Proof: `Council::Members` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalOf` (r:1 w:1)
+	/// Proof: `Council::ProposalOf` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Proposals` (r:1 w:1)
+	/// Proof: `Council::Proposals` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::ProposalCount` (r:1 w:1)
+	/// Proof: `Council::ProposalCount` (`max_values`: Some(1), `max_size`: None, mode: `Measured`)
+	/// Storage: `Council::Voting` (r:0 w:1)
+	/// Proof: `Council::Voting` (`max_values`: None, `max_size`: None, mode: `Measured`)
+	fn propose_to_set_intent_storage_quota() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `626`
+		//  Estimated: `4126`
+		// Minimum execution time: 21_714_000 picoseconds.
+		Weight::from_parts(23_100_000, 4126)
+			.saturating_add(RocksDbWeight::get().reads(5_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+	}
 }
 
 
@@ -561,7 +1357,7 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 3973
+				> 68979
 		);
 	}
 	#[test]
@@ -573,7 +1369,7 @@ mod tests {
 				.max_extrinsic
 				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
 				.proof_size()
-				> 3973
+				> 68979
 		);
 	}
 	#[test]
@@ -696,4 +1492,184 @@ mod tests {
 				> 4126
 		);
 	}
+	#[test]
+	fn test_update_schema_status_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3593
+		);
+	}
+	#[test]
+	fn test_propose_to_update_schema_status() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4126
+		);
+	}
+	#[test]
+	fn test_set_intent_compatibility_policy_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3478
+		);
+	}
+	#[test]
+	fn test_propose_to_set_intent_compatibility_policy() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4126
+		);
+	}
+	#[test]
+	fn test_propose_to_set_namespace_owner() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4126
+		);
+	}
+	#[test]
+	fn test_create_provider_intent() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3613
+		);
+	}
+	#[test]
+	fn test_create_provider_intent_group() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3613
+		);
+	}
+	#[test]
+	fn test_create_provider_schema() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 69479
+		);
+	}
+	#[test]
+	fn test_remove_provider_intent() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3613
+		);
+	}
+	#[test]
+	fn test_remove_provider_intent_group() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3613
+		);
+	}
+	#[test]
+	fn test_update_provider_schema_status() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3613
+		);
+	}
+	#[test]
+	fn test_set_intent_retention_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3479
+		);
+	}
+	#[test]
+	fn test_propose_to_set_intent_retention() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4126
+		);
+	}
+	#[test]
+	fn test_set_intent_storage_quota_via_governance() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 3478
+		);
+	}
+	#[test]
+	fn test_propose_to_set_intent_storage_quota() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 4126
+		);
+	}
 }
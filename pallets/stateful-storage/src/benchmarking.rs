@@ -2,12 +2,13 @@
 use super::*;
 use crate::{types::ItemAction, Pallet as StatefulStoragePallet, StatefulChildTree};
 use common_primitives::{
-	schema::{ModelType, PayloadLocation},
-	stateful_storage::{PageHash, PageId},
+	benchmarks::{avro_benchmark_model, avro_benchmark_payload},
+	schema::{IntentSetting, ModelType, PayloadLocation},
+	stateful_storage::{compress_page_data_lz4, PageCompression, PageHash, PageId},
 	utils::wrap_binary_data,
 };
 use frame_benchmarking::{v2::*, whitelisted_caller};
-use frame_support::assert_ok;
+use frame_support::{assert_ok, traits::tokens::fungible::Mutate};
 use frame_system::RawOrigin;
 use parity_scale_codec::{Decode, Encode};
 use sp_core::{bounded::BoundedVec, crypto::KeyTypeId};
@@ -27,6 +28,12 @@ mod app_sr25519 {
 }
 
 type SignerId = app_sr25519::Public;
+
+/// Charges a deposit for every page byte and funds the caller so that it is held
+fn fund_storage_deposit<T: Config>(caller: &T::AccountId) {
+	StorageByteDeposit::<T>::put(BalanceOf::<T>::from(1u32));
+	assert_ok!(T::Currency::set_balance(caller, BalanceOf::<T>::from(1_000_000_000u32)));
+}
 pub const NONEXISTENT_PAGE_HASH: PageHash = 0;
 
 fn itemized_actions_populate<T: Config>(
@@ -36,7 +43,7 @@ fn itemized_actions_populate<T: Config>(
 ) -> BoundedVec<ItemAction<T::MaxItemizedBlobSizeBytes>, T::MaxItemizedActionsCount> {
 	let mut actions = vec![];
 	for _ in 0..n {
-		let payload = vec![0u8; s];
+		let payload = avro_benchmark_payload(s as u32);
 		actions.push(ItemAction::Add { data: payload.try_into().unwrap() });
 	}
 	for i in 0..delete_actions {
@@ -45,15 +52,39 @@ fn itemized_actions_populate<T: Config>(
 	actions.try_into().expect("Invalid actions")
 }
 
+fn itemized_update_actions_populate<T: Config>(
+	n: u32,
+	s: usize,
+) -> BoundedVec<ItemAction<T::MaxItemizedBlobSizeBytes>, T::MaxItemizedActionsCount> {
+	let mut actions = vec![];
+	for i in 0..n {
+		let payload = avro_benchmark_payload(s as u32);
+		actions.push(ItemAction::Update { index: i as u16, data: payload.try_into().unwrap() });
+	}
+	actions.try_into().expect("Invalid actions")
+}
+
+/// Adds a generated key to an MSA, returning the key, its account and the MSA controlled by it
+fn transfer_msa_data_account<T: Config>(
+	seed: &str,
+	msa_id: MessageSourceId,
+) -> Result<(SignerId, T::AccountId, MessageSourceId), BenchmarkError> {
+	let key = SignerId::generate_pair(Some(seed.as_bytes().to_vec()));
+	let account = T::AccountId::decode(&mut &key.encode()[..]).unwrap();
+	T::MsaBenchmarkHelper::add_key(msa_id, account.clone())?;
+	let msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&account)?;
+	Ok((key, account, msa_id))
+}
+
 fn create_intent_and_schema<T: Config>(location: PayloadLocation) -> Result<(), DispatchError> {
 	let intent_id = T::SchemaBenchmarkHelper::create_intent(
 		b"benchmark.test".to_vec(),
 		location,
-		Vec::default(),
+		vec![IntentSetting::ValidatePayload],
 	)?;
 	let _ = T::SchemaBenchmarkHelper::create_schema(
 		intent_id,
-		Vec::from(r#"{"Message": "some-random-hash"}"#.as_bytes()),
+		avro_benchmark_model(),
 		ModelType::AvroBinary,
 		location,
 	)?;
@@ -134,6 +165,7 @@ mod benchmarks {
 		let schema_id = constants::ITEMIZED_SCHEMA;
 		let intent_id = constants::ITEMIZED_INTENT;
 		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
 		let num_of_items = s / T::MaxItemizedBlobSizeBytes::get();
 
 		T::SchemaBenchmarkHelper::set_intent_count(intent_id - 1);
@@ -241,6 +273,68 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn apply_item_actions_update(
+		s: Linear<
+			{ T::MaxItemizedBlobSizeBytes::get() },
+			{ T::MaxItemizedBlobSizeBytes::get() * T::MaxItemizedActionsCount::get() },
+		>,
+	) -> Result<(), BenchmarkError> {
+		let provider_msa_id = 1u64;
+		let delegator_msa_id = 2u64;
+		let schema_id = constants::ITEMIZED_SCHEMA;
+		let intent_id = constants::ITEMIZED_INTENT;
+		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
+		let num_of_items = s / T::MaxItemizedBlobSizeBytes::get();
+
+		T::SchemaBenchmarkHelper::set_intent_count(intent_id - 1);
+		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
+		assert_ok!(create_intent_and_schema::<T>(PayloadLocation::Itemized));
+		assert_ok!(T::MsaBenchmarkHelper::add_key(provider_msa_id, caller.clone()));
+		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(
+			provider_msa_id.into(),
+			delegator_msa_id.into(),
+			[intent_id].to_vec()
+		));
+
+		let actions = itemized_actions_populate::<T>(
+			num_of_items,
+			T::MaxItemizedBlobSizeBytes::get() as usize,
+			0,
+		);
+		assert_ok!(StatefulStoragePallet::<T>::apply_item_actions(
+			RawOrigin::Signed(caller.clone()).into(),
+			delegator_msa_id,
+			schema_id,
+			NONEXISTENT_PAGE_HASH,
+			actions
+		));
+		let content_hash = get_itemized_page::<T>(delegator_msa_id, intent_id)
+			.expect("page should exist")
+			.get_hash();
+
+		let actions = itemized_update_actions_populate::<T>(
+			num_of_items,
+			T::MaxItemizedBlobSizeBytes::get() as usize,
+		);
+		#[block]
+		{
+			assert_ok!(StatefulStoragePallet::<T>::apply_item_actions(
+				RawOrigin::Signed(caller).into(),
+				delegator_msa_id,
+				schema_id,
+				content_hash,
+				actions
+			));
+		}
+
+		let page_result = get_itemized_page::<T>(delegator_msa_id, intent_id);
+		assert!(page_result.is_some());
+		assert!(page_result.unwrap().data.len() > 0);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn upsert_page(
 		s: Linear<1, { T::MaxPaginatedPageSizeBytes::get() }>,
@@ -251,7 +345,8 @@ mod benchmarks {
 		let schema_id = constants::PAGINATED_SCHEMA;
 		let intent_id = constants::PAGINATED_INTENT;
 		let caller: T::AccountId = whitelisted_caller();
-		let payload = vec![1u8; s as usize];
+		fund_storage_deposit::<T>(&caller);
+		let payload = avro_benchmark_payload(s);
 		let max_payload = vec![1u8; T::MaxPaginatedPageSizeBytes::get() as usize];
 		let page = PaginatedPage::<T>::from(BoundedVec::try_from(max_payload).unwrap());
 
@@ -307,6 +402,7 @@ mod benchmarks {
 		let intent_id = constants::PAGINATED_INTENT;
 		let page_id: PageId = 1;
 		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
 		let payload = BoundedVec::<u8, T::MaxPaginatedPageSizeBytes>::try_from(vec![
 			0u8;
 			T::MaxPaginatedPageSizeBytes::get()
@@ -325,8 +421,16 @@ mod benchmarks {
 			[intent_id].to_vec()
 		));
 
-		Pallet::<T>::update_paginated(delegator_msa_id, intent_id, schema_id, page_id, 0, page)
-			.expect("failed to write page");
+		Pallet::<T>::update_paginated(
+			&caller,
+			delegator_msa_id,
+			intent_id,
+			schema_id,
+			page_id,
+			0,
+			page,
+		)
+		.expect("failed to write page");
 		let key = (intent_id, page_id);
 		let content_hash = StatefulChildTree::<T::KeyHasher>::try_read::<_, PaginatedPage<T>>(
 			&delegator_msa_id,
@@ -357,6 +461,7 @@ mod benchmarks {
 		let schema_id = constants::ITEMIZED_SCHEMA;
 		let intent_id = constants::ITEMIZED_INTENT;
 		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
 		let num_of_items = s / T::MaxItemizedBlobSizeBytes::get();
 		let expiration = BlockNumberFor::<T>::from(10u32);
 
@@ -495,6 +600,83 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn apply_item_actions_with_signature_v2_update(
+		s: Linear<
+			{ T::MaxItemizedBlobSizeBytes::get() },
+			{ T::MaxItemizedBlobSizeBytes::get() * T::MaxItemizedActionsCount::get() },
+		>,
+	) -> Result<(), BenchmarkError> {
+		let msa_id = 1u64;
+		let schema_id = constants::ITEMIZED_SCHEMA;
+		let intent_id = constants::ITEMIZED_INTENT;
+		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
+		let num_of_items = s / T::MaxItemizedBlobSizeBytes::get();
+		let expiration = BlockNumberFor::<T>::from(10u32);
+
+		let delegator_account_public = SignerId::generate_pair(Some(
+			constants::BENCHMARK_SIGNATURE_ACCOUNT_SEED.as_bytes().to_vec(),
+		));
+		let delegator_account =
+			T::AccountId::decode(&mut &delegator_account_public.encode()[..]).unwrap();
+		let delegator_msa_id = constants::SIGNATURE_MSA_ID;
+
+		T::SchemaBenchmarkHelper::set_intent_count(intent_id - 1);
+		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
+		assert_ok!(create_intent_and_schema::<T>(PayloadLocation::Itemized));
+		assert_ok!(T::MsaBenchmarkHelper::add_key(msa_id, caller.clone()));
+		assert_ok!(T::MsaBenchmarkHelper::add_key(delegator_msa_id, delegator_account.clone()));
+		assert_ok!(T::MsaBenchmarkHelper::set_delegation_relationship(
+			msa_id.into(),
+			delegator_msa_id.into(),
+			[intent_id].to_vec()
+		));
+
+		let actions = itemized_actions_populate::<T>(
+			num_of_items,
+			T::MaxItemizedBlobSizeBytes::get() as usize,
+			0,
+		);
+		assert_ok!(StatefulStoragePallet::<T>::apply_item_actions(
+			RawOrigin::Signed(caller.clone()).into(),
+			delegator_msa_id,
+			schema_id,
+			NONEXISTENT_PAGE_HASH,
+			actions
+		));
+		let content_hash = get_itemized_page::<T>(delegator_msa_id, intent_id)
+			.expect("page should exist")
+			.get_hash();
+
+		let actions = itemized_update_actions_populate::<T>(
+			num_of_items,
+			T::MaxItemizedBlobSizeBytes::get() as usize,
+		);
+		let payload = ItemizedSignaturePayloadV2 {
+			actions,
+			target_hash: content_hash,
+			expiration,
+			schema_id,
+		};
+		let encode_data_new_key_data = wrap_binary_data(payload.encode());
+		let signature = delegator_account_public.sign(&encode_data_new_key_data).unwrap();
+		#[block]
+		{
+			assert_ok!(StatefulStoragePallet::<T>::apply_item_actions_with_signature_v2(
+				RawOrigin::Signed(caller).into(),
+				delegator_account,
+				MultiSignature::Sr25519(signature.into()),
+				payload
+			));
+		}
+
+		let page_result = get_itemized_page::<T>(delegator_msa_id, intent_id);
+		assert!(page_result.is_some());
+		assert!(page_result.unwrap().data.len() > 0);
+		Ok(())
+	}
+
 	#[benchmark]
 	fn upsert_page_with_signature_v2(
 		s: Linear<1, { T::MaxPaginatedPageSizeBytes::get() }>,
@@ -503,7 +685,8 @@ mod benchmarks {
 		let schema_id = constants::PAGINATED_SCHEMA;
 		let intent_id = constants::PAGINATED_INTENT;
 		let caller: T::AccountId = whitelisted_caller();
-		let payload = vec![0u8; s as usize];
+		fund_storage_deposit::<T>(&caller);
+		let payload = avro_benchmark_payload(s);
 		let max_payload = vec![1u8; T::MaxPaginatedPageSizeBytes::get() as usize];
 		let page = PaginatedPage::<T>::from(BoundedVec::try_from(max_payload).unwrap());
 		let expiration = BlockNumberFor::<T>::from(10u32);
@@ -568,6 +751,7 @@ mod benchmarks {
 		let intent_id = constants::PAGINATED_INTENT;
 		let page_id: PageId = 1;
 		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
 		let payload = BoundedVec::<u8, T::MaxPaginatedPageSizeBytes>::try_from(vec![
 			0u8;
 			T::MaxPaginatedPageSizeBytes::get()
@@ -590,8 +774,16 @@ mod benchmarks {
 		assert_ok!(T::MsaBenchmarkHelper::add_key(delegator_msa_id, delegator_account.clone()));
 
 		let key = (intent_id, page_id);
-		Pallet::<T>::update_paginated(delegator_msa_id, intent_id, schema_id, page_id, 0, page)
-			.expect("failed to write page");
+		Pallet::<T>::update_paginated(
+			&caller,
+			delegator_msa_id,
+			intent_id,
+			schema_id,
+			page_id,
+			0,
+			page,
+		)
+		.expect("failed to write page");
 		let content_hash = StatefulChildTree::<T::KeyHasher>::try_read::<_, PaginatedPage<T>>(
 			&delegator_msa_id,
 			PALLET_STORAGE_PREFIX,
@@ -827,6 +1019,291 @@ mod benchmarks {
 		Ok(())
 	}
 
+	#[benchmark]
+	fn paginated_v2_to_v3_miss() -> Result<(), BenchmarkError> {
+		// Setup
+		let msa_id: MessageSourceId = T::MsaBenchmarkHelper::create_msa(whitelisted_caller())?;
+
+		let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+			msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+		);
+		let mut cursor = migration::v2::ChildCursor::<migration::v2::PaginatedKeyLength> {
+			id: msa_id,
+			last_key: BoundedVec::default(),
+			cumulative_pages: 0,
+		};
+
+		// Execute
+		#[block]
+		{
+			migration::v3::process_paginated_page::<T, migration::v2::PaginatedKeyLength>(
+				&child,
+				&mut cursor,
+			)
+			.expect("failed to seed paginated page usage");
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn paginated_v2_to_v3_hit() -> Result<(), BenchmarkError> {
+		// Setup
+		let msa_id: MessageSourceId = T::MsaBenchmarkHelper::create_msa(whitelisted_caller())?;
+		let intent_id: IntentId = 1;
+		let page_id: PageId = 1;
+
+		let payload: BoundedVec<u8, T::MaxPaginatedPageSizeBytes> =
+			vec![1; T::MaxPaginatedPageSizeBytes::get() as usize]
+				.try_into()
+				.expect("Unable to create BoundedVec payload");
+		let page: PaginatedPage<T> = payload.into();
+		let keys: PaginatedKey = (intent_id, page_id);
+		StatefulChildTree::<T::KeyHasher>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&keys,
+			&page,
+		);
+
+		let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+			msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+		);
+		let mut cursor = migration::v2::ChildCursor::<migration::v2::PaginatedKeyLength> {
+			id: msa_id,
+			last_key: BoundedVec::default(),
+			cumulative_pages: 0,
+		};
+
+		// Execute
+		#[block]
+		{
+			migration::v3::process_paginated_page::<T, migration::v2::PaginatedKeyLength>(
+				&child,
+				&mut cursor,
+			)
+			.expect("failed to seed paginated page usage");
+		}
+
+		let usage = StorageUsages::<T>::get(msa_id, intent_id);
+		assert_eq!(usage.pages, 1);
+		assert_eq!(usage.bytes, page.encoded_size() as u32);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn itemized_v2_to_v3_miss() -> Result<(), BenchmarkError> {
+		// Setup
+		let msa_id: MessageSourceId = T::MsaBenchmarkHelper::create_msa(whitelisted_caller())?;
+
+		let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+			msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+		);
+		let mut cursor = migration::v2::ChildCursor::<migration::v2::ItemizedKeyLength> {
+			id: msa_id,
+			last_key: BoundedVec::default(),
+			cumulative_pages: 0,
+		};
+
+		// Execute
+		#[block]
+		{
+			migration::v3::process_itemized_page::<T, migration::v2::ItemizedKeyLength>(
+				&child,
+				&mut cursor,
+			)
+			.expect("failed to seed itemized page usage");
+		}
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn itemized_v2_to_v3_hit() -> Result<(), BenchmarkError> {
+		// Setup
+		let msa_id: MessageSourceId = T::MsaBenchmarkHelper::create_msa(whitelisted_caller())?;
+		let intent_id: IntentId = 1;
+
+		let payload: BoundedVec<u8, T::MaxItemizedPageSizeBytes> =
+			vec![1; T::MaxItemizedPageSizeBytes::get() as usize]
+				.try_into()
+				.expect("Unable to create BoundedVec payload");
+		let page: ItemizedPage<T> = payload.into();
+		let keys: ItemizedKey = (intent_id,);
+		StatefulChildTree::<T::KeyHasher>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&keys,
+			&page,
+		);
+
+		let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+			msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+		);
+		let mut cursor = migration::v2::ChildCursor::<migration::v2::ItemizedKeyLength> {
+			id: msa_id,
+			last_key: BoundedVec::default(),
+			cumulative_pages: 0,
+		};
+
+		// Execute
+		#[block]
+		{
+			migration::v3::process_itemized_page::<T, migration::v2::ItemizedKeyLength>(
+				&child,
+				&mut cursor,
+			)
+			.expect("failed to seed itemized page usage");
+		}
+
+		let usage = StorageUsages::<T>::get(msa_id, intent_id);
+		assert_eq!(usage.pages, 1);
+		assert_eq!(usage.bytes, page.encoded_size() as u32);
+
+		Ok(())
+	}
+
+	#[benchmark]
+	fn set_storage_byte_deposit() -> Result<(), BenchmarkError> {
+		let deposit_per_byte = BalanceOf::<T>::from(1_000u32);
+
+		#[extrinsic_call]
+		_(RawOrigin::Root, deposit_per_byte);
+
+		assert_eq!(StorageByteDeposit::<T>::get(), deposit_per_byte);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_msa_data(
+		n: Linear<1, { T::MaxTransferIntents::get() }>,
+	) -> Result<(), BenchmarkError> {
+		let caller: T::AccountId = whitelisted_caller();
+		let (source_key, source_account, source_msa_id) = transfer_msa_data_account::<T>(
+			constants::BENCHMARK_SIGNATURE_ACCOUNT_SEED,
+			constants::SIGNATURE_MSA_ID,
+		)?;
+		let (target_key, target_account, target_msa_id) = transfer_msa_data_account::<T>(
+			"//benchmark-transfer-target",
+			constants::SIGNATURE_MSA_ID + 1,
+		)?;
+
+		let intent_ids: Vec<IntentId> =
+			(0..n).map(|i| constants::PAGINATED_INTENT + i as IntentId).collect();
+		T::SchemaBenchmarkHelper::set_intent_count(constants::PAGINATED_INTENT - 1);
+		for i in 0..n {
+			let name = [b"benchmark.transfer-".as_slice(), &[b'a' + i as u8]].concat();
+			let _ =
+				T::SchemaBenchmarkHelper::create_intent(name, PayloadLocation::Paginated, vec![])?;
+		}
+		let payload = MsaDataTransferSignaturePayload::<T> {
+			source_msa_id,
+			target_msa_id,
+			intent_ids: BoundedVec::try_from(intent_ids).unwrap(),
+			mode: MsaDataTransferMode::Move,
+			nonce: MsaDataTransferNonces::<T>::get(source_msa_id),
+			expiration: BlockNumberFor::<T>::from(10u32),
+		};
+		let encoded = wrap_binary_data(payload.encode());
+		let source_proof = MultiSignature::Sr25519(source_key.sign(&encoded).unwrap().into());
+		let target_proof = MultiSignature::Sr25519(target_key.sign(&encoded).unwrap().into());
+
+		#[extrinsic_call]
+		_(
+			RawOrigin::Signed(caller),
+			source_account,
+			source_proof,
+			target_account,
+			target_proof,
+			payload,
+		);
+
+		assert!(MsaDataTransfers::<T>::contains_key(source_msa_id));
+		assert_eq!(MsaDataTransferNonces::<T>::get(source_msa_id), 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn transfer_msa_data_step() -> Result<(), BenchmarkError> {
+		let intent_id = constants::PAGINATED_INTENT;
+		let schema_id = constants::PAGINATED_SCHEMA;
+		let page_id: PageId = 0;
+		let (source_msa_id, target_msa_id) = (1, 2);
+		let caller: T::AccountId = whitelisted_caller();
+		fund_storage_deposit::<T>(&caller);
+		let page = || {
+			PaginatedPage::<T>::from(
+				BoundedVec::try_from(vec![1u8; T::MaxPaginatedPageSizeBytes::get() as usize])
+					.unwrap(),
+			)
+		};
+		for msa_id in [source_msa_id, target_msa_id] {
+			Pallet::<T>::update_paginated(
+				&caller,
+				msa_id,
+				intent_id,
+				schema_id,
+				page_id,
+				0,
+				page(),
+			)
+			.expect("failed to write page");
+		}
+		let mut transfer = MsaDataTransfer::<T> {
+			target_msa_id,
+			intent_ids: BoundedVec::try_from(vec![intent_id]).unwrap(),
+			mode: MsaDataTransferMode::Copy,
+			payer: caller,
+			cursor: MsaDataTransferCursor { intent_index: 0, step: 1 },
+			pages_transferred: 0,
+		};
+
+		#[block]
+		{
+			assert_ok!(Pallet::<T>::transfer_next_page(source_msa_id, &mut transfer));
+		}
+
+		assert_eq!(transfer.pages_transferred, 1);
+		Ok(())
+	}
+
+	#[benchmark]
+	fn decompress_paginated_page() -> Result<(), BenchmarkError> {
+		let schema_id = constants::PAGINATED_SCHEMA;
+		let intent_id = constants::PAGINATED_INTENT;
+		T::SchemaBenchmarkHelper::set_intent_count(intent_id - 1);
+		T::SchemaBenchmarkHelper::set_schema_count(schema_id - 1);
+		assert_ok!(create_intent_and_schema::<T>(PayloadLocation::Paginated));
+		let schema = T::SchemaProvider::get_schema_info_by_id(schema_id)
+			.ok_or(BenchmarkError::Stop("schema not found"))?;
+		// the decompressed page is as large as allowed and as costly to validate as it can be
+		let max_size = T::MaxDecompressedPaginatedPageSizeBytes::get();
+		let data = compress_page_data_lz4(&avro_benchmark_payload(max_size));
+		let decompressed;
+
+		#[block]
+		{
+			decompressed = Pallet::<T>::decompress_paginated_data(PageCompression::Lz4, &data)
+				.and_then(|decompressed| {
+					Pallet::<T>::check_payload(&schema, &decompressed).map(|_| decompressed)
+				});
+		}
+
+		assert_eq!(decompressed.map(|d| d.len()), Ok(max_size as usize));
+		Ok(())
+	}
+
 	impl_benchmark_test_suite!(
 		StatefulStoragePallet,
 		crate::tests::mock::new_test_ext_keystore(),
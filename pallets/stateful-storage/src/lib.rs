@@ -45,16 +45,24 @@ pub mod weights;
 extern crate alloc;
 extern crate core;
 
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 
-use crate::{stateful_child_tree::StatefulChildTree, types::*};
+use crate::{
+	stateful_child_tree::{MultipartKey, StatefulChildTree},
+	types::*,
+};
 use common_primitives::{
+	capacity::Nontransferable,
 	msa::{DelegatorId, GrantValidator, MessageSourceId, MsaLookup, MsaValidator, ProviderId},
 	node::EIP712Encode,
-	schema::{IntentSetting, PayloadLocation, SchemaId, SchemaInfoResponse, SchemaProvider},
+	schema::{
+		IntentSetting, PayloadLocation, SchemaId, SchemaInfoResponse, SchemaProvider, SchemaStatus,
+	},
 	stateful_storage::{
-		ItemizedStoragePageResponse, ItemizedStoragePageResponseV2, ItemizedStorageResponseV2,
-		PageHash, PageId, PaginatedStorageResponse, PaginatedStorageResponseV2,
+		decompress_page_data, IntentStorageUsage, ItemizedStoragePageResponse,
+		ItemizedStoragePageResponseV2, ItemizedStorageResponseV2, PageCompression,
+		PageDecompressionError, PageHash, PageId, PageStorageKey, PaginatedStorageResponse,
+		PaginatedStorageResponseV2, PaginatedStorageResponseV3, StorageUsageResponse,
 	},
 };
 
@@ -63,7 +71,15 @@ use frame_support::{
 	dispatch::{DispatchInfo, DispatchResult},
 	ensure,
 	pallet_prelude::*,
-	traits::{Get, IsSubType},
+	storage::with_storage_layer,
+	traits::{
+		tokens::{
+			fungible::{Inspect as InspectFungible, Mutate, MutateHold},
+			Precision,
+		},
+		Get, IsSubType,
+	},
+	weights::WeightMeter,
 };
 use frame_system::pallet_prelude::*;
 pub use pallet::*;
@@ -71,7 +87,7 @@ use sp_core::{bounded::BoundedVec, crypto::AccountId32};
 use sp_runtime::{
 	traits::{
 		AsSystemOriginSigner, Convert, DispatchInfoOf, Dispatchable, PostDispatchInfoOf,
-		TransactionExtension,
+		Saturating, TransactionExtension, Zero,
 	},
 	DispatchError, MultiSignature,
 };
@@ -108,6 +124,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxPaginatedPageSizeBytes: Get<u32> + Default;
 
+		/// The maximum size of the decompressed data of a compressed Paginated page (in bytes)
+		#[pallet::constant]
+		type MaxDecompressedPaginatedPageSizeBytes: Get<u32>;
+
 		/// The maximum size of a single item in an itemized storage model (in bytes)
 		#[pallet::constant]
 		type MaxItemizedBlobSizeBytes: Get<u32> + Clone + core::fmt::Debug + PartialEq;
@@ -144,6 +164,37 @@ pub mod pallet {
 		/// in a block by weight, as multiple of these events in a block is not really useful
 		/// or desireable.
 		type MigrateEmitEvery: Get<u32> + Clone + Debug;
+
+		/// The overarching hold reason.
+		type RuntimeHoldReason: From<HoldReason>;
+
+		/// Functions that allow a fungible balance to be held for page storage deposits.
+		type Currency: MutateHold<Self::AccountId, Reason = Self::RuntimeHoldReason>
+			+ Mutate<Self::AccountId>
+			+ InspectFungible<Self::AccountId>;
+
+		/// The Capacity that page storage deposits are drawn from when the paying account cannot
+		/// cover them.
+		type Capacity: Nontransferable<Balance = BalanceOf<Self>>;
+
+		/// The origin that is allowed to set the storage deposit charged per page byte.
+		type StorageDepositGovernanceOrigin: EnsureOrigin<Self::RuntimeOrigin>;
+
+		/// The maximum number of Intents in a single MSA data transfer
+		#[pallet::constant]
+		type MaxTransferIntents: Get<u32>;
+
+		/// The maximum number of page operations applied in a single batch
+		#[pallet::constant]
+		type MaxPageOperations: Get<u32>;
+	}
+
+	/// A reason for holding funds
+	/// Creates a hold reason for this pallet that is aggregated by `construct_runtime`.
+	#[pallet::composite_enum]
+	pub enum HoldReason {
+		/// Funds held as a deposit for the bytes of a stateful storage page
+		PageStorageDeposit,
 	}
 
 	// Simple declaration of the `Pallet` type. It is placeholder we use to implement traits and
@@ -156,6 +207,68 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(super) type MigrationPageIndex<T: Config> = StorageValue<_, u32, ValueQuery>;
 
+	/// The deposit charged for each byte of a stored page, set by governance
+	#[pallet::storage]
+	pub type StorageByteDeposit<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	/// Storage for the deposits charged for the stored pages
+	/// - Keys: (state owner [`MessageSourceId`], [`IntentId`], [`PageId`] or `None` for the Itemized page)
+	/// - Value: [`PageDeposit`]
+	#[pallet::storage]
+	pub type PageDeposits<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, MessageSourceId>,
+			NMapKey<Twox64Concat, IntentId>,
+			NMapKey<Twox64Concat, Option<PageId>>,
+		),
+		PageDeposit<T>,
+		OptionQuery,
+	>;
+
+	/// Storage for the pending MSA data transfers, processed with the spare weight of each block
+	/// - Key: source [`MessageSourceId`]
+	/// - Value: [`MsaDataTransfer`]
+	#[pallet::storage]
+	pub type MsaDataTransfers<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, MsaDataTransfer<T>, OptionQuery>;
+
+	/// Storage of the (MSA, Intent) pairs whose pages cannot be written while a transfer is pending
+	/// - Keys: [`MessageSourceId`], [`IntentId`]
+	/// - Value: source [`MessageSourceId`] of the pending transfer
+	#[pallet::storage]
+	pub type MsaDataTransferLocks<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageSourceId,
+		Twox64Concat,
+		IntentId,
+		MessageSourceId,
+		OptionQuery,
+	>;
+
+	/// Storage of the nonce each MSA data transfer payload must carry, incremented by each transfer
+	/// so that a signed payload cannot start a transfer more than once
+	/// - Key: source [`MessageSourceId`]
+	/// - Value: the nonce of the next transfer from the MSA
+	#[pallet::storage]
+	pub type MsaDataTransferNonces<T: Config> =
+		StorageMap<_, Twox64Concat, MessageSourceId, u32, ValueQuery>;
+
+	/// Storage of the total bytes and pages stored by each MSA for each Intent
+	/// - Keys: state owner [`MessageSourceId`], [`IntentId`]
+	/// - Value: [`IntentStorageUsage`]
+	#[pallet::storage]
+	pub type StorageUsages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageSourceId,
+		Twox64Concat,
+		IntentId,
+		IntentStorageUsage,
+		ValueQuery,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Page would exceed the highest allowable PageId
@@ -205,6 +318,33 @@ pub mod pallet {
 
 		/// Specified payload location is not valid for the entity
 		PayloadLocationMismatch,
+
+		/// Schema is unsupported and can no longer be written to
+		SchemaUnsupported,
+
+		/// Payload does not conform to the schema model
+		InvalidPayload,
+
+		/// Neither the paying account nor its Capacity can cover the page storage deposit
+		InsufficientStorageDeposit,
+
+		/// The MSA data transfer has the same source and target, or no or duplicate Intents
+		InvalidMsaDataTransfer,
+
+		/// The pages are locked by a pending MSA data transfer
+		MsaDataTransferInProgress,
+
+		/// The compressed page data is not valid for its compression codec
+		MalformedCompressedPage,
+
+		/// The decompressed page data exceeds the maximum allowed size
+		DecompressedPageTooLarge,
+
+		/// The write would exceed the storage quota of the Intent
+		StorageQuotaExceeded,
+
+		/// The MSA data transfer payload nonce is not the next transfer nonce of the source MSA
+		InvalidMsaDataTransferNonce,
 	}
 
 	#[pallet::event]
@@ -265,11 +405,92 @@ pub mod pallet {
 			/// Total number of pages migrated
 			total_page_count: u64,
 		},
+
+		/// Warning that a page was written with a deprecated schema
+		/// Writers should move to a newer version of the schema before it becomes unsupported
+		DeprecatedSchemaUsed {
+			/// The deprecated schema
+			schema_id: SchemaId,
+		},
+
+		/// The storage deposit charged per page byte was updated
+		StorageByteDepositUpdated {
+			/// The new deposit per byte
+			deposit_per_byte: BalanceOf<T>,
+		},
+
+		/// A storage deposit was charged for a page
+		PageDepositCharged {
+			/// message source id of storage owner
+			msa_id: MessageSourceId,
+			/// intent related to the storage
+			intent_id: IntentId,
+			/// id of the page, or `None` for the Itemized page
+			page_id: Option<PageId>,
+			/// where the deposit was charged from
+			source: DepositSource<T::AccountId>,
+			/// the amount charged
+			amount: BalanceOf<T>,
+		},
+
+		/// The storage deposit of a page was released
+		PageDepositReleased {
+			/// message source id of storage owner
+			msa_id: MessageSourceId,
+			/// intent related to the storage
+			intent_id: IntentId,
+			/// id of the page, or `None` for the Itemized page
+			page_id: Option<PageId>,
+			/// where the deposit was charged from
+			source: DepositSource<T::AccountId>,
+			/// the amount released
+			amount: BalanceOf<T>,
+		},
+
+		/// A transfer of the pages of an MSA was accepted and will be processed in the coming blocks
+		MsaDataTransferStarted {
+			/// MSA the pages are transferred from
+			source_msa_id: MessageSourceId,
+			/// MSA the pages are transferred to
+			target_msa_id: MessageSourceId,
+			/// Whether the pages are kept in the source MSA
+			mode: MsaDataTransferMode,
+		},
+
+		/// All the pages of an MSA data transfer were transferred
+		MsaDataTransferCompleted {
+			/// MSA the pages were transferred from
+			source_msa_id: MessageSourceId,
+			/// MSA the pages were transferred to
+			target_msa_id: MessageSourceId,
+			/// Number of pages transferred
+			pages_transferred: u32,
+		},
+
+		/// An MSA data transfer was stopped before all its pages were transferred, because the
+		/// storage deposit of a copied page could not be charged
+		MsaDataTransferAborted {
+			/// MSA the pages were transferred from
+			source_msa_id: MessageSourceId,
+			/// MSA the pages were transferred to
+			target_msa_id: MessageSourceId,
+			/// Number of pages transferred before stopping
+			pages_transferred: u32,
+		},
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_current: BlockNumberFor<T>, remaining_weight: Weight) -> Weight {
+			let mut meter = WeightMeter::with_limit(remaining_weight);
+			Self::process_msa_data_transfers(&mut meter);
+			meter.consumed()
+		}
 	}
 
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
-		/// Applies the Add or Delete Actions on the requested Itemized page.
+		/// Applies the Add, Delete or Update Actions on the requested Itemized page.
 		/// This is treated as a transaction so either all actions succeed or none will be executed.
 		///
 		/// Note: if called by the state owner, call may succeed even on `SignatureRequired` schemas.
@@ -280,11 +501,13 @@ pub mod pallet {
 		/// # Events
 		/// * [`Event::ItemizedPageUpdated`]
 		/// * [`Event::ItemizedPageDeleted`]
+		/// * [`Event::DeprecatedSchemaUsed`]
 		///
 		#[pallet::call_index(0)]
 		#[pallet::weight(
             T::WeightInfo::apply_item_actions_delete(actions.len() as u32)
             .max(T::WeightInfo::apply_item_actions_add(Pallet::<T>::sum_add_actions_bytes(actions)))
+            .max(T::WeightInfo::apply_item_actions_update(Pallet::<T>::sum_update_actions_bytes(actions)))
         )]
 		pub fn apply_item_actions(
 			origin: OriginFor<T>,
@@ -301,15 +524,26 @@ pub mod pallet {
 				.ok_or(Error::<T>::InvalidSchemaId)?;
 			let is_pruning = actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
 			let caller_msa_id =
-				Self::check_msa_and_grants(key, state_owner_msa_id, schema.intent_id)?;
+				Self::check_msa_and_grants(&key, state_owner_msa_id, schema.intent_id)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
-			Self::check_schema_for_write(
+			let schema = Self::check_schema_for_write(
 				schema_id,
 				PayloadLocation::Itemized,
 				caller_is_state_owner,
 				is_pruning,
 			)?;
+			if actions.iter().any(|a| matches!(a, ItemAction::Update { .. })) {
+				Self::check_schema_for_update(&schema)?;
+			}
+			if actions
+				.iter()
+				.any(|a| matches!(a, ItemAction::Add { .. } | ItemAction::Update { .. }))
+			{
+				Self::check_schema_status_for_write(&schema)?;
+				Self::check_item_actions_payloads(&schema, &actions)?;
+			}
 			Self::update_itemized(
+				&key,
 				state_owner_msa_id,
 				schema.intent_id,
 				schema_id,
@@ -319,7 +553,9 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Creates or updates a Paginated page with new payload
+		/// Creates or updates a Paginated page with new payload. The payload is stored
+		/// uncompressed; a compressed page can only be written with [`Call::apply_page_operations`]
+		/// or [`Call::apply_page_operations_with_signature`].
 		///
 		/// Note: if called by the state owner, call may succeed even on `SignatureRequired` schemas.
 		/// The fact that the entire (signed) transaction is submitted by the owner's keypair is
@@ -328,6 +564,7 @@ pub mod pallet {
 		///
 		/// # Events
 		/// * [`Event::PaginatedPageUpdated`]
+		/// * [`Event::DeprecatedSchemaUsed`]
 		///
 		#[pallet::call_index(1)]
 		#[pallet::weight(T::WeightInfo::upsert_page(payload.len() as u32))]
@@ -344,15 +581,18 @@ pub mod pallet {
 			let schema = T::SchemaProvider::get_schema_info_by_id(schema_id)
 				.ok_or(Error::<T>::InvalidSchemaId)?;
 			let caller_msa_id =
-				Self::check_msa_and_grants(provider_key, state_owner_msa_id, schema.intent_id)?;
+				Self::check_msa_and_grants(&provider_key, state_owner_msa_id, schema.intent_id)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
-			Self::check_schema_for_write(
+			let schema = Self::check_schema_for_write(
 				schema_id,
 				PayloadLocation::Paginated,
 				caller_is_state_owner,
 				false,
 			)?;
+			Self::check_schema_status_for_write(&schema)?;
+			Self::check_payload(&schema, &payload)?;
 			Self::update_paginated(
+				&provider_key,
 				state_owner_msa_id,
 				schema.intent_id,
 				schema_id,
@@ -387,7 +627,7 @@ pub mod pallet {
 			let schema = T::SchemaProvider::get_schema_info_by_id(schema_id)
 				.ok_or(Error::<T>::InvalidSchemaId)?;
 			let caller_msa_id =
-				Self::check_msa_and_grants(provider_key, state_owner_msa_id, schema.intent_id)?;
+				Self::check_msa_and_grants(&provider_key, state_owner_msa_id, schema.intent_id)?;
 			let caller_is_state_owner = caller_msa_id == state_owner_msa_id;
 			let SchemaInfoResponse { intent_id, .. } = Self::check_schema_for_write(
 				schema_id,
@@ -402,18 +642,20 @@ pub mod pallet {
 		// REMOVED upsert_page_with_signature() at call index 4
 		// REMOVED delete_page_with_signature() at call index 5
 
-		/// Applies the Add or Delete Actions on the requested Itemized page that requires signature
+		/// Applies the Add, Delete or Update Actions on the requested Itemized page that requires signature
 		/// since the signature of delegator is checked there is no need for delegation validation
 		/// This is treated as a transaction so either all actions succeed or none will be executed.
 		///
 		/// # Events
 		/// * [`Event::ItemizedPageUpdated`]
 		/// * [`Event::ItemizedPageDeleted`]
+		/// * [`Event::DeprecatedSchemaUsed`]
 		///
 		#[pallet::call_index(6)]
 		#[pallet::weight(
             T::WeightInfo::apply_item_actions_with_signature_v2_delete(payload.actions.len() as u32)
             .max(T::WeightInfo::apply_item_actions_with_signature_v2_add(Pallet::<T>::sum_add_actions_bytes(&payload.actions)))
+            .max(T::WeightInfo::apply_item_actions_with_signature_v2_update(Pallet::<T>::sum_update_actions_bytes(&payload.actions)))
         )]
 		pub fn apply_item_actions_with_signature_v2(
 			origin: OriginFor<T>,
@@ -421,7 +663,7 @@ pub mod pallet {
 			proof: MultiSignature,
 			payload: ItemizedSignaturePayloadV2<T>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
+			let payer = ensure_signed(origin)?;
 
 			let is_pruning = payload.actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
 			Self::check_payload_expiration(
@@ -437,7 +679,19 @@ pub mod pallet {
 				true,
 				is_pruning,
 			)?;
+			if payload.actions.iter().any(|a| matches!(a, ItemAction::Update { .. })) {
+				Self::check_schema_for_update(&schema)?;
+			}
+			if payload
+				.actions
+				.iter()
+				.any(|a| matches!(a, ItemAction::Add { .. } | ItemAction::Update { .. }))
+			{
+				Self::check_schema_status_for_write(&schema)?;
+				Self::check_item_actions_payloads(&schema, &payload.actions)?;
+			}
 			Self::update_itemized(
+				&payer,
 				state_owner_msa_id,
 				schema.intent_id,
 				payload.schema_id,
@@ -449,9 +703,12 @@ pub mod pallet {
 
 		/// Creates or updates Paginated storage with new payload that requires signature.
 		/// Since the signature of delegator is checked, there is no need for delegation validation.
+		/// The payload is stored uncompressed; a compressed page can only be written with
+		/// [`Call::apply_page_operations_with_signature`].
 		///
 		/// # Events
 		/// * [`Event::PaginatedPageUpdated`]
+		/// * [`Event::DeprecatedSchemaUsed`]
 		///
 		#[pallet::call_index(7)]
 		#[pallet::weight(
@@ -463,7 +720,7 @@ pub mod pallet {
 			proof: MultiSignature,
 			payload: PaginatedUpsertSignaturePayloadV2<T>,
 		) -> DispatchResult {
-			ensure_signed(origin)?;
+			let payer = ensure_signed(origin)?;
 			ensure!(
 				payload.page_id <= T::MaxPaginatedPageId::get(),
 				Error::<T>::PageIdExceedsMaxAllowed
@@ -481,7 +738,10 @@ pub mod pallet {
 				true,
 				false,
 			)?;
+			Self::check_schema_status_for_write(&schema)?;
+			Self::check_payload(&schema, &payload.payload)?;
 			Self::update_paginated(
+				&payer,
 				state_owner_msa_id,
 				schema.intent_id,
 				payload.schema_id,
@@ -532,6 +792,198 @@ pub mod pallet {
 			)?;
 			Ok(())
 		}
+
+		/// Sets the storage deposit charged for each byte of a stored page. Deposits already
+		/// charged are not affected until their page is next written.
+		///
+		/// # Events
+		/// * [`Event::StorageByteDepositUpdated`]
+		///
+		#[pallet::call_index(9)]
+		#[pallet::weight(T::WeightInfo::set_storage_byte_deposit())]
+		pub fn set_storage_byte_deposit(
+			origin: OriginFor<T>,
+			#[pallet::compact] deposit_per_byte: BalanceOf<T>,
+		) -> DispatchResult {
+			T::StorageDepositGovernanceOrigin::ensure_origin(origin)?;
+
+			StorageByteDeposit::<T>::put(deposit_per_byte);
+			Self::deposit_event(Event::StorageByteDepositUpdated { deposit_per_byte });
+			Ok(())
+		}
+
+		/// Starts copying or moving the Itemized and Paginated pages of a set of Intents from one
+		/// MSA to another. The payload must be signed by a control key of each MSA. The pages are
+		/// transferred over the following blocks with their spare weight, and the pages of the
+		/// Intents of both MSAs cannot be written until the transfer completes. A transferred page
+		/// replaces the page stored at the same location in the target MSA.
+		///
+		/// Moved pages keep their storage deposit. The deposits of copied pages are charged to the
+		/// caller, and the transfer is aborted if one cannot be charged.
+		///
+		/// The payload carries the next transfer nonce of the source MSA, which is incremented when
+		/// the transfer starts, so a signed payload cannot be replayed.
+		///
+		/// # Events
+		/// * [`Event::MsaDataTransferStarted`]
+		///
+		/// # Errors
+		/// * [`Error::ProofHasExpired`] - The payload has expired.
+		/// * [`Error::ProofNotYetValid`] - The payload expiration is too far in the future.
+		/// * [`Error::InvalidSignature`] - A signature does not match its key and the payload.
+		/// * [`Error::InvalidMessageSourceAccount`] - A key does not control the MSA of the payload.
+		/// * [`Error::InvalidMsaDataTransfer`] - Same source and target, or no or duplicate Intents.
+		/// * [`Error::InvalidIntentId`] - An Intent does not exist.
+		/// * [`Error::MsaDataTransferInProgress`] - A transfer of the same pages is already pending.
+		/// * [`Error::InvalidMsaDataTransferNonce`] - The payload nonce is not the next transfer nonce of the source MSA.
+		///
+		#[pallet::call_index(10)]
+		#[pallet::weight(T::WeightInfo::transfer_msa_data(payload.intent_ids.len() as u32))]
+		pub fn transfer_msa_data(
+			origin: OriginFor<T>,
+			source_key: T::AccountId,
+			source_proof: MultiSignature,
+			target_key: T::AccountId,
+			target_proof: MultiSignature,
+			payload: MsaDataTransferSignaturePayload<T>,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+
+			Self::check_payload_expiration(
+				frame_system::Pallet::<T>::block_number(),
+				payload.expiration,
+			)?;
+			Self::check_signature(&source_proof, &source_key, &payload)?;
+			Self::check_signature(&target_proof, &target_key, &payload)?;
+			for (key, msa_id) in
+				[(&source_key, payload.source_msa_id), (&target_key, payload.target_msa_id)]
+			{
+				let key_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(key)
+					.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+				ensure!(key_msa_id == msa_id, Error::<T>::InvalidMessageSourceAccount);
+			}
+
+			let MsaDataTransferSignaturePayload {
+				source_msa_id,
+				target_msa_id,
+				intent_ids,
+				mode,
+				nonce,
+				..
+			} = payload;
+			ensure!(
+				source_msa_id != target_msa_id &&
+					!intent_ids.is_empty() &&
+					intent_ids.iter().collect::<BTreeSet<_>>().len() == intent_ids.len(),
+				Error::<T>::InvalidMsaDataTransfer
+			);
+			ensure!(
+				!MsaDataTransfers::<T>::contains_key(source_msa_id),
+				Error::<T>::MsaDataTransferInProgress
+			);
+			for intent_id in intent_ids.iter() {
+				ensure!(
+					T::SchemaProvider::get_intent_by_id(*intent_id).is_some(),
+					Error::<T>::InvalidIntentId
+				);
+				for msa_id in [source_msa_id, target_msa_id] {
+					ensure!(
+						!MsaDataTransferLocks::<T>::contains_key(msa_id, intent_id),
+						Error::<T>::MsaDataTransferInProgress
+					);
+				}
+			}
+			ensure!(
+				nonce == MsaDataTransferNonces::<T>::get(source_msa_id),
+				Error::<T>::InvalidMsaDataTransferNonce
+			);
+
+			MsaDataTransferNonces::<T>::insert(source_msa_id, nonce.wrapping_add(1));
+			for intent_id in intent_ids.iter() {
+				MsaDataTransferLocks::<T>::insert(source_msa_id, intent_id, source_msa_id);
+				MsaDataTransferLocks::<T>::insert(target_msa_id, intent_id, source_msa_id);
+			}
+			MsaDataTransfers::<T>::insert(
+				source_msa_id,
+				MsaDataTransfer {
+					target_msa_id,
+					intent_ids,
+					mode,
+					payer,
+					cursor: MsaDataTransferCursor::default(),
+					pages_transferred: 0,
+				},
+			);
+			Self::deposit_event(Event::MsaDataTransferStarted {
+				source_msa_id,
+				target_msa_id,
+				mode,
+			});
+			Ok(())
+		}
+
+		/// Applies a batch of page operations across the Intents of an MSA.
+		/// This is treated as a transaction so either all operations succeed or none will be executed.
+		///
+		/// Note: if called by the state owner, call may succeed even on `SignatureRequired` schemas.
+		/// The fact that the entire (signed) transaction is submitted by the owner's keypair is
+		/// considered equivalent to supplying a separate signature. Note in that case that a delegate
+		/// submitting this extrinsic on behalf of a user would fail.
+		///
+		/// # Events
+		/// * [`Event::ItemizedPageUpdated`]
+		/// * [`Event::ItemizedPageDeleted`]
+		/// * [`Event::PaginatedPageUpdated`]
+		/// * [`Event::PaginatedPageDeleted`]
+		/// * [`Event::DeprecatedSchemaUsed`]
+		///
+		#[pallet::call_index(11)]
+		#[pallet::weight(Pallet::<T>::page_operations_weight(operations, false))]
+		pub fn apply_page_operations(
+			origin: OriginFor<T>,
+			#[pallet::compact] state_owner_msa_id: MessageSourceId,
+			operations: BoundedVec<PageOperation<T>, T::MaxPageOperations>,
+		) -> DispatchResult {
+			let key = ensure_signed(origin)?;
+			for operation in operations {
+				Self::apply_page_operation(&key, state_owner_msa_id, Some(&key), operation)?;
+			}
+			Ok(())
+		}
+
+		/// Applies a batch of page operations across the Intents of an MSA with a single
+		/// signature of the MSA owner covering all of them. Since the signature of delegator is
+		/// checked, there is no need for delegation validation.
+		/// This is treated as a transaction so either all operations succeed or none will be executed.
+		///
+		/// # Events
+		/// * [`Event::ItemizedPageUpdated`]
+		/// * [`Event::ItemizedPageDeleted`]
+		/// * [`Event::PaginatedPageUpdated`]
+		/// * [`Event::PaginatedPageDeleted`]
+		/// * [`Event::DeprecatedSchemaUsed`]
+		///
+		#[pallet::call_index(12)]
+		#[pallet::weight(Pallet::<T>::page_operations_weight(&payload.operations, true))]
+		pub fn apply_page_operations_with_signature(
+			origin: OriginFor<T>,
+			delegator_key: T::AccountId,
+			proof: MultiSignature,
+			payload: PageOperationsSignaturePayload<T>,
+		) -> DispatchResult {
+			let payer = ensure_signed(origin)?;
+			Self::check_payload_expiration(
+				frame_system::Pallet::<T>::block_number(),
+				payload.expiration,
+			)?;
+			Self::check_signature(&proof, &delegator_key, &payload)?;
+			let state_owner_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&delegator_key)
+				.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
+			for operation in payload.operations {
+				Self::apply_page_operation(&payer, state_owner_msa_id, None, operation)?;
+			}
+			Ok(())
+		}
 	}
 }
 
@@ -551,6 +1003,88 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Sums the total bytes over all item update actions
+	pub fn sum_update_actions_bytes(
+		actions: &BoundedVec<
+			ItemAction<<T as Config>::MaxItemizedBlobSizeBytes>,
+			<T as Config>::MaxItemizedActionsCount,
+		>,
+	) -> u32 {
+		actions.iter().fold(0, |acc, a| {
+			acc.saturating_add(match a {
+				ItemAction::Update { data, .. } => data.len() as u32,
+				_ => 0,
+			})
+		})
+	}
+
+	/// Weight of decompressing the data of a page compressed with `compression`, if any, and of
+	/// checking the decompressed data against the schema
+	pub fn decompression_weight(compression: &Option<PageCompression>) -> Weight {
+		match compression {
+			Some(_) => T::WeightInfo::decompress_paginated_page(),
+			None => Weight::zero(),
+		}
+	}
+
+	/// Decompresses the data of a Paginated page, bounded by
+	/// [`Config::MaxDecompressedPaginatedPageSizeBytes`]
+	///
+	/// # Errors
+	/// * [`Error::MalformedCompressedPage`]
+	/// * [`Error::DecompressedPageTooLarge`]
+	///
+	pub fn decompress_paginated_data(
+		compression: PageCompression,
+		data: &[u8],
+	) -> Result<Vec<u8>, DispatchError> {
+		decompress_page_data(
+			compression,
+			data,
+			T::MaxDecompressedPaginatedPageSizeBytes::get() as usize,
+		)
+		.map_err(|e| match e {
+			PageDecompressionError::Malformed => Error::<T>::MalformedCompressedPage.into(),
+			PageDecompressionError::TooLarge => Error::<T>::DecompressedPageTooLarge.into(),
+		})
+	}
+
+	/// Sums the weights of the single page extrinsics matching each operation of a batch
+	pub fn page_operations_weight(
+		operations: &BoundedVec<PageOperation<T>, T::MaxPageOperations>,
+		is_payload_signed: bool,
+	) -> Weight {
+		operations.iter().fold(Weight::zero(), |acc, operation| {
+			acc.saturating_add(match (operation, is_payload_signed) {
+				(PageOperation::ApplyItemActions { actions, .. }, false) =>
+					T::WeightInfo::apply_item_actions_delete(actions.len() as u32)
+						.max(T::WeightInfo::apply_item_actions_add(Self::sum_add_actions_bytes(
+							actions,
+						)))
+						.max(T::WeightInfo::apply_item_actions_update(
+							Self::sum_update_actions_bytes(actions),
+						)),
+				(PageOperation::ApplyItemActions { actions, .. }, true) =>
+					T::WeightInfo::apply_item_actions_with_signature_v2_delete(actions.len() as u32)
+						.max(T::WeightInfo::apply_item_actions_with_signature_v2_add(
+							Self::sum_add_actions_bytes(actions),
+						))
+						.max(T::WeightInfo::apply_item_actions_with_signature_v2_update(
+							Self::sum_update_actions_bytes(actions),
+						)),
+				(PageOperation::UpsertPage { payload, compression, .. }, false) =>
+					T::WeightInfo::upsert_page(payload.len() as u32)
+						.saturating_add(Self::decompression_weight(compression)),
+				(PageOperation::UpsertPage { payload, compression, .. }, true) =>
+					T::WeightInfo::upsert_page_with_signature_v2(payload.len() as u32)
+						.saturating_add(Self::decompression_weight(compression)),
+				(PageOperation::DeletePage { .. }, false) => T::WeightInfo::delete_page(),
+				(PageOperation::DeletePage { .. }, true) =>
+					T::WeightInfo::delete_page_with_signature_v2(),
+			})
+		})
+	}
+
 	/// This function returns all the paginated storage associated with `msa_id` and `schema_id`
 	///
 	/// Warning: since this function iterates over all the potential keys it should never called
@@ -570,6 +1104,9 @@ impl<T: Config> Pallet<T> {
 
 	/// This function returns all the paginated storage associated with `msa_id` and `schema_id`
 	///
+	/// The payload of a compressed page is returned as stored, without its codec; use
+	/// [`Self::get_paginated_storage_with_compression`] to tell compressed pages apart.
+	///
 	/// Warning: since this function iterates over all the potential keys it should never called
 	/// from runtime.
 	pub fn get_paginated_storage(
@@ -599,6 +1136,38 @@ impl<T: Config> Pallet<T> {
 		.collect())
 	}
 
+	/// This function returns all the paginated storage associated with `msa_id` and `intent_id`,
+	/// with the decompressed payload of compressed pages
+	pub fn get_paginated_storage_with_compression(
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+	) -> Result<Vec<PaginatedStorageResponseV3>, DispatchError> {
+		Self::check_intent_for_read(intent_id, PayloadLocation::Paginated)?;
+		let prefix: PaginatedPrefixKey = (intent_id,);
+		Ok(StatefulChildTree::<T::KeyHasher>::prefix_iterator::<
+			PaginatedPage<T>,
+			PaginatedKey,
+			PaginatedPrefixKey,
+		>(&msa_id, PALLET_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX, &prefix)
+		.map(|(k, v)| {
+			let compression = v.compression();
+			PaginatedStorageResponseV3 {
+				intent_id,
+				page_id: k.1,
+				msa_id,
+				schema_id: v.schema_id.unwrap_or_default(),
+				content_hash: v.get_hash(),
+				nonce: v.nonce,
+				compression,
+				decompressed_payload: compression.and_then(|compression| {
+					Self::decompress_paginated_data(compression, &v.data).ok()
+				}),
+				payload: v.data.into_inner(),
+			}
+		})
+		.collect())
+	}
+
 	/// This function returns all the itemized storage associated with `msa_id` and `schema_id`
 	pub fn get_itemized_storage_v1(
 		msa_id: MessageSourceId,
@@ -643,6 +1212,58 @@ impl<T: Config> Pallet<T> {
 		))
 	}
 
+	/// This function returns the location of the page of `msa_id` and `intent_id` in the state,
+	/// so that a read proof of the page can be built and checked against a block's state root.
+	/// Itemized intents have a single page, so `page_id` must be `None` for them and `Some` for
+	/// paginated intents.
+	///
+	/// # Errors
+	/// * [`Error::InvalidIntentId`]
+	/// * [`Error::PayloadLocationMismatch`]
+	/// * [`Error::PageIdExceedsMaxAllowed`]
+	///
+	pub fn get_page_storage_key(
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+	) -> Result<PageStorageKey, DispatchError> {
+		let (storage_name, page_key) = match page_id {
+			None => {
+				Self::check_intent_for_read(intent_id, PayloadLocation::Itemized)?;
+				let key: ItemizedKey = (intent_id,);
+				(ITEMIZED_STORAGE_PREFIX, <ItemizedKey as MultipartKey<T::KeyHasher>>::hash(&key))
+			},
+			Some(page_id) => {
+				Self::check_intent_for_read(intent_id, PayloadLocation::Paginated)?;
+				ensure!(
+					page_id <= T::MaxPaginatedPageId::get(),
+					Error::<T>::PageIdExceedsMaxAllowed
+				);
+				let key: PaginatedKey = (intent_id, page_id);
+				(PAGINATED_STORAGE_PREFIX, <PaginatedKey as MultipartKey<T::KeyHasher>>::hash(&key))
+			},
+		};
+		let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+			msa_id,
+			PALLET_STORAGE_PREFIX,
+			storage_name,
+		);
+		Ok(PageStorageKey { child_trie_key: child.storage_key().to_vec(), page_key })
+	}
+
+	/// This function returns the bytes and pages stored by `msa_id` for each Intent it has
+	/// stateful storage for, along with the storage quota of the Intent
+	pub fn get_storage_usage(msa_id: MessageSourceId) -> Vec<StorageUsageResponse> {
+		StorageUsages::<T>::iter_prefix(msa_id)
+			.map(|(intent_id, usage)| StorageUsageResponse {
+				intent_id,
+				bytes: usage.bytes,
+				pages: usage.pages,
+				quota: T::SchemaProvider::get_intent_storage_quota(intent_id),
+			})
+			.collect()
+	}
+
 	/// This function checks to ensure `payload_expire_block` is in a valid range
 	///
 	/// # Errors
@@ -763,6 +1384,69 @@ impl<T: Config> Pallet<T> {
 		Ok(schema)
 	}
 
+	/// Checks that the items of the schema's Intent may be updated in place.
+	/// `AppendOnly` Intents only allow updates with the `AllowUpdates` setting.
+	///
+	/// # Errors
+	/// * [`Error::UnsupportedOperationForSchema`]
+	///
+	fn check_schema_for_update(schema: &SchemaInfoResponse) -> DispatchResult {
+		if schema.settings.contains(&IntentSetting::AppendOnly) {
+			ensure!(
+				schema.settings.contains(&IntentSetting::AllowUpdates),
+				Error::<T>::UnsupportedOperationForSchema
+			);
+		}
+		Ok(())
+	}
+
+	/// Checks the schema status before new data is written.
+	/// Deletes are always allowed so that data written with an unsupported schema can be removed,
+	/// and writes to deprecated schemas emit a warning event.
+	///
+	/// # Errors
+	/// * [`Error::SchemaUnsupported`]
+	///
+	fn check_schema_status_for_write(schema: &SchemaInfoResponse) -> DispatchResult {
+		match schema.status {
+			SchemaStatus::Active => {},
+			SchemaStatus::Deprecated =>
+				Self::deposit_event(Event::DeprecatedSchemaUsed { schema_id: schema.schema_id }),
+			SchemaStatus::Unsupported => return Err(Error::<T>::SchemaUnsupported.into()),
+		}
+		Ok(())
+	}
+
+	/// Checks the payload against the schema model when the Intent requires it
+	///
+	/// # Errors
+	/// * [`Error::InvalidPayload`]
+	///
+	fn check_payload(schema: &SchemaInfoResponse, payload: &[u8]) -> DispatchResult {
+		if schema.settings.contains(&IntentSetting::ValidatePayload) {
+			T::SchemaProvider::validate_payload(schema.schema_id, payload)
+				.map_err(|_| Error::<T>::InvalidPayload)?;
+		}
+		Ok(())
+	}
+
+	/// Checks the data of every Add and Update action against the schema model when the Intent requires it
+	///
+	/// # Errors
+	/// * [`Error::InvalidPayload`]
+	///
+	fn check_item_actions_payloads(
+		schema: &SchemaInfoResponse,
+		actions: &[ItemAction<T::MaxItemizedBlobSizeBytes>],
+	) -> DispatchResult {
+		for action in actions {
+			if let ItemAction::Add { data } | ItemAction::Update { data, .. } = action {
+				Self::check_payload(schema, data)?;
+			}
+		}
+		Ok(())
+	}
+
 	/// Checks that existence of Msa for certain key and if the grant is valid when the caller Msa
 	/// is different from the state owner Msa
 	///
@@ -771,11 +1455,11 @@ impl<T: Config> Pallet<T> {
 	/// * [`Error::UnauthorizedDelegate`]
 	///
 	fn check_msa_and_grants(
-		key: T::AccountId,
+		key: &T::AccountId,
 		state_owner_msa_id: MessageSourceId,
 		intent_id: IntentId,
 	) -> Result<MessageSourceId, DispatchError> {
-		let caller_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(&key)
+		let caller_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(key)
 			.map_err(|_| Error::<T>::InvalidMessageSourceAccount)?;
 
 		// if caller and owner are the same no delegation is needed
@@ -800,6 +1484,7 @@ impl<T: Config> Pallet<T> {
 	/// * [`Error::StalePageState`] - Page content hash does not match on-chain hash.
 	/// * [`Error::CorruptedState`] - Unable to read the existing page data.
 	/// * [`Error::InvalidItemAction`] - Supplied action item is invalid.
+	/// * [`Error::StorageQuotaExceeded`] - The page does not fit in the storage quota of the Intent.
 	///
 	///
 	/// # Events
@@ -807,15 +1492,18 @@ impl<T: Config> Pallet<T> {
 	/// * [`Event::ItemizedPageDeleted`]
 	///
 	fn update_itemized(
+		payer: &T::AccountId,
 		state_owner_msa_id: MessageSourceId,
 		intent_id: IntentId,
 		schema_id: SchemaId,
 		target_hash: PageHash,
 		actions: BoundedVec<ItemAction<T::MaxItemizedBlobSizeBytes>, T::MaxItemizedActionsCount>,
 	) -> DispatchResult {
+		Self::ensure_no_pending_transfer(state_owner_msa_id, intent_id)?;
 		let key: ItemizedKey = (intent_id,);
-		let existing_page =
-			Self::get_itemized_page_for(state_owner_msa_id, intent_id)?.unwrap_or_default();
+		let stored_page = Self::get_itemized_page_for(state_owner_msa_id, intent_id)?;
+		let prev_page_bytes = stored_page.as_ref().map(|page| page.encoded_size());
+		let existing_page = stored_page.unwrap_or_default();
 
 		let prev_content_hash = existing_page.get_hash();
 		ensure!(target_hash == prev_content_hash, Error::<T>::StalePageState);
@@ -841,6 +1529,8 @@ impl<T: Config> Pallet<T> {
 					ITEMIZED_STORAGE_PREFIX,
 					&key,
 				);
+				Self::release_page_deposit(state_owner_msa_id, intent_id, None)?;
+				Self::update_storage_usage(state_owner_msa_id, intent_id, prev_page_bytes, None)?;
 				Self::deposit_event(Event::ItemizedPageDeleted {
 					msa_id: state_owner_msa_id,
 					intent_id,
@@ -855,6 +1545,19 @@ impl<T: Config> Pallet<T> {
 					&key,
 					&updated_page,
 				);
+				Self::charge_page_deposit(
+					payer,
+					state_owner_msa_id,
+					intent_id,
+					None,
+					updated_page.encoded_size(),
+				)?;
+				Self::update_storage_usage(
+					state_owner_msa_id,
+					intent_id,
+					prev_page_bytes,
+					Some(updated_page.encoded_size()),
+				)?;
 				Self::deposit_event(Event::ItemizedPageUpdated {
 					msa_id: state_owner_msa_id,
 					intent_id,
@@ -872,6 +1575,7 @@ impl<T: Config> Pallet<T> {
 	/// * [`Event::PaginatedPageUpdated`]
 	///
 	fn update_paginated(
+		payer: &T::AccountId,
 		state_owner_msa_id: MessageSourceId,
 		intent_id: IntentId,
 		schema_id: SchemaId,
@@ -879,15 +1583,19 @@ impl<T: Config> Pallet<T> {
 		target_hash: PageHash,
 		mut new_page: PaginatedPage<T>,
 	) -> DispatchResult {
+		Self::ensure_no_pending_transfer(state_owner_msa_id, intent_id)?;
 		let keys: PaginatedKey = (intent_id, page_id);
-		let existing_page: PaginatedPage<T> =
-			Self::get_paginated_page_for(state_owner_msa_id, intent_id, page_id)?
-				.unwrap_or_default();
+		let stored_page = Self::get_paginated_page_for(state_owner_msa_id, intent_id, page_id)?;
+		let prev_page_bytes = stored_page.as_ref().map(|page| page.encoded_size());
+		let existing_page: PaginatedPage<T> = stored_page.unwrap_or_default();
 
 		let prev_content_hash: PageHash = existing_page.get_hash();
 		ensure!(target_hash == prev_content_hash, Error::<T>::StalePageState);
 
-		new_page.page_version = PageVersion::V2;
+		// compressed pages keep their codec, any other page is written with the current version
+		if !matches!(new_page.page_version, PageVersion::V3(_)) {
+			new_page.page_version = PageVersion::V2;
+		}
 		new_page.schema_id = Some(schema_id);
 		new_page.nonce = existing_page.nonce.wrapping_add(1);
 
@@ -898,6 +1606,19 @@ impl<T: Config> Pallet<T> {
 			&keys,
 			&new_page,
 		);
+		Self::charge_page_deposit(
+			payer,
+			state_owner_msa_id,
+			intent_id,
+			Some(page_id),
+			new_page.encoded_size(),
+		)?;
+		Self::update_storage_usage(
+			state_owner_msa_id,
+			intent_id,
+			prev_page_bytes,
+			Some(new_page.encoded_size()),
+		)?;
 		Self::deposit_event(Event::PaginatedPageUpdated {
 			msa_id: state_owner_msa_id,
 			intent_id,
@@ -919,6 +1640,7 @@ impl<T: Config> Pallet<T> {
 		page_id: PageId,
 		target_hash: PageHash,
 	) -> DispatchResult {
+		Self::ensure_no_pending_transfer(state_owner_msa_id, intent_id)?;
 		let keys: PaginatedKey = (intent_id, page_id);
 		if let Some(existing_page) =
 			Self::get_paginated_page_for(state_owner_msa_id, intent_id, page_id)?
@@ -931,6 +1653,13 @@ impl<T: Config> Pallet<T> {
 				PAGINATED_STORAGE_PREFIX,
 				&keys,
 			);
+			Self::release_page_deposit(state_owner_msa_id, intent_id, Some(page_id))?;
+			Self::update_storage_usage(
+				state_owner_msa_id,
+				intent_id,
+				Some(existing_page.encoded_size()),
+				None,
+			)?;
 			Self::deposit_event(Event::PaginatedPageDeleted {
 				msa_id: state_owner_msa_id,
 				intent_id,
@@ -942,6 +1671,553 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Applies a single operation of a batch to the pages of the state owner. When the batch was
+	/// not signed by the state owner, `caller_key` must hold a grant for the operation's Intent.
+	///
+	/// # Events
+	/// * [`Event::ItemizedPageUpdated`]
+	/// * [`Event::ItemizedPageDeleted`]
+	/// * [`Event::PaginatedPageUpdated`]
+	/// * [`Event::PaginatedPageDeleted`]
+	/// * [`Event::DeprecatedSchemaUsed`]
+	///
+	fn apply_page_operation(
+		payer: &T::AccountId,
+		state_owner_msa_id: MessageSourceId,
+		caller_key: Option<&T::AccountId>,
+		operation: PageOperation<T>,
+	) -> DispatchResult {
+		let schema_id = operation.schema_id();
+		let is_payload_signed = match caller_key {
+			Some(key) => {
+				let schema = T::SchemaProvider::get_schema_info_by_id(schema_id)
+					.ok_or(Error::<T>::InvalidSchemaId)?;
+				Self::check_msa_and_grants(key, state_owner_msa_id, schema.intent_id)? ==
+					state_owner_msa_id
+			},
+			None => true,
+		};
+
+		match operation {
+			PageOperation::ApplyItemActions { target_hash, actions, .. } => {
+				let is_pruning = actions.iter().any(|a| matches!(a, ItemAction::Delete { .. }));
+				let schema = Self::check_schema_for_write(
+					schema_id,
+					PayloadLocation::Itemized,
+					is_payload_signed,
+					is_pruning,
+				)?;
+				if actions.iter().any(|a| matches!(a, ItemAction::Update { .. })) {
+					Self::check_schema_for_update(&schema)?;
+				}
+				if actions
+					.iter()
+					.any(|a| matches!(a, ItemAction::Add { .. } | ItemAction::Update { .. }))
+				{
+					Self::check_schema_status_for_write(&schema)?;
+					Self::check_item_actions_payloads(&schema, &actions)?;
+				}
+				Self::update_itemized(
+					payer,
+					state_owner_msa_id,
+					schema.intent_id,
+					schema_id,
+					target_hash,
+					actions,
+				)
+			},
+			PageOperation::UpsertPage { page_id, target_hash, compression, payload, .. } => {
+				ensure!(
+					page_id <= T::MaxPaginatedPageId::get(),
+					Error::<T>::PageIdExceedsMaxAllowed
+				);
+				let schema = Self::check_schema_for_write(
+					schema_id,
+					PayloadLocation::Paginated,
+					is_payload_signed,
+					false,
+				)?;
+				Self::check_schema_status_for_write(&schema)?;
+				let mut page = PaginatedPage::<T>::from(payload);
+				match compression {
+					Some(compression) => {
+						let decompressed =
+							Self::decompress_paginated_data(compression, &page.data)?;
+						Self::check_payload(&schema, &decompressed)?;
+						page.page_version = PageVersion::V3(compression);
+					},
+					None => Self::check_payload(&schema, &page.data)?,
+				}
+				Self::update_paginated(
+					payer,
+					state_owner_msa_id,
+					schema.intent_id,
+					schema_id,
+					page_id,
+					target_hash,
+					page,
+				)
+			},
+			PageOperation::DeletePage { page_id, target_hash, .. } => {
+				ensure!(
+					page_id <= T::MaxPaginatedPageId::get(),
+					Error::<T>::PageIdExceedsMaxAllowed
+				);
+				let schema = Self::check_schema_for_write(
+					schema_id,
+					PayloadLocation::Paginated,
+					is_payload_signed,
+					true,
+				)?;
+				Self::delete_paginated(state_owner_msa_id, schema.intent_id, page_id, target_hash)
+			},
+		}
+	}
+
+	/// Charges the storage deposit for a page of `page_bytes` bytes, replacing the deposit
+	/// previously charged for it. The deposit is held from the paying account, or drawn from the
+	/// Capacity of its MSA when the account cannot cover it. Capacity is a non-refundable fee, so
+	/// a page whose deposit was drawn from Capacity is only charged for the bytes it grows beyond
+	/// the size already paid for.
+	///
+	/// # Errors
+	/// * [`Error::InsufficientStorageDeposit`] - Neither the account nor its Capacity can cover the deposit.
+	///
+	/// # Events
+	/// * [`Event::PageDepositCharged`]
+	/// * [`Event::PageDepositReleased`]
+	///
+	fn charge_page_deposit(
+		payer: &T::AccountId,
+		state_owner_msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+		page_bytes: usize,
+	) -> DispatchResult {
+		let amount = StorageByteDeposit::<T>::get().saturating_mul((page_bytes as u32).into());
+		if let Some(PageDeposit { source: DepositSource::Capacity(_), amount: paid }) =
+			PageDeposits::<T>::get((state_owner_msa_id, intent_id, page_id))
+		{
+			if amount <= paid {
+				return Ok(())
+			}
+			let increase = amount.saturating_sub(paid);
+			if let Ok(payer_msa_id) = T::MsaInfoProvider::ensure_valid_msa_key(payer) {
+				if T::Capacity::deduct(payer_msa_id, increase).is_ok() {
+					let source = DepositSource::Capacity(payer_msa_id);
+					PageDeposits::<T>::insert(
+						(state_owner_msa_id, intent_id, page_id),
+						PageDeposit { source: source.clone(), amount },
+					);
+					Self::deposit_event(Event::PageDepositCharged {
+						msa_id: state_owner_msa_id,
+						intent_id,
+						page_id,
+						source,
+						amount: increase,
+					});
+					return Ok(())
+				}
+			}
+		}
+
+		Self::release_page_deposit(state_owner_msa_id, intent_id, page_id)?;
+		if amount.is_zero() {
+			return Ok(())
+		}
+
+		let source = match T::Currency::hold(&HoldReason::PageStorageDeposit.into(), payer, amount)
+		{
+			Ok(_) => DepositSource::Account(payer.clone()),
+			Err(_) => {
+				let payer_msa_id = T::MsaInfoProvider::ensure_valid_msa_key(payer)
+					.map_err(|_| Error::<T>::InsufficientStorageDeposit)?;
+				T::Capacity::deduct(payer_msa_id, amount)
+					.map_err(|_| Error::<T>::InsufficientStorageDeposit)?;
+				DepositSource::Capacity(payer_msa_id)
+			},
+		};
+		PageDeposits::<T>::insert(
+			(state_owner_msa_id, intent_id, page_id),
+			PageDeposit { source: source.clone(), amount },
+		);
+		Self::deposit_event(Event::PageDepositCharged {
+			msa_id: state_owner_msa_id,
+			intent_id,
+			page_id,
+			source,
+			amount,
+		});
+		Ok(())
+	}
+
+	/// Releases the storage deposit charged for a page, if any. Deposits held from an account are
+	/// returned to it. Deposits drawn from Capacity are a fee and are not refunded, as Capacity is
+	/// replenished every epoch.
+	///
+	/// # Events
+	/// * [`Event::PageDepositReleased`]
+	///
+	fn release_page_deposit(
+		state_owner_msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+	) -> DispatchResult {
+		if let Some(PageDeposit { source, amount }) =
+			PageDeposits::<T>::take((state_owner_msa_id, intent_id, page_id))
+		{
+			if let DepositSource::Account(depositor) = &source {
+				T::Currency::release(
+					&HoldReason::PageStorageDeposit.into(),
+					depositor,
+					amount,
+					Precision::BestEffort,
+				)?;
+			}
+			Self::deposit_event(Event::PageDepositReleased {
+				msa_id: state_owner_msa_id,
+				intent_id,
+				page_id,
+				source,
+				amount,
+			});
+		}
+		Ok(())
+	}
+
+	/// Moves the storage deposit of a page from `source_msa_id` to the page it replaces at
+	/// `target_msa_id`. As Capacity is not refunded, a deposit of the replaced page drawn from
+	/// Capacity is kept when it covers at least as much as the moved deposit.
+	///
+	/// # Events
+	/// * [`Event::PageDepositReleased`]
+	///
+	fn move_page_deposit(
+		source_msa_id: MessageSourceId,
+		target_msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+	) -> DispatchResult {
+		let moved = PageDeposits::<T>::take((source_msa_id, intent_id, page_id));
+		if let Some(PageDeposit { source: DepositSource::Capacity(_), amount: paid }) =
+			PageDeposits::<T>::get((target_msa_id, intent_id, page_id))
+		{
+			let covered = match &moved {
+				None => true,
+				Some(PageDeposit { source: DepositSource::Capacity(_), amount }) => *amount <= paid,
+				Some(PageDeposit { source: DepositSource::Account(_), .. }) => false,
+			};
+			if covered {
+				return Ok(())
+			}
+		}
+
+		Self::release_page_deposit(target_msa_id, intent_id, page_id)?;
+		if let Some(deposit) = moved {
+			PageDeposits::<T>::insert((target_msa_id, intent_id, page_id), deposit);
+		}
+		Ok(())
+	}
+
+	/// Replaces a page of `prev_page_bytes` bytes, or `None` if there was no page, with a page of
+	/// `page_bytes` bytes, or `None` if the page is removed, in the storage usage of the MSA.
+	/// Usage may only grow while it is within the storage quota of the Intent, so that pages
+	/// stored before a quota was set can still be shrunk or removed.
+	///
+	/// # Errors
+	/// * [`Error::StorageQuotaExceeded`]
+	///
+	fn update_storage_usage(
+		state_owner_msa_id: MessageSourceId,
+		intent_id: IntentId,
+		prev_page_bytes: Option<usize>,
+		page_bytes: Option<usize>,
+	) -> DispatchResult {
+		let usage = StorageUsages::<T>::get(state_owner_msa_id, intent_id);
+		let mut new_usage = usage;
+		if let Some(bytes) = prev_page_bytes {
+			new_usage.bytes = new_usage.bytes.saturating_sub(bytes as u32);
+			new_usage.pages = new_usage.pages.saturating_sub(1);
+		}
+		if let Some(bytes) = page_bytes {
+			new_usage.bytes = new_usage.bytes.saturating_add(bytes as u32);
+			new_usage.pages = new_usage.pages.saturating_add(1);
+		}
+
+		if new_usage.bytes > usage.bytes || new_usage.pages > usage.pages {
+			if let Some(quota) = T::SchemaProvider::get_intent_storage_quota(intent_id) {
+				ensure!(
+					new_usage.bytes <= quota.max_bytes && new_usage.pages <= quota.max_pages,
+					Error::<T>::StorageQuotaExceeded
+				);
+			}
+		}
+
+		match new_usage == IntentStorageUsage::default() {
+			true => StorageUsages::<T>::remove(state_owner_msa_id, intent_id),
+			false => StorageUsages::<T>::insert(state_owner_msa_id, intent_id, new_usage),
+		}
+		Ok(())
+	}
+
+	/// Checks that the pages of the Intent of the MSA are not locked by a pending transfer
+	///
+	/// # Errors
+	/// * [`Error::MsaDataTransferInProgress`]
+	///
+	fn ensure_no_pending_transfer(msa_id: MessageSourceId, intent_id: IntentId) -> DispatchResult {
+		ensure!(
+			!MsaDataTransferLocks::<T>::contains_key(msa_id, intent_id),
+			Error::<T>::MsaDataTransferInProgress
+		);
+		Ok(())
+	}
+
+	/// Transfers pages of the pending MSA data transfers until the weight runs out. Completed or
+	/// aborted transfers are removed and their pages unlocked.
+	///
+	/// # Events
+	/// * [`Event::MsaDataTransferCompleted`]
+	/// * [`Event::MsaDataTransferAborted`]
+	///
+	pub fn process_msa_data_transfers(meter: &mut WeightMeter) {
+		if !Self::should_extrinsics_be_run() {
+			return
+		}
+		let step_weight = T::WeightInfo::transfer_msa_data_step();
+		let finish_weight = T::DbWeight::get()
+			.writes(T::MaxTransferIntents::get().saturating_mul(2).saturating_add(1).into());
+
+		let mut transfers = MsaDataTransfers::<T>::iter();
+		loop {
+			if meter.try_consume(T::DbWeight::get().reads_writes(1, 1)).is_err() {
+				return
+			}
+			let Some((source_msa_id, mut transfer)) = transfers.next() else { return };
+
+			let initial_cursor = transfer.cursor;
+			let mut result = Ok(false);
+			// keeps room to finish the transfer after any step, as any step may be the last one
+			while meter.can_consume(step_weight.saturating_add(finish_weight)) {
+				meter.consume(step_weight);
+				result =
+					with_storage_layer(|| Self::transfer_next_page(source_msa_id, &mut transfer));
+				if result != Ok(false) {
+					break
+				}
+			}
+
+			match result {
+				Ok(false) => {
+					if transfer.cursor != initial_cursor {
+						MsaDataTransfers::<T>::insert(source_msa_id, transfer);
+					}
+					return
+				},
+				Ok(true) => {
+					meter.consume(finish_weight);
+					Self::finish_msa_data_transfer(source_msa_id, &transfer);
+					Self::deposit_event(Event::MsaDataTransferCompleted {
+						source_msa_id,
+						target_msa_id: transfer.target_msa_id,
+						pages_transferred: transfer.pages_transferred,
+					});
+				},
+				Err(e) => {
+					log::warn!("aborted transfer of msa={source_msa_id:?} data: {e:?}");
+					meter.consume(finish_weight);
+					Self::finish_msa_data_transfer(source_msa_id, &transfer);
+					Self::deposit_event(Event::MsaDataTransferAborted {
+						source_msa_id,
+						target_msa_id: transfer.target_msa_id,
+						pages_transferred: transfer.pages_transferred,
+					});
+				},
+			}
+		}
+	}
+
+	/// Removes a transfer and unlocks its pages
+	fn finish_msa_data_transfer(source_msa_id: MessageSourceId, transfer: &MsaDataTransfer<T>) {
+		for intent_id in transfer.intent_ids.iter() {
+			MsaDataTransferLocks::<T>::remove(source_msa_id, intent_id);
+			MsaDataTransferLocks::<T>::remove(transfer.target_msa_id, intent_id);
+		}
+		MsaDataTransfers::<T>::remove(source_msa_id);
+	}
+
+	/// Transfers the page at the cursor of the transfer, if it exists, and advances the cursor.
+	/// Returns whether all the pages of the transfer were processed.
+	///
+	/// # Errors
+	/// * [`Error::CorruptedState`] - Unable to read a page.
+	/// * [`Error::InsufficientStorageDeposit`] - The deposit of a copied page cannot be charged.
+	///
+	pub fn transfer_next_page(
+		source_msa_id: MessageSourceId,
+		transfer: &mut MsaDataTransfer<T>,
+	) -> Result<bool, DispatchError> {
+		let Some(intent_id) =
+			transfer.intent_ids.get(transfer.cursor.intent_index as usize).copied()
+		else {
+			return Ok(true)
+		};
+
+		let transferred = match transfer.cursor.step {
+			0 => match Self::get_itemized_page_for(source_msa_id, intent_id)? {
+				Some(page) => {
+					Self::transfer_page(
+						source_msa_id,
+						transfer,
+						intent_id,
+						None,
+						&(intent_id,),
+						page,
+					)?;
+					true
+				},
+				None => false,
+			},
+			step => {
+				let page_id = (step - 1) as PageId;
+				match Self::get_paginated_page_for(source_msa_id, intent_id, page_id)? {
+					Some(page) => {
+						Self::transfer_page(
+							source_msa_id,
+							transfer,
+							intent_id,
+							Some(page_id),
+							&(intent_id, page_id),
+							page,
+						)?;
+						true
+					},
+					None => false,
+				}
+			},
+		};
+		if transferred {
+			transfer.pages_transferred = transfer.pages_transferred.saturating_add(1);
+		}
+
+		if transfer.cursor.step > T::MaxPaginatedPageId::get() as u32 {
+			transfer.cursor = MsaDataTransferCursor {
+				intent_index: transfer.cursor.intent_index.saturating_add(1),
+				step: 0,
+			};
+		} else {
+			transfer.cursor.step = transfer.cursor.step.saturating_add(1);
+		}
+		Ok(transfer.cursor.intent_index as usize >= transfer.intent_ids.len())
+	}
+
+	/// Writes a page of the source MSA to the target MSA of a transfer, moving or charging its
+	/// storage deposit, and removes it from the source MSA when it is moved
+	///
+	/// # Errors
+	/// * [`Error::StorageQuotaExceeded`] - The page does not fit in the quota of the target MSA
+	///
+	/// # Events
+	/// * [`Event::ItemizedPageUpdated`] or [`Event::PaginatedPageUpdated`]
+	/// * [`Event::ItemizedPageDeleted`] or [`Event::PaginatedPageDeleted`]
+	///
+	fn transfer_page<K: MultipartKey<T::KeyHasher>, PageDataSize: Get<u32>>(
+		source_msa_id: MessageSourceId,
+		transfer: &MsaDataTransfer<T>,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+		key: &K,
+		page: Page<PageDataSize>,
+	) -> DispatchResult {
+		let target_msa_id = transfer.target_msa_id;
+		let storage_prefix = match page_id {
+			None => ITEMIZED_STORAGE_PREFIX,
+			Some(_) => PAGINATED_STORAGE_PREFIX,
+		};
+		let target_page = StatefulChildTree::<T::KeyHasher>::try_read::<_, Page<PageDataSize>>(
+			&target_msa_id,
+			PALLET_STORAGE_PREFIX,
+			storage_prefix,
+			key,
+		)
+		.map_err(|_| Error::<T>::CorruptedState)?;
+		let prev_content_hash = target_page
+			.as_ref()
+			.map(|target_page| target_page.get_hash())
+			.unwrap_or_default();
+		let content_hash = page.get_hash();
+		Self::update_storage_usage(
+			target_msa_id,
+			intent_id,
+			target_page.map(|target_page| target_page.encoded_size()),
+			Some(page.encoded_size()),
+		)?;
+
+		StatefulChildTree::<T::KeyHasher>::write(
+			&target_msa_id,
+			PALLET_STORAGE_PREFIX,
+			storage_prefix,
+			key,
+			&page,
+		);
+		match transfer.mode {
+			MsaDataTransferMode::Copy => Self::charge_page_deposit(
+				&transfer.payer,
+				target_msa_id,
+				intent_id,
+				page_id,
+				page.encoded_size(),
+			)?,
+			MsaDataTransferMode::Move => {
+				Self::move_page_deposit(source_msa_id, target_msa_id, intent_id, page_id)?;
+				StatefulChildTree::<T::KeyHasher>::kill(
+					&source_msa_id,
+					PALLET_STORAGE_PREFIX,
+					storage_prefix,
+					key,
+				);
+				Self::update_storage_usage(
+					source_msa_id,
+					intent_id,
+					Some(page.encoded_size()),
+					None,
+				)?;
+			},
+		}
+
+		match page_id {
+			None => Self::deposit_event(Event::ItemizedPageUpdated {
+				msa_id: target_msa_id,
+				intent_id,
+				prev_content_hash,
+				curr_content_hash: content_hash,
+			}),
+			Some(page_id) => Self::deposit_event(Event::PaginatedPageUpdated {
+				msa_id: target_msa_id,
+				intent_id,
+				page_id,
+				prev_content_hash,
+				curr_content_hash: content_hash,
+			}),
+		}
+		if transfer.mode == MsaDataTransferMode::Move {
+			match page_id {
+				None => Self::deposit_event(Event::ItemizedPageDeleted {
+					msa_id: source_msa_id,
+					intent_id,
+					prev_content_hash: content_hash,
+				}),
+				Some(page_id) => Self::deposit_event(Event::PaginatedPageDeleted {
+					msa_id: source_msa_id,
+					intent_id,
+					page_id,
+					prev_content_hash: content_hash,
+				}),
+			}
+		}
+		Ok(())
+	}
+
 	/// Gets a paginated storage for desired parameters
 	pub fn get_paginated_page_for(
 		msa_id: MessageSourceId,
@@ -961,7 +2237,7 @@ impl<T: Config> Pallet<T> {
 		// hence it indicates some kind of corrupted state. In future, the pallet should be able
 		// to support reading pages with multiple versions.
 		if let Some(Page { page_version, .. }) = &page {
-			if *page_version != PageVersion::V2 {
+			if !matches!(page_version, PageVersion::V2 | PageVersion::V3(_)) {
 				return Err(Error::<T>::UnsupportedPageVersion.into());
 			}
 		};
@@ -5,3 +5,5 @@ pub mod v1;
 mod tests;
 /// Migration module for migrating from V2 to V3
 pub mod v2;
+/// Migration module for seeding the storage usage of each MSA
+pub mod v3;
@@ -6,15 +6,18 @@ use crate::{
 		new_test_ext, run_to_block_with_migrations, AllPalletsWithSystem, MigratorServiceWeight,
 		System, Test as T,
 	},
-	types::{PageError, ITEMIZED_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX, PALLET_STORAGE_PREFIX},
-	weights, Config,
+	types::{
+		PageError, ITEMIZED_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX, PALLET_STORAGE_PREFIX,
+		STATEFUL_STORAGE_VERSION,
+	},
+	weights, Config, StorageUsages,
 };
-use common_primitives::msa::MessageSourceId;
+use common_primitives::{msa::MessageSourceId, stateful_storage::IntentStorageUsage};
 use frame_support::{
 	assert_err, pallet_prelude::StorageVersion, traits::OnRuntimeUpgrade, BoundedVec,
 };
 use pallet_migrations::WeightInfo as _;
-use parity_scale_codec::Decode;
+use parity_scale_codec::{Decode, Encode};
 
 fn check_paginated_pages<OkPageType: Decode, ErrPageType: Decode>(msa_ids: &Vec<MessageSourceId>) {
 	for msa_id in msa_ids {
@@ -148,13 +151,13 @@ fn lazy_migration_works() {
 
 		for block in 2..=(MAX_BLOCKS + 2) {
 			run_to_block_with_migrations(block as u32);
-			if StorageVersion::new(2) == StorageVersion::get::<crate::Pallet<T>>() {
+			if STATEFUL_STORAGE_VERSION == StorageVersion::get::<crate::Pallet<T>>() {
 				break;
 			}
 		}
 
 		// Make sure migration completed (ie, storage version was bumped)
-		assert_eq!(StorageVersion::get::<crate::Pallet<T>>(), StorageVersion::new(2));
+		assert_eq!(StorageVersion::get::<crate::Pallet<T>>(), STATEFUL_STORAGE_VERSION);
 
 		// Check that all pages have been migrated
 		check_paginated_pages::<crate::PaginatedPage<T>, v1::PaginatedPage<T>>(&msa_ids);
@@ -176,3 +179,65 @@ fn lazy_migration_works() {
 		});
 	});
 }
+
+#[test]
+fn storage_usage_migration_seeds_usage_of_stored_pages() {
+	new_test_ext().execute_with(|| {
+		let msa_ids = vec![1, 2, 3];
+		let page_count = <T as Config>::MaxPaginatedPageId::get() as u32 - 1;
+		let paginated_page: crate::PaginatedPage<T> =
+			BoundedVec::try_from(vec![1; 100]).unwrap().into();
+		let itemized_page: crate::ItemizedPage<T> =
+			BoundedVec::try_from(vec![2; 40]).unwrap().into();
+		for msa_id in &msa_ids {
+			for page_id in 1u16..<T as Config>::MaxPaginatedPageId::get() {
+				StatefulChildTree::<<T as Config>::KeyHasher>::write(
+					msa_id,
+					PALLET_STORAGE_PREFIX,
+					PAGINATED_STORAGE_PREFIX,
+					&(UNDELEGATED_PAGINATED_SCHEMA, page_id),
+					&paginated_page,
+				);
+			}
+			StatefulChildTree::<<T as Config>::KeyHasher>::write(
+				msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&(UNDELEGATED_ITEMIZED_SCHEMA,),
+				&itemized_page,
+			);
+		}
+
+		let limit = <T as pallet_migrations::Config>::WeightInfo::progress_mbms_none() +
+			pallet_migrations::Pallet::<T>::exec_migration_max_weight() +
+			<weights::SubstrateWeight<T> as crate::weights::WeightInfo>::paginated_v2_to_v3_hit() *
+				16;
+		MigratorServiceWeight::set(&limit);
+
+		StorageVersion::new(2).put::<crate::Pallet<T>>();
+		System::set_block_number(1);
+		AllPalletsWithSystem::on_runtime_upgrade(); // onboard MBMs
+
+		for block in 2..=52 {
+			run_to_block_with_migrations(block);
+			if STATEFUL_STORAGE_VERSION == StorageVersion::get::<crate::Pallet<T>>() {
+				break;
+			}
+		}
+
+		assert_eq!(StorageVersion::get::<crate::Pallet<T>>(), STATEFUL_STORAGE_VERSION);
+		for msa_id in &msa_ids {
+			assert_eq!(
+				StorageUsages::<T>::get(msa_id, UNDELEGATED_PAGINATED_SCHEMA),
+				IntentStorageUsage {
+					bytes: paginated_page.encoded_size() as u32 * page_count,
+					pages: page_count,
+				}
+			);
+			assert_eq!(
+				StorageUsages::<T>::get(msa_id, UNDELEGATED_ITEMIZED_SCHEMA),
+				IntentStorageUsage { bytes: itemized_page.encoded_size() as u32, pages: 1 }
+			);
+		}
+	});
+}
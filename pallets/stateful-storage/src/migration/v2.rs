@@ -47,7 +47,7 @@ impl<N: Get<u32>> Default for ChildCursor<N> {
 	}
 }
 
-fn next_key<N: Get<u32>>(
+pub(crate) fn next_key<N: Get<u32>>(
 	child: &ChildInfo,
 	after: &BoundedVec<u8, N>,
 ) -> Result<Option<BoundedVec<u8, N>>, SteppedMigrationError> {
@@ -0,0 +1,253 @@
+use crate::{
+	migration::v2::{next_key, ChildCursor, ItemizedKeyLength, PaginatedKeyLength},
+	stateful_child_tree::{MultipartKey, StatefulChildTree},
+	types::{
+		ItemizedKey, PaginatedKey, ITEMIZED_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX,
+		PALLET_STORAGE_PREFIX,
+	},
+	weights, Config, Event, Pallet, StorageUsages,
+};
+use common_primitives::{
+	msa::{MessageSourceId, MsaLookup},
+	schema::{IntentId, PayloadLocation},
+};
+use core::marker::PhantomData;
+use frame_support::{
+	migrations::{MigrationId, SteppedMigration, SteppedMigrationError},
+	pallet_prelude::{Get, GetStorageVersion, StorageVersion},
+	weights::WeightMeter,
+	BoundedVec,
+};
+use parity_scale_codec::Encode;
+use sp_core::storage::ChildInfo;
+
+const LOG_TARGET: &str = "pallet::stateful-storage::migration::v3";
+
+/// Adds a stored page to the storage usage of its MSA and Intent
+fn add_page_usage<T: Config>(msa_id: MessageSourceId, intent_id: IntentId, page_bytes: usize) {
+	StorageUsages::<T>::mutate(msa_id, intent_id, |usage| {
+		usage.bytes = usage.bytes.saturating_add(page_bytes as u32);
+		usage.pages = usage.pages.saturating_add(1);
+	});
+}
+
+/// Moves the cursor to the next MSA once all of the pages of the current one are counted
+fn finish_child<T: Config, N: Get<u32>>(cur: &mut ChildCursor<N>, location: PayloadLocation) {
+	if cur.id.is_multiple_of(<u64>::from(T::MigrateEmitEvery::get())) {
+		Pallet::<T>::deposit_event(Event::<T>::StatefulPagesMigrated {
+			last_trie: (cur.id, location),
+			total_page_count: cur.cumulative_pages,
+		});
+	}
+	cur.last_key = BoundedVec::default();
+	cur.id += 1;
+}
+
+/// Counts a single PaginatedPage in the storage usage of its MSA
+pub fn process_paginated_page<T: Config, N: Get<u32>>(
+	child: &ChildInfo,
+	cur: &mut ChildCursor<N>,
+) -> Result<bool, SteppedMigrationError> {
+	let Some(k) = next_key(child, &cur.last_key)? else {
+		finish_child::<T, N>(cur, PayloadLocation::Paginated);
+		return Ok(false);
+	};
+
+	if let Some(page) =
+		StatefulChildTree::<T::KeyHasher>::try_read_raw::<crate::PaginatedPage<T>>(child, &k)
+			.map_err(|_| SteppedMigrationError::Failed)?
+	{
+		let (intent_id, _page_id) = <PaginatedKey as MultipartKey<T::KeyHasher>>::decode(&k[..])
+			.map_err(|_| SteppedMigrationError::Failed)?;
+		add_page_usage::<T>(cur.id, intent_id, page.encoded_size());
+	}
+
+	cur.last_key = k;
+	cur.cumulative_pages += 1;
+	Ok(true)
+}
+
+/// Counts a single ItemizedPage in the storage usage of its MSA
+pub fn process_itemized_page<T: Config, N: Get<u32>>(
+	child: &ChildInfo,
+	cur: &mut ChildCursor<N>,
+) -> Result<bool, SteppedMigrationError> {
+	let Some(k) = next_key(child, &cur.last_key)? else {
+		finish_child::<T, N>(cur, PayloadLocation::Itemized);
+		return Ok(false);
+	};
+
+	if let Some(page) =
+		StatefulChildTree::<T::KeyHasher>::try_read_raw::<crate::ItemizedPage<T>>(child, &k)
+			.map_err(|_| SteppedMigrationError::Failed)?
+	{
+		let (intent_id,) = <ItemizedKey as MultipartKey<T::KeyHasher>>::decode(&k[..])
+			.map_err(|_| SteppedMigrationError::Failed)?;
+		add_page_usage::<T>(cur.id, intent_id, page.encoded_size());
+	}
+
+	cur.last_key = k;
+	cur.cumulative_pages += 1;
+	Ok(true)
+}
+
+/// Seeds [`StorageUsages`] with the Paginated pages stored before usage was tracked.
+pub struct SeedPaginatedStorageUsage<T: Config, W: weights::WeightInfo>(PhantomData<(T, W)>);
+impl<T: Config, W: weights::WeightInfo> SteppedMigration for SeedPaginatedStorageUsage<T, W> {
+	type Cursor = ChildCursor<PaginatedKeyLength>;
+	// Without the explicit length here the construction of the ID would not be infallible.
+	type Identifier = MigrationId<50>;
+
+	/// The identifier of this migration. Which should be globally unique.
+	fn id() -> Self::Identifier {
+		MigrationId {
+			pallet_id: *b"pallet::stateful-storage::migration::paginated::v3",
+			version_from: 2,
+			version_to: 3,
+		}
+	}
+
+	fn step(
+		cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		meter.try_consume(T::DbWeight::get().reads(2)).map_err(|_| {
+			SteppedMigrationError::InsufficientWeight { required: T::DbWeight::get().reads(2) }
+		})?;
+		if StorageVersion::new(3) <= Pallet::<T>::on_chain_storage_version() {
+			log::info!(target: LOG_TARGET, "Skipping seeding paginated storage usage: storage version already set to 3");
+			return Ok(None);
+		}
+		let max_id = <T::MsaInfoProvider>::get_max_msa_id();
+		let mut cur = cursor.unwrap_or_else(|| {
+			log::info!(target: LOG_TARGET, "Starting seeding paginated storage usage, max MSA: {max_id}");
+			Self::Cursor::default()
+		});
+		let hit_weight = W::paginated_v2_to_v3_hit();
+		let miss_weight = W::paginated_v2_to_v3_miss();
+
+		if meter.remaining().any_lt(hit_weight) {
+			return Err(SteppedMigrationError::InsufficientWeight { required: hit_weight });
+		}
+
+		while cur.id <= max_id {
+			let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+				cur.id,
+				PALLET_STORAGE_PREFIX,
+				PAGINATED_STORAGE_PREFIX,
+			);
+
+			loop {
+				if !meter.can_consume(hit_weight) {
+					return Ok(Some(cur));
+				}
+				if !process_paginated_page::<T, PaginatedKeyLength>(&child, &mut cur)? {
+					meter.consume(miss_weight);
+					break;
+				}
+				meter.consume(hit_weight);
+			}
+		}
+
+		log::info!(target: LOG_TARGET, "Finished seeding paginated storage usage; counted {} total pages", cur.cumulative_pages);
+		Ok(None)
+	}
+}
+
+/// Seeds [`StorageUsages`] with the Itemized pages stored before usage was tracked.
+pub struct SeedItemizedStorageUsage<T: Config, W: weights::WeightInfo>(PhantomData<(T, W)>);
+impl<T: Config, W: weights::WeightInfo> SteppedMigration for SeedItemizedStorageUsage<T, W> {
+	type Cursor = ChildCursor<ItemizedKeyLength>;
+	// Without the explicit length here the construction of the ID would not be infallible.
+	type Identifier = MigrationId<49>;
+
+	/// The identifier of this migration. Which should be globally unique.
+	fn id() -> Self::Identifier {
+		MigrationId {
+			pallet_id: *b"pallet::stateful-storage::migration::itemized::v3",
+			version_from: 2,
+			version_to: 3,
+		}
+	}
+
+	fn step(
+		cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		meter.try_consume(T::DbWeight::get().reads(2)).map_err(|_| {
+			SteppedMigrationError::InsufficientWeight { required: T::DbWeight::get().reads(2) }
+		})?;
+		if StorageVersion::new(3) <= Pallet::<T>::on_chain_storage_version() {
+			log::info!(target: LOG_TARGET, "Skipping seeding itemized storage usage: storage version already set to 3");
+			return Ok(None);
+		}
+		let max_id = <T::MsaInfoProvider>::get_max_msa_id();
+		let mut cur = cursor.unwrap_or_else(|| {
+			log::info!(target: LOG_TARGET, "Starting seeding itemized storage usage, max MSA: {max_id}");
+			Self::Cursor::default()
+		});
+		let hit_weight = W::itemized_v2_to_v3_hit();
+		let miss_weight = W::itemized_v2_to_v3_miss();
+
+		if meter.remaining().any_lt(hit_weight) {
+			return Err(SteppedMigrationError::InsufficientWeight { required: hit_weight });
+		}
+
+		while cur.id <= max_id {
+			let child = StatefulChildTree::<T::KeyHasher>::get_child_tree_for_storage(
+				cur.id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+			);
+
+			loop {
+				if !meter.can_consume(hit_weight) {
+					return Ok(Some(cur));
+				}
+				if !process_itemized_page::<T, ItemizedKeyLength>(&child, &mut cur)? {
+					meter.consume(miss_weight);
+					break;
+				}
+				meter.consume(hit_weight);
+			}
+		}
+
+		log::info!(target: LOG_TARGET, "Finished seeding itemized storage usage; counted {} total pages", cur.cumulative_pages);
+		Ok(None)
+	}
+}
+
+/// Finalize the migration by updating the pallet storage version, which re-enables writes.
+pub struct FinalizeV3Migration<T: Config, W: weights::WeightInfo>(PhantomData<(T, W)>);
+impl<T: Config, W: weights::WeightInfo> SteppedMigration for FinalizeV3Migration<T, W> {
+	type Cursor = ();
+	// Without the explicit length here the construction of the ID would not be infallible.
+	type Identifier = MigrationId<51>;
+
+	/// The identifier of this migration. Which should be globally unique.
+	fn id() -> Self::Identifier {
+		MigrationId {
+			pallet_id: *b"pallet::stateful-storage::migration::usage-finalize",
+			version_from: 2,
+			version_to: 3,
+		}
+	}
+
+	fn step(
+		_cursor: Option<Self::Cursor>,
+		meter: &mut WeightMeter,
+	) -> Result<Option<Self::Cursor>, SteppedMigrationError> {
+		let required = T::DbWeight::get().reads_writes(1, 1);
+		if meter.try_consume(required).is_err() {
+			return Err(SteppedMigrationError::InsufficientWeight { required });
+		}
+		if StorageVersion::new(3) <= Pallet::<T>::on_chain_storage_version() {
+			log::info!(target: LOG_TARGET, "Skipping finalization of storage usage migration: storage version already set to 3 or higher");
+			return Ok(None);
+		}
+		StorageVersion::new(3).put::<Pallet<T>>();
+
+		log::info!(target: LOG_TARGET, "Finalized storage usage migration: storage version set to 3");
+		Ok(None)
+	}
+}
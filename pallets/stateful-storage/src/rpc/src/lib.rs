@@ -12,7 +12,9 @@
 use common_primitives::{
 	msa::MessageSourceId,
 	schema::*,
-	stateful_storage::{ItemizedStoragePageResponseV2, PaginatedStorageResponseV2},
+	stateful_storage::{
+		ItemizedStoragePageResponseV2, PaginatedStorageResponseV2, PaginatedStorageResponseV3,
+	},
 };
 use jsonrpsee::{
 	core::{async_trait, RpcResult},
@@ -26,13 +28,17 @@ use sp_runtime::{traits::Block as BlockT, DispatchError};
 extern crate alloc;
 use alloc::{sync::Arc, vec::Vec};
 
+mod proof;
+pub use proof::*;
+
 #[cfg(test)]
 mod tests;
 
 /// Frequency Stateful Storage Custom RPC API
 #[rpc(client, server)]
 pub trait StatefulStorageApi<BlockHash> {
-	/// retrieving pages of stateful storage
+	/// retrieving pages of stateful storage; compressed pages are returned as stored, without
+	/// their codec
 	#[method(name = "statefulStorage_getPaginatedStorage")]
 	fn get_paginated_storage(
 		&self,
@@ -47,6 +53,14 @@ pub trait StatefulStorageApi<BlockHash> {
 		msa_id: MessageSourceId,
 		intent_id: IntentId,
 	) -> RpcResult<ItemizedStoragePageResponseV2>;
+
+	/// retrieving pages of stateful storage, with the decompressed data of compressed pages
+	#[method(name = "statefulStorage_getPaginatedStorageWithCompression")]
+	fn get_paginated_storage_with_compression(
+		&self,
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+	) -> RpcResult<Vec<PaginatedStorageResponseV3>>;
 }
 
 /// The client handler for the API used by Frequency Service RPC with `jsonrpsee`
@@ -91,6 +105,17 @@ where
 		let api_result = api.get_itemized_storage_v2(at, msa_id, intent_id);
 		map_result(api_result)
 	}
+
+	fn get_paginated_storage_with_compression(
+		&self,
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+	) -> RpcResult<Vec<PaginatedStorageResponseV3>> {
+		let api = self.client.runtime_api();
+		let at = self.client.info().best_hash;
+		let api_result = api.get_paginated_storage_v3(at, msa_id, intent_id);
+		map_result(api_result)
+	}
 }
 
 fn map_result<T>(api_result: Result<Result<T, DispatchError>, ApiError>) -> RpcResult<T> {
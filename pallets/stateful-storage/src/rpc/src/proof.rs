@@ -0,0 +1,98 @@
+//! Read proofs of stateful storage pages, so that clients can check pages against the state root
+//! of a block instead of trusting the RPC node
+
+use crate::map_result;
+use common_primitives::{
+	msa::MessageSourceId,
+	schema::IntentId,
+	stateful_storage::{PageId, PageStorageKey},
+};
+use jsonrpsee::{
+	core::{async_trait, RpcResult},
+	proc_macros::rpc,
+	types::error::{ErrorCode, ErrorObject},
+};
+use pallet_stateful_storage_runtime_api::StatefulStorageRuntimeApi;
+use sc_client_api::ProofProvider;
+use serde::{Deserialize, Serialize};
+use sp_api::ProvideRuntimeApi;
+use sp_blockchain::HeaderBackend;
+use sp_core::{storage::ChildInfo, Bytes};
+use sp_runtime::traits::Block as BlockT;
+use std::sync::Arc;
+
+/// A read proof of a stateful storage page
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PageProof<BlockHash> {
+	/// Hash of the block whose state root the proof is checked against
+	pub at: BlockHash,
+	/// Location of the page in the state
+	pub storage_key: PageStorageKey,
+	/// Trie nodes proving the page, or its absence, in the child trie of the MSA
+	pub proof: Vec<Bytes>,
+}
+
+/// Frequency Stateful Storage Proof Custom RPC API
+#[rpc(client, server)]
+pub trait StatefulStorageProofApi<BlockHash> {
+	/// Read proof of a page of stateful storage at the given block, or at the best block.
+	/// `page_id` must be `None` for itemized intents. The proof is checked with
+	/// `common_primitives::stateful_storage::verify_page_proof`.
+	#[method(name = "statefulStorage_getPageProof")]
+	fn get_page_proof(
+		&self,
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+		at: Option<BlockHash>,
+	) -> RpcResult<PageProof<BlockHash>>;
+}
+
+/// The client handler for the proof API used by Frequency Service RPC with `jsonrpsee`
+pub struct StatefulStorageProofHandler<C, M> {
+	client: Arc<C>,
+	_marker: std::marker::PhantomData<M>,
+}
+
+impl<C, M> StatefulStorageProofHandler<C, M> {
+	/// Create new instance with the given reference to the client.
+	pub fn new(client: Arc<C>) -> Self {
+		Self { client, _marker: Default::default() }
+	}
+}
+
+#[async_trait]
+impl<C, Block> StatefulStorageProofApiServer<<Block as BlockT>::Hash>
+	for StatefulStorageProofHandler<C, Block>
+where
+	Block: BlockT,
+	C: 'static + ProvideRuntimeApi<Block> + HeaderBackend<Block> + ProofProvider<Block>,
+	C::Api: StatefulStorageRuntimeApi<Block>,
+{
+	fn get_page_proof(
+		&self,
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: Option<PageId>,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> RpcResult<PageProof<<Block as BlockT>::Hash>> {
+		let at = at.unwrap_or_else(|| self.client.info().best_hash);
+		let api_result =
+			self.client.runtime_api().get_page_storage_key(at, msa_id, intent_id, page_id);
+		let storage_key = map_result(api_result)?;
+
+		let child_info = ChildInfo::new_default(&storage_key.child_trie_key);
+		let proof = self
+			.client
+			.read_child_proof(at, &child_info, &mut [storage_key.page_key.as_slice()].into_iter())
+			.map_err(|e| {
+				ErrorObject::owned(
+					ErrorCode::ServerError(302).code(), // No real reason for this value
+					"Proof Error",
+					Some(format!("{e:?}")),
+				)
+			})?;
+
+		Ok(PageProof { at, storage_key, proof: proof.into_iter_nodes().map(Into::into).collect() })
+	}
+}
@@ -44,11 +44,30 @@ sp_api::mock_impl_runtime_apis! {
 				_ => Err(DispatchError::Other("some error")),
 			}
 		}
+
+		fn get_paginated_storage_v3(msa_id: MessageSourceId, intent_id: IntentId) -> Result<Vec<PaginatedStorageResponseV3>, DispatchError> {
+			match (msa_id, intent_id) {
+				(SUCCESSFUL_MSA_ID, SUCCESSFUL_INTENT_ID) => Ok(vec![PaginatedStorageResponseV3 {
+					intent_id,
+					page_id: 0,
+					msa_id,
+					schema_id: SUCCESSFUL_SCHEMA_ID,
+					content_hash: DUMMY_STATE_HASH,
+					nonce: NONCE,
+					compression: Some(PageCompression::Lz4),
+					payload: compress_page_data_lz4(SUCCESSFUL_PAYLOAD),
+					decompressed_payload: Some(SUCCESSFUL_PAYLOAD.to_vec()),
+				}]),
+				_ => Err(DispatchError::Other("some error")),
+			}
+		}
 	}
 }
 
 type PaginatedStateResult =
 	Result<Vec<PaginatedStorageResponseV2>, jsonrpsee::types::ErrorObjectOwned>;
+type CompressedPaginatedStateResult =
+	Result<Vec<PaginatedStorageResponseV3>, jsonrpsee::types::ErrorObjectOwned>;
 type ItemizedStateResult =
 	Result<ItemizedStoragePageResponseV2, jsonrpsee::types::ErrorObjectOwned>;
 
@@ -98,6 +117,36 @@ async fn get_paginated_storage_with_success() {
 	assert_eq!(SUCCESSFUL_PAYLOAD.to_vec(), page.payload);
 }
 
+#[tokio::test]
+async fn get_paginated_storage_with_compression_with_non_existent_msa_id_should_return_error() {
+	let client = Arc::new(TestApi {});
+	let api = StatefulStorageHandler::new(client);
+
+	let result: CompressedPaginatedStateResult = api.get_paginated_storage_with_compression(
+		1029, // Non-existing Msa Id
+		1,
+	);
+
+	assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn get_paginated_storage_with_compression_should_return_raw_and_decompressed_data() {
+	let client = Arc::new(TestApi {});
+	let api = StatefulStorageHandler::new(client);
+
+	let result: CompressedPaginatedStateResult =
+		api.get_paginated_storage_with_compression(SUCCESSFUL_MSA_ID, SUCCESSFUL_INTENT_ID);
+
+	assert!(result.is_ok());
+	let response = result.unwrap();
+	assert_eq!(1, response.len());
+	let page = &response[0];
+	assert_eq!(Some(PageCompression::Lz4), page.compression);
+	assert_eq!(compress_page_data_lz4(SUCCESSFUL_PAYLOAD), page.payload);
+	assert_eq!(Some(SUCCESSFUL_PAYLOAD.to_vec()), page.decompressed_payload);
+}
+
 #[tokio::test]
 async fn get_itemized_storage_with_non_existent_schema_id_should_return_error() {
 	let client = Arc::new(TestApi {});
@@ -21,8 +21,9 @@ use common_primitives::{
 	msa::MessageSourceId,
 	schema::{IntentId, SchemaId},
 	stateful_storage::{
-		ItemizedStoragePageResponse, ItemizedStoragePageResponseV2, PaginatedStorageResponse,
-		PaginatedStorageResponseV2,
+		ItemizedStoragePageResponse, ItemizedStoragePageResponseV2, PageId, PageStorageKey,
+		PaginatedStorageResponse, PaginatedStorageResponseV2, PaginatedStorageResponseV3,
+		StorageUsageResponse,
 	},
 };
 use sp_runtime::DispatchError;
@@ -48,11 +49,28 @@ sp_api::decl_runtime_apis! {
 		#[deprecated(note = "Use get_itemized_storage_v2 instead")]
 		fn get_itemized_storage(msa_id: MessageSourceId, schema_id: SchemaId) -> Result<ItemizedStoragePageResponse, DispatchError>;
 
-		/// Retrieve the paginated storage for a particular msa and schema
+		/// Retrieve the paginated storage for a particular msa and schema.
+		/// Compressed pages are returned as stored, without their codec: use
+		/// `get_paginated_storage_v3` to read them.
 		#[api_version(2)]
 		fn get_paginated_storage_v2(msa_id: MessageSourceId, intent_id: IntentId) -> Result<Vec<PaginatedStorageResponseV2>, DispatchError>;
 		/// Retrieve the itemized storage for a particular msa and schema
 		#[api_version(2)]
 		fn get_itemized_storage_v2(msa_id: MessageSourceId, intent_id: IntentId) -> Result<ItemizedStoragePageResponseV2, DispatchError>;
+
+		/// Retrieve the location of a page in the state, to build a read proof of the page.
+		/// `page_id` must be `None` for itemized intents.
+		#[api_version(3)]
+		fn get_page_storage_key(msa_id: MessageSourceId, intent_id: IntentId, page_id: Option<PageId>) -> Result<PageStorageKey, DispatchError>;
+
+		/// Retrieve the paginated storage for a particular msa and intent, with both the raw
+		/// and the decompressed data of compressed pages
+		#[api_version(4)]
+		fn get_paginated_storage_v3(msa_id: MessageSourceId, intent_id: IntentId) -> Result<Vec<PaginatedStorageResponseV3>, DispatchError>;
+
+		/// Retrieve the bytes and pages stored by a particular msa for each intent, with the
+		/// storage quota of the intent
+		#[api_version(5)]
+		fn get_storage_usage(msa_id: MessageSourceId) -> Vec<StorageUsageResponse>;
 	}
 }
@@ -25,6 +25,24 @@ mod test_constants {
 	pub const PAGINATED_APPEND_ONLY_SCHEMA: SchemaId = 207;
 	pub const UNDELEGATED_PAGINATED_INTENT: IntentId = 108;
 	pub const UNDELEGATED_PAGINATED_SCHEMA: SchemaId = 208;
+
+	/// Schemas of the default intents with a non-active status
+	pub const ITEMIZED_DEPRECATED_SCHEMA: SchemaId = 209;
+	pub const ITEMIZED_UNSUPPORTED_SCHEMA: SchemaId = 210;
+	pub const PAGINATED_DEPRECATED_SCHEMA: SchemaId = 211;
+	pub const PAGINATED_UNSUPPORTED_SCHEMA: SchemaId = 212;
+
+	/// Intents with the `ValidatePayload` setting
+	pub const ITEMIZED_VALIDATED_INTENT: IntentId = 109;
+	pub const ITEMIZED_VALIDATED_SCHEMA: SchemaId = 213;
+	pub const PAGINATED_VALIDATED_INTENT: IntentId = 110;
+	pub const PAGINATED_VALIDATED_SCHEMA: SchemaId = 214;
+	/// Model of the `ValidatePayload` schemas
+	pub const VALIDATED_SCHEMA_MODEL: &str = r#"{"type":"array","items":"int"}"#;
+
+	/// Intent with the `AppendOnly` and `AllowUpdates` settings
+	pub const ITEMIZED_UPDATABLE_INTENT: IntentId = 111;
+	pub const ITEMIZED_UPDATABLE_SCHEMA: SchemaId = 215;
 }
 
 ///
@@ -52,11 +70,19 @@ pub mod constants {
 #[cfg(test)]
 pub mod test_utility {
 	use crate::{
-		pallet, tests::mock::Test, types::PageVersion, Config, ItemHeader, ItemizedPage, Page,
+		pallet,
+		stateful_child_tree::StatefulChildTree,
+		tests::mock::Test,
+		types::{
+			PageVersion, PaginatedPage, ITEMIZED_STORAGE_PREFIX, PAGINATED_STORAGE_PREFIX,
+			PALLET_STORAGE_PREFIX,
+		},
+		Config, ItemHeader, ItemizedPage, Page,
 	};
 	use common_primitives::{
-		schema::{ModelType, PayloadLocation, SchemaId},
-		stateful_storage::PageNonce,
+		msa::MessageSourceId,
+		schema::{IntentId, ModelType, PayloadLocation, SchemaId},
+		stateful_storage::{PageId, PageNonce},
 	};
 	use frame_support::BoundedVec;
 	use parity_scale_codec::{Decode, Encode, MaxEncodedLen};
@@ -117,6 +143,36 @@ pub mod test_utility {
 		let data = BoundedVec::<u8, T::MaxItemizedPageSizeBytes>::try_from(buffer).unwrap();
 		ItemizedPage::<T> { page_version: Default::default(), schema_id: None, nonce, data }
 	}
+
+	/// Reads the Itemized page of an Intent stored for an MSA
+	pub fn itemized_page(
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+	) -> Option<ItemizedPage<Test>> {
+		<StatefulChildTree>::try_read(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&(intent_id,),
+		)
+		.unwrap()
+	}
+
+	/// Reads a Paginated page of an Intent stored for an MSA
+	pub fn paginated_page(
+		msa_id: MessageSourceId,
+		intent_id: IntentId,
+		page_id: PageId,
+	) -> Option<PaginatedPage<Test>> {
+		<StatefulChildTree>::try_read(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&(intent_id, page_id),
+		)
+		.unwrap()
+	}
+
 	#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, MaxEncodedLen)]
 	/// A structure defining a Schema
 	pub struct TestStruct {
@@ -473,6 +473,177 @@ fn apply_delete_item_on_append_only_fails() {
 	});
 }
 
+#[test]
+fn apply_update_item_should_replace_item_and_keep_indices() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = test_public(1);
+		let msa_id = 1;
+		let schema_id = ITEMIZED_SCHEMA;
+		let intent_id = ITEMIZED_INTENT;
+		let payloads: Vec<BoundedVec<u8, ItemizedBlobSize>> = vec![
+			vec![1; 5].try_into().unwrap(),
+			vec![2; 5].try_into().unwrap(),
+			vec![3; 5].try_into().unwrap(),
+		];
+		let new_payload: BoundedVec<u8, ItemizedBlobSize> = vec![4; 7].try_into().unwrap();
+		let page = create_itemized_page_from::<Test>(Some(1), &payloads);
+		let prev_content_hash = page.get_hash();
+		let keys = (intent_id,);
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&keys,
+			&page,
+		);
+		let actions = vec![ItemAction::Update { index: 1, data: new_payload.clone() }];
+
+		// act
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			schema_id,
+			prev_content_hash,
+			BoundedVec::try_from(actions).unwrap(),
+		));
+
+		// assert
+		let updated_page: ItemizedPage<Test> =
+			StatefulChildTree::<<Test as Config>::KeyHasher>::try_read(
+				&msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&keys,
+			)
+			.unwrap()
+			.unwrap();
+		let parsed = ItemizedOperations::<Test>::try_parse(&updated_page, false).unwrap();
+		assert_eq!(parsed.items.len(), 3);
+		assert_eq!(parsed.items[&0].payload, payloads[0].as_slice());
+		assert_eq!(parsed.items[&1].payload, new_payload.as_slice());
+		assert_eq!(parsed.items[&2].payload, payloads[2].as_slice());
+		System::assert_last_event(
+			StatefulEvent::ItemizedPageUpdated {
+				msa_id,
+				intent_id,
+				prev_content_hash,
+				curr_content_hash: updated_page.get_hash(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn apply_update_item_on_append_only_fails() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = test_public(1);
+		let msa_id = 1;
+		let schema_id = ITEMIZED_APPEND_ONLY_SCHEMA;
+		let intent_id = ITEMIZED_APPEND_ONLY_INTENT;
+		let payload = vec![1; 5];
+		let page = create_itemized_page_from::<Test>(None, &[payload.clone().try_into().unwrap()]);
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&(intent_id,),
+			&page,
+		);
+		let actions = vec![ItemAction::Update { index: 0, data: payload.try_into().unwrap() }];
+
+		// act
+		assert_err!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				schema_id,
+				page.get_hash(),
+				BoundedVec::try_from(actions).unwrap(),
+			),
+			Error::<Test>::UnsupportedOperationForSchema
+		);
+	});
+}
+
+#[test]
+fn apply_update_item_on_append_only_with_allow_updates_succeeds() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = test_public(1);
+		let msa_id = 1;
+		let schema_id = ITEMIZED_UPDATABLE_SCHEMA;
+		let intent_id = ITEMIZED_UPDATABLE_INTENT;
+		let page = create_itemized_page_from::<Test>(None, &[vec![1; 5].try_into().unwrap()]);
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&(intent_id,),
+			&page,
+		);
+		let update = vec![ItemAction::Update { index: 0, data: vec![2; 5].try_into().unwrap() }];
+		let delete = vec![ItemAction::Delete { index: 0 }];
+
+		// act
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1.clone()),
+			msa_id,
+			schema_id,
+			page.get_hash(),
+			BoundedVec::try_from(update).unwrap(),
+		));
+
+		// assert
+		let updated_page: ItemizedPage<Test> =
+			StatefulChildTree::<<Test as Config>::KeyHasher>::try_read(
+				&msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&(intent_id,),
+			)
+			.unwrap()
+			.unwrap();
+		let parsed = ItemizedOperations::<Test>::try_parse(&updated_page, false).unwrap();
+		assert_eq!(parsed.items[&0].payload, vec![2u8; 5].as_slice());
+		// deletes are still not allowed
+		assert_err!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				schema_id,
+				updated_page.get_hash(),
+				BoundedVec::try_from(delete).unwrap(),
+			),
+			Error::<Test>::UnsupportedOperationForSchema
+		);
+	});
+}
+
+#[test]
+fn apply_update_item_with_invalid_index_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let caller_1 = test_public(1);
+		let msa_id = 1;
+		let actions = vec![ItemAction::Update { index: 0, data: vec![1; 5].try_into().unwrap() }];
+
+		// act
+		assert_err!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				ITEMIZED_SCHEMA,
+				NONEXISTENT_PAGE_HASH,
+				BoundedVec::try_from(actions).unwrap(),
+			),
+			Error::<Test>::InvalidItemAction
+		);
+	});
+}
+
 #[test]
 fn apply_item_actions_with_signature_v2_having_wrong_signature_should_fail() {
 	new_test_ext().execute_with(|| {
@@ -235,6 +235,93 @@ fn applying_delete_action_with_non_existing_index_should_fail() {
 	assert_eq!(result.is_err(), true);
 }
 
+#[test]
+fn applying_update_action_with_existing_index_should_replace_item_in_place() {
+	// arrange
+	let payloads = vec![
+		generate_payload_bytes::<ItemizedBlobSize>(Some(2)),
+		generate_payload_bytes::<ItemizedBlobSize>(Some(4)),
+		generate_payload_bytes::<ItemizedBlobSize>(Some(6)),
+	];
+	let page = create_itemized_page_from::<Test>(None, payloads.as_slice());
+	let new_payload = generate_payload_bytes::<ItemizedBlobSize>(Some(8));
+	let expecting_page = create_itemized_page_from::<Test>(
+		None,
+		&vec![payloads[0].clone(), new_payload.clone(), payloads[2].clone()][..],
+	);
+	let actions = vec![ItemAction::Update { index: 1, data: new_payload }];
+
+	// act
+	let result = ItemizedOperations::<Test>::apply_item_actions(&page, 0 as SchemaId, &actions[..]);
+
+	// assert
+	assert_ok!(&result);
+	let updated = result.unwrap();
+	assert_eq!(expecting_page.data, updated.data);
+}
+
+#[test]
+fn applying_update_action_with_non_existing_index_should_fail() {
+	// arrange
+	let payloads = vec![generate_payload_bytes::<ItemizedBlobSize>(Some(2))];
+	let page = create_itemized_page_from::<Test>(None, payloads.as_slice());
+	let actions = vec![ItemAction::Update {
+		index: 1,
+		data: generate_payload_bytes::<ItemizedBlobSize>(Some(4)),
+	}];
+
+	// act
+	let result =
+		ItemizedOperations::<Test>::apply_item_actions(&page, ITEMIZED_SCHEMA, &actions[..]);
+
+	// assert
+	assert_eq!(result, Err(PageError::InvalidAction("item index is invalid")));
+}
+
+#[test]
+fn applying_update_action_on_a_deleted_index_should_fail() {
+	// arrange
+	let payloads = vec![
+		generate_payload_bytes::<ItemizedBlobSize>(Some(2)),
+		generate_payload_bytes::<ItemizedBlobSize>(Some(4)),
+	];
+	let page = create_itemized_page_from::<Test>(None, payloads.as_slice());
+	let actions = vec![
+		ItemAction::Delete { index: 0 },
+		ItemAction::Update { index: 0, data: generate_payload_bytes::<ItemizedBlobSize>(Some(6)) },
+	];
+
+	// act
+	let result =
+		ItemizedOperations::<Test>::apply_item_actions(&page, ITEMIZED_SCHEMA, &actions[..]);
+
+	// assert
+	assert_eq!(result, Err(PageError::InvalidAction("item index is invalid")));
+}
+
+#[test]
+fn applying_delete_action_after_update_action_should_delete_item() {
+	// arrange
+	let payloads = vec![
+		generate_payload_bytes::<ItemizedBlobSize>(Some(2)),
+		generate_payload_bytes::<ItemizedBlobSize>(Some(4)),
+	];
+	let page = create_itemized_page_from::<Test>(None, payloads.as_slice());
+	let expecting_page = create_itemized_page_from::<Test>(None, &payloads[1..]);
+	let actions = vec![
+		ItemAction::Update { index: 0, data: generate_payload_bytes::<ItemizedBlobSize>(Some(6)) },
+		ItemAction::Delete { index: 0 },
+	];
+
+	// act
+	let result = ItemizedOperations::<Test>::apply_item_actions(&page, 0 as SchemaId, &actions[..]);
+
+	// assert
+	assert_ok!(&result);
+	let updated = result.unwrap();
+	assert_eq!(expecting_page.data, updated.data);
+}
+
 #[test]
 fn applying_add_action_with_full_page_should_fail() {
 	// arrange
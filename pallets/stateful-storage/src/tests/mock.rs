@@ -6,14 +6,17 @@ use crate::test_common::{
 	constants::{BENCHMARK_SIGNATURE_ACCOUNT_SEED, PAGINATED_INTENT, SIGNATURE_MSA_ID},
 };
 use common_primitives::{
+	avro::{parse_avro_schema, validate_avro_datum},
+	capacity::Nontransferable,
 	msa::{
 		Delegation, DelegationValidator, DelegatorId, GrantValidator, MessageSourceId, MsaLookup,
 		MsaValidator, ProviderId, ProviderLookup,
 	},
 	node::AccountId,
 	schema::{
-		IntentId, IntentResponse, IntentSetting, ModelType, PayloadLocation, SchemaId,
-		SchemaInfoResponse, SchemaProvider, SchemaResponseV2, SchemaStatus,
+		IntentId, IntentResponse, IntentRetention, IntentSetting, ModelType, PayloadLocation,
+		PayloadValidationError, SchemaId, SchemaInfoResponse, SchemaProvider, SchemaResponseV2,
+		SchemaStatus, StorageQuota,
 	},
 };
 use common_runtime::weights::rocksdb_weights::constants::RocksDbWeight;
@@ -23,16 +26,17 @@ use frame_support::{
 	migrations::MultiStepMigrator,
 	pallet_prelude::Weight,
 	parameter_types,
-	traits::{ConstU16, ConstU32},
+	traits::{ConstU16, ConstU32, ConstU64},
 	Twox128,
 };
 use frame_system as system;
-use frame_system::pallet_prelude::BlockNumberFor;
+use frame_system::{pallet_prelude::BlockNumberFor, EnsureRoot};
 use sp_core::{crypto::AccountId32, sr25519, ByteArray, Pair, H256};
 use sp_runtime::{
 	traits::{BlakeTwo256, ConvertInto, IdentityLookup},
 	BuildStorage, DispatchError,
 };
+use std::collections::BTreeMap;
 
 type Block = frame_system::mocking::MockBlockU32<Test>;
 
@@ -47,7 +51,8 @@ frame_support::construct_runtime!(
 	pub enum Test
 	{
 		System: frame_system::{Pallet, Call, Config<T>, Storage, Event<T>},
-		StatefulStoragePallet: pallet_stateful_storage::{Pallet, Call, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		StatefulStoragePallet: pallet_stateful_storage::{Pallet, Call, Storage, Event<T>, HoldReason},
 		Migrator: pallet_migrations,
 	}
 );
@@ -70,7 +75,7 @@ impl system::Config for Test {
 	type BlockHashCount = ConstU32<250>;
 	type Version = ();
 	type PalletInfo = PalletInfo;
-	type AccountData = ();
+	type AccountData = pallet_balances::AccountData<u64>;
 	type OnNewAccount = ();
 	type OnKilledAccount = ();
 	type SystemWeightInfo = ();
@@ -96,12 +101,32 @@ impl pallet_migrations::Config for Test {
 		crate::migration::v2::MigratePaginatedV1ToV2<Test, crate::SubstrateWeight<Test>>,
 		crate::migration::v2::MigrateItemizedV1ToV2<Test, crate::SubstrateWeight<Test>>,
 		crate::migration::v2::FinalizeV2Migration<Test, crate::SubstrateWeight<Test>>,
+		crate::migration::v3::SeedPaginatedStorageUsage<Test, crate::SubstrateWeight<Test>>,
+		crate::migration::v3::SeedItemizedStorageUsage<Test, crate::SubstrateWeight<Test>>,
+		crate::migration::v3::FinalizeV3Migration<Test, crate::SubstrateWeight<Test>>,
 	);
 	#[cfg(feature = "runtime-benchmarks")]
 	type Migrations = pallet_migrations::mock_helpers::MockedMigrations;
 	type MaxServiceWeight = MigratorServiceWeight;
 }
 
+impl pallet_balances::Config for Test {
+	type Balance = u64;
+	type DustRemoval = ();
+	type RuntimeEvent = RuntimeEvent;
+	type ExistentialDeposit = ConstU64<1>;
+	type AccountStore = System;
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type WeightInfo = ();
+	type FreezeIdentifier = RuntimeFreezeReason;
+	type MaxFreezes = ConstU32<1>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type RuntimeFreezeReason = RuntimeFreezeReason;
+	type DoneSlashHandler = ();
+}
+
 pub type MaxItemizedActionsCount = ConstU32<6>;
 pub type MaxSchemaGrantsPerDelegation = ConstU32<30>;
 pub type StatefulMortalityWindowSize = ConstU32<10>;
@@ -228,6 +253,46 @@ impl GrantValidator<IntentId, BlockNumberFor<Test>> for SchemaGrantValidationHan
 	}
 }
 
+parameter_types! {
+	/// The storage quota of each Intent that has one
+	pub static IntentStorageQuotas: BTreeMap<IntentId, StorageQuota> = BTreeMap::new();
+}
+
+parameter_types! {
+	/// The available Capacity of each MSA
+	pub static CapacityBalances: BTreeMap<MessageSourceId, u64> = BTreeMap::new();
+}
+
+/// Capacity kept in [`CapacityBalances`]
+pub struct CapacityHandler;
+impl Nontransferable for CapacityHandler {
+	type Balance = u64;
+
+	fn balance(msa_id: MessageSourceId) -> Self::Balance {
+		CapacityBalances::get().get(&msa_id).copied().unwrap_or_default()
+	}
+
+	fn replenishable_balance(msa_id: MessageSourceId) -> Self::Balance {
+		Self::balance(msa_id)
+	}
+
+	fn deduct(msa_id: MessageSourceId, capacity_amount: Self::Balance) -> DispatchResult {
+		let remaining = Self::balance(msa_id)
+			.checked_sub(capacity_amount)
+			.ok_or(DispatchError::Other("insufficient capacity"))?;
+		CapacityBalances::mutate(|balances| balances.insert(msa_id, remaining));
+		Ok(())
+	}
+
+	fn deposit(
+		_msa_id: MessageSourceId,
+		_token_amount: Self::Balance,
+		_capacity_amount: Self::Balance,
+	) -> DispatchResult {
+		Ok(())
+	}
+}
+
 pub struct SchemaHandler;
 
 fn generate_schema_response(
@@ -235,6 +300,22 @@ fn generate_schema_response(
 	intent_id: IntentId,
 	payload_location: PayloadLocation,
 	settings: Vec<IntentSetting>,
+) -> Option<SchemaResponseV2> {
+	generate_schema_response_with_status(
+		schema_id,
+		intent_id,
+		payload_location,
+		settings,
+		SchemaStatus::Active,
+	)
+}
+
+fn generate_schema_response_with_status(
+	schema_id: SchemaId,
+	intent_id: IntentId,
+	payload_location: PayloadLocation,
+	settings: Vec<IntentSetting>,
+	status: SchemaStatus,
 ) -> Option<SchemaResponseV2> {
 	Some(SchemaResponseV2 {
 		schema_id,
@@ -243,6 +324,22 @@ fn generate_schema_response(
 		model_type: ModelType::AvroBinary,
 		payload_location,
 		settings,
+		status,
+	})
+}
+
+fn generate_validated_schema_response(
+	schema_id: SchemaId,
+	intent_id: IntentId,
+	payload_location: PayloadLocation,
+) -> Option<SchemaResponseV2> {
+	Some(SchemaResponseV2 {
+		schema_id,
+		intent_id,
+		model: constants::VALIDATED_SCHEMA_MODEL.as_bytes().to_vec(),
+		model_type: ModelType::AvroBinary,
+		payload_location,
+		settings: vec![IntentSetting::ValidatePayload],
 		status: SchemaStatus::Active,
 	})
 }
@@ -277,6 +374,12 @@ impl SchemaProvider<u16> for SchemaHandler {
 				PayloadLocation::Itemized,
 				vec![IntentSetting::AppendOnly],
 			),
+			constants::ITEMIZED_UPDATABLE_SCHEMA => generate_schema_response(
+				schema_id,
+				constants::ITEMIZED_UPDATABLE_INTENT,
+				PayloadLocation::Itemized,
+				vec![IntentSetting::AppendOnly, IntentSetting::AllowUpdates],
+			),
 			constants::UNDELEGATED_ITEMIZED_APPEND_ONLY_SCHEMA => generate_schema_response(
 				schema_id,
 				constants::UNDELEGATED_ITEMIZED_APPEND_ONLY_INTENT,
@@ -313,6 +416,44 @@ impl SchemaProvider<u16> for SchemaHandler {
 				PayloadLocation::Paginated,
 				vec![IntentSetting::AppendOnly],
 			),
+			constants::ITEMIZED_DEPRECATED_SCHEMA => generate_schema_response_with_status(
+				schema_id,
+				constants::ITEMIZED_INTENT,
+				PayloadLocation::Itemized,
+				Vec::new(),
+				SchemaStatus::Deprecated,
+			),
+			constants::ITEMIZED_UNSUPPORTED_SCHEMA => generate_schema_response_with_status(
+				schema_id,
+				constants::ITEMIZED_INTENT,
+				PayloadLocation::Itemized,
+				Vec::new(),
+				SchemaStatus::Unsupported,
+			),
+			constants::PAGINATED_DEPRECATED_SCHEMA => generate_schema_response_with_status(
+				schema_id,
+				PAGINATED_INTENT,
+				PayloadLocation::Paginated,
+				Vec::new(),
+				SchemaStatus::Deprecated,
+			),
+			constants::PAGINATED_UNSUPPORTED_SCHEMA => generate_schema_response_with_status(
+				schema_id,
+				PAGINATED_INTENT,
+				PayloadLocation::Paginated,
+				Vec::new(),
+				SchemaStatus::Unsupported,
+			),
+			constants::ITEMIZED_VALIDATED_SCHEMA => generate_validated_schema_response(
+				schema_id,
+				constants::ITEMIZED_VALIDATED_INTENT,
+				PayloadLocation::Itemized,
+			),
+			constants::PAGINATED_VALIDATED_SCHEMA => generate_validated_schema_response(
+				schema_id,
+				constants::PAGINATED_VALIDATED_INTENT,
+				PayloadLocation::Paginated,
+			),
 			INVALID_SCHEMA_ID => None,
 
 			_ => generate_schema_response(
@@ -332,7 +473,7 @@ impl SchemaProvider<u16> for SchemaHandler {
 				settings: schema.settings,
 				model_type: schema.model_type,
 				payload_location: schema.payload_location,
-				status: SchemaStatus::Active,
+				status: schema.status,
 			})
 		})
 	}
@@ -348,6 +489,11 @@ impl SchemaProvider<u16> for SchemaHandler {
 				PayloadLocation::Itemized,
 				vec![IntentSetting::AppendOnly],
 			),
+			constants::ITEMIZED_UPDATABLE_INTENT => generate_intent_response(
+				intent_id,
+				PayloadLocation::Itemized,
+				vec![IntentSetting::AppendOnly, IntentSetting::AllowUpdates],
+			),
 			constants::UNDELEGATED_ITEMIZED_APPEND_ONLY_INTENT => generate_intent_response(
 				intent_id,
 				PayloadLocation::Itemized,
@@ -372,6 +518,16 @@ impl SchemaProvider<u16> for SchemaHandler {
 				PayloadLocation::Paginated,
 				vec![IntentSetting::AppendOnly],
 			),
+			constants::ITEMIZED_VALIDATED_INTENT => generate_intent_response(
+				intent_id,
+				PayloadLocation::Itemized,
+				vec![IntentSetting::ValidatePayload],
+			),
+			constants::PAGINATED_VALIDATED_INTENT => generate_intent_response(
+				intent_id,
+				PayloadLocation::Paginated,
+				vec![IntentSetting::ValidatePayload],
+			),
 			INVALID_INTENT_ID => None,
 
 			_ => generate_intent_response(
@@ -381,6 +537,25 @@ impl SchemaProvider<u16> for SchemaHandler {
 			),
 		}
 	}
+
+	fn validate_payload(schema_id: SchemaId, payload: &[u8]) -> Result<(), PayloadValidationError> {
+		let schema =
+			Self::get_schema_by_id(schema_id).ok_or(PayloadValidationError::SchemaNotFound)?;
+		let definition =
+			parse_avro_schema(&schema.model).map_err(|_| PayloadValidationError::InvalidModel)?;
+		validate_avro_datum(&definition, payload).map_err(PayloadValidationError::InvalidPayload)
+	}
+
+	fn get_intent_retention_periods(
+		_start_after: Option<IntentId>,
+		_limit: u32,
+	) -> Vec<(IntentId, IntentRetention)> {
+		Vec::new()
+	}
+
+	fn get_intent_storage_quota(intent_id: IntentId) -> Option<StorageQuota> {
+		IntentStorageQuotas::get().get(&intent_id).copied()
+	}
 }
 
 impl Clone for MaxPaginatedPageId {
@@ -477,6 +652,13 @@ impl pallet_stateful_storage::Config for Test {
 	/// The number of blocks per virtual bucket
 	type MortalityWindowSize = StatefulMortalityWindowSize;
 	type MigrateEmitEvery = ConstU32<1>;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	type Capacity = CapacityHandler;
+	type StorageDepositGovernanceOrigin = EnsureRoot<AccountId>;
+	type MaxTransferIntents = ConstU32<4>;
+	type MaxPageOperations = ConstU32<4>;
+	type MaxDecompressedPaginatedPageSizeBytes = ConstU32<4096>;
 }
 
 pub fn new_test_ext() -> sp_io::TestExternalities {
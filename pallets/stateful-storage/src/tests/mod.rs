@@ -4,5 +4,13 @@ mod apply_item_actions_tests;
 mod child_tree_tests;
 mod delete_page_tests;
 mod itemized_operations_tests;
+mod msa_data_transfer_tests;
 mod other_tests;
+mod page_compression_tests;
+mod page_operations_tests;
+mod page_storage_key_tests;
+mod payload_validation_tests;
+mod schema_status_tests;
+mod storage_deposit_tests;
+mod storage_usage_tests;
 mod upsert_page_tests;
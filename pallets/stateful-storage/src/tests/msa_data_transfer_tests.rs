@@ -0,0 +1,567 @@
+use crate::{
+	pallet::{
+		MsaDataTransferLocks, MsaDataTransferNonces, MsaDataTransfers, PageDeposits, StorageUsages,
+	},
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error, Event as StatefulEvent, WeightInfo,
+};
+use common_primitives::{
+	msa::MessageSourceId,
+	schema::{IntentId, StorageQuota},
+	utils::wrap_binary_data,
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+	BoundedVec,
+};
+use parity_scale_codec::{Decode, Encode};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::{sr25519, ByteArray, Pair};
+use sp_runtime::{AccountId32, MultiSignature};
+
+fn target_account() -> (MessageSourceId, sr25519::Pair) {
+	let pair = sr25519::Pair::from_seed(&[2; 32]);
+	let msa_id = MessageSourceId::decode(&mut pair.public().as_slice()).unwrap();
+	(msa_id, pair)
+}
+
+fn transfer_payload(
+	source_msa_id: MessageSourceId,
+	target_msa_id: MessageSourceId,
+	intent_ids: Vec<IntentId>,
+	mode: MsaDataTransferMode,
+) -> MsaDataTransferSignaturePayload<Test> {
+	MsaDataTransferSignaturePayload {
+		source_msa_id,
+		target_msa_id,
+		intent_ids: BoundedVec::try_from(intent_ids).unwrap(),
+		mode,
+		nonce: MsaDataTransferNonces::<Test>::get(source_msa_id),
+		expiration: 10,
+	}
+}
+
+fn sign(pair: &sr25519::Pair, payload: &MsaDataTransferSignaturePayload<Test>) -> MultiSignature {
+	pair.sign(&wrap_binary_data(payload.encode())).into()
+}
+
+fn transfer(
+	source: &sr25519::Pair,
+	target: &sr25519::Pair,
+	payload: MsaDataTransferSignaturePayload<Test>,
+) -> sp_runtime::DispatchResult {
+	StatefulStoragePallet::transfer_msa_data(
+		RuntimeOrigin::signed(test_public(1)),
+		source.public().into(),
+		sign(source, &payload),
+		target.public().into(),
+		sign(target, &payload),
+		payload,
+	)
+}
+
+/// Writes an Itemized page and the Paginated pages `0` and `5` for an MSA
+fn populate_pages(msa_id: MessageSourceId, owner: &sr25519::Pair) {
+	let owner: AccountId32 = owner.public().into();
+	assert_ok!(StatefulStoragePallet::apply_item_actions(
+		RuntimeOrigin::signed(owner.clone()),
+		msa_id,
+		ITEMIZED_SCHEMA,
+		NONEXISTENT_PAGE_HASH,
+		BoundedVec::try_from(vec![ItemAction::Add { data: vec![1; 5].try_into().unwrap() }])
+			.unwrap(),
+	));
+	for page_id in [0, 5] {
+		assert_ok!(StatefulStoragePallet::upsert_page(
+			RuntimeOrigin::signed(owner.clone()),
+			msa_id,
+			PAGINATED_SCHEMA,
+			page_id,
+			NONEXISTENT_PAGE_HASH,
+			generate_payload_bytes::<PaginatedPageSize>(Some(page_id as u8)),
+		));
+	}
+}
+
+fn process_transfers() {
+	process_transfers_with_limit(Weight::MAX);
+}
+
+fn process_transfers_with_limit(limit: Weight) {
+	STATEFUL_STORAGE_VERSION.put::<StatefulStoragePallet>();
+	StatefulStoragePallet::on_idle(System::block_number(), limit);
+}
+
+#[test]
+fn move_should_transfer_all_pages_and_complete() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		let pages = (
+			itemized_page(source_msa_id, ITEMIZED_INTENT),
+			paginated_page(source_msa_id, PAGINATED_INTENT, 5),
+		);
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![ITEMIZED_INTENT, PAGINATED_INTENT],
+				MsaDataTransferMode::Move
+			),
+		));
+		System::assert_last_event(
+			StatefulEvent::MsaDataTransferStarted {
+				source_msa_id,
+				target_msa_id,
+				mode: MsaDataTransferMode::Move,
+			}
+			.into(),
+		);
+		process_transfers();
+
+		assert_eq!(
+			(
+				itemized_page(target_msa_id, ITEMIZED_INTENT),
+				paginated_page(target_msa_id, PAGINATED_INTENT, 5)
+			),
+			pages
+		);
+		assert!(paginated_page(target_msa_id, PAGINATED_INTENT, 0).is_some());
+		assert!(itemized_page(source_msa_id, ITEMIZED_INTENT).is_none());
+		assert!(paginated_page(source_msa_id, PAGINATED_INTENT, 0).is_none());
+		assert!(paginated_page(source_msa_id, PAGINATED_INTENT, 5).is_none());
+		assert!(MsaDataTransfers::<Test>::get(source_msa_id).is_none());
+		assert!(MsaDataTransferLocks::<Test>::get(target_msa_id, PAGINATED_INTENT).is_none());
+		System::assert_last_event(
+			StatefulEvent::MsaDataTransferCompleted {
+				source_msa_id,
+				target_msa_id,
+				pages_transferred: 3,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn copy_should_keep_source_pages() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Copy
+			),
+		));
+		process_transfers();
+
+		assert_eq!(
+			paginated_page(target_msa_id, PAGINATED_INTENT, 0),
+			paginated_page(source_msa_id, PAGINATED_INTENT, 0)
+		);
+		assert!(paginated_page(source_msa_id, PAGINATED_INTENT, 0).is_some());
+		assert!(itemized_page(target_msa_id, ITEMIZED_INTENT).is_none());
+		System::assert_last_event(
+			StatefulEvent::MsaDataTransferCompleted {
+				source_msa_id,
+				target_msa_id,
+				pages_transferred: 2,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_should_progress_within_weight_limit() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![ITEMIZED_INTENT],
+				MsaDataTransferMode::Move
+			),
+		));
+
+		let db_weight = <Test as frame_system::Config>::DbWeight::get();
+		let finish_weight =
+			db_weight.writes((<Test as Config>::MaxTransferIntents::get() * 2 + 1).into());
+		let one_step = db_weight
+			.reads_writes(1, 1)
+			.saturating_add(<() as WeightInfo>::transfer_msa_data_step())
+			.saturating_add(finish_weight);
+		process_transfers_with_limit(one_step);
+
+		let pending = MsaDataTransfers::<Test>::get(source_msa_id).unwrap();
+		assert_eq!(pending.cursor, MsaDataTransferCursor { intent_index: 0, step: 1 });
+		assert_eq!(pending.pages_transferred, 1);
+		assert!(itemized_page(target_msa_id, ITEMIZED_INTENT).is_some());
+
+		process_transfers();
+		assert!(MsaDataTransfers::<Test>::get(source_msa_id).is_none());
+	});
+}
+
+#[test]
+fn writes_to_pages_of_a_pending_transfer_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Move
+			),
+		));
+
+		for (owner, msa_id) in [(source.public(), source_msa_id), (target.public(), target_msa_id)]
+		{
+			assert_noop!(
+				StatefulStoragePallet::upsert_page(
+					RuntimeOrigin::signed(owner.into()),
+					msa_id,
+					PAGINATED_SCHEMA,
+					1,
+					NONEXISTENT_PAGE_HASH,
+					generate_payload_bytes::<PaginatedPageSize>(None),
+				),
+				Error::<Test>::MsaDataTransferInProgress
+			);
+		}
+		assert_noop!(
+			transfer(
+				&source,
+				&target,
+				transfer_payload(
+					source_msa_id,
+					target_msa_id,
+					vec![ITEMIZED_INTENT],
+					MsaDataTransferMode::Copy
+				),
+			),
+			Error::<Test>::MsaDataTransferInProgress
+		);
+
+		process_transfers();
+		assert_ok!(StatefulStoragePallet::upsert_page(
+			RuntimeOrigin::signed(target.public().into()),
+			target_msa_id,
+			PAGINATED_SCHEMA,
+			1,
+			NONEXISTENT_PAGE_HASH,
+			generate_payload_bytes::<PaginatedPageSize>(None),
+		));
+	});
+}
+
+#[test]
+fn transfer_with_invalid_signatures_or_msas_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		let payload = |source_msa_id, target_msa_id| {
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![ITEMIZED_INTENT],
+				MsaDataTransferMode::Move,
+			)
+		};
+
+		// target did not sign
+		let valid = payload(source_msa_id, target_msa_id);
+		assert_noop!(
+			StatefulStoragePallet::transfer_msa_data(
+				RuntimeOrigin::signed(test_public(1)),
+				source.public().into(),
+				sign(&source, &valid),
+				target.public().into(),
+				sign(&source, &valid),
+				valid,
+			),
+			Error::<Test>::InvalidSignature
+		);
+		// keys do not control the MSAs of the payload
+		assert_noop!(
+			transfer(&source, &target, payload(source_msa_id, 3)),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+		assert_noop!(
+			transfer(&target, &source, payload(source_msa_id, target_msa_id)),
+			Error::<Test>::InvalidMessageSourceAccount
+		);
+	});
+}
+
+#[test]
+fn transfer_with_invalid_intents_or_same_msa_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+
+		for (payload, error) in [
+			(
+				transfer_payload(
+					source_msa_id,
+					source_msa_id,
+					vec![ITEMIZED_INTENT],
+					MsaDataTransferMode::Move,
+				),
+				Error::<Test>::InvalidMsaDataTransfer,
+			),
+			(
+				transfer_payload(source_msa_id, target_msa_id, vec![], MsaDataTransferMode::Move),
+				Error::<Test>::InvalidMsaDataTransfer,
+			),
+			(
+				transfer_payload(
+					source_msa_id,
+					target_msa_id,
+					vec![ITEMIZED_INTENT, ITEMIZED_INTENT],
+					MsaDataTransferMode::Move,
+				),
+				Error::<Test>::InvalidMsaDataTransfer,
+			),
+			(
+				transfer_payload(
+					source_msa_id,
+					target_msa_id,
+					vec![INVALID_INTENT_ID],
+					MsaDataTransferMode::Move,
+				),
+				Error::<Test>::InvalidIntentId,
+			),
+		] {
+			let target = if payload.target_msa_id == source_msa_id { &source } else { &target };
+			assert_noop!(transfer(&source, target, payload), error);
+		}
+	});
+}
+
+#[test]
+fn copy_without_storage_deposit_should_abort() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		assert_ok!(StatefulStoragePallet::set_storage_byte_deposit(RuntimeOrigin::root(), 1));
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Copy
+			),
+		));
+		process_transfers();
+
+		assert!(paginated_page(target_msa_id, PAGINATED_INTENT, 0).is_none());
+		assert!(MsaDataTransfers::<Test>::get(source_msa_id).is_none());
+		assert!(MsaDataTransferLocks::<Test>::get(source_msa_id, PAGINATED_INTENT).is_none());
+		System::assert_last_event(
+			StatefulEvent::MsaDataTransferAborted {
+				source_msa_id,
+				target_msa_id,
+				pages_transferred: 0,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn move_should_move_storage_usage() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		let usage = StorageUsages::<Test>::get(source_msa_id, PAGINATED_INTENT);
+		assert_eq!(usage.pages, 2);
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Move
+			),
+		));
+		process_transfers();
+
+		assert_eq!(StorageUsages::<Test>::get(target_msa_id, PAGINATED_INTENT), usage);
+		assert!(!StorageUsages::<Test>::contains_key(source_msa_id, PAGINATED_INTENT));
+	});
+}
+
+#[test]
+fn move_should_keep_capacity_deposit_of_replaced_page() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		let page = paginated_page(source_msa_id, PAGINATED_INTENT, 0);
+		CapacityBalances::mutate(|balances| balances.insert(target_msa_id, 10_000));
+		assert_ok!(StatefulStoragePallet::set_storage_byte_deposit(RuntimeOrigin::root(), 1));
+		assert_ok!(StatefulStoragePallet::upsert_page(
+			RuntimeOrigin::signed(target.public().into()),
+			target_msa_id,
+			PAGINATED_SCHEMA,
+			0,
+			NONEXISTENT_PAGE_HASH,
+			generate_payload_bytes::<PaginatedPageSize>(Some(1)),
+		));
+		let deposit = PageDeposits::<Test>::get((target_msa_id, PAGINATED_INTENT, Some(0)));
+		assert!(matches!(
+			deposit,
+			Some(PageDeposit { source: DepositSource::Capacity(msa_id), .. }) if msa_id == target_msa_id
+		));
+		let capacity = CapacityBalances::get().get(&target_msa_id).copied();
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Move
+			),
+		));
+		process_transfers();
+
+		assert_eq!(paginated_page(target_msa_id, PAGINATED_INTENT, 0), page);
+		assert_eq!(PageDeposits::<Test>::get((target_msa_id, PAGINATED_INTENT, Some(0))), deposit);
+		assert_eq!(CapacityBalances::get().get(&target_msa_id).copied(), capacity);
+	});
+}
+
+#[test]
+fn copy_exceeding_storage_quota_should_abort() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		IntentStorageQuotas::mutate(|quotas| {
+			quotas.insert(PAGINATED_INTENT, StorageQuota { max_bytes: u32::MAX, max_pages: 1 });
+		});
+
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Copy
+			),
+		));
+		process_transfers();
+
+		assert!(paginated_page(target_msa_id, PAGINATED_INTENT, 0).is_some());
+		assert!(paginated_page(target_msa_id, PAGINATED_INTENT, 5).is_none());
+		assert_eq!(StorageUsages::<Test>::get(target_msa_id, PAGINATED_INTENT).pages, 1);
+		System::assert_last_event(
+			StatefulEvent::MsaDataTransferAborted {
+				source_msa_id,
+				target_msa_id,
+				pages_transferred: 1,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn transfer_payload_should_not_change_once_signed() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		let signed = transfer_payload(
+			source_msa_id,
+			target_msa_id,
+			vec![ITEMIZED_INTENT],
+			MsaDataTransferMode::Copy,
+		);
+		let tampered =
+			MsaDataTransferSignaturePayload { mode: MsaDataTransferMode::Move, ..signed.clone() };
+
+		assert_noop!(
+			StatefulStoragePallet::transfer_msa_data(
+				RuntimeOrigin::signed(test_public(1)),
+				source.public().into(),
+				sign(&source, &signed),
+				target.public().into(),
+				sign(&target, &signed),
+				tampered,
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn replaying_a_transfer_payload_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (source_msa_id, source) = get_signature_account();
+		let (target_msa_id, target) = target_account();
+		populate_pages(source_msa_id, &source);
+		let payload = transfer_payload(
+			source_msa_id,
+			target_msa_id,
+			vec![PAGINATED_INTENT],
+			MsaDataTransferMode::Copy,
+		);
+
+		assert_ok!(transfer(&source, &target, payload.clone()));
+		assert_eq!(MsaDataTransferNonces::<Test>::get(source_msa_id), 1);
+		process_transfers();
+		assert!(MsaDataTransfers::<Test>::get(source_msa_id).is_none());
+
+		assert_noop!(
+			transfer(&source, &target, payload),
+			Error::<Test>::InvalidMsaDataTransferNonce
+		);
+		// a payload signed with the next nonce is accepted
+		assert_ok!(transfer(
+			&source,
+			&target,
+			transfer_payload(
+				source_msa_id,
+				target_msa_id,
+				vec![PAGINATED_INTENT],
+				MsaDataTransferMode::Copy
+			),
+		));
+	});
+}
@@ -0,0 +1,206 @@
+use crate::{
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error,
+};
+use common_primitives::{
+	msa::MessageSourceId,
+	schema::SchemaId,
+	stateful_storage::{compress_page_data_lz4, PageCompression},
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::Get;
+
+/// `b"frequency "` repeated 40 times, compressed with `zstd -19 --no-check`
+const ZSTD_PAGE: [u8; 27] = [
+	0x28, 0xb5, 0x2f, 0xfd, 0x60, 0x90, 0x00, 0x8d, 0x00, 0x00, 0x50, 0x66, 0x72, 0x65, 0x71, 0x75,
+	0x65, 0x6e, 0x63, 0x79, 0x20, 0x01, 0x00, 0x83, 0x55, 0x0b, 0x12,
+];
+
+fn upsert_compressed(
+	msa_id: MessageSourceId,
+	schema_id: SchemaId,
+	compression: Option<PageCompression>,
+	payload: Vec<u8>,
+) -> frame_support::dispatch::DispatchResult {
+	StatefulStoragePallet::apply_page_operations(
+		RuntimeOrigin::signed(test_public(msa_id)),
+		msa_id,
+		BoundedVec::try_from(vec![PageOperation::UpsertPage {
+			schema_id,
+			page_id: 0,
+			target_hash: NONEXISTENT_PAGE_HASH,
+			compression,
+			payload: payload.try_into().unwrap(),
+		}])
+		.unwrap(),
+	)
+}
+
+#[test]
+fn upsert_lz4_page_should_store_compressed_data_as_v3_page() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let data = generate_payload_bytes::<PaginatedPageSize>(None).into_inner();
+		let compressed = compress_page_data_lz4(&data);
+
+		assert_ok!(upsert_compressed(
+			msa_id,
+			PAGINATED_SCHEMA,
+			Some(PageCompression::Lz4),
+			compressed.clone()
+		));
+
+		let page = paginated_page(msa_id, PAGINATED_INTENT, 0).unwrap();
+		assert_eq!(page.page_version, PageVersion::V3(PageCompression::Lz4));
+		assert_eq!(page.data.into_inner(), compressed);
+	});
+}
+
+#[test]
+fn upsert_zstd_page_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+
+		assert_ok!(upsert_compressed(
+			msa_id,
+			PAGINATED_SCHEMA,
+			Some(PageCompression::Zstd),
+			ZSTD_PAGE.to_vec()
+		));
+
+		let page = paginated_page(msa_id, PAGINATED_INTENT, 0).unwrap();
+		assert_eq!(page.compression(), Some(PageCompression::Zstd));
+		assert_eq!(
+			StatefulStoragePallet::decompress_paginated_data(PageCompression::Zstd, &page.data),
+			Ok(b"frequency ".repeat(40))
+		);
+	});
+}
+
+#[test]
+fn upsert_malformed_compressed_page_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			upsert_compressed(1, PAGINATED_SCHEMA, Some(PageCompression::Zstd), vec![1, 2, 3, 4]),
+			Error::<Test>::MalformedCompressedPage
+		);
+		assert_noop!(
+			upsert_compressed(1, PAGINATED_SCHEMA, Some(PageCompression::Lz4), vec![0xf0]),
+			Error::<Test>::MalformedCompressedPage
+		);
+	});
+}
+
+#[test]
+fn upsert_compressed_page_decompressing_past_max_size_should_fail() {
+	new_test_ext().execute_with(|| {
+		let max_size: u32 = <Test as Config>::MaxDecompressedPaginatedPageSizeBytes::get();
+		let compressed = compress_page_data_lz4(&vec![7u8; max_size as usize + 1]);
+
+		assert_noop!(
+			upsert_compressed(1, PAGINATED_SCHEMA, Some(PageCompression::Lz4), compressed),
+			Error::<Test>::DecompressedPageTooLarge
+		);
+		assert_ok!(upsert_compressed(
+			1,
+			PAGINATED_SCHEMA,
+			Some(PageCompression::Lz4),
+			compress_page_data_lz4(&vec![7u8; max_size as usize])
+		));
+	});
+}
+
+#[test]
+fn upsert_compressed_page_should_validate_decompressed_data() {
+	new_test_ext().execute_with(|| {
+		// An array with one block of two ints followed by the end of the array, and an array
+		// block that counts more items than there are bytes left
+		let conforming = compress_page_data_lz4(&[0x04, 0x02, 0x7f, 0x00]);
+		let non_conforming = compress_page_data_lz4(&[0x04, 0x02]);
+
+		assert_noop!(
+			upsert_compressed(
+				1,
+				PAGINATED_VALIDATED_SCHEMA,
+				Some(PageCompression::Lz4),
+				non_conforming
+			),
+			Error::<Test>::InvalidPayload
+		);
+		assert_ok!(upsert_compressed(
+			1,
+			PAGINATED_VALIDATED_SCHEMA,
+			Some(PageCompression::Lz4),
+			conforming
+		));
+	});
+}
+
+#[test]
+fn compressed_page_hash_should_cover_codec_and_raw_data() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(upsert_compressed(
+			1,
+			PAGINATED_SCHEMA,
+			Some(PageCompression::Zstd),
+			ZSTD_PAGE.to_vec()
+		));
+		let mut page = paginated_page(1, PAGINATED_INTENT, 0).unwrap();
+		let compressed_hash = page.get_hash();
+
+		page.page_version = PageVersion::V2;
+		assert_ne!(page.get_hash(), compressed_hash);
+		page.data = b"frequency ".repeat(40).try_into().unwrap();
+		page.page_version = PageVersion::V3(PageCompression::Zstd);
+		assert_ne!(page.get_hash(), compressed_hash);
+	});
+}
+
+#[test]
+fn get_paginated_storage_with_compression_should_return_raw_and_decompressed_data() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		assert_ok!(StatefulStoragePallet::apply_page_operations(
+			RuntimeOrigin::signed(test_public(msa_id)),
+			msa_id,
+			BoundedVec::try_from(vec![
+				PageOperation::UpsertPage {
+					schema_id: PAGINATED_SCHEMA,
+					page_id: 0,
+					target_hash: NONEXISTENT_PAGE_HASH,
+					compression: Some(PageCompression::Zstd),
+					payload: ZSTD_PAGE.to_vec().try_into().unwrap(),
+				},
+				PageOperation::UpsertPage {
+					schema_id: PAGINATED_SCHEMA,
+					page_id: 1,
+					target_hash: NONEXISTENT_PAGE_HASH,
+					compression: None,
+					payload: generate_payload_bytes::<PaginatedPageSize>(Some(1)),
+				},
+			])
+			.unwrap(),
+		));
+
+		let pages =
+			StatefulStoragePallet::get_paginated_storage_with_compression(msa_id, PAGINATED_INTENT)
+				.unwrap();
+
+		assert_eq!(pages.len(), 2);
+		let compressed = pages.iter().find(|page| page.page_id == 0).unwrap();
+		assert_eq!(compressed.compression, Some(PageCompression::Zstd));
+		assert_eq!(compressed.payload, ZSTD_PAGE.to_vec());
+		assert_eq!(compressed.decompressed_payload, Some(b"frequency ".repeat(40)));
+		assert_eq!(
+			compressed.content_hash,
+			paginated_page(msa_id, PAGINATED_INTENT, 0).unwrap().get_hash()
+		);
+		let uncompressed = pages.iter().find(|page| page.page_id == 1).unwrap();
+		assert_eq!(uncompressed.compression, None);
+		assert_eq!(uncompressed.decompressed_payload, None);
+	});
+}
@@ -0,0 +1,260 @@
+use crate::{
+	stateful_child_tree::StatefulChildTree,
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error, Event as StatefulEvent,
+};
+use common_primitives::{
+	schema::SchemaId,
+	stateful_storage::{PageHash, PageId},
+	utils::wrap_binary_data,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::Pair;
+use sp_runtime::{AccountId32, MultiSignature};
+
+fn add_item(schema_id: SchemaId, target_hash: PageHash) -> PageOperation<Test> {
+	PageOperation::ApplyItemActions {
+		schema_id,
+		target_hash,
+		actions: BoundedVec::try_from(vec![ItemAction::Add {
+			data: vec![1; 5].try_into().unwrap(),
+		}])
+		.unwrap(),
+	}
+}
+
+fn upsert(page_id: PageId, target_hash: PageHash) -> PageOperation<Test> {
+	PageOperation::UpsertPage {
+		schema_id: PAGINATED_SCHEMA,
+		page_id,
+		target_hash,
+		compression: None,
+		payload: generate_payload_bytes::<PaginatedPageSize>(Some(page_id as u8)),
+	}
+}
+
+fn operations(
+	operations: Vec<PageOperation<Test>>,
+) -> BoundedVec<PageOperation<Test>, <Test as Config>::MaxPageOperations> {
+	BoundedVec::try_from(operations).unwrap()
+}
+
+fn signed_payload(
+	operations_list: Vec<PageOperation<Test>>,
+) -> PageOperationsSignaturePayload<Test> {
+	PageOperationsSignaturePayload { operations: operations(operations_list), expiration: 10 }
+}
+
+#[test]
+fn apply_page_operations_should_write_all_pages() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+
+		assert_ok!(StatefulStoragePallet::apply_page_operations(
+			RuntimeOrigin::signed(caller),
+			msa_id,
+			operations(vec![
+				add_item(ITEMIZED_SCHEMA, NONEXISTENT_PAGE_HASH),
+				upsert(0, NONEXISTENT_PAGE_HASH),
+				upsert(1, NONEXISTENT_PAGE_HASH),
+			]),
+		));
+
+		assert!(itemized_page(msa_id, ITEMIZED_INTENT).is_some());
+		let page = paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap();
+		System::assert_has_event(
+			StatefulEvent::PaginatedPageUpdated {
+				msa_id,
+				intent_id: PAGINATED_INTENT,
+				page_id: 1,
+				prev_content_hash: NONEXISTENT_PAGE_HASH,
+				curr_content_hash: page.get_hash(),
+			}
+			.into(),
+		);
+		assert!(paginated_page(msa_id, PAGINATED_INTENT, 0).is_some());
+	});
+}
+
+#[test]
+fn apply_page_operations_should_delete_pages() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+		assert_ok!(StatefulStoragePallet::apply_page_operations(
+			RuntimeOrigin::signed(caller.clone()),
+			msa_id,
+			operations(vec![upsert(0, NONEXISTENT_PAGE_HASH)]),
+		));
+		let hash = paginated_page(msa_id, PAGINATED_INTENT, 0).unwrap().get_hash();
+
+		assert_ok!(StatefulStoragePallet::apply_page_operations(
+			RuntimeOrigin::signed(caller),
+			msa_id,
+			operations(vec![
+				PageOperation::DeletePage {
+					schema_id: PAGINATED_SCHEMA,
+					page_id: 0,
+					target_hash: hash
+				},
+				upsert(1, NONEXISTENT_PAGE_HASH),
+			]),
+		));
+
+		assert!(paginated_page(msa_id, PAGINATED_INTENT, 0).is_none());
+		assert!(paginated_page(msa_id, PAGINATED_INTENT, 1).is_some());
+	});
+}
+
+#[test]
+fn apply_page_operations_with_stale_hash_should_write_nothing() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_page_operations(
+				RuntimeOrigin::signed(caller),
+				msa_id,
+				operations(vec![
+					add_item(ITEMIZED_SCHEMA, NONEXISTENT_PAGE_HASH),
+					upsert(0, NONEXISTENT_PAGE_HASH),
+					upsert(1, 1234),
+				]),
+			),
+			Error::<Test>::StalePageState
+		);
+		assert!(itemized_page(msa_id, ITEMIZED_INTENT).is_none());
+		assert!(paginated_page(msa_id, PAGINATED_INTENT, 0).is_none());
+	});
+}
+
+#[test]
+fn apply_page_operations_without_grant_for_every_intent_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let provider = test_public(2);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_page_operations(
+				RuntimeOrigin::signed(provider),
+				msa_id,
+				operations(vec![
+					upsert(0, NONEXISTENT_PAGE_HASH),
+					add_item(UNDELEGATED_ITEMIZED_SCHEMA, NONEXISTENT_PAGE_HASH),
+				]),
+			),
+			Error::<Test>::UnauthorizedDelegate
+		);
+	});
+}
+
+#[test]
+fn apply_page_operations_by_provider_on_signature_required_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let provider = test_public(2);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_page_operations(
+				RuntimeOrigin::signed(provider),
+				msa_id,
+				operations(vec![add_item(
+					ITEMIZED_SIGNATURE_REQUIRED_SCHEMA,
+					NONEXISTENT_PAGE_HASH
+				)]),
+			),
+			Error::<Test>::UnsupportedOperationForSchema
+		);
+	});
+}
+
+#[test]
+fn apply_page_operations_with_signature_should_write_all_pages() {
+	new_test_ext().execute_with(|| {
+		let (msa_id, pair) = get_signature_account();
+		let delegator_key: AccountId32 = pair.public().into();
+		let payload = signed_payload(vec![
+			add_item(ITEMIZED_SIGNATURE_REQUIRED_SCHEMA, NONEXISTENT_PAGE_HASH),
+			upsert(0, NONEXISTENT_PAGE_HASH),
+		]);
+		let proof: MultiSignature = pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_ok!(StatefulStoragePallet::apply_page_operations_with_signature(
+			RuntimeOrigin::signed(test_public(1)),
+			delegator_key,
+			proof,
+			payload,
+		));
+
+		assert!(paginated_page(msa_id, PAGINATED_INTENT, 0).is_some());
+		System::assert_has_event(
+			StatefulEvent::ItemizedPageUpdated {
+				msa_id,
+				intent_id: ITEMIZED_SIGNATURE_REQUIRED_INTENT,
+				prev_content_hash: NONEXISTENT_PAGE_HASH,
+				curr_content_hash: <StatefulChildTree>::try_read::<_, ItemizedPage<Test>>(
+					&msa_id,
+					PALLET_STORAGE_PREFIX,
+					ITEMIZED_STORAGE_PREFIX,
+					&(ITEMIZED_SIGNATURE_REQUIRED_INTENT,),
+				)
+				.unwrap()
+				.unwrap()
+				.get_hash(),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn apply_page_operations_with_signature_over_other_operations_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (_, pair) = get_signature_account();
+		let delegator_key: AccountId32 = pair.public().into();
+		let payload = signed_payload(vec![upsert(0, NONEXISTENT_PAGE_HASH)]);
+		let proof: MultiSignature = pair.sign(&wrap_binary_data(payload.encode())).into();
+		let tampered = signed_payload(vec![
+			upsert(0, NONEXISTENT_PAGE_HASH),
+			upsert(1, NONEXISTENT_PAGE_HASH),
+		]);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_page_operations_with_signature(
+				RuntimeOrigin::signed(test_public(1)),
+				delegator_key,
+				proof,
+				tampered,
+			),
+			Error::<Test>::InvalidSignature
+		);
+	});
+}
+
+#[test]
+fn apply_page_operations_with_signature_with_expired_proof_should_fail() {
+	new_test_ext().execute_with(|| {
+		let (_, pair) = get_signature_account();
+		let delegator_key: AccountId32 = pair.public().into();
+		let payload = signed_payload(vec![upsert(0, NONEXISTENT_PAGE_HASH)]);
+		let proof: MultiSignature = pair.sign(&wrap_binary_data(payload.encode())).into();
+		System::set_block_number(payload.expiration);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_page_operations_with_signature(
+				RuntimeOrigin::signed(test_public(1)),
+				delegator_key,
+				proof,
+				payload,
+			),
+			Error::<Test>::ProofHasExpired
+		);
+	});
+}
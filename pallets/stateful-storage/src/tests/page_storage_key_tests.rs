@@ -0,0 +1,103 @@
+use crate::{
+	stateful_child_tree::StatefulChildTree,
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error,
+};
+use common_primitives::stateful_storage::PageId;
+use frame_support::{
+	assert_err,
+	storage::child::{self, ChildInfo},
+};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::Get;
+
+#[test]
+fn get_page_storage_key_for_itemized_page_should_locate_the_page() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let page = create_itemized_page_from::<Test>(None, &[generate_payload_bytes(None)]);
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&(ITEMIZED_INTENT,),
+			&page,
+		);
+
+		// act
+		let storage_key =
+			StatefulStoragePallet::get_page_storage_key(msa_id, ITEMIZED_INTENT, None).unwrap();
+
+		// assert
+		let child_info = ChildInfo::new_default(&storage_key.child_trie_key);
+		assert_eq!(child::get_raw(&child_info, &storage_key.page_key), Some(page.encode()));
+	});
+}
+
+#[test]
+fn get_page_storage_key_for_paginated_page_should_locate_the_page() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let page_id: PageId = 1;
+		let page: PaginatedPage<Test> = generate_payload_bytes::<PaginatedPageSize>(None).into();
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&(PAGINATED_INTENT, page_id),
+			&page,
+		);
+
+		// act
+		let storage_key =
+			StatefulStoragePallet::get_page_storage_key(msa_id, PAGINATED_INTENT, Some(page_id))
+				.unwrap();
+		let other_page_key = StatefulStoragePallet::get_page_storage_key(
+			msa_id,
+			PAGINATED_INTENT,
+			Some(page_id + 1),
+		)
+		.unwrap();
+
+		// assert
+		let child_info = ChildInfo::new_default(&storage_key.child_trie_key);
+		assert_eq!(child::get_raw(&child_info, &storage_key.page_key), Some(page.encode()));
+		assert_eq!(child::get_raw(&child_info, &other_page_key.page_key), None);
+	});
+}
+
+#[test]
+fn get_page_storage_key_with_mismatched_page_id_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_err!(
+			StatefulStoragePallet::get_page_storage_key(1, ITEMIZED_INTENT, Some(0)),
+			Error::<Test>::PayloadLocationMismatch
+		);
+		assert_err!(
+			StatefulStoragePallet::get_page_storage_key(1, PAGINATED_INTENT, None),
+			Error::<Test>::PayloadLocationMismatch
+		);
+		assert_err!(
+			StatefulStoragePallet::get_page_storage_key(1, INVALID_INTENT_ID, None),
+			Error::<Test>::InvalidIntentId
+		);
+	});
+}
+
+#[test]
+fn get_page_storage_key_with_page_id_out_of_bounds_should_fail() {
+	new_test_ext().execute_with(|| {
+		let page_id: PageId = <Test as Config>::MaxPaginatedPageId::get() + 1;
+
+		assert_err!(
+			StatefulStoragePallet::get_page_storage_key(1, PAGINATED_INTENT, Some(page_id)),
+			Error::<Test>::PageIdExceedsMaxAllowed
+		);
+	});
+}
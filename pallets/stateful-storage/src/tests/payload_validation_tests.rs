@@ -0,0 +1,162 @@
+use crate::{
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error,
+};
+use common_primitives::{stateful_storage::PageHash, utils::wrap_binary_data};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_core::Pair;
+use sp_runtime::MultiSignature;
+
+/// An array with one block of two ints followed by the end of the array
+const CONFORMING_PAYLOAD: [u8; 4] = [0x04, 0x02, 0x7f, 0x00];
+/// An array block that counts more items than there are bytes left
+const NON_CONFORMING_PAYLOAD: [u8; 2] = [0x04, 0x02];
+
+fn add_actions(
+	payloads: &[&[u8]],
+) -> BoundedVec<
+	ItemAction<<Test as Config>::MaxItemizedBlobSizeBytes>,
+	<Test as Config>::MaxItemizedActionsCount,
+> {
+	payloads
+		.iter()
+		.map(|payload| ItemAction::Add { data: payload.to_vec().try_into().unwrap() })
+		.collect::<Vec<_>>()
+		.try_into()
+		.unwrap()
+}
+
+#[test]
+fn apply_item_actions_with_conforming_payloads_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			ITEMIZED_VALIDATED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			add_actions(&[&CONFORMING_PAYLOAD, &[0x00]]),
+		));
+	});
+}
+
+#[test]
+fn apply_item_actions_with_any_non_conforming_payload_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+
+		assert_noop!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				ITEMIZED_VALIDATED_SCHEMA,
+				NONEXISTENT_PAGE_HASH,
+				add_actions(&[&CONFORMING_PAYLOAD, &NON_CONFORMING_PAYLOAD]),
+			),
+			Error::<Test>::InvalidPayload
+		);
+	});
+}
+
+#[test]
+fn apply_item_actions_without_validate_payload_setting_should_not_check_payloads() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			add_actions(&[&NON_CONFORMING_PAYLOAD]),
+		));
+	});
+}
+
+#[test]
+fn apply_item_actions_with_signature_v2_with_non_conforming_payload_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = test_public(1);
+		let (_, pair) = get_signature_account();
+		let payload = ItemizedSignaturePayloadV2 {
+			actions: add_actions(&[&NON_CONFORMING_PAYLOAD]),
+			target_hash: PageHash::default(),
+			expiration: 10,
+			schema_id: ITEMIZED_VALIDATED_SCHEMA,
+		};
+		let owner_signature: MultiSignature = pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			StatefulStoragePallet::apply_item_actions_with_signature_v2(
+				RuntimeOrigin::signed(caller_1),
+				pair.public().into(),
+				owner_signature,
+				payload
+			),
+			Error::<Test>::InvalidPayload
+		);
+	});
+}
+
+#[test]
+fn upsert_page_should_validate_payload() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+
+		assert_noop!(
+			StatefulStoragePallet::upsert_page(
+				RuntimeOrigin::signed(caller_1.clone()),
+				msa_id,
+				PAGINATED_VALIDATED_SCHEMA,
+				1,
+				NONEXISTENT_PAGE_HASH,
+				NON_CONFORMING_PAYLOAD.to_vec().try_into().unwrap(),
+			),
+			Error::<Test>::InvalidPayload
+		);
+		assert_ok!(StatefulStoragePallet::upsert_page(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			PAGINATED_VALIDATED_SCHEMA,
+			1,
+			NONEXISTENT_PAGE_HASH,
+			CONFORMING_PAYLOAD.to_vec().try_into().unwrap(),
+		));
+	});
+}
+
+#[test]
+fn upsert_page_with_signature_v2_with_non_conforming_payload_should_fail() {
+	new_test_ext().execute_with(|| {
+		let caller_1 = test_public(1);
+		let (_, pair) = get_signature_account();
+		let payload = PaginatedUpsertSignaturePayloadV2 {
+			payload: NON_CONFORMING_PAYLOAD.to_vec().try_into().unwrap(),
+			target_hash: PageHash::default(),
+			expiration: 10,
+			schema_id: PAGINATED_VALIDATED_SCHEMA,
+			page_id: 1,
+		};
+		let owner_signature: MultiSignature = pair.sign(&wrap_binary_data(payload.encode())).into();
+
+		assert_noop!(
+			StatefulStoragePallet::upsert_page_with_signature_v2(
+				RuntimeOrigin::signed(caller_1),
+				pair.public().into(),
+				owner_signature,
+				payload
+			),
+			Error::<Test>::InvalidPayload
+		);
+	});
+}
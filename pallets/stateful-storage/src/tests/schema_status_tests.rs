@@ -0,0 +1,184 @@
+use crate::{
+	stateful_child_tree::StatefulChildTree,
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Config, Error, Event as StatefulEvent,
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+
+#[test]
+fn apply_item_actions_with_deprecated_schema_should_emit_warning() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let actions = vec![ItemAction::Add { data: vec![1; 5].try_into().unwrap() }];
+
+		// act
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			ITEMIZED_DEPRECATED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			BoundedVec::try_from(actions).unwrap(),
+		));
+
+		// assert
+		System::assert_has_event(
+			StatefulEvent::DeprecatedSchemaUsed { schema_id: ITEMIZED_DEPRECATED_SCHEMA }.into(),
+		);
+	});
+}
+
+#[test]
+fn apply_item_actions_add_with_unsupported_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let actions = vec![ItemAction::Add { data: vec![1; 5].try_into().unwrap() }];
+
+		// act
+		assert_noop!(
+			StatefulStoragePallet::apply_item_actions(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				ITEMIZED_UNSUPPORTED_SCHEMA,
+				NONEXISTENT_PAGE_HASH,
+				BoundedVec::try_from(actions).unwrap(),
+			),
+			Error::<Test>::SchemaUnsupported
+		);
+	});
+}
+
+#[test]
+fn apply_item_actions_delete_with_unsupported_schema_should_succeed() {
+	new_test_ext().execute_with(|| {
+		// arrange
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let keys = (ITEMIZED_INTENT,);
+		let add_actions = vec![ItemAction::Add { data: vec![1; 5].try_into().unwrap() }];
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1.clone()),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			BoundedVec::try_from(add_actions).unwrap(),
+		));
+		let page: ItemizedPage<Test> = StatefulChildTree::<<Test as Config>::KeyHasher>::try_read(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			ITEMIZED_STORAGE_PREFIX,
+			&keys,
+		)
+		.unwrap()
+		.expect("page should exist");
+
+		// act
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			ITEMIZED_UNSUPPORTED_SCHEMA,
+			page.get_hash(),
+			BoundedVec::try_from(vec![ItemAction::Delete { index: 0 }]).unwrap(),
+		));
+
+		// assert
+		let page: Option<ItemizedPage<Test>> =
+			StatefulChildTree::<<Test as Config>::KeyHasher>::try_read(
+				&msa_id,
+				PALLET_STORAGE_PREFIX,
+				ITEMIZED_STORAGE_PREFIX,
+				&keys,
+			)
+			.unwrap();
+		assert_eq!(page, None);
+	});
+}
+
+#[test]
+fn upsert_page_with_unsupported_schema_should_fail() {
+	new_test_ext().execute_with(|| {
+		// setup
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let payload = generate_payload_bytes::<PaginatedPageSize>(Some(100));
+
+		assert_noop!(
+			StatefulStoragePallet::upsert_page(
+				RuntimeOrigin::signed(caller_1),
+				msa_id,
+				PAGINATED_UNSUPPORTED_SCHEMA,
+				1,
+				NONEXISTENT_PAGE_HASH,
+				payload
+			),
+			Error::<Test>::SchemaUnsupported
+		);
+	})
+}
+
+#[test]
+fn upsert_page_with_deprecated_schema_should_emit_warning() {
+	new_test_ext().execute_with(|| {
+		// setup
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let payload = generate_payload_bytes::<PaginatedPageSize>(Some(100));
+
+		assert_ok!(StatefulStoragePallet::upsert_page(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			PAGINATED_DEPRECATED_SCHEMA,
+			1,
+			NONEXISTENT_PAGE_HASH,
+			payload
+		));
+
+		System::assert_has_event(
+			StatefulEvent::DeprecatedSchemaUsed { schema_id: PAGINATED_DEPRECATED_SCHEMA }.into(),
+		);
+	})
+}
+
+#[test]
+fn delete_page_with_unsupported_schema_should_succeed() {
+	new_test_ext().execute_with(|| {
+		// setup
+		let msa_id = 1;
+		let caller_1 = test_public(msa_id);
+		let page_id = 11;
+		let page: PaginatedPage<Test> = generate_page(Some(PAGINATED_SCHEMA), None, None);
+		let page_hash = page.get_hash();
+		let keys = (PAGINATED_INTENT, page_id);
+		<StatefulChildTree>::write(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&keys,
+			&page,
+		);
+
+		assert_ok!(StatefulStoragePallet::delete_page(
+			RuntimeOrigin::signed(caller_1),
+			msa_id,
+			PAGINATED_UNSUPPORTED_SCHEMA,
+			page_id,
+			page_hash
+		));
+
+		let page: Option<PaginatedPage<Test>> = <StatefulChildTree>::try_read(
+			&msa_id,
+			PALLET_STORAGE_PREFIX,
+			PAGINATED_STORAGE_PREFIX,
+			&keys,
+		)
+		.unwrap();
+		assert_eq!(page, None);
+	})
+}
@@ -0,0 +1,300 @@
+use crate::{
+	pallet::{PageDeposits, StorageByteDeposit},
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Error, Event as StatefulEvent, HoldReason,
+};
+use common_primitives::{
+	msa::MessageSourceId,
+	stateful_storage::{PageHash, PageId},
+};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::fungible::{InspectHold, Mutate},
+	BoundedVec,
+};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+use sp_runtime::{AccountId32, DispatchError::BadOrigin};
+
+const DEPOSIT_PER_BYTE: u64 = 2;
+
+fn set_deposit_per_byte(deposit_per_byte: u64) {
+	assert_ok!(StatefulStoragePallet::set_storage_byte_deposit(
+		RuntimeOrigin::root(),
+		deposit_per_byte
+	));
+}
+
+fn funded_account(msa_id: MessageSourceId, amount: u64) -> AccountId32 {
+	let account = test_public(msa_id);
+	assert_ok!(Balances::set_balance(&account, amount));
+	account
+}
+
+fn held_deposit(account: &AccountId32) -> u64 {
+	Balances::balance_on_hold(&HoldReason::PageStorageDeposit.into(), account)
+}
+
+fn upsert(
+	caller: &AccountId32,
+	msa_id: MessageSourceId,
+	page_id: PageId,
+	target_hash: PageHash,
+	size: usize,
+) -> PageHash {
+	assert_ok!(StatefulStoragePallet::upsert_page(
+		RuntimeOrigin::signed(caller.clone()),
+		msa_id,
+		PAGINATED_SCHEMA,
+		page_id,
+		target_hash,
+		vec![1; size].try_into().unwrap(),
+	));
+	paginated_page(msa_id, PAGINATED_INTENT, page_id).unwrap().get_hash()
+}
+
+#[test]
+fn set_storage_byte_deposit_should_update_price() {
+	new_test_ext().execute_with(|| {
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		assert_eq!(StorageByteDeposit::<Test>::get(), DEPOSIT_PER_BYTE);
+		System::assert_last_event(
+			StatefulEvent::StorageByteDepositUpdated { deposit_per_byte: DEPOSIT_PER_BYTE }.into(),
+		);
+	});
+}
+
+#[test]
+fn set_storage_byte_deposit_without_governance_should_fail() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			StatefulStoragePallet::set_storage_byte_deposit(
+				RuntimeOrigin::signed(test_public(1)),
+				DEPOSIT_PER_BYTE
+			),
+			BadOrigin
+		);
+	});
+}
+
+#[test]
+fn upsert_page_should_hold_deposit_for_page_bytes() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = funded_account(msa_id, 10_000);
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+
+		let amount = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+		assert_eq!(held_deposit(&caller), amount);
+		assert_eq!(
+			PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))),
+			Some(PageDeposit { source: DepositSource::Account(caller.clone()), amount })
+		);
+		System::assert_has_event(
+			StatefulEvent::PageDepositCharged {
+				msa_id,
+				intent_id: PAGINATED_INTENT,
+				page_id: Some(1),
+				source: DepositSource::Account(caller),
+				amount,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn upsert_page_should_adjust_deposit_to_new_page_size() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = funded_account(msa_id, 10_000);
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		let hash = upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+		let grown_hash = upsert(&caller, msa_id, 1, hash, 300);
+		let grown = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+		assert_eq!(held_deposit(&caller), grown);
+
+		upsert(&caller, msa_id, 1, grown_hash, 10);
+		let shrunk = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+		assert!(shrunk < grown);
+		assert_eq!(held_deposit(&caller), shrunk);
+	});
+}
+
+#[test]
+fn delete_page_should_release_deposit() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = funded_account(msa_id, 10_000);
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+		let hash = upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+		let amount = held_deposit(&caller);
+
+		assert_ok!(StatefulStoragePallet::delete_page(
+			RuntimeOrigin::signed(caller.clone()),
+			msa_id,
+			PAGINATED_SCHEMA,
+			1,
+			hash,
+		));
+
+		assert_eq!(held_deposit(&caller), 0);
+		assert_eq!(Balances::free_balance(&caller), 10_000);
+		assert!(PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))).is_none());
+		System::assert_has_event(
+			StatefulEvent::PageDepositReleased {
+				msa_id,
+				intent_id: PAGINATED_INTENT,
+				page_id: Some(1),
+				source: DepositSource::Account(caller),
+				amount,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn removing_items_should_release_deposit() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = funded_account(msa_id, 10_000);
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+		let add = |size: usize| ItemAction::Add { data: vec![1; size].try_into().unwrap() };
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller.clone()),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			BoundedVec::try_from(vec![add(20), add(30)]).unwrap(),
+		));
+		let page = itemized_page(msa_id, ITEMIZED_INTENT).unwrap();
+		assert_eq!(held_deposit(&caller), DEPOSIT_PER_BYTE * page.encoded_size() as u64);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller.clone()),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			page.get_hash(),
+			BoundedVec::try_from(vec![ItemAction::Delete { index: 1 }]).unwrap(),
+		));
+		let page = itemized_page(msa_id, ITEMIZED_INTENT).unwrap();
+		assert_eq!(held_deposit(&caller), DEPOSIT_PER_BYTE * page.encoded_size() as u64);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(caller.clone()),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			page.get_hash(),
+			BoundedVec::try_from(vec![ItemAction::Delete { index: 0 }]).unwrap(),
+		));
+		assert_eq!(held_deposit(&caller), 0);
+		assert!(PageDeposits::<Test>::get((msa_id, ITEMIZED_INTENT, None::<PageId>)).is_none());
+	});
+}
+
+#[test]
+fn upsert_page_without_balance_should_draw_deposit_from_capacity() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+		CapacityBalances::mutate(|balances| balances.insert(msa_id, 10_000));
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+
+		let amount = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+		assert_eq!(CapacityBalances::get().get(&msa_id), Some(&(10_000 - amount)));
+		assert_eq!(
+			PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))),
+			Some(PageDeposit { source: DepositSource::Capacity(msa_id), amount })
+		);
+	});
+}
+
+#[test]
+fn rewriting_page_paid_with_capacity_should_only_charge_size_increase() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+		CapacityBalances::mutate(|balances| balances.insert(msa_id, 10_000));
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		let hash = upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+		let paid = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+
+		// shrinking the page is covered by the Capacity already paid
+		let hash = upsert(&caller, msa_id, 1, hash, 50);
+		assert_eq!(CapacityBalances::get().get(&msa_id), Some(&(10_000 - paid)));
+		assert_eq!(
+			PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))),
+			Some(PageDeposit { source: DepositSource::Capacity(msa_id), amount: paid })
+		);
+
+		// growing the page only draws the increase
+		upsert(&caller, msa_id, 1, hash, 150);
+		let amount = DEPOSIT_PER_BYTE *
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().encoded_size() as u64;
+		assert_eq!(CapacityBalances::get().get(&msa_id), Some(&(10_000 - amount)));
+		assert_eq!(
+			PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))),
+			Some(PageDeposit { source: DepositSource::Capacity(msa_id), amount })
+		);
+		System::assert_last_event(
+			StatefulEvent::PageDepositCharged {
+				msa_id,
+				intent_id: PAGINATED_INTENT,
+				page_id: Some(1),
+				source: DepositSource::Capacity(msa_id),
+				amount: amount - paid,
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn upsert_page_without_balance_or_capacity_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+		set_deposit_per_byte(DEPOSIT_PER_BYTE);
+
+		assert_noop!(
+			StatefulStoragePallet::upsert_page(
+				RuntimeOrigin::signed(caller),
+				msa_id,
+				PAGINATED_SCHEMA,
+				1,
+				NONEXISTENT_PAGE_HASH,
+				vec![1; 100].try_into().unwrap(),
+			),
+			Error::<Test>::InsufficientStorageDeposit
+		);
+	});
+}
+
+#[test]
+fn upsert_page_without_deposit_price_should_not_charge_deposit() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = test_public(msa_id);
+
+		upsert(&caller, msa_id, 1, NONEXISTENT_PAGE_HASH, 100);
+
+		assert!(PageDeposits::<Test>::get((msa_id, PAGINATED_INTENT, Some(1))).is_none());
+	});
+}
@@ -0,0 +1,198 @@
+use crate::{
+	test_common::{constants::*, test_utility::*},
+	tests::mock::*,
+	types::*,
+	Error, StorageUsages,
+};
+use common_primitives::{
+	msa::MessageSourceId,
+	schema::StorageQuota,
+	stateful_storage::{IntentStorageUsage, PageId, StorageUsageResponse},
+};
+use frame_support::{assert_noop, assert_ok, BoundedVec};
+use parity_scale_codec::Encode;
+#[allow(unused_imports)]
+use pretty_assertions::{assert_eq, assert_ne, assert_str_eq};
+
+fn upsert(msa_id: MessageSourceId, page_id: PageId, size: usize) -> sp_runtime::DispatchResult {
+	let target_hash = paginated_page(msa_id, PAGINATED_INTENT, page_id)
+		.map(|page| page.get_hash())
+		.unwrap_or(NONEXISTENT_PAGE_HASH);
+	StatefulStoragePallet::upsert_page(
+		RuntimeOrigin::signed(test_public(msa_id)),
+		msa_id,
+		PAGINATED_SCHEMA,
+		page_id,
+		target_hash,
+		vec![page_id as u8; size].try_into().unwrap(),
+	)
+}
+
+fn set_quota(max_bytes: u32, max_pages: u32) {
+	IntentStorageQuotas::mutate(|quotas| {
+		quotas.insert(PAGINATED_INTENT, StorageQuota { max_bytes, max_pages });
+	});
+}
+
+#[test]
+fn paginated_writes_should_update_storage_usage() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		assert_ok!(upsert(msa_id, 0, 10));
+		assert_ok!(upsert(msa_id, 1, 20));
+		let page_bytes = |page_id| {
+			paginated_page(msa_id, PAGINATED_INTENT, page_id).unwrap().encoded_size() as u32
+		};
+		assert_eq!(
+			StorageUsages::<Test>::get(msa_id, PAGINATED_INTENT),
+			IntentStorageUsage { bytes: page_bytes(0) + page_bytes(1), pages: 2 }
+		);
+
+		// Replacing a page only changes the bytes
+		assert_ok!(upsert(msa_id, 1, 5));
+		assert_eq!(
+			StorageUsages::<Test>::get(msa_id, PAGINATED_INTENT),
+			IntentStorageUsage { bytes: page_bytes(0) + page_bytes(1), pages: 2 }
+		);
+
+		for page_id in [0, 1] {
+			assert_ok!(StatefulStoragePallet::delete_page(
+				RuntimeOrigin::signed(test_public(msa_id)),
+				msa_id,
+				PAGINATED_SCHEMA,
+				page_id,
+				paginated_page(msa_id, PAGINATED_INTENT, page_id).unwrap().get_hash(),
+			));
+		}
+		assert!(!StorageUsages::<Test>::contains_key(msa_id, PAGINATED_INTENT));
+	});
+}
+
+#[test]
+fn itemized_writes_should_update_storage_usage() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		let caller = RuntimeOrigin::signed(test_public(msa_id));
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			caller.clone(),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			BoundedVec::try_from(vec![
+				ItemAction::Add { data: vec![1; 5].try_into().unwrap() },
+				ItemAction::Add { data: vec![2; 8].try_into().unwrap() },
+			])
+			.unwrap(),
+		));
+		let page = itemized_page(msa_id, ITEMIZED_INTENT).unwrap();
+		assert_eq!(
+			StorageUsages::<Test>::get(msa_id, ITEMIZED_INTENT),
+			IntentStorageUsage { bytes: page.encoded_size() as u32, pages: 1 }
+		);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			caller.clone(),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			page.get_hash(),
+			BoundedVec::try_from(vec![ItemAction::Delete { index: 0 }]).unwrap(),
+		));
+		let page = itemized_page(msa_id, ITEMIZED_INTENT).unwrap();
+		assert_eq!(
+			StorageUsages::<Test>::get(msa_id, ITEMIZED_INTENT),
+			IntentStorageUsage { bytes: page.encoded_size() as u32, pages: 1 }
+		);
+
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			caller,
+			msa_id,
+			ITEMIZED_SCHEMA,
+			page.get_hash(),
+			BoundedVec::try_from(vec![ItemAction::Delete { index: 0 }]).unwrap(),
+		));
+		assert!(!StorageUsages::<Test>::contains_key(msa_id, ITEMIZED_INTENT));
+	});
+}
+
+#[test]
+fn write_exceeding_page_quota_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		set_quota(u32::MAX, 1);
+		assert_ok!(upsert(msa_id, 0, 10));
+
+		assert_noop!(upsert(msa_id, 1, 10), Error::<Test>::StorageQuotaExceeded);
+		assert_ok!(upsert(msa_id, 0, 20));
+	});
+}
+
+#[test]
+fn write_exceeding_byte_quota_should_fail() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		assert_ok!(upsert(msa_id, 0, 10));
+		let page_bytes = paginated_page(msa_id, PAGINATED_INTENT, 0).unwrap().encoded_size() as u32;
+		set_quota(page_bytes + 5, 10);
+
+		assert_noop!(upsert(msa_id, 0, 16), Error::<Test>::StorageQuotaExceeded);
+		assert_ok!(upsert(msa_id, 0, 15));
+	});
+}
+
+#[test]
+fn shrinking_storage_over_quota_should_succeed() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		assert_ok!(upsert(msa_id, 0, 50));
+		assert_ok!(upsert(msa_id, 1, 50));
+		set_quota(10, 1);
+
+		assert_noop!(upsert(msa_id, 2, 1), Error::<Test>::StorageQuotaExceeded);
+		assert_ok!(upsert(msa_id, 0, 20));
+		assert_ok!(StatefulStoragePallet::delete_page(
+			RuntimeOrigin::signed(test_public(msa_id)),
+			msa_id,
+			PAGINATED_SCHEMA,
+			1,
+			paginated_page(msa_id, PAGINATED_INTENT, 1).unwrap().get_hash(),
+		));
+		assert_eq!(StorageUsages::<Test>::get(msa_id, PAGINATED_INTENT).pages, 1);
+	});
+}
+
+#[test]
+fn get_storage_usage_should_return_usage_and_quota_of_each_intent() {
+	new_test_ext().execute_with(|| {
+		let msa_id = 1;
+		set_quota(4096, 8);
+		assert_ok!(upsert(msa_id, 3, 10));
+		assert_ok!(StatefulStoragePallet::apply_item_actions(
+			RuntimeOrigin::signed(test_public(msa_id)),
+			msa_id,
+			ITEMIZED_SCHEMA,
+			NONEXISTENT_PAGE_HASH,
+			BoundedVec::try_from(vec![ItemAction::Add { data: vec![1; 5].try_into().unwrap() }])
+				.unwrap(),
+		));
+
+		let mut usage = StatefulStoragePallet::get_storage_usage(msa_id);
+		usage.sort_by_key(|response| response.intent_id);
+		let mut expected = vec![
+			StorageUsageResponse {
+				intent_id: ITEMIZED_INTENT,
+				bytes: itemized_page(msa_id, ITEMIZED_INTENT).unwrap().encoded_size() as u32,
+				pages: 1,
+				quota: None,
+			},
+			StorageUsageResponse {
+				intent_id: PAGINATED_INTENT,
+				bytes: paginated_page(msa_id, PAGINATED_INTENT, 3).unwrap().encoded_size() as u32,
+				pages: 1,
+				quota: Some(StorageQuota { max_bytes: 4096, max_pages: 8 }),
+			},
+		];
+		expected.sort_by_key(|response| response.intent_id);
+		assert_eq!(usage, expected);
+		assert_eq!(StatefulStoragePallet::get_storage_usage(msa_id + 1), vec![]);
+	});
+}
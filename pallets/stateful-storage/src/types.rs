@@ -1,10 +1,11 @@
 //! Types for the Stateful Storage Pallet
 use crate::Config;
 use common_primitives::{
+	msa::MessageSourceId,
 	node::EIP712Encode,
 	schema::{IntentId, SchemaId},
 	signatures::get_eip712_encoding_prefix,
-	stateful_storage::{PageHash, PageId, PageNonce},
+	stateful_storage::{PageCompression, PageHash, PageId, PageNonce},
 	utils::to_abi_compatible_number,
 };
 use frame_support::pallet_prelude::*;
@@ -20,12 +21,12 @@ use core::{
 	fmt::{Debug, Formatter},
 	hash::{Hash, Hasher},
 };
-use frame_support::traits::Len;
+use frame_support::traits::{tokens::fungible::Inspect as InspectFungible, Len};
 use sp_core::U256;
 use twox_hash::XxHash64;
 
 /// Current storage version of the pallet.
-pub const STATEFUL_STORAGE_VERSION: StorageVersion = StorageVersion::new(2);
+pub const STATEFUL_STORAGE_VERSION: StorageVersion = StorageVersion::new(3);
 /// pallet storage prefix
 pub const PALLET_STORAGE_PREFIX: &[u8] = b"stateful-storage";
 /// itemized storage prefix
@@ -44,6 +45,31 @@ pub type ItemizedPage<T> = Page<<T as Config>::MaxItemizedPageSizeBytes>;
 /// Paginated Page type
 pub type PaginatedPage<T> = Page<<T as Config>::MaxPaginatedPageSizeBytes>;
 
+/// Balance type of the currency page storage deposits are held in
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as InspectFungible<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// Where the storage deposit of a page was charged from
+#[derive(
+	Clone, Encode, Decode, DecodeWithMemTracking, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen,
+)]
+pub enum DepositSource<AccountId> {
+	/// Held from the balance of an account
+	Account(AccountId),
+	/// Drawn from the Capacity of an MSA
+	Capacity(MessageSourceId),
+}
+
+/// A storage deposit charged for a page
+#[derive(Clone, Encode, Decode, PartialEq, Debug, TypeInfo, Eq, MaxEncodedLen)]
+#[scale_info(skip_type_params(T))]
+pub struct PageDeposit<T: Config> {
+	/// Where the deposit was charged from
+	pub source: DepositSource<T::AccountId>,
+	/// The amount charged
+	pub amount: BalanceOf<T>,
+}
+
 /// Operations on Itemized storage
 pub trait ItemizedOperations<T: Config> {
 	/// Applies all actions to specified page and returns the updated page
@@ -73,6 +99,13 @@ pub enum ItemAction<DataSize: Get<u32> + Clone + core::fmt::Debug + PartialEq> {
 		/// Index (0+) to delete
 		index: u16,
 	},
+	/// Replacing the data of an existing item in place, keeping its index. Index number starts from 0
+	Update {
+		/// Index (0+) to update
+		index: u16,
+		/// The data replacing the existing item data
+		data: BoundedVec<u8, DataSize>,
+	},
 }
 
 /// This header is used to specify the byte size of an item stored inside the buffer
@@ -167,14 +200,6 @@ impl<T: Config> EIP712Encode for ItemizedSignaturePayloadV2<T> {
 			// signed payload
 			static ref MAIN_TYPE_HASH: [u8; 32] =
 				sp_io::hashing::keccak_256(b"ItemizedSignaturePayloadV2(uint16 schemaId,uint32 targetHash,uint32 expiration,ItemAction[] actions)ItemAction(string actionType,bytes data,uint16 index)");
-
-			static ref SUB_TYPE_HASH: [u8; 32] =
-				sp_io::hashing::keccak_256(b"ItemAction(string actionType,bytes data,uint16 index)");
-
-			static ref ITEM_ACTION_ADD: [u8; 32] = sp_io::hashing::keccak_256(b"Add");
-			static ref ITEM_ACTION_DELETE: [u8; 32] = sp_io::hashing::keccak_256(b"Delete");
-
-			static ref EMPTY_BYTES_HASH: [u8; 32] = sp_io::hashing::keccak_256([].as_slice());
 		}
 		// get prefix and domain separator
 		let prefix_domain_separator: Box<[u8]> =
@@ -183,33 +208,7 @@ impl<T: Config> EIP712Encode for ItemizedSignaturePayloadV2<T> {
 		let coded_target_hash = to_abi_compatible_number(self.target_hash);
 		let expiration: U256 = self.expiration.into();
 		let coded_expiration = to_abi_compatible_number(expiration.as_u128());
-		let coded_actions = {
-			let values: Vec<u8> = self
-				.actions
-				.iter()
-				.flat_map(|a| match a {
-					ItemAction::Add { data } => sp_io::hashing::keccak_256(
-						&[
-							SUB_TYPE_HASH.as_slice(),
-							ITEM_ACTION_ADD.as_slice(),
-							&sp_io::hashing::keccak_256(data.as_slice()),
-							[0u8; 32].as_slice(),
-						]
-						.concat(),
-					),
-					ItemAction::Delete { index } => sp_io::hashing::keccak_256(
-						&[
-							SUB_TYPE_HASH.as_slice(),
-							ITEM_ACTION_DELETE.as_slice(),
-							EMPTY_BYTES_HASH.as_slice(),
-							to_abi_compatible_number(*index).as_slice(),
-						]
-						.concat(),
-					),
-				})
-				.collect();
-			sp_io::hashing::keccak_256(&values)
-		};
+		let coded_actions = item_actions_eip_712_hash(&self.actions);
 		let message = sp_io::hashing::keccak_256(
 			&[
 				MAIN_TYPE_HASH.as_slice(),
@@ -225,6 +224,53 @@ impl<T: Config> EIP712Encode for ItemizedSignaturePayloadV2<T> {
 	}
 }
 
+/// EIP-712 hash of an `ItemAction[]` array member
+fn item_actions_eip_712_hash<DataSize: Get<u32> + Clone + Debug + PartialEq>(
+	actions: &[ItemAction<DataSize>],
+) -> [u8; 32] {
+	lazy_static! {
+		static ref SUB_TYPE_HASH: [u8; 32] =
+			sp_io::hashing::keccak_256(b"ItemAction(string actionType,bytes data,uint16 index)");
+		static ref ITEM_ACTION_ADD: [u8; 32] = sp_io::hashing::keccak_256(b"Add");
+		static ref ITEM_ACTION_DELETE: [u8; 32] = sp_io::hashing::keccak_256(b"Delete");
+		static ref ITEM_ACTION_UPDATE: [u8; 32] = sp_io::hashing::keccak_256(b"Update");
+		static ref EMPTY_BYTES_HASH: [u8; 32] = sp_io::hashing::keccak_256([].as_slice());
+	}
+	let values: Vec<u8> = actions
+		.iter()
+		.flat_map(|a| match a {
+			ItemAction::Add { data } => sp_io::hashing::keccak_256(
+				&[
+					SUB_TYPE_HASH.as_slice(),
+					ITEM_ACTION_ADD.as_slice(),
+					&sp_io::hashing::keccak_256(data.as_slice()),
+					[0u8; 32].as_slice(),
+				]
+				.concat(),
+			),
+			ItemAction::Delete { index } => sp_io::hashing::keccak_256(
+				&[
+					SUB_TYPE_HASH.as_slice(),
+					ITEM_ACTION_DELETE.as_slice(),
+					EMPTY_BYTES_HASH.as_slice(),
+					to_abi_compatible_number(*index).as_slice(),
+				]
+				.concat(),
+			),
+			ItemAction::Update { index, data } => sp_io::hashing::keccak_256(
+				&[
+					SUB_TYPE_HASH.as_slice(),
+					ITEM_ACTION_UPDATE.as_slice(),
+					&sp_io::hashing::keccak_256(data.as_slice()),
+					to_abi_compatible_number(*index).as_slice(),
+				]
+				.concat(),
+			),
+		})
+		.collect();
+	sp_io::hashing::keccak_256(&values)
+}
+
 // REMOVED PaginatedSignaturePayload
 
 /// Payload containing all necessary fields to verify signatures to upsert a Paginated storage
@@ -411,6 +457,321 @@ impl<T: Config> EIP712Encode for PaginatedDeleteSignaturePayloadV3<T> {
 	}
 }
 
+/// A write to a single page, applied together with the other operations of a batch
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialEq,
+	RuntimeDebugNoBound,
+	Clone,
+)]
+#[scale_info(skip_type_params(T))]
+pub enum PageOperation<T: Config> {
+	/// Applies actions to the Itemized page of the Schema's Intent
+	ApplyItemActions {
+		/// Schema id of this storage
+		#[codec(compact)]
+		schema_id: SchemaId,
+		/// Hash of targeted page to avoid race conditions
+		#[codec(compact)]
+		target_hash: PageHash,
+		/// Actions to apply to storage from possible: [`ItemAction`]
+		actions: BoundedVec<
+			ItemAction<<T as Config>::MaxItemizedBlobSizeBytes>,
+			<T as Config>::MaxItemizedActionsCount,
+		>,
+	},
+	/// Creates or updates a Paginated page of the Schema's Intent
+	UpsertPage {
+		/// Schema id of this storage
+		#[codec(compact)]
+		schema_id: SchemaId,
+		/// Page id of this storage
+		#[codec(compact)]
+		page_id: PageId,
+		/// Hash of targeted page to avoid race conditions
+		#[codec(compact)]
+		target_hash: PageHash,
+		/// Codec the payload is compressed with, stored as a [`PageVersion::V3`] page
+		compression: Option<PageCompression>,
+		/// The data that should be stored on the page
+		payload: BoundedVec<u8, <T as Config>::MaxPaginatedPageSizeBytes>,
+	},
+	/// Deletes a Paginated page of the Schema's Intent
+	DeletePage {
+		/// Schema id of this storage
+		#[codec(compact)]
+		schema_id: SchemaId,
+		/// Page id of this storage
+		#[codec(compact)]
+		page_id: PageId,
+		/// Hash of targeted page to avoid race conditions
+		#[codec(compact)]
+		target_hash: PageHash,
+	},
+}
+
+impl<T: Config> PageOperation<T> {
+	/// Schema the operation writes with
+	pub fn schema_id(&self) -> SchemaId {
+		match self {
+			PageOperation::ApplyItemActions { schema_id, .. } |
+			PageOperation::UpsertPage { schema_id, .. } |
+			PageOperation::DeletePage { schema_id, .. } => *schema_id,
+		}
+	}
+}
+
+/// Payload containing all necessary fields to verify signatures to apply a batch of page operations
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialEq,
+	RuntimeDebugNoBound,
+	Clone,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct PageOperationsSignaturePayload<T: Config> {
+	/// Operations to apply, all or none
+	pub operations: BoundedVec<PageOperation<T>, <T as Config>::MaxPageOperations>,
+
+	/// The block number at which the signed proof will expire
+	pub expiration: BlockNumberFor<T>,
+}
+
+impl<T: Config> EIP712Encode for PageOperationsSignaturePayload<T> {
+	fn encode_eip_712(&self, chain_id: u32) -> Box<[u8]> {
+		lazy_static! {
+			// signed payload
+			static ref MAIN_TYPE_HASH: [u8; 32] =
+				sp_io::hashing::keccak_256(b"PageOperationsSignaturePayload(PageOperation[] operations,uint32 expiration)ItemAction(string actionType,bytes data,uint16 index)PageOperation(string operationType,uint16 schemaId,uint16 pageId,uint32 targetHash,string compression,bytes payload,ItemAction[] actions)");
+
+			static ref SUB_TYPE_HASH: [u8; 32] =
+				sp_io::hashing::keccak_256(b"PageOperation(string operationType,uint16 schemaId,uint16 pageId,uint32 targetHash,string compression,bytes payload,ItemAction[] actions)ItemAction(string actionType,bytes data,uint16 index)");
+
+			static ref OPERATION_APPLY_ITEM_ACTIONS: [u8; 32] = sp_io::hashing::keccak_256(b"ApplyItemActions");
+			static ref OPERATION_UPSERT_PAGE: [u8; 32] = sp_io::hashing::keccak_256(b"UpsertPage");
+			static ref OPERATION_DELETE_PAGE: [u8; 32] = sp_io::hashing::keccak_256(b"DeletePage");
+
+			static ref COMPRESSION_LZ4: [u8; 32] = sp_io::hashing::keccak_256(b"Lz4");
+			static ref COMPRESSION_ZSTD: [u8; 32] = sp_io::hashing::keccak_256(b"Zstd");
+
+			static ref EMPTY_HASH: [u8; 32] = sp_io::hashing::keccak_256([].as_slice());
+		}
+		// get prefix and domain separator
+		let prefix_domain_separator: Box<[u8]> =
+			get_eip712_encoding_prefix("0xcccccccccccccccccccccccccccccccccccccccc", chain_id);
+		let coded_operations = {
+			let values: Vec<u8> = self
+				.operations
+				.iter()
+				.flat_map(|operation| {
+					let (
+						operation_type,
+						page_id,
+						target_hash,
+						compression_hash,
+						payload_hash,
+						actions_hash,
+					) = match operation {
+						PageOperation::ApplyItemActions { target_hash, actions, .. } => (
+							*OPERATION_APPLY_ITEM_ACTIONS,
+							0,
+							*target_hash,
+							*EMPTY_HASH,
+							*EMPTY_HASH,
+							item_actions_eip_712_hash(actions),
+						),
+						PageOperation::UpsertPage {
+							page_id,
+							target_hash,
+							compression,
+							payload,
+							..
+						} => (
+							*OPERATION_UPSERT_PAGE,
+							*page_id,
+							*target_hash,
+							match compression {
+								None => *EMPTY_HASH,
+								Some(PageCompression::Lz4) => *COMPRESSION_LZ4,
+								Some(PageCompression::Zstd) => *COMPRESSION_ZSTD,
+							},
+							sp_io::hashing::keccak_256(payload.as_slice()),
+							*EMPTY_HASH,
+						),
+						PageOperation::DeletePage { page_id, target_hash, .. } => (
+							*OPERATION_DELETE_PAGE,
+							*page_id,
+							*target_hash,
+							*EMPTY_HASH,
+							*EMPTY_HASH,
+							*EMPTY_HASH,
+						),
+					};
+					sp_io::hashing::keccak_256(
+						&[
+							SUB_TYPE_HASH.as_slice(),
+							operation_type.as_slice(),
+							&to_abi_compatible_number(operation.schema_id()),
+							&to_abi_compatible_number(page_id),
+							&to_abi_compatible_number(target_hash),
+							compression_hash.as_slice(),
+							payload_hash.as_slice(),
+							actions_hash.as_slice(),
+						]
+						.concat(),
+					)
+				})
+				.collect();
+			sp_io::hashing::keccak_256(&values)
+		};
+		let expiration: U256 = self.expiration.into();
+		let coded_expiration = to_abi_compatible_number(expiration.as_u128());
+		let message = sp_io::hashing::keccak_256(
+			&[MAIN_TYPE_HASH.as_slice(), &coded_operations, &coded_expiration].concat(),
+		);
+		let combined = [prefix_domain_separator.as_ref(), &message].concat();
+		combined.into_boxed_slice()
+	}
+}
+
+/// Whether the pages of an MSA data transfer are kept in the source MSA
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialEq,
+	Eq,
+	Debug,
+	Clone,
+	Copy,
+)]
+pub enum MsaDataTransferMode {
+	/// The pages are copied to the target MSA and kept in the source MSA
+	Copy,
+	/// The pages are moved to the target MSA and removed from the source MSA
+	Move,
+}
+
+/// Payload signed by the control keys of both MSAs to transfer the stateful storage of a set of
+/// Intents from one MSA to the other
+#[derive(
+	Encode,
+	Decode,
+	DecodeWithMemTracking,
+	TypeInfo,
+	MaxEncodedLen,
+	PartialEq,
+	RuntimeDebugNoBound,
+	Clone,
+)]
+#[scale_info(skip_type_params(T))]
+pub struct MsaDataTransferSignaturePayload<T: Config> {
+	/// MSA the pages are transferred from
+	#[codec(compact)]
+	pub source_msa_id: MessageSourceId,
+
+	/// MSA the pages are transferred to
+	#[codec(compact)]
+	pub target_msa_id: MessageSourceId,
+
+	/// Intents whose Itemized and Paginated pages are transferred
+	pub intent_ids: BoundedVec<IntentId, <T as Config>::MaxTransferIntents>,
+
+	/// Whether the pages are kept in the source MSA
+	pub mode: MsaDataTransferMode,
+
+	/// The next transfer nonce of the source MSA, see [`crate::MsaDataTransferNonces`]
+	pub nonce: u32,
+
+	/// The block number at which the signed proof will expire
+	pub expiration: BlockNumberFor<T>,
+}
+
+impl<T: Config> EIP712Encode for MsaDataTransferSignaturePayload<T> {
+	fn encode_eip_712(&self, chain_id: u32) -> Box<[u8]> {
+		lazy_static! {
+			// signed payload
+			static ref MAIN_TYPE_HASH: [u8; 32] =
+				sp_io::hashing::keccak_256(b"MsaDataTransferSignaturePayload(uint64 sourceMsaId,uint64 targetMsaId,uint16[] intentIds,string mode,uint32 nonce,uint32 expiration)");
+
+			static ref MODE_COPY: [u8; 32] = sp_io::hashing::keccak_256(b"Copy");
+			static ref MODE_MOVE: [u8; 32] = sp_io::hashing::keccak_256(b"Move");
+		}
+		// get prefix and domain separator
+		let prefix_domain_separator: Box<[u8]> =
+			get_eip712_encoding_prefix("0xcccccccccccccccccccccccccccccccccccccccc", chain_id);
+		let coded_source_msa_id = to_abi_compatible_number(self.source_msa_id);
+		let coded_target_msa_id = to_abi_compatible_number(self.target_msa_id);
+		let coded_intent_ids = {
+			let values: Vec<u8> = self
+				.intent_ids
+				.iter()
+				.flat_map(|intent_id| to_abi_compatible_number(*intent_id))
+				.collect();
+			sp_io::hashing::keccak_256(&values)
+		};
+		let coded_mode = match self.mode {
+			MsaDataTransferMode::Copy => *MODE_COPY,
+			MsaDataTransferMode::Move => *MODE_MOVE,
+		};
+		let coded_nonce = to_abi_compatible_number(self.nonce);
+		let expiration: U256 = self.expiration.into();
+		let coded_expiration = to_abi_compatible_number(expiration.as_u128());
+		let message = sp_io::hashing::keccak_256(
+			&[
+				MAIN_TYPE_HASH.as_slice(),
+				&coded_source_msa_id,
+				&coded_target_msa_id,
+				&coded_intent_ids,
+				&coded_mode,
+				&coded_nonce,
+				&coded_expiration,
+			]
+			.concat(),
+		);
+		let combined = [prefix_domain_separator.as_ref(), &message].concat();
+		combined.into_boxed_slice()
+	}
+}
+
+/// Position of a pending MSA data transfer. Each Intent is processed in steps: step `0` is the
+/// Itemized page, and step `n` is the Paginated page `n - 1`.
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, Eq, Debug, Clone, Copy, Default)]
+pub struct MsaDataTransferCursor {
+	/// Index of the Intent being processed
+	pub intent_index: u32,
+	/// Next step within the Intent
+	pub step: u32,
+}
+
+/// A pending transfer of the stateful storage of an MSA
+#[derive(Encode, Decode, TypeInfo, MaxEncodedLen, PartialEq, RuntimeDebugNoBound, Clone)]
+#[scale_info(skip_type_params(T))]
+pub struct MsaDataTransfer<T: Config> {
+	/// MSA the pages are transferred to
+	pub target_msa_id: MessageSourceId,
+	/// Intents whose pages are transferred
+	pub intent_ids: BoundedVec<IntentId, <T as Config>::MaxTransferIntents>,
+	/// Whether the pages are kept in the source MSA
+	pub mode: MsaDataTransferMode,
+	/// Account charged the storage deposits of copied pages
+	pub payer: T::AccountId,
+	/// Position of the next page to transfer
+	pub cursor: MsaDataTransferCursor,
+	/// Number of pages transferred so far
+	pub pages_transferred: u32,
+}
+
 /// Indicates the version of the Page storage (header format, etc)
 #[derive(
 	Encode, Decode, DecodeWithMemTracking, Default, Clone, TypeInfo, MaxEncodedLen, Debug, PartialEq,
@@ -426,6 +787,10 @@ pub enum PageVersion {
 	#[codec(index = 2)]
 	#[default] // NOTE: Move the default attribute when adding a new variant
 	V2,
+
+	/// Page storage version 3, a Paginated page whose data is compressed with the given codec
+	#[codec(index = 3)]
+	V3(PageCompression),
 }
 
 /// A generic page of data which supports both Itemized and Paginated
@@ -485,7 +850,17 @@ impl<PageDataSize: Get<u32>> Page<PageDataSize> {
 		self.data.is_empty()
 	}
 
-	/// Retrieve the hash of the page
+	/// Compression codec of the page data, if it is compressed
+	pub fn compression(&self) -> Option<PageCompression> {
+		match self.page_version {
+			PageVersion::V3(compression) => Some(compression),
+			_ => None,
+		}
+	}
+
+	/// Retrieve the hash of the page.
+	/// The hash covers the page as stored: its header, including the compression codec of a
+	/// [`PageVersion::V3`] page, and its raw data, which is never decompressed for hashing.
 	pub fn get_hash(&self) -> PageHash {
 		if self.is_empty() {
 			return PageHash::default();
@@ -545,6 +920,7 @@ impl<T: Config> ItemizedOperations<T> for ItemizedPage<T> {
 
 		let mut updated_page_buffer = Vec::with_capacity(parsed.page_size);
 		let mut add_buffer = Vec::new();
+		let mut update_buffers = BTreeMap::new();
 
 		for action in actions {
 			match action {
@@ -554,6 +930,23 @@ impl<T: Config> ItemizedOperations<T> for ItemizedPage<T> {
 						PageError::InvalidAction("item index is invalid")
 					);
 					parsed.items.remove(index);
+					update_buffers.remove(index);
+				},
+				ItemAction::Update { index, data } => {
+					ensure!(
+						parsed.items.contains_key(index),
+						PageError::InvalidAction("item index is invalid")
+					);
+					let header = ItemHeader::V2 {
+						schema_id,
+						payload_len: data
+							.len()
+							.try_into()
+							.map_err(|_| PageError::InvalidAction("invalid payload size"))?,
+					};
+					let mut update_buffer = header.encode();
+					update_buffer.extend_from_slice(&data[..]);
+					update_buffers.insert(*index, update_buffer);
 				},
 				ItemAction::Add { data } => {
 					let header = ItemHeader::V2 {
@@ -570,8 +963,11 @@ impl<T: Config> ItemizedOperations<T> for ItemizedPage<T> {
 		}
 
 		// since BTreeMap is sorted by key, all items will be kept in their existing order
-		for (_, slice) in parsed.items.iter() {
-			updated_page_buffer.extend_from_slice(slice.payload);
+		for (index, slice) in parsed.items.iter() {
+			match update_buffers.get(index) {
+				Some(update_buffer) => updated_page_buffer.extend_from_slice(update_buffer),
+				None => updated_page_buffer.extend_from_slice(slice.payload),
+			}
 		}
 		updated_page_buffer.append(&mut add_buffer);
 
@@ -48,6 +48,16 @@ pub trait WeightInfo {
 	fn paginated_v1_to_v2_hit() -> Weight;
 	fn itemized_v1_to_v2_miss() -> Weight;
 	fn itemized_v1_to_v2_hit() -> Weight;
+	fn paginated_v2_to_v3_miss() -> Weight;
+	fn paginated_v2_to_v3_hit() -> Weight;
+	fn itemized_v2_to_v3_miss() -> Weight;
+	fn itemized_v2_to_v3_hit() -> Weight;
+	fn apply_item_actions_update(s: u32, ) -> Weight;
+	fn apply_item_actions_with_signature_v2_update(s: u32, ) -> Weight;
+	fn set_storage_byte_deposit() -> Weight;
+	fn transfer_msa_data(n: u32, ) -> Weight;
+	fn transfer_msa_data_step() -> Weight;
+	fn decompress_paginated_page() -> Weight;
 }
 
 /// Weights for `pallet_stateful_storage` using the Substrate node and recommended hardware.
@@ -55,22 +65,35 @@ pub struct SubstrateWeight<T>(PhantomData<T>);
 impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1024, 5120]`.
 	fn apply_item_actions_add(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `562`
-		//  Estimated: `6007`
-		// Minimum execution time: 39_786_000 picoseconds.
-		Weight::from_parts(39_886_428, 6007)
-			// Standard Error: 132
-			.saturating_add(Weight::from_parts(1_771, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(82_786_428, 84736)
+			.saturating_add(Weight::from_parts(2_912, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -82,35 +105,61 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 5]`.
 	fn apply_item_actions_delete(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `10972`
-		//  Estimated: `16417`
-		// Minimum execution time: 58_444_000 picoseconds.
-		Weight::from_parts(66_223_312, 16417)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `30045`
+		// Minimum execution time: 91_444_000 picoseconds.
+		Weight::from_parts(106_823_312, 30045)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 1024]`.
 	fn upsert_page(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1722`
-		//  Estimated: `7167`
-		// Minimum execution time: 43_762_000 picoseconds.
-		Weight::from_parts(45_740_095, 7167)
-			// Standard Error: 245
-			.saturating_add(Weight::from_parts(239, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(88_640_095, 85896)
+			.saturating_add(Weight::from_parts(1_486, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -122,13 +171,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn delete_page() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1724`
-		//  Estimated: `7169`
-		// Minimum execution time: 42_243_000 picoseconds.
-		Weight::from_parts(44_270_000, 7169)
-			.saturating_add(T::DbWeight::get().reads(3_u64))
+		//  Estimated: `20797`
+		// Minimum execution time: 75_243_000 picoseconds.
+		Weight::from_parts(84_870_000, 20797)
+			.saturating_add(T::DbWeight::get().reads(9_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -136,18 +198,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1024, 5120]`.
 	fn apply_item_actions_with_signature_v2_add(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `569`
-		//  Estimated: `6014`
-		// Minimum execution time: 129_726_000 picoseconds.
-		Weight::from_parts(119_592_180, 6014)
-			// Standard Error: 396
-			.saturating_add(Weight::from_parts(10_917, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(162_492_180, 84743)
+			.saturating_add(Weight::from_parts(12_064, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -157,14 +232,27 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 5]`.
 	fn apply_item_actions_with_signature_v2_delete(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `10979`
-		//  Estimated: `16424`
-		// Minimum execution time: 139_036_000 picoseconds.
-		Weight::from_parts(149_551_853, 16424)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Estimated: `30052`
+		// Minimum execution time: 172_036_000 picoseconds.
+		Weight::from_parts(190_151_853, 30052)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -172,18 +260,31 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 1024]`.
 	fn upsert_page_with_signature_v2(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1655`
-		//  Estimated: `7100`
-		// Minimum execution time: 130_833_000 picoseconds.
-		Weight::from_parts(136_770_265, 7100)
-			// Standard Error: 1_710
-			.saturating_add(Weight::from_parts(10_211, 0).saturating_mul(s.into()))
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(179_670_265, 85829)
+			.saturating_add(Weight::from_parts(11_452, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -193,13 +294,26 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn delete_page_with_signature_v2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1657`
-		//  Estimated: `7102`
-		// Minimum execution time: 128_629_000 picoseconds.
-		Weight::from_parts(131_361_000, 7102)
-			.saturating_add(T::DbWeight::get().reads(2_u64))
+		//  Estimated: `20730`
+		// Minimum execution time: 161_629_000 picoseconds.
+		Weight::from_parts(171_961_000, 20730)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -249,28 +363,239 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	fn paginated_v2_to_v3_miss() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11`
+		//  Estimated: `5456`
+		// Minimum execution time: 2_871_000 picoseconds.
+		Weight::from_parts(2_990_000, 5456)
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb1f1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb1f1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:0)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn paginated_v2_to_v3_hit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208`
+		//  Estimated: `8163`
+		// Minimum execution time: 14_212_000 picoseconds.
+		Weight::from_parts(14_893_000, 8163)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	fn itemized_v2_to_v3_miss() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11`
+		//  Estimated: `5456`
+		// Minimum execution time: 2_794_000 picoseconds.
+		Weight::from_parts(2_957_000, 5456)
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb10100` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb10100` (r:1 w:0)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn itemized_v2_to_v3_hit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `365`
+		//  Estimated: `7320`
+		// Minimum execution time: 13_906_000 picoseconds.
+		Weight::from_parts(14_517_000, 7320)
+			.saturating_add(T::DbWeight::get().reads(1_u64))
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1024, 5120]`.
+	fn apply_item_actions_update(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10979`
+		//  Estimated: `84736`
+		// Minimum execution time: 101_921_000 picoseconds.
+		Weight::from_parts(114_040_512, 84736)
+			// Standard Error: 141
+			.saturating_add(Weight::from_parts(2_931, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(11_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1024, 5120]`.
+	fn apply_item_actions_with_signature_v2_update(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10986`
+		//  Estimated: `84743`
+		// Minimum execution time: 191_377_000 picoseconds.
+		Weight::from_parts(194_783_204, 84743)
+			// Standard Error: 402
+			.saturating_add(Weight::from_parts(12_101, 0).saturating_mul(s.into()))
+			.saturating_add(T::DbWeight::get().reads(10_u64))
+			.saturating_add(T::DbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
+	}
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:0 w:1)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_storage_byte_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_110_000 picoseconds.
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(T::DbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransfers` (r:1 w:1)
+	/// Proof: `StatefulStorage::MsaDataTransfers` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:16 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:32 w:32)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransferNonces` (r:1 w:1)
+	/// Proof: `StatefulStorage::MsaDataTransferNonces` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn transfer_msa_data(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3560`
+		//  Estimated: `9525`
+		// Minimum execution time: 94_310_000 picoseconds.
+		Weight::from_parts(100_100_000, 9525)
+			// Standard Error: 980_000
+			.saturating_add(Weight::from_parts(9_800_000, 0).saturating_mul(n.into()))
+			.saturating_add(T::DbWeight::get().reads(4_u64))
+			.saturating_add(T::DbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(T::DbWeight::get().writes(2_u64))
+			.saturating_add(T::DbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7531).saturating_mul(n.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:2 w:2)
+	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:2 w:2)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:2 w:2)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:2 w:2)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_msa_data_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13040`
+		//  Estimated: `24051`
+		// Minimum execution time: 57_528_000 picoseconds.
+		Weight::from_parts(67_300_000, 24051)
+			.saturating_add(T::DbWeight::get().reads(8_u64))
+			.saturating_add(T::DbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(2_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	fn decompress_paginated_page() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65213`
+		//  Estimated: `68979`
+		// Minimum execution time: 2_950_000_000 picoseconds.
+		Weight::from_parts(3_080_000_000, 68979)
+			.saturating_add(T::DbWeight::get().reads(2_u64))
+	}
 }
 
 // For backwards compatibility and tests.
 impl WeightInfo for () {
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1024, 5120]`.
 	fn apply_item_actions_add(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `562`
-		//  Estimated: `6007`
-		// Minimum execution time: 39_786_000 picoseconds.
-		Weight::from_parts(39_886_428, 6007)
-			// Standard Error: 132
-			.saturating_add(Weight::from_parts(1_771, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(82_786_428, 84736)
+			.saturating_add(Weight::from_parts(2_912, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -282,35 +607,61 @@ impl WeightInfo for () {
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 5]`.
 	fn apply_item_actions_delete(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `10972`
-		//  Estimated: `16417`
-		// Minimum execution time: 58_444_000 picoseconds.
-		Weight::from_parts(66_223_312, 16417)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `30045`
+		// Minimum execution time: 91_444_000 picoseconds.
+		Weight::from_parts(106_823_312, 30045)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 1024]`.
 	fn upsert_page(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1722`
-		//  Estimated: `7167`
-		// Minimum execution time: 43_762_000 picoseconds.
-		Weight::from_parts(45_740_095, 7167)
-			// Standard Error: 245
-			.saturating_add(Weight::from_parts(239, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(88_640_095, 85896)
+			.saturating_add(Weight::from_parts(1_486, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -322,13 +673,26 @@ impl WeightInfo for () {
 	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn delete_page() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1724`
-		//  Estimated: `7169`
-		// Minimum execution time: 42_243_000 picoseconds.
-		Weight::from_parts(44_270_000, 7169)
-			.saturating_add(RocksDbWeight::get().reads(3_u64))
+		//  Estimated: `20797`
+		// Minimum execution time: 75_243_000 picoseconds.
+		Weight::from_parts(84_870_000, 20797)
+			.saturating_add(RocksDbWeight::get().reads(9_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -336,18 +700,31 @@ impl WeightInfo for () {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1024, 5120]`.
 	fn apply_item_actions_with_signature_v2_add(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `569`
-		//  Estimated: `6014`
-		// Minimum execution time: 129_726_000 picoseconds.
-		Weight::from_parts(119_592_180, 6014)
-			// Standard Error: 396
-			.saturating_add(Weight::from_parts(10_917, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(162_492_180, 84743)
+			.saturating_add(Weight::from_parts(12_064, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -357,14 +734,27 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `n` is `[1, 5]`.
 	fn apply_item_actions_with_signature_v2_delete(_n: u32, ) -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `10979`
-		//  Estimated: `16424`
-		// Minimum execution time: 139_036_000 picoseconds.
-		Weight::from_parts(149_551_853, 16424)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Estimated: `30052`
+		// Minimum execution time: 172_036_000 picoseconds.
+		Weight::from_parts(190_151_853, 30052)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -372,18 +762,31 @@ impl WeightInfo for () {
 	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
 	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	/// The range of component `s` is `[1, 1024]`.
 	fn upsert_page_with_signature_v2(s: u32, ) -> Weight {
-		// Proof Size summary in bytes:
-		//  Measured:  `1655`
-		//  Estimated: `7100`
-		// Minimum execution time: 130_833_000 picoseconds.
-		Weight::from_parts(136_770_265, 7100)
-			// Standard Error: 1_710
-			.saturating_add(Weight::from_parts(10_211, 0).saturating_mul(s.into()))
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		// Not benchmarked: estimated by hand until regenerated with `scripts/run_benchmarks.sh`
+		Weight::from_parts(179_670_265, 85829)
+			.saturating_add(Weight::from_parts(11_452, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -393,13 +796,26 @@ impl WeightInfo for () {
 	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
 	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
 	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
 	fn delete_page_with_signature_v2() -> Weight {
 		// Proof Size summary in bytes:
 		//  Measured:  `1657`
-		//  Estimated: `7102`
-		// Minimum execution time: 128_629_000 picoseconds.
-		Weight::from_parts(131_361_000, 7102)
-			.saturating_add(RocksDbWeight::get().reads(2_u64))
+		//  Estimated: `20730`
+		// Minimum execution time: 161_629_000 picoseconds.
+		Weight::from_parts(171_961_000, 20730)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
 			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
@@ -449,6 +865,204 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
 			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
 	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	fn paginated_v2_to_v3_miss() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11`
+		//  Estimated: `5456`
+		// Minimum execution time: 2_871_000 picoseconds.
+		Weight::from_parts(2_990_000, 5456)
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb1f1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb1f1577fbf1d628fdddb4ebfc6e8d95fb1` (r:1 w:0)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn paginated_v2_to_v3_hit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `1208`
+		//  Estimated: `8163`
+		// Minimum execution time: 14_212_000 picoseconds.
+		Weight::from_parts(14_893_000, 8163)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	fn itemized_v2_to_v3_miss() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `11`
+		//  Estimated: `5456`
+		// Minimum execution time: 2_794_000 picoseconds.
+		Weight::from_parts(2_957_000, 5456)
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+	}
+	/// Storage: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0x` (r:1 w:0)
+	/// Storage: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb10100` (r:1 w:0)
+	/// Proof: UNKNOWN KEY `0xf1577fbf1d628fdddb4ebfc6e8d95fb10100` (r:1 w:0)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	fn itemized_v2_to_v3_hit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `365`
+		//  Estimated: `7320`
+		// Minimum execution time: 13_906_000 picoseconds.
+		Weight::from_parts(14_517_000, 7320)
+			.saturating_add(RocksDbWeight::get().reads(1_u64))
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Msa::DelegatorAndProviderToDelegation` (r:1 w:0)
+	/// Proof: `Msa::DelegatorAndProviderToDelegation` (`max_values`: None, `max_size`: Some(217), added: 2692, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1024, 5120]`.
+	fn apply_item_actions_update(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10979`
+		//  Estimated: `84736`
+		// Minimum execution time: 101_921_000 picoseconds.
+		Weight::from_parts(114_040_512, 84736)
+			// Standard Error: 141
+			.saturating_add(Weight::from_parts(2_931, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(11_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:1 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	/// Storage: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Proof: UNKNOWN KEY `0xbd1557c8db6bd8599a811a7175fbc2fc6400` (r:1 w:1)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:1 w:0)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:1 w:1)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:1 w:1)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	/// The range of component `s` is `[1024, 5120]`.
+	fn apply_item_actions_with_signature_v2_update(s: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `10986`
+		//  Estimated: `84743`
+		// Minimum execution time: 191_377_000 picoseconds.
+		Weight::from_parts(194_783_204, 84743)
+			// Standard Error: 402
+			.saturating_add(Weight::from_parts(12_101, 0).saturating_mul(s.into()))
+			.saturating_add(RocksDbWeight::get().reads(10_u64))
+			.saturating_add(RocksDbWeight::get().writes(4_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(1_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(1_u64))
+	}
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:0 w:1)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	fn set_storage_byte_deposit() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `0`
+		//  Estimated: `0`
+		// Minimum execution time: 6_110_000 picoseconds.
+		Weight::from_parts(6_500_000, 0)
+			.saturating_add(RocksDbWeight::get().writes(1_u64))
+	}
+	/// Storage: `Msa::PublicKeyToMsaId` (r:2 w:0)
+	/// Proof: `Msa::PublicKeyToMsaId` (`max_values`: None, `max_size`: Some(48), added: 2523, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransfers` (r:1 w:1)
+	/// Proof: `StatefulStorage::MsaDataTransfers` (`max_values`: None, `max_size`: Some(116), added: 2591, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentInfos` (r:16 w:0)
+	/// Proof: `Schemas::IntentInfos` (`max_values`: None, `max_size`: Some(22), added: 2497, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransferLocks` (r:32 w:32)
+	/// Proof: `StatefulStorage::MsaDataTransferLocks` (`max_values`: None, `max_size`: Some(42), added: 2517, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::MsaDataTransferNonces` (r:1 w:1)
+	/// Proof: `StatefulStorage::MsaDataTransferNonces` (`max_values`: None, `max_size`: Some(20), added: 2495, mode: `MaxEncodedLen`)
+	/// The range of component `n` is `[1, 16]`.
+	fn transfer_msa_data(n: u32, ) -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `3560`
+		//  Estimated: `9525`
+		// Minimum execution time: 94_310_000 picoseconds.
+		Weight::from_parts(100_100_000, 9525)
+			// Standard Error: 980_000
+			.saturating_add(Weight::from_parts(9_800_000, 0).saturating_mul(n.into()))
+			.saturating_add(RocksDbWeight::get().reads(4_u64))
+			.saturating_add(RocksDbWeight::get().reads((3_u64).saturating_mul(n.into())))
+			.saturating_add(RocksDbWeight::get().writes(2_u64))
+			.saturating_add(RocksDbWeight::get().writes((2_u64).saturating_mul(n.into())))
+			.saturating_add(Weight::from_parts(0, 7531).saturating_mul(n.into()))
+	}
+	/// Storage: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:2 w:2)
+	/// Proof: UNKNOWN KEY `0x0763c98381dc89abe38627fe2f98cb7af1577fbf1d628fdddb4ebfc6e8d95fb1` (r:2 w:2)
+	/// Storage: `StatefulStorage::StorageByteDeposit` (r:1 w:0)
+	/// Proof: `StatefulStorage::StorageByteDeposit` (`max_values`: Some(1), `max_size`: Some(16), added: 511, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::PageDeposits` (r:2 w:2)
+	/// Proof: `StatefulStorage::PageDeposits` (`max_values`: None, `max_size`: Some(100), added: 2575, mode: `MaxEncodedLen`)
+	/// Storage: `StatefulStorage::StorageUsages` (r:2 w:2)
+	/// Proof: `StatefulStorage::StorageUsages` (`max_values`: None, `max_size`: Some(34), added: 2509, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::IntentStorageQuotas` (r:1 w:0)
+	/// Proof: `Schemas::IntentStorageQuotas` (`max_values`: None, `max_size`: Some(18), added: 2493, mode: `MaxEncodedLen`)
+	/// Storage: `Balances::Holds` (r:1 w:1)
+	/// Proof: `Balances::Holds` (`max_values`: None, `max_size`: Some(949), added: 3424, mode: `MaxEncodedLen`)
+	/// Storage: `System::Account` (r:1 w:1)
+	/// Proof: `System::Account` (`max_values`: None, `max_size`: Some(128), added: 2603, mode: `MaxEncodedLen`)
+	fn transfer_msa_data_step() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `13040`
+		//  Estimated: `24051`
+		// Minimum execution time: 57_528_000 picoseconds.
+		Weight::from_parts(67_300_000, 24051)
+			.saturating_add(RocksDbWeight::get().reads(8_u64))
+			.saturating_add(RocksDbWeight::get().writes(6_u64))
+			.saturating_add(RocksDbWeightChild::get().reads(2_u64))
+			.saturating_add(RocksDbWeightChild::get().writes(2_u64))
+	}
+	/// Storage: `Schemas::SchemaInfos` (r:1 w:0)
+	/// Proof: `Schemas::SchemaInfos` (`max_values`: None, `max_size`: Some(17), added: 2492, mode: `MaxEncodedLen`)
+	/// Storage: `Schemas::SchemaPayloads` (r:1 w:0)
+	/// Proof: `Schemas::SchemaPayloads` (`max_values`: None, `max_size`: Some(65514), added: 67989, mode: `MaxEncodedLen`)
+	fn decompress_paginated_page() -> Weight {
+		// Proof Size summary in bytes:
+		//  Measured:  `65213`
+		//  Estimated: `68979`
+		// Minimum execution time: 2_950_000_000 picoseconds.
+		Weight::from_parts(3_080_000_000, 68979)
+			.saturating_add(RocksDbWeight::get().reads(2_u64))
+	}
 }
 
 
@@ -618,4 +1232,112 @@ mod tests {
 				> 5774
 		);
 	}
+	#[test]
+	fn test_paginated_v2_to_v3_miss() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5456
+		);
+	}
+	#[test]
+	fn test_paginated_v2_to_v3_hit() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 8163
+		);
+	}
+	#[test]
+	fn test_itemized_v2_to_v3_miss() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 5456
+		);
+	}
+	#[test]
+	fn test_itemized_v2_to_v3_hit() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 7320
+		);
+	}
+	#[test]
+	fn test_apply_item_actions_update() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6007
+		);
+	}
+	#[test]
+	fn test_apply_item_actions_with_signature_v2_update() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 6014
+		);
+	}
+	#[test]
+	fn test_transfer_msa_data() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 9525
+		);
+	}
+	#[test]
+	fn test_transfer_msa_data_step() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 24051
+		);
+	}
+	#[test]
+	fn test_decompress_paginated_page() {
+		assert!(
+			BlockWeights::get()
+				.per_class
+				.get(frame_support::dispatch::DispatchClass::Normal)
+				.max_extrinsic
+				.unwrap_or_else(<Weight as sp_runtime::traits::Bounded>::max_value)
+				.proof_size()
+				> 68979
+		);
+	}
 }
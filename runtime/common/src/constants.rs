@@ -130,6 +130,10 @@ impl Get<u32> for MsaMaxLogoSize {
 		1024 * 128
 	}
 }
+/// The minimum width and height of a provider logo (in pixels)
+pub type MsaMinLogoDimension = ConstU32<16>;
+/// The maximum width and height of a provider logo (in pixels)
+pub type MsaMaxLogoDimension = ConstU32<2048>;
 // -end- MSA Pallet ---
 
 // --- Schemas Pallet ---
@@ -146,7 +150,11 @@ pub type SchemasMaxRegistrations = ConstU16<65_000>;
 /// The minimum schema model size (in bytes)
 pub type SchemasMinModelSizeBytes = ConstU32<8>;
 /// The maximum number of grants allowed per schema
-pub type MaxSchemaSettingsPerSchema = ConstU32<2>;
+pub type MaxSchemaSettingsPerSchema = ConstU32<4>;
+/// The deposit held for each Intent, IntentGroup or Schema created in a provider-owned namespace
+pub type SchemasNamespaceEntityDeposit = ConstU128<{ currency::deposit(1, 128) }>;
+/// The maximum number of Intents that can have a message retention period
+pub type SchemasMaxIntentsWithRetention = ConstU32<64>;
 
 impl Encode for SchemasMaxBytesBoundedVecLimit {}
 
@@ -335,6 +343,14 @@ parameter_types! {
 }
 /// How often to emit a tracking event when migrating messages in the `messages` pallet
 pub type MessagesMigrateEmitEvery = ConstU32<10_000>; // 10K
+/// The maximum number of messages in a single `add_ipfs_messages` or `add_onchain_messages` batch
+pub type MessagesMaxMessagesPerBatch = ConstU32<20>;
+/// The maximum length in bytes of the URI of an off-chain message payload
+pub type MessagesMaxOffchainUriLength = ConstU32<2048>;
+/// The maximum number of URI schemes allowed for off-chain message payloads
+pub type MessagesMaxUriSchemes = ConstU32<16>;
+/// The maximum number of codecs, and of multihash codes, accepted for IPFS message CIDs
+pub type MessagesMaxCidPolicyEntries = ConstU32<8>;
 
 impl Clone for MessagesMaxPayloadSizeBytes {
 	fn clone(&self) -> Self {
@@ -390,6 +406,12 @@ pub type MaxItemizedActionsCount = ConstU32<5>;
 pub type StatefulMortalityWindowSize = ConstU32<{ 2 * DAYS }>;
 /// How often to emit a tracking event when migrating pages in the `stateful-storage` pallet
 pub type StatefulMigrateEmitEvery = ConstU32<10_000>;
+/// The maximum number of Intents in a single MSA data transfer
+pub type StatefulMaxTransferIntents = ConstU32<16>;
+/// The maximum number of page operations in a single `stateful-storage` batch
+pub type StatefulMaxPageOperations = ConstU32<16>;
+/// The maximum size of the decompressed data of a compressed `stateful-storage` Paginated page
+pub type StatefulMaxDecompressedPaginatedPageSizeBytes = ConstU32<{ 8 * 1024 }>;
 // -end- Stateful Storage Pallet
 
 impl Default for MaxItemizedPageSizeBytes {
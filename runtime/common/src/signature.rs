@@ -5,6 +5,11 @@ extern crate alloc;
 use crate::constants::CHAIN_ID;
 use sp_core::Encode;
 
+/// Verifies `signature` against any of the supported encodings of `payload`
+/// - SCALE encoded bytes
+/// - `<Bytes>` wrapped SCALE encoded bytes for non-ethereum signatures
+/// - EIP-191 (`personal_sign`) prefixed SCALE encoded bytes or their `0x` hex text for ethereum signatures
+/// - EIP-712 encoding for ethereum signatures
 pub fn check_signature<P>(signature: &MultiSignature, signer: AccountId32, payload: &P) -> bool
 where
 	P: Encode + EIP712Encode,
@@ -109,6 +114,58 @@ mod tests {
 		));
 	}
 
+	#[test]
+	fn test_ethereum_verify_personal_sign_signature_should_work() {
+		let (signer, _) = ecdsa::Pair::generate();
+
+		let payload = b"test_payload";
+		let prefixed =
+			[b"\x19Ethereum Signed Message:\n12".as_slice(), payload.as_slice()].concat();
+		let signature: MultiSignature = signer.sign_prehashed(&keccak_256(&prefixed)).into();
+		let unified_signer = UnifiedSigner::from(signer.public());
+
+		assert!(check_signature(
+			&signature,
+			unified_signer.into_account(),
+			&TestArrayWrapper(payload.clone())
+		));
+	}
+
+	#[test]
+	fn test_ethereum_verify_personal_sign_signature_over_hex_text_should_work() {
+		let (signer, _) = ecdsa::Pair::generate();
+
+		let payload = b"test_payload";
+		let hex_text = format!("0x{}", sp_core::hexdisplay::HexDisplay::from(&payload.to_vec()));
+		let prefixed =
+			format!("\x19Ethereum Signed Message:\n{}{}", hex_text.len(), hex_text).into_bytes();
+		let signature: MultiSignature = signer.sign_prehashed(&keccak_256(&prefixed)).into();
+		let unified_signer = UnifiedSigner::from(signer.public());
+
+		assert!(check_signature(
+			&signature,
+			unified_signer.into_account(),
+			&TestArrayWrapper(payload.clone())
+		));
+	}
+
+	#[test]
+	fn test_ethereum_verify_personal_sign_signature_with_wrong_length_should_fail() {
+		let (signer, _) = ecdsa::Pair::generate();
+
+		let payload = b"test_payload";
+		let prefixed =
+			[b"\x19Ethereum Signed Message:\n13".as_slice(), payload.as_slice()].concat();
+		let signature: MultiSignature = signer.sign_prehashed(&keccak_256(&prefixed)).into();
+		let unified_signer = UnifiedSigner::from(signer.public());
+
+		assert!(!check_signature(
+			&signature,
+			unified_signer.into_account(),
+			&TestArrayWrapper(payload.clone())
+		));
+	}
+
 	#[test]
 	fn test_ethereum_verify_signature_wrapped_bytes_should_fail() {
 		let (signer, _) = ecdsa::Pair::generate();
@@ -98,7 +98,8 @@ use common_primitives::{
 	msa::{
 		AccountId20Response, ApplicationIndex, DelegationGrant, DelegationResponse,
 		DelegationValidator, DelegatorId, GrantValidator, MessageSourceId,
-		ProviderApplicationContext, ProviderId, H160,
+		ProviderApplicationContext, ProviderApplicationContextV2, ProviderDirectoryRequest,
+		ProviderDirectoryResponse, ProviderId, H160,
 	},
 	node::{
 		AccountId, Address, Balance, BlockNumber, Hash, Header, Index, ProposalProvider, Signature,
@@ -107,8 +108,9 @@ use common_primitives::{
 	rpc::RpcEvent,
 	schema::{PayloadLocation, SchemaId, SchemaVersionResponse},
 	stateful_storage::{
-		ItemizedStoragePageResponse, ItemizedStoragePageResponseV2, PaginatedStorageResponse,
-		PaginatedStorageResponseV2,
+		ItemizedStoragePageResponse, ItemizedStoragePageResponseV2, PageId, PageStorageKey,
+		PaginatedStorageResponse, PaginatedStorageResponseV2, PaginatedStorageResponseV3,
+		StorageUsageResponse,
 	},
 };
 
@@ -164,7 +166,9 @@ use polkadot_runtime_common::{BlockHashCount, SlowAdjustingFeeUpdate};
 
 use common_primitives::{
 	capacity::UnclaimedRewardInfo,
-	messages::{BlockPaginationRequest, BlockPaginationResponse, MessageResponseV2},
+	messages::{
+		BlockPaginationRequest, BlockPaginationResponse, MessageResponseV2, MessageResponseV3,
+	},
 	schema::*,
 };
 use common_runtime::weights::rocksdb_weights::constants::RocksDbWeight;
@@ -506,6 +510,7 @@ pub type Executive = frame_executive::Executive<
 		MigratePalletsCurrentStorage<Runtime>,
 		SetSafeXcmVersion<Runtime>,
 		pallet_schemas::migration::MigrateV4ToV5<Runtime>,
+		pallet_schemas::migration::MigrateV5ToV6<Runtime>,
 	),
 >;
 
@@ -516,7 +521,11 @@ pub type Executive = frame_executive::Executive<
 	frame_system::ChainContext<Runtime>,
 	Runtime,
 	AllPalletsWithSystem,
-	(MigratePalletsCurrentStorage<Runtime>, pallet_schemas::migration::MigrateV4ToV5<Runtime>),
+	(
+		MigratePalletsCurrentStorage<Runtime>,
+		pallet_schemas::migration::MigrateV4ToV5<Runtime>,
+		pallet_schemas::migration::MigrateV5ToV6<Runtime>,
+	),
 >;
 
 pub struct MigratePalletsCurrentStorage<T>(core::marker::PhantomData<T>);
@@ -673,7 +682,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("frequency"),
 	impl_name: Cow::Borrowed("frequency"),
 	authoring_version: 1,
-	spec_version: 191,
+	spec_version: 192,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -687,7 +696,7 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	spec_name: Cow::Borrowed("frequency-testnet"),
 	impl_name: Cow::Borrowed("frequency"),
 	authoring_version: 1,
-	spec_version: 191,
+	spec_version: 192,
 	impl_version: 0,
 	apis: RUNTIME_API_VERSIONS,
 	transaction_version: 1,
@@ -866,6 +875,10 @@ impl pallet_msa::Config for Runtime {
 	type MaxLocaleCount = MsaMaxLocaleCount;
 	// The maximum logo size (in bytes)
 	type MaxLogoSize = MsaMaxLogoSize;
+	// The minimum logo width and height (in pixels)
+	type MinLogoDimension = MsaMinLogoDimension;
+	// The maximum logo width and height (in pixels)
+	type MaxLogoDimension = MsaMaxLogoDimension;
 }
 
 parameter_types! {
@@ -923,6 +936,16 @@ impl pallet_schemas::Config for Runtime {
 	>;
 	// Maximum number of schema grants that are allowed per schema
 	type MaxSchemaSettingsPerSchema = MaxSchemaSettingsPerSchema;
+	type RuntimeHoldReason = RuntimeHoldReason;
+	type Currency = Balances;
+	// The deposit held for each entity created in a provider-owned namespace
+	type NamespaceEntityDeposit = SchemasNamespaceEntityDeposit;
+	// The type that provides MSA lookups
+	type MsaInfoProvider = Msa;
+	// The maximum number of Intents with a message retention period
+	type MaxIntentsWithRetention = SchemasMaxIntentsWithRetention;
+	#[cfg(feature = "runtime-benchmarks")]
+	type MsaBenchmarkHelper = Msa;
 }
 
 // One storage item; key size is 32; value is size 4+4+16+32 bytes = 56 bytes.
@@ -1320,6 +1343,38 @@ use pallet_messages::Call as MessagesCall;
 use pallet_msa::Call as MsaCall;
 use pallet_stateful_storage::Call as StatefulStorageCall;
 
+/// Sums the stable weights of the single page calls matching each operation of a batch
+fn page_operations_stable_weight(
+	operations: &[pallet_stateful_storage::types::PageOperation<Runtime>],
+	is_payload_signed: bool,
+) -> Weight {
+	use pallet_frequency_tx_payment::capacity_stable_weights::WeightInfo;
+	use pallet_stateful_storage::types::PageOperation;
+	type StableWeights = capacity_stable_weights::SubstrateWeight<Runtime>;
+	operations.iter().fold(Weight::zero(), |acc, operation| {
+		acc.saturating_add(match (operation, is_payload_signed) {
+			(PageOperation::ApplyItemActions { actions, .. }, false) =>
+				StableWeights::apply_item_actions(
+					StatefulStorage::sum_add_actions_bytes(actions)
+						.saturating_add(StatefulStorage::sum_update_actions_bytes(actions)),
+				),
+			(PageOperation::ApplyItemActions { actions, .. }, true) =>
+				StableWeights::apply_item_actions_with_signature(
+					StatefulStorage::sum_add_actions_bytes(actions)
+						.saturating_add(StatefulStorage::sum_update_actions_bytes(actions)),
+				),
+			(PageOperation::UpsertPage { payload, compression, .. }, false) =>
+				StableWeights::upsert_page(payload.len() as u32)
+					.saturating_add(StatefulStorage::decompression_weight(compression)),
+			(PageOperation::UpsertPage { payload, compression, .. }, true) =>
+				StableWeights::upsert_page_with_signature(payload.len() as u32)
+					.saturating_add(StatefulStorage::decompression_weight(compression)),
+			(PageOperation::DeletePage { .. }, false) => StableWeights::delete_page(),
+			(PageOperation::DeletePage { .. }, true) => StableWeights::delete_page_with_signature(),
+		})
+	})
+}
+
 pub struct CapacityEligibleCalls;
 impl GetStableWeight<RuntimeCall, Weight> for CapacityEligibleCalls {
 	fn get_stable_weight(call: &RuntimeCall) -> Option<Weight> {
@@ -1338,12 +1393,17 @@ impl GetStableWeight<RuntimeCall, Weight> for CapacityEligibleCalls {
             ),
             RuntimeCall::Messages(MessagesCall::add_ipfs_message { .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::add_ipfs_message()),
             RuntimeCall::Messages(MessagesCall::add_onchain_message { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::add_onchain_message(payload.len() as u32)),
-            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_item_actions { actions, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::apply_item_actions(StatefulStorage::sum_add_actions_bytes(actions))),
+            RuntimeCall::Messages(MessagesCall::add_ipfs_messages { entries }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::add_ipfs_messages(entries.len() as u32)),
+            RuntimeCall::Messages(MessagesCall::add_onchain_messages { entries }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::add_onchain_messages(entries.len() as u32, Messages::sum_payload_bytes(entries))),
+            RuntimeCall::Messages(MessagesCall::add_offchain_message { .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::add_offchain_message()),
+            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_item_actions { actions, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::apply_item_actions(StatefulStorage::sum_add_actions_bytes(actions).saturating_add(StatefulStorage::sum_update_actions_bytes(actions)))),
             RuntimeCall::StatefulStorage(StatefulStorageCall::upsert_page { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::upsert_page(payload.len() as u32)),
             RuntimeCall::StatefulStorage(StatefulStorageCall::delete_page { .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::delete_page()),
-            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_item_actions_with_signature_v2 { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::apply_item_actions_with_signature(StatefulStorage::sum_add_actions_bytes(&payload.actions))),
+            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_item_actions_with_signature_v2 { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::apply_item_actions_with_signature(StatefulStorage::sum_add_actions_bytes(&payload.actions).saturating_add(StatefulStorage::sum_update_actions_bytes(&payload.actions)))),
             RuntimeCall::StatefulStorage(StatefulStorageCall::upsert_page_with_signature_v2 { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::upsert_page_with_signature(payload.payload.len() as u32)),
             RuntimeCall::StatefulStorage(StatefulStorageCall::delete_page_with_signature_v2 { .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::delete_page_with_signature()),
+            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_page_operations { operations, .. }) => Some(page_operations_stable_weight(operations, false)),
+            RuntimeCall::StatefulStorage(StatefulStorageCall::apply_page_operations_with_signature { payload, .. }) => Some(page_operations_stable_weight(&payload.operations, true)),
             RuntimeCall::Handles(HandlesCall::claim_handle { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::claim_handle(payload.base_handle.len() as u32)),
             RuntimeCall::Handles(HandlesCall::change_handle { payload, .. }) => Some(capacity_stable_weights::SubstrateWeight::<Runtime>::change_handle(payload.base_handle.len() as u32)),
             _ => None,
@@ -1384,6 +1444,7 @@ impl pallet_passkey::Config for Runtime {
 	type WeightInfo = pallet_passkey::weights::SubstrateWeight<Runtime>;
 	type ConvertIntoAccountId32 = ConvertInto;
 	type PasskeyCallFilter = PasskeyCallFilter;
+	type MsaInfoProvider = Msa;
 	#[cfg(feature = "runtime-benchmarks")]
 	type Currency = Balances;
 }
@@ -1567,6 +1628,24 @@ impl pallet_messages::Config for Runtime {
 	type SchemaProvider = Schemas;
 	// The maximum message payload in bytes
 	type MessagesMaxPayloadSizeBytes = MessagesMaxPayloadSizeBytes;
+	// The maximum number of messages in a batch
+	type MaxMessagesPerBatch = MessagesMaxMessagesPerBatch;
+	// The maximum length of the URI of an off-chain message payload
+	type MaxOffchainUriLength = MessagesMaxOffchainUriLength;
+	// The maximum number of URI schemes allowed for off-chain message payloads
+	type MaxUriSchemes = MessagesMaxUriSchemes;
+	// The origin that sets the URI schemes allowed for off-chain message payloads
+	type UriSchemeGovernanceOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	// The maximum number of codecs, and of multihash codes, accepted for IPFS message CIDs
+	type MaxCidPolicyEntries = MessagesMaxCidPolicyEntries;
+	// The origin that sets the CIDs accepted for IPFS messages
+	type CidPolicyGovernanceOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
 	type MigrateEmitEvery = MessagesMigrateEmitEvery;
 
 	/// A set of helper functions for benchmarking.
@@ -1608,6 +1687,23 @@ impl pallet_stateful_storage::Config for Runtime {
 	#[cfg(feature = "runtime-benchmarks")]
 	type SchemaBenchmarkHelper = Schemas;
 	type MigrateEmitEvery = StatefulMigrateEmitEvery;
+	/// The overarching hold reason
+	type RuntimeHoldReason = RuntimeHoldReason;
+	/// The currency page storage deposits are held in
+	type Currency = Balances;
+	/// The Capacity page storage deposits are drawn from
+	type Capacity = Capacity;
+	/// The origin that sets the storage deposit charged per page byte
+	type StorageDepositGovernanceOrigin = EitherOfDiverse<
+		EnsureRoot<AccountId>,
+		pallet_collective::EnsureProportionMoreThan<AccountId, CouncilCollective, 1, 2>,
+	>;
+	/// The maximum number of Intents in a single MSA data transfer
+	type MaxTransferIntents = StatefulMaxTransferIntents;
+	/// The maximum number of page operations in a single batch
+	type MaxPageOperations = StatefulMaxPageOperations;
+	/// The maximum size of the decompressed data of a compressed Paginated page
+	type MaxDecompressedPaginatedPageSizeBytes = StatefulMaxDecompressedPaginatedPageSizeBytes;
 }
 
 impl pallet_handles::Config for Runtime {
@@ -1699,6 +1795,18 @@ impl pallet_migrations::Config for Runtime {
 			Runtime,
 			pallet_stateful_storage::weights::SubstrateWeight<Runtime>,
 		>,
+		pallet_stateful_storage::migration::v3::SeedPaginatedStorageUsage<
+			Runtime,
+			pallet_stateful_storage::weights::SubstrateWeight<Runtime>,
+		>,
+		pallet_stateful_storage::migration::v3::SeedItemizedStorageUsage<
+			Runtime,
+			pallet_stateful_storage::weights::SubstrateWeight<Runtime>,
+		>,
+		pallet_stateful_storage::migration::v3::FinalizeV3Migration<
+			Runtime,
+			pallet_stateful_storage::weights::SubstrateWeight<Runtime>,
+		>,
 		pallet_messages::migration::MigrateV2ToV3<
 			Runtime,
 			pallet_messages::weights::SubstrateWeight<Runtime>,
@@ -1778,8 +1886,8 @@ construct_runtime!(
 		// Frequency related pallets
 		Msa: pallet_msa::{Pallet, Call, Storage, Event<T>} = 60,
 		Messages: pallet_messages::{Pallet, Call, Storage, Event<T>} = 61,
-		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>, Config<T>} = 62,
-		StatefulStorage: pallet_stateful_storage::{Pallet, Call, Storage, Event<T>} = 63,
+		Schemas: pallet_schemas::{Pallet, Call, Storage, Event<T>, Config<T>, HoldReason} = 62,
+		StatefulStorage: pallet_stateful_storage::{Pallet, Call, Storage, Event<T>, HoldReason} = 63,
 		Capacity: pallet_capacity::{Pallet, Call, Storage, Event<T>, FreezeReason} = 64,
 		FrequencyTxPayment: pallet_frequency_tx_payment::{Pallet, Call, Event<T>} = 65,
 		Handles: pallet_handles::{Pallet, Call, Storage, Event<T>} = 66,
@@ -2052,7 +2160,7 @@ sp_api::impl_runtime_apis! {
 	}
 
 	// Frequency runtime APIs
-	#[api_version(2)]
+	#[api_version(5)]
 	impl pallet_messages_runtime_api::MessagesRuntimeApi<Block> for Runtime {
 		fn get_messages_by_schema_and_block(schema_id: SchemaId, schema_payload_location: PayloadLocation, block_number: BlockNumber,) ->
 			Vec<MessageResponse> {
@@ -2061,7 +2169,7 @@ sp_api::impl_runtime_apis! {
 					intent_id,
 					schema_payload_location,
 					block_number,
-				).into_iter().map(|r| r.into()).collect(),
+				).into_iter().map(|r| Into::<MessageResponseV2>::into(r).into()).collect(),
 				_ => vec![],
 			}
 		}
@@ -2070,12 +2178,28 @@ sp_api::impl_runtime_apis! {
 			Messages::get_messages_by_intent_id(intent_id, pagination)
 		}
 
+		fn get_oldest_retained_block(intent_id: IntentId) -> BlockNumber {
+			Messages::get_oldest_retained_block(intent_id)
+		}
+
+		fn get_messages_by_index(block_number: BlockNumber, message_keys: Vec<(IntentId, u16)>) -> Vec<MessageResponseV2> {
+			Messages::get_messages_by_index(block_number, message_keys)
+		}
+
+		fn get_messages_by_intent_id_v2(intent_id: IntentId, pagination: BlockPaginationRequest) -> BlockPaginationResponse<MessageResponseV3> {
+			Messages::get_messages_by_intent_id_v2(intent_id, pagination)
+		}
+
+		fn get_messages_by_index_v2(block_number: BlockNumber, message_keys: Vec<(IntentId, u16)>) -> Vec<MessageResponseV3> {
+			Messages::get_messages_by_index_v2(block_number, message_keys)
+		}
+
 		fn get_schema_by_id(schema_id: SchemaId) -> Option<SchemaResponse> {
 			Schemas::get_schema_by_id(schema_id).map(|r| r.into())
 		}
 	}
 
-	#[api_version(3)]
+	#[api_version(6)]
 	impl pallet_schemas_runtime_api::SchemasRuntimeApi<Block> for Runtime {
 		fn get_by_schema_id(schema_id: SchemaId) -> Option<SchemaResponse> {
 			Schemas::get_schema_by_id(schema_id).map(|v2| v2.into())
@@ -2095,7 +2219,7 @@ sp_api::impl_runtime_apis! {
 
 		fn get_intent_by_id(intent_id: IntentId, include_schemas: bool) -> Option<IntentResponse> {
 			match include_schemas {
-				true => Schemas::get_intent_by_id_with_schemas(intent_id),
+				true => Schemas::get_intent_by_id_with_schemas(intent_id).map(|r| r.into()),
 				false => Schemas::get_intent_by_id(intent_id),
 			}
 		}
@@ -2103,6 +2227,33 @@ sp_api::impl_runtime_apis! {
 		fn get_intent_group_by_id(group_id: IntentGroupId) -> Option<IntentGroupResponse> {
 			Schemas::get_intent_group_by_id(group_id)
 		}
+
+		fn validate_payload(schema_id: SchemaId, payload: Vec<u8>) -> Result<(), PayloadValidationError> {
+			Schemas::validate_payload(schema_id, &payload)
+		}
+
+		fn list_intents(request: EntityPaginationRequest) -> EntityPaginationResponse<IntentResponseV2> {
+			Schemas::list_intents(request)
+		}
+
+		fn list_schemas(request: EntityPaginationRequest) -> EntityPaginationResponse<SchemaInfoResponse> {
+			Schemas::list_schemas(request)
+		}
+
+		fn list_intent_groups(request: EntityPaginationRequest) -> EntityPaginationResponse<IntentGroupResponse> {
+			Schemas::list_intent_groups(request)
+		}
+
+		fn get_intent_by_id_v2(intent_id: IntentId, include_schemas: bool) -> Option<IntentResponseV2> {
+			match include_schemas {
+				true => Schemas::get_intent_by_id_with_schemas(intent_id),
+				false => Schemas::get_intent_by_id_v2(intent_id),
+			}
+		}
+
+		fn get_schema_versions_by_name_v2(schema_name: Vec<u8>) -> Option<Vec<SchemaVersionResponseV2>> {
+			Schemas::get_schema_versions_v2(schema_name)
+		}
 	}
 
 	impl system_runtime_api::AdditionalRuntimeApi<Block> for Runtime {
@@ -2111,7 +2262,7 @@ sp_api::impl_runtime_apis! {
 		}
 	}
 
-	#[api_version(4)]
+	#[api_version(6)]
 	impl pallet_msa_runtime_api::MsaRuntimeApi<Block, AccountId> for Runtime {
 		fn has_delegation(delegator: DelegatorId, provider: ProviderId, block_number: BlockNumber, intent_id: Option<IntentId>) -> bool {
 			match intent_id {
@@ -2147,9 +2298,21 @@ sp_api::impl_runtime_apis! {
 		fn get_provider_application_context(provider_id: ProviderId, application_id: Option<ApplicationIndex>, locale: Option<Vec<u8>>) -> Option<ProviderApplicationContext> {
 			Msa::get_provider_application_context(provider_id, application_id, locale)
 		}
+
+		fn get_provider_directory(request: ProviderDirectoryRequest) -> ProviderDirectoryResponse {
+			Msa::get_provider_directory(request)
+		}
+
+		fn get_approved_logo(logo_cid: Vec<u8>) -> Option<Vec<u8>> {
+			Msa::get_approved_logo(logo_cid)
+		}
+
+		fn get_provider_application_context_v2(provider_id: ProviderId, application_id: Option<ApplicationIndex>, locale: Option<Vec<u8>>) -> Option<ProviderApplicationContextV2> {
+			Msa::get_provider_application_context_v2(provider_id, application_id, locale)
+		}
 	}
 
-	#[api_version(2)]
+	#[api_version(5)]
 	impl pallet_stateful_storage_runtime_api::StatefulStorageRuntimeApi<Block> for Runtime {
 		fn get_paginated_storage(msa_id: MessageSourceId, schema_id: SchemaId) -> Result<Vec<PaginatedStorageResponse>, DispatchError> {
 			StatefulStorage::get_paginated_storage_v1(msa_id, schema_id)
@@ -2166,6 +2329,18 @@ sp_api::impl_runtime_apis! {
 		fn get_itemized_storage_v2(msa_id: MessageSourceId, intent_id: IntentId) -> Result<ItemizedStoragePageResponseV2, DispatchError> {
 			StatefulStorage::get_itemized_storage(msa_id, intent_id)
 		}
+
+		fn get_page_storage_key(msa_id: MessageSourceId, intent_id: IntentId, page_id: Option<PageId>) -> Result<PageStorageKey, DispatchError> {
+			StatefulStorage::get_page_storage_key(msa_id, intent_id, page_id)
+		}
+
+		fn get_paginated_storage_v3(msa_id: MessageSourceId, intent_id: IntentId) -> Result<Vec<PaginatedStorageResponseV3>, DispatchError> {
+			StatefulStorage::get_paginated_storage_with_compression(msa_id, intent_id)
+		}
+
+		fn get_storage_usage(msa_id: MessageSourceId) -> Vec<StorageUsageResponse> {
+			StatefulStorage::get_storage_usage(msa_id)
+		}
 	}
 
 	#[api_version(3)]